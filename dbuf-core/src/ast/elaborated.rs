@@ -4,13 +4,43 @@ use std::hash::Hash;
 use super::operators::OpCall;
 use indexmap::IndexMap;
 
+/// Separator of segments in qualified names like `acme.billing.Header`.
+pub const PACKAGE_SEPARATOR: char = '.';
+
+/// Qualifies `name` with `package`.
+#[must_use]
+pub fn qualify<S: AsRef<str>>(package: &[S], name: &str) -> String {
+    package
+        .iter()
+        .map(AsRef::as_ref)
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join(&PACKAGE_SEPARATOR.to_string())
+}
+
+/// Splits qualified name into package segments and a bare name.
+#[must_use]
+pub fn split_qualified(name: &str) -> (Vec<&str>, &str) {
+    let mut segments: Vec<_> = name.split(PACKAGE_SEPARATOR).collect();
+    let bare = segments.pop().unwrap_or_default();
+    (segments, bare)
+}
+
 /// An elaborated DependoBuf module.
+///
+/// Names of types and constructors declared in a package are qualified with it.
 #[derive(Debug, Clone)]
 pub struct Module<Str> {
-    /// Elaborated types in topologically sorted order.
+    /// Package of the module, outermost segment first.
+    pub package: Vec<Str>,
+    /// Elaborated types in topologically sorted order,
+    /// mutually recursive types go next to each other.
     pub types: IndexMap<Str, Type<Str>>,
     /// Collection of elaborated constructors for types.
     pub constructors: BTreeMap<Str, Constructor<Str>>,
+    /// Type aliases in topologically sorted order.
+    /// Types refer to their targets, since aliases are expanded during elaboration.
+    pub aliases: IndexMap<Str, Alias<Str>>,
 }
 
 impl<Str: Ord + Clone + Hash + Eq> Module<Str> {
@@ -20,9 +50,13 @@ impl<Str: Ord + Clone + Hash + Eq> Module<Str> {
         types.extend(b.types);
         let mut constructors = self.constructors.clone();
         constructors.extend(b.constructors);
+        let mut aliases = self.aliases.clone();
+        aliases.extend(b.aliases);
         Module {
+            package: self.package.clone(),
             types,
             constructors,
+            aliases,
         }
     }
 }
@@ -34,6 +68,19 @@ pub struct Type<Str> {
     pub dependencies: Context<Str>,
     /// List of elaborated constructors' names.
     pub constructor_names: ConstructorNames<Str>,
+    /// Refinements of dependencies.
+    pub refinements: Refinements<Str>,
+}
+
+/// Elaborated DependoBuf type alias.
+#[derive(Debug, Clone)]
+pub struct Alias<Str> {
+    /// List of elaborated dependencies.
+    pub dependencies: Context<Str>,
+    /// Refinements of dependencies.
+    pub refinements: Refinements<Str>,
+    /// Aliased type, which refers to the dependencies.
+    pub target: TypeExpression<Str>,
 }
 
 /// Constructor names of a type.
@@ -54,11 +101,22 @@ pub struct Constructor<Str> {
     pub fields: Context<Str>,
     /// Elaborated result type.
     pub result_type: TypeExpression<Str>,
+    /// Refinements of fields, preceded by refinements of dependencies
+    /// expressed with implicit arguments.
+    pub refinements: Refinements<Str>,
+    /// Default values of fields expressed with implicit arguments.
+    pub defaults: Defaults<Str>,
 }
 
 /// Context is a list of typed variables.
 pub type Context<Str> = Vec<(Str, TypeExpression<Str>)>;
 
+/// Refinements are `Bool` predicates paired with name of the refined variable.
+pub type Refinements<Str> = Vec<(Str, ValueExpression<Str>)>;
+
+/// Defaults are values paired with name of the field they are used for.
+pub type Defaults<Str> = Vec<(Str, ValueExpression<Str>)>;
+
 /// Elaborated DependoBuf expression returning value.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValueExpression<Str> {
@@ -76,6 +134,8 @@ pub enum ValueExpression<Str> {
     },
     /// Just a variable.
     Variable { name: Str, ty: TypeExpression<Str> },
+    /// Type passed as argument of parametric builtin type, like `T` in `List T`.
+    Type { ty: TypeExpression<Str> },
 }
 
 /// Elaborated DependoBuf expression returning type.
@@ -88,7 +148,7 @@ pub enum TypeExpression<Str> {
     },
 }
 
-/// Shared list of value subexpressions. There are no type subexpressions for now.
+/// Shared list of value subexpressions. Type arguments are represented as `ValueExpression::Type`.
 pub type ValueExprs<S> = Rec<[ValueExpression<S>]>;
 
 /// Expression uses Arc for recursion.
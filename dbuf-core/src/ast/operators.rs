@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::fmt::Write;
+
 /// Possible shapes of DependoBuf operator calls.
 ///
 /// NOTE: this includes literals as they can be viewed as "nullary operators".
@@ -18,6 +21,145 @@ pub enum Literal {
     Int(i64),
     UInt(u64),
     Str(String),
+    Bytes(Vec<u8>),
+    Double(Double),
+    Sized(SizedInt),
+}
+
+impl std::fmt::Display for Literal {
+    /// Shows literal in source syntax, e.g. `5u` or `"text"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Int(n) => write!(f, "{n}"),
+            Self::UInt(n) => write!(f, "{n}u"),
+            Self::Str(s) => write!(f, "\"{}\"", s.escape_debug()),
+            Self::Bytes(b) => f.write_str(&bytes_literal(b)),
+            Self::Double(d) => f.write_str(&double_literal(d.0)),
+            Self::Sized(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// Integer of fixed width, written with a suffix, e.g. `5i8` or `7u32`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SizedInt {
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+}
+
+impl SizedInt {
+    /// Literal suffix, which is also the name of the type in lower case.
+    #[must_use]
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::I8(_) => "i8",
+            Self::I16(_) => "i16",
+            Self::I32(_) => "i32",
+            Self::I64(_) => "i64",
+            Self::U8(_) => "u8",
+            Self::U16(_) => "u16",
+            Self::U32(_) => "u32",
+            Self::U64(_) => "u64",
+        }
+    }
+
+    /// Value of the integer, which fits every width.
+    #[must_use]
+    pub fn value(self) -> i128 {
+        match self {
+            Self::I8(v) => v.into(),
+            Self::I16(v) => v.into(),
+            Self::I32(v) => v.into(),
+            Self::I64(v) => v.into(),
+            Self::U8(v) => v.into(),
+            Self::U16(v) => v.into(),
+            Self::U32(v) => v.into(),
+            Self::U64(v) => v.into(),
+        }
+    }
+
+    /// Integer of the same width with `value`, if it fits.
+    #[must_use]
+    pub fn with_value(self, value: i128) -> Option<Self> {
+        match self {
+            Self::I8(_) => value.try_into().ok().map(Self::I8),
+            Self::I16(_) => value.try_into().ok().map(Self::I16),
+            Self::I32(_) => value.try_into().ok().map(Self::I32),
+            Self::I64(_) => value.try_into().ok().map(Self::I64),
+            Self::U8(_) => value.try_into().ok().map(Self::U8),
+            Self::U16(_) => value.try_into().ok().map(Self::U16),
+            Self::U32(_) => value.try_into().ok().map(Self::U32),
+            Self::U64(_) => value.try_into().ok().map(Self::U64),
+        }
+    }
+}
+
+impl std::fmt::Display for SizedInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value(), self.suffix())
+    }
+}
+
+/// Finite IEEE-754 double used in literals.
+///
+/// Doubles are compared bitwise, so `0.0` and `-0.0` are different values,
+/// and ordered by IEEE-754 total order, which is consistent with that equality.
+#[derive(Clone, Copy, Debug)]
+pub struct Double(pub f64);
+
+impl PartialEq for Double {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Double {}
+
+impl PartialOrd for Double {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Double {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Source representation of double literal, e.g. `1.5` or `1.0e300`.
+#[must_use]
+pub fn double_literal(value: f64) -> String {
+    let repr = format!("{value:?}");
+    match repr.split_once('e') {
+        Some((mantissa, exponent)) if !mantissa.contains('.') => {
+            format!("{mantissa}.0e{exponent}")
+        }
+        _ => repr,
+    }
+}
+
+/// Source representation of bytes literal, e.g. `b"ab\x00"`.
+#[must_use]
+pub fn bytes_literal(bytes: &[u8]) -> String {
+    let mut result = String::from("b\"");
+    for byte in bytes {
+        match byte {
+            b'"' => result.push_str("\\\""),
+            b'\\' => result.push_str("\\\\"),
+            b' '..=b'~' => result.push(char::from(*byte)),
+            _ => write!(result, "\\x{byte:02x}").expect("writing into String is infallible"),
+        }
+    }
+    result.push('"');
+    result
 }
 
 /// Unary operators used in DependoBuf expressions.
@@ -40,8 +182,115 @@ pub enum BinaryOp {
     Minus,
     /// Multiplication
     Star,
+    /// Division, `/`, rounding towards zero.
+    Slash,
+    /// Remainder of division, `%`, which has sign of the dividend.
+    Percent,
+    /// Left shift, `<<`.
+    ShiftLeft,
+    /// Right shift, `>>`, which is arithmetic for signed integers.
+    ShiftRight,
+    /// Bitwise exclusive or, `^`.
+    Caret,
     /// Binary and.
     BinaryAnd,
     /// Binary or.
     BinaryOr,
+    /// Equality, `==`.
+    Equal,
+    /// Inequality, `!=`.
+    NotEqual,
+    /// Less than, `<`.
+    Less,
+    /// Less than or equal, `<=`.
+    LessEq,
+    /// Greater than, `>`.
+    Greater,
+    /// Greater than or equal, `>=`.
+    GreaterEq,
+    /// Whether string starts with prefix, `startsWith(s, prefix)`.
+    StartsWith,
+}
+
+impl BinaryOp {
+    /// Source representation of operator.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Star => "*",
+            Self::Slash => "/",
+            Self::Percent => "%",
+            Self::ShiftLeft => "<<",
+            Self::ShiftRight => ">>",
+            Self::Caret => "^",
+            Self::BinaryAnd => "&",
+            Self::BinaryOr => "|",
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::Less => "<",
+            Self::LessEq => "<=",
+            Self::Greater => ">",
+            Self::GreaterEq => ">=",
+            Self::StartsWith => "startsWith",
+        }
+    }
+
+    /// Whether comparison holds for operands ordered as `ordering`.
+    ///
+    /// # Panics
+    /// If operator is not a comparison.
+    #[must_use]
+    pub fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering == Ordering::Equal,
+            Self::NotEqual => ordering != Ordering::Equal,
+            Self::Less => ordering == Ordering::Less,
+            Self::LessEq => ordering != Ordering::Greater,
+            Self::Greater => ordering == Ordering::Greater,
+            Self::GreaterEq => ordering != Ordering::Less,
+            _ => panic!("{self:?} is not a comparison"),
+        }
+    }
+
+    /// Whether operator compares its operands, so its result is `Bool`.
+    #[must_use]
+    pub const fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            Self::Equal
+                | Self::NotEqual
+                | Self::Less
+                | Self::LessEq
+                | Self::Greater
+                | Self::GreaterEq
+        )
+    }
+
+    /// Whether result of operator is `Bool` whatever types of its operands are.
+    #[must_use]
+    pub const fn is_predicate(self) -> bool {
+        self.is_comparison() || matches!(self, Self::StartsWith)
+    }
+
+    /// Whether operator is builtin function, which is written as `symbol(lhs, rhs)`.
+    #[must_use]
+    pub const fn is_function(self) -> bool {
+        matches!(self, Self::StartsWith)
+    }
+
+    /// Whether operator is integer operator, which can't be expressed by polynomials.
+    ///
+    /// Calls of such operators are opaque: they are equal only if their operands are equal.
+    ///
+    /// Division by zero is an error. Division overflow wraps, so `MIN / -1` is `MIN`
+    /// and `MIN % -1` is `0`. Shifts by amounts out of `0..width` shift all bits out.
+    #[must_use]
+    pub const fn is_opaque(self) -> bool {
+        matches!(
+            self,
+            Self::Slash | Self::Percent | Self::ShiftLeft | Self::ShiftRight | Self::Caret
+        )
+    }
 }
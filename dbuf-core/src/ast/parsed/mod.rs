@@ -1,18 +1,40 @@
 pub mod definition;
 
+use std::ops::Deref;
+
 use super::operators::{Literal, OpCall};
 use definition::Definitions;
 
 /// A single DependoBuf module is a list of type definitions.
 pub type Module<Loc, Str> = Definitions<Loc, Str, TypeDeclaration<Loc, Str>>;
 
+/// Package the types of a DependoBuf file are declared in.
+#[derive(Clone, Debug)]
+pub struct Package<Loc> {
+    /// Location of the package declaration.
+    pub loc: Loc,
+    /// Segments of the package name, outermost first.
+    pub path: Vec<String>,
+}
+
+/// Import of another DependoBuf file.
+#[derive(Clone, Debug)]
+pub struct Import<Loc> {
+    /// Location of the import declaration.
+    pub loc: Loc,
+    /// Path of the imported file as written in the declaration.
+    pub path: String,
+}
+
 /// Declaration of a DependoBuf type.
 #[derive(Debug)]
 pub struct TypeDeclaration<Loc, Str> {
     /// List of dependencies & their types.
-    pub dependencies: Definitions<Loc, Str, TypeExpression<Loc, Str>>,
+    pub dependencies: Definitions<Loc, Str, Typed<Loc, Str>>,
     /// Definition.
     pub body: TypeDefinition<Loc, Str>,
+    /// Names of lints silenced by `dbuf:allow` pragma in the comment before the declaration.
+    pub allowed_lints: Vec<String>,
 }
 
 /// Definition of a DependoBuf type.
@@ -22,6 +44,8 @@ pub enum TypeDefinition<Loc, Str> {
     Message(ConstructorBody<Loc, Str>),
     /// Enum can have several branches.
     Enum(Vec<EnumBranch<Loc, Str>>),
+    /// Alias names a type expression over its dependencies.
+    Alias(TypeExpression<Loc, Str>),
 }
 
 /// Single branch of a DependoBuf enum type.
@@ -34,7 +58,25 @@ pub struct EnumBranch<Loc, Str> {
 }
 
 /// Constructor body is a list of typed variables.
-pub type ConstructorBody<Loc, Str> = Definitions<Loc, Str, TypeExpression<Loc, Str>>;
+pub type ConstructorBody<Loc, Str> = Definitions<Loc, Str, Typed<Loc, Str>>;
+
+/// Type of a field or dependency.
+#[derive(Clone, Debug)]
+pub struct Typed<Loc, Str> {
+    /// Declared type.
+    pub ty: TypeExpression<Loc, Str>,
+    /// `Bool` expression which value of the variable must satisfy, written after `where`.
+    pub refinement: Option<Expression<Loc, Str>>,
+    /// Value of a field used when it is not provided, written after `=`.
+    pub default: Option<Expression<Loc, Str>>,
+}
+
+impl<Loc, Str> Deref for Typed<Loc, Str> {
+    type Target = TypeExpression<Loc, Str>;
+    fn deref(&self) -> &Self::Target {
+        &self.ty
+    }
+}
 
 /// Type expression is just an expression returning a type.
 pub type TypeExpression<Loc, Str> = Expression<Loc, Str>;
@@ -27,6 +27,33 @@ pub fn convert(file: &Tree) -> ParsedModule {
     }
 }
 
+/// Collects import declarations of `CST` file.
+pub fn convert_imports(file: &Tree) -> Vec<Import<LocationAST>> {
+    if file.kind != TreeKind::File {
+        return vec![];
+    }
+
+    file.children
+        .iter()
+        .filter_map(|child| match child {
+            Child::Tree(t) if t.kind == TreeKind::Import => Some(convert_import(t)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects package declaration of `CST` file.
+pub fn convert_package(file: &Tree) -> Option<Package<LocationAST>> {
+    if file.kind != TreeKind::File {
+        return None;
+    }
+
+    file.children.iter().find_map(|child| match child {
+        Child::Tree(t) if t.kind == TreeKind::Package => Some(convert_package_declaration(t)),
+        _ => None,
+    })
+}
+
 #[derive(Clone, Copy)]
 enum NameKind {
     UC,
@@ -41,6 +68,10 @@ fn to_name(child: &Child, kind: NameKind) -> Option<NameAST> {
                 content: name.to_owned().into(),
                 start: loc.start,
             }),
+            Child::Tree(t) if t.kind == TreeKind::QualifiedType => Some(NameAST {
+                content: qualified_name(t).into(),
+                start: t.location.start,
+            }),
             _ => None,
         },
         LC => match child {
@@ -75,6 +106,7 @@ fn convert_file(file: &Tree) -> ParsedModule {
             continue;
         };
         match t.kind {
+            TreeKind::Alias => ans.push(convert_alias(t)),
             TreeKind::Message => ans.push(convert_message(t)),
             TreeKind::Enum => ans.push(convert_enum(t)),
             _ => (),
@@ -84,6 +116,59 @@ fn convert_file(file: &Tree) -> ParsedModule {
     ans
 }
 
+/// Joins segments of qualified type name with dots.
+fn qualified_name(qualified: &Tree) -> String {
+    assert!(qualified.kind == TreeKind::QualifiedType);
+
+    qualified
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            Child::Token(Token::LCIdentifier(name) | Token::UCIdentifier(name), _) => {
+                Some(name.as_str())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn convert_package_declaration(package: &Tree) -> Package<LocationAST> {
+    assert!(package.kind == TreeKind::Package);
+
+    let path = package
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            Child::Token(Token::LCIdentifier(name), _) => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+
+    Package {
+        loc: package.into(),
+        path,
+    }
+}
+
+fn convert_import(import: &Tree) -> Import<LocationAST> {
+    assert!(import.kind == TreeKind::Import);
+
+    let path = import
+        .children
+        .iter()
+        .find_map(|child| match child {
+            Child::Token(Token::StringLiteral(path), _) => Some(path.clone()),
+            _ => None,
+        })
+        .expect("StringLiteral child in Import tree");
+
+    Import {
+        loc: import.into(),
+        path,
+    }
+}
+
 fn convert_message(
     message: &Tree,
 ) -> Definition<LocationAST, NameAST, TypeDeclaration<LocationAST, NameAST>> {
@@ -113,13 +198,96 @@ fn convert_message(
         data: TypeDeclaration {
             dependencies,
             body: TypeDefinition::Message(body.expect("Body identifier child in Message tree")),
+            allowed_lints: convert_allowed_lints(message),
+        },
+    }
+}
+
+fn convert_alias(
+    alias: &Tree,
+) -> Definition<LocationAST, NameAST, TypeDeclaration<LocationAST, NameAST>> {
+    assert!(alias.kind == TreeKind::Alias);
+
+    let mut dependencies = vec![];
+    let mut name = None;
+    let mut children = alias.children.iter();
+    for child in children.by_ref() {
+        if let Child::Token(Token::Assign, _) = child {
+            break;
+        }
+        if let Some(n) = to_name(child, UC) {
+            name = Some(n);
+            continue;
+        }
+        if let Child::Tree(t) = child
+            && t.kind == TreeKind::Definition
+        {
+            dependencies.push(convert_definition(t));
+        }
+    }
+
+    let (type_name, args) = convert_type_application(children);
+
+    let te_start = type_name.start;
+    let mut te_end = type_name.end();
+    if let Some(l) = args.last() {
+        te_end = l.loc.end();
+    }
+
+    Definition {
+        loc: alias.into(),
+        name: name.expect("UCIdentifier child in Alias tree"),
+        data: TypeDeclaration {
+            dependencies,
+            body: TypeDefinition::Alias(TypeExpression {
+                loc: LocationAST {
+                    start: te_start,
+                    length: (te_end - te_start).expect("correct range"),
+                },
+                node: ExpressionNode::FunCall {
+                    fun: type_name,
+                    args: args.into_boxed_slice().into(),
+                },
+            }),
+            allowed_lints: convert_allowed_lints(alias),
         },
     }
 }
 
+/// Collects lint names of `dbuf:allow(...)` pragmas in comments before the declaration keyword.
+fn convert_allowed_lints(declaration: &Tree) -> Vec<String> {
+    const PRAGMA: &str = "dbuf:allow(";
+
+    let comments = declaration.children.iter().map_while(|child| match child {
+        Child::Token(Token::LineComment(comment) | Token::BlockComment(comment), _) => {
+            Some(Some(comment))
+        }
+        Child::Token(Token::Space | Token::NewLine, _) => Some(None),
+        _ => None,
+    });
+
+    let mut lints = vec![];
+    for comment in comments.flatten() {
+        for (start, _) in comment.match_indices(PRAGMA) {
+            let args = &comment[start + PRAGMA.len()..];
+            let Some(end) = args.find(')') else {
+                continue;
+            };
+            lints.extend(
+                args[..end]
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|lint| !lint.is_empty())
+                    .map(str::to_owned),
+            );
+        }
+    }
+    lints
+}
+
 fn convert_definition(
     definition: &Tree,
-) -> Definition<LocationAST, NameAST, TypeExpression<LocationAST, NameAST>> {
+) -> Definition<LocationAST, NameAST, Typed<LocationAST, NameAST>> {
     assert!(definition.kind == TreeKind::Definition);
 
     let child_iter = definition.children.iter();
@@ -130,11 +298,78 @@ fn convert_definition(
         .map(|c| to_name(c, LC).expect("iterated to name"))
         .expect("LCIdentifier child in Definition tree");
 
-    let mut child_iter = child_iter.skip_while(|c| to_name(c, UC).is_none());
+    let (type_name, args) = convert_type_application(child_iter);
+
+    let te_start = type_name.start;
+    let mut te_end = type_name.end();
+    if let Some(l) = args.last() {
+        te_end = l.loc.end();
+    }
+
+    let refinement = definition.children.iter().find_map(|c| match c {
+        Child::Tree(t) if t.kind == TreeKind::Refinement => Some(convert_refinement(t)),
+        _ => None,
+    });
+    let default = definition.children.iter().find_map(|c| match c {
+        Child::Tree(t) if t.kind == TreeKind::Default => Some(convert_default(t)),
+        _ => None,
+    });
+
+    Definition {
+        loc: definition.into(),
+        name,
+        data: Typed {
+            ty: TypeExpression {
+                loc: LocationAST {
+                    start: te_start,
+                    length: (te_end - te_start).expect("correct range"),
+                },
+                node: ExpressionNode::FunCall {
+                    fun: type_name,
+                    args: args.into_boxed_slice().into(),
+                },
+            },
+            refinement,
+            default,
+        },
+    }
+}
+
+fn convert_refinement(refinement: &Tree) -> Expression<LocationAST, NameAST> {
+    assert!(refinement.kind == TreeKind::Refinement);
+
+    refinement
+        .children
+        .iter()
+        .find_map(|c| match c {
+            Child::Tree(t) if is_expression(t) => Some(convert_expression(t)),
+            _ => None,
+        })
+        .expect("expression in Refinement tree")
+}
+
+fn convert_default(default: &Tree) -> Expression<LocationAST, NameAST> {
+    assert!(default.kind == TreeKind::Default);
+
+    default
+        .children
+        .iter()
+        .find_map(|c| match c {
+            Child::Tree(t) if is_expression(t) => Some(convert_expression(t)),
+            _ => None,
+        })
+        .expect("expression in Default tree")
+}
+
+/// Converts type name with its arguments, that are the rest of `children`.
+fn convert_type_application<'a>(
+    children: impl Iterator<Item = &'a Child>,
+) -> (NameAST, Vec<Expression<LocationAST, NameAST>>) {
+    let mut child_iter = children.skip_while(|c| to_name(c, UC).is_none());
     let type_name = child_iter
         .next()
         .map(|c| to_name(c, UC).expect("iterated to name"))
-        .expect("UCIdentifier child in Definition tree");
+        .expect("UCIdentifier child in type application");
 
     let mut args = vec![];
     for child in child_iter {
@@ -148,26 +383,7 @@ fn convert_definition(
         args.push(convert_expression(t));
     }
 
-    let te_start = type_name.start;
-    let mut te_end = type_name.end();
-    if let Some(l) = args.last() {
-        te_end = l.loc.end();
-    }
-
-    Definition {
-        loc: definition.into(),
-        name,
-        data: TypeExpression {
-            loc: LocationAST {
-                start: te_start,
-                length: (te_end - te_start).expect("correct range"),
-            },
-            node: ExpressionNode::FunCall {
-                fun: type_name,
-                args: args.into_boxed_slice().into(),
-            },
-        },
-    }
+    (type_name, args)
 }
 
 fn is_expression(expression: &Tree) -> bool {
@@ -180,7 +396,9 @@ fn is_expression(expression: &Tree) -> bool {
             | TreeKind::ExprIdentifier
             | TreeKind::ExprBinary
             | TreeKind::ExprUnary
+            | TreeKind::ExprCall
             | TreeKind::ExprHole
+            | TreeKind::TypeArgument
     )
 }
 
@@ -262,6 +480,17 @@ fn convert_expression(expression: &Tree) -> Expression<LocationAST, NameAST> {
                         Child::Token(Token::Star, _) => BinaryOp::Star,
                         Child::Token(Token::Amp, _) => BinaryOp::BinaryAnd,
                         Child::Token(Token::Pipe, _) => BinaryOp::BinaryOr,
+                        Child::Token(Token::EqEq, _) => BinaryOp::Equal,
+                        Child::Token(Token::BangEq, _) => BinaryOp::NotEqual,
+                        Child::Token(Token::Less, _) => BinaryOp::Less,
+                        Child::Token(Token::LessEq, _) => BinaryOp::LessEq,
+                        Child::Token(Token::Greater, _) => BinaryOp::Greater,
+                        Child::Token(Token::GreaterEq, _) => BinaryOp::GreaterEq,
+                        Child::Token(Token::Slash, _) => BinaryOp::Slash,
+                        Child::Token(Token::Percent, _) => BinaryOp::Percent,
+                        Child::Token(Token::LessLess, _) => BinaryOp::ShiftLeft,
+                        Child::Token(Token::GreaterGreater, _) => BinaryOp::ShiftRight,
+                        Child::Token(Token::Caret, _) => BinaryOp::Caret,
                         _ => continue,
                     };
                     op = cur_op.into();
@@ -307,7 +536,39 @@ fn convert_expression(expression: &Tree) -> Expression<LocationAST, NameAST> {
                 rhs.expect("rhs expression in ExprUnary tree").into(),
             ))
         }
+        TreeKind::ExprCall => {
+            let mut args = expression.children.iter().filter_map(|c| match c {
+                Child::Tree(t) if is_expression(t) => Some(convert_expression(t)),
+                _ => None,
+            });
+            let op = expression
+                .children
+                .iter()
+                .find_map(|c| match c {
+                    Child::Token(Token::LCIdentifier(name), _)
+                        if name == BinaryOp::StartsWith.symbol() =>
+                    {
+                        Some(BinaryOp::StartsWith)
+                    }
+                    _ => None,
+                })
+                .expect("function in ExprCall tree");
+            ExpressionNode::OpCall(OpCall::Binary(
+                op,
+                args.next().expect("first argument in ExprCall tree").into(),
+                args.next()
+                    .expect("second argument in ExprCall tree")
+                    .into(),
+            ))
+        }
         TreeKind::ExprHole => ExpressionNode::TypedHole,
+        TreeKind::TypeArgument => {
+            let (fun, args) = convert_type_application(expression.children.iter());
+            ExpressionNode::FunCall {
+                fun,
+                args: args.into_boxed_slice().into(),
+            }
+        }
         _ => panic!("bad expression tree kind"),
     };
 
@@ -379,7 +640,10 @@ fn convert_literal(e: &Tree) -> Option<Literal> {
             Token::BoolLiteral(b) => Literal::Bool(*b),
             Token::IntLiteral(i) => Literal::Int(*i),
             Token::UintLiteral(ui) => Literal::UInt(*ui),
+            Token::DoubleLiteral(d) => Literal::Double(Double(*d)),
+            Token::SizedIntLiteral(s) => Literal::Sized(*s),
             Token::StringLiteral(s) => Literal::Str(s.clone()),
+            Token::BytesLiteral(b) => Literal::Bytes(b.clone()),
             _ => continue,
         }
         .into();
@@ -490,6 +754,7 @@ fn convert_enum(
         data: TypeDeclaration {
             dependencies,
             body: TypeDefinition::Enum(body.expect("EnumBody in Enum tree")),
+            allowed_lints: convert_allowed_lints(e),
         },
     }
 }
@@ -15,6 +15,10 @@ pub enum Label {
     Field,
     /// Definition
     Definition,
+    /// Refinement
+    Refinement,
+    /// Default Value
+    DefaultValue,
     /// Expression
     Expression,
     /// Parened Expression
@@ -29,6 +33,14 @@ pub enum Label {
     IntLiteral,
     /// Typed Hole
     TypedHole,
+    /// Function Call
+    FunctionCall,
+    /// Type Argument
+    TypeArgument,
+    /// Import Path
+    ImportPath,
+    /// Package Name
+    PackageName,
     /// Space
     Space,
     /// New Line
@@ -23,6 +23,7 @@ use super::location::Locatable;
 use crate::location::Offset;
 use crate::location::{LocatedName, Location};
 
+use crate::ast::operators::SizedInt;
 use crate::error::LexingError;
 use crate::error::lexing::{ErrorKind, LexingStage};
 
@@ -123,6 +124,14 @@ pub enum Token {
     Message,
     #[token("enum", at_callback)]
     Enum,
+    #[token("import", at_callback)]
+    Import,
+    #[token("package", at_callback)]
+    Package,
+    #[token("where", at_callback)]
+    Where,
+    #[token("type", at_callback)]
+    Type,
 
     #[token("true", |lex| at_callback_with(lex, true))]
     #[token("false", |lex| at_callback_with(lex, false))]
@@ -133,8 +142,16 @@ pub enum Token {
     // Number without u or ., followed by u, followed by any
     #[regex(r"[0-9]([a-tv-zA-Z0-9])*u[a-zA-Z0-9.]*", parse_uint)]
     UintLiteral(u64),
+    // Number followed by width suffix
+    #[regex(r"[0-9]+[iu](8|16|32|64)", parse_sized_int, priority = 5)]
+    SizedIntLiteral(SizedInt),
+    // Number with fraction part and optional exponent
+    #[regex(r"[0-9]+\.[0-9]+([eE][+-]?[0-9]+)?", parse_double)]
+    DoubleLiteral(f64),
     #[regex(r#""([^"\\]|\\.)*""#, parse_string_literal)]
     StringLiteral(String),
+    #[regex(r#"b"([^"\\]|\\.)*""#, parse_bytes_literal)]
+    BytesLiteral(Vec<u8>),
 
     #[regex(r"[A-Z]\w*", parse_uc_identifier)]
     UCIdentifier(String),
@@ -147,6 +164,8 @@ pub enum Token {
     Colon,
     #[token(";", at_callback)]
     Semicolon,
+    #[token("=", at_callback)]
+    Assign,
     #[token(",", at_callback)]
     Comma,
     #[token(".", at_callback)]
@@ -172,6 +191,28 @@ pub enum Token {
     Pipe,
     #[token("!", at_callback)]
     Bang,
+    #[token("==", at_callback)]
+    EqEq,
+    #[token("!=", at_callback)]
+    BangEq,
+    #[token("<", at_callback)]
+    Less,
+    #[token("<=", at_callback)]
+    LessEq,
+    #[token(">", at_callback)]
+    Greater,
+    #[token(">=", at_callback)]
+    GreaterEq,
+    #[token("/", at_callback)]
+    Slash,
+    #[token("%", at_callback)]
+    Percent,
+    #[token("<<", at_callback)]
+    LessLess,
+    #[token(">>", at_callback)]
+    GreaterGreater,
+    #[token("^", at_callback)]
+    Caret,
 
     #[token("_", at_callback)]
     Underscore,
@@ -277,6 +318,50 @@ fn parse_uint(lex: &mut Lexer<'_, Token>) -> Result<u64, LexingError> {
     })
 }
 
+/// Parser for `SizedIntLiteral` token. Splits off its width suffix, parses number of
+/// that width and return `Result`.
+///
+/// Errors
+///   * `LexingErrorKind::IntegerOverflow` when integer doesn't fit into the width.
+///   * `LexingErrorKind::InvalidInteger` when text is not integer.
+fn parse_sized_int(lex: &mut Lexer<'_, Token>) -> Result<SizedInt, LexingError> {
+    at_callback(lex);
+    let s = lex.slice();
+    let (digits, suffix) = s.split_at(s.find(['i', 'u']).expect("regex requires suffix"));
+
+    let value = match suffix {
+        "i8" => digits.parse().map(SizedInt::I8),
+        "i16" => digits.parse().map(SizedInt::I16),
+        "i32" => digits.parse().map(SizedInt::I32),
+        "i64" => digits.parse().map(SizedInt::I64),
+        "u8" => digits.parse().map(SizedInt::U8),
+        "u16" => digits.parse().map(SizedInt::U16),
+        "u32" => digits.parse().map(SizedInt::U32),
+        "u64" => digits.parse().map(SizedInt::U64),
+        _ => unreachable!("regex allows only known suffixes"),
+    };
+    value.map_err(|err: ParseIntError| {
+        let kind = match err.kind() {
+            PosOverflow | NegOverflow => ErrorKind::IntegerOverflow,
+            _ => ErrorKind::InvalidInteger,
+        };
+        LexingError::from_lexer(lex, kind)
+    })
+}
+
+/// Parser for `DoubleLiteral` token. Parses f64 and return `Result`.
+///
+/// Errors
+///   * `LexingErrorKind::DoubleOverflow` when double is not finite.
+fn parse_double(lex: &mut Lexer<'_, Token>) -> Result<f64, LexingError> {
+    at_callback(lex);
+    lex.slice()
+        .parse()
+        .ok()
+        .filter(|value: &f64| value.is_finite())
+        .ok_or(LexingError::from_lexer(lex, ErrorKind::DoubleOverflow))
+}
+
 /// Parser for `StringLiteral` token. Parses string and return `Result`.
 ///
 /// Errors
@@ -292,6 +377,50 @@ fn parse_string_literal(lex: &mut Lexer<'_, Token>) -> Result<String, LexingErro
     ))
 }
 
+/// Parser for `BytesLiteral` token. Parses bytes and return `Result`.
+///
+/// Besides ASCII characters, literal may contain escapes `\xHH`, `\n`, `\r`, `\t`,
+/// `\0`, `\\` and `\"`.
+///
+/// Errors
+///   * `LexingErrorKind::InvalidBytesLiteral` when literal contains bad escape symbols
+///     or non-ASCII characters.
+fn parse_bytes_literal(lex: &mut Lexer<'_, Token>) -> Result<Vec<u8>, LexingError> {
+    at_callback(lex);
+    let s = lex.slice();
+    let trimmed = &s[2..s.len() - 1];
+    unescape_bytes(trimmed).ok_or(LexingError::from_lexer(lex, ErrorKind::InvalidBytesLiteral))
+}
+
+fn unescape_bytes(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            bytes.push(u8::try_from(c).ok().filter(u8::is_ascii)?);
+            continue;
+        }
+        let byte = match chars.next()? {
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                if hex.len() != 2 {
+                    return None;
+                }
+                u8::from_str_radix(&hex, 16).ok()?
+            }
+            'n' => b'\n',
+            'r' => b'\r',
+            't' => b'\t',
+            '0' => 0,
+            '\\' => b'\\',
+            '"' => b'"',
+            _ => return None,
+        };
+        bytes.push(byte);
+    }
+    Some(bytes)
+}
+
 /// Parser for `UCIdentifier` token. Parses string and return `Result`.
 ///
 /// Errors
@@ -381,6 +510,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sized_int_correct() {
+        test_same("127i8", &[Some(Token::SizedIntLiteral(SizedInt::I8(127)))]);
+        test_same("255u8", &[Some(Token::SizedIntLiteral(SizedInt::U8(255)))]);
+        test_same("7i16", &[Some(Token::SizedIntLiteral(SizedInt::I16(7)))]);
+        test_same("7u32", &[Some(Token::SizedIntLiteral(SizedInt::U32(7)))]);
+        test_same(
+            "18446744073709551615u64",
+            &[Some(Token::SizedIntLiteral(SizedInt::U64(u64::MAX)))],
+        );
+        test_same(
+            "1i64+2i64",
+            &[
+                Some(Token::SizedIntLiteral(SizedInt::I64(1))),
+                Some(Token::Plus),
+                Some(Token::SizedIntLiteral(SizedInt::I64(2))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_sized_int_incorrect() {
+        test_same("128i8", &[None]);
+        test_same("256u8", &[None]);
+        test_same("65536u16", &[None]);
+        test_same("5i7", &[None]);
+        test_same("5u8x", &[None]);
+    }
+
+    #[test]
+    fn test_double_correct() {
+        test_same("1.5", &[Some(Token::DoubleLiteral(1.5))]);
+        test_same("0.25e2", &[Some(Token::DoubleLiteral(25.0))]);
+        test_same("1.0E-3", &[Some(Token::DoubleLiteral(0.001))]);
+        test_same(
+            "1.5*2.0",
+            &[
+                Some(Token::DoubleLiteral(1.5)),
+                Some(Token::Star),
+                Some(Token::DoubleLiteral(2.0)),
+            ],
+        );
+        test_same(
+            "a.b",
+            &[
+                Some(Token::LCIdentifier("a".into())),
+                Some(Token::Dot),
+                Some(Token::LCIdentifier("b".into())),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_double_incorrect() {
+        test_same("1.0e999", &[None]);
+    }
+
     #[test]
     fn test_string_correct() {
         test_same("\"aba\"", &[Some(Token::StringLiteral("aba".into()))]);
@@ -394,6 +580,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bytes_correct() {
+        test_same("b\"aba\"", &[Some(Token::BytesLiteral(b"aba".to_vec()))]);
+        test_same(
+            "b\"\\x00\\xff\\n\\\"\"",
+            &[Some(Token::BytesLiteral(vec![0x00, 0xff, b'\n', b'"']))],
+        );
+        test_same("b\"\"", &[Some(Token::BytesLiteral(vec![]))]);
+        test_same(
+            "b \"a\"",
+            &[
+                Some(Token::LCIdentifier("b".into())),
+                Some(Token::Space),
+                Some(Token::StringLiteral("a".into())),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_bytes_incorrect() {
+        test_same("b\"\\x0\"", &[None]);
+        test_same("b\"\\q\"", &[None]);
+        test_same("b\"ы\"", &[None]);
+    }
+
+    #[test]
+    fn test_comparison_correct() {
+        test_same(
+            "a<=b",
+            &[
+                Some(Token::LCIdentifier("a".into())),
+                Some(Token::LessEq),
+                Some(Token::LCIdentifier("b".into())),
+            ],
+        );
+        test_same(
+            "==!=<>=>",
+            &[
+                Some(Token::EqEq),
+                Some(Token::BangEq),
+                Some(Token::Less),
+                Some(Token::GreaterEq),
+                Some(Token::Greater),
+            ],
+        );
+        test_same("!=!", &[Some(Token::BangEq), Some(Token::Bang)]);
+    }
+
+    #[test]
+    fn test_division_correct() {
+        test_same(
+            "n/8%m^k",
+            &[
+                Some(Token::LCIdentifier("n".into())),
+                Some(Token::Slash),
+                Some(Token::IntLiteral(8)),
+                Some(Token::Percent),
+                Some(Token::LCIdentifier("m".into())),
+                Some(Token::Caret),
+                Some(Token::LCIdentifier("k".into())),
+            ],
+        );
+        test_same(
+            "<<<=>>>=",
+            &[
+                Some(Token::LessLess),
+                Some(Token::LessEq),
+                Some(Token::GreaterGreater),
+                Some(Token::GreaterEq),
+            ],
+        );
+        test_same(
+            "/ //",
+            &[
+                Some(Token::Slash),
+                Some(Token::Space),
+                Some(Token::LineComment("//".into())),
+            ],
+        );
+    }
+
     #[test]
     fn test_comment_correct() {
         test_same(
@@ -447,4 +714,79 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_import_correct() {
+        test_same(
+            "import \"types.dbuf\";",
+            &[
+                Some(Token::Import),
+                Some(Token::Space),
+                Some(Token::StringLiteral("types.dbuf".into())),
+                Some(Token::Semicolon),
+            ],
+        );
+        test_same("imports", &[Some(Token::LCIdentifier("imports".into()))]);
+    }
+
+    #[test]
+    fn test_package_correct() {
+        test_same(
+            "package acme.billing;",
+            &[
+                Some(Token::Package),
+                Some(Token::Space),
+                Some(Token::LCIdentifier("acme".into())),
+                Some(Token::Dot),
+                Some(Token::LCIdentifier("billing".into())),
+                Some(Token::Semicolon),
+            ],
+        );
+        test_same("packages", &[Some(Token::LCIdentifier("packages".into()))]);
+    }
+
+    #[test]
+    fn test_where_correct() {
+        test_same(
+            "x UInt where x<10",
+            &[
+                Some(Token::LCIdentifier("x".into())),
+                Some(Token::Space),
+                Some(Token::UCIdentifier("UInt".into())),
+                Some(Token::Space),
+                Some(Token::Where),
+                Some(Token::Space),
+                Some(Token::LCIdentifier("x".into())),
+                Some(Token::Less),
+                Some(Token::IntLiteral(10)),
+            ],
+        );
+        test_same("wherever", &[Some(Token::LCIdentifier("wherever".into()))]);
+    }
+
+    #[test]
+    fn test_type_alias_correct() {
+        test_same(
+            "type Id = UInt;",
+            &[
+                Some(Token::Type),
+                Some(Token::Space),
+                Some(Token::UCIdentifier("Id".into())),
+                Some(Token::Space),
+                Some(Token::Assign),
+                Some(Token::Space),
+                Some(Token::UCIdentifier("UInt".into())),
+                Some(Token::Semicolon),
+            ],
+        );
+        test_same("typed", &[Some(Token::LCIdentifier("typed".into()))]);
+        test_same(
+            "a==b",
+            &[
+                Some(Token::LCIdentifier("a".into())),
+                Some(Token::EqEq),
+                Some(Token::LCIdentifier("b".into())),
+            ],
+        );
+    }
 }
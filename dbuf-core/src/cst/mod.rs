@@ -16,11 +16,11 @@ use chumsky::Parser;
 use chumsky::input::{Input, Stream};
 use logos::Logos;
 
-use cst_to_ast::convert;
+use cst_to_ast::{convert, convert_imports, convert_package};
 use located_token::LocatedLexer;
 
 use crate::arena::InternedString;
-use crate::ast::parsed::Module;
+use crate::ast::parsed::{Import, Module, Package};
 use crate::error::Error;
 use crate::error::parsing::ParsingStage;
 use crate::location::LocatedName;
@@ -39,12 +39,28 @@ pub enum TreeKind {
     /// Contains whole file.
     File,
 
+    /// Contains package declaration.
+    Package,
+    /// Contains import declaration.
+    Import,
+
+    /// Contains type alias definition.
+    Alias,
+
     /// Contains message definition.
     Message,
     /// Contains body of message / constructor.
     Body,
     /// Contains definion of field / dependency.
     Definition,
+    /// Contains refinement of field / dependency.
+    Refinement,
+    /// Contains default value of field.
+    Default,
+    /// Contains type name qualified with package.
+    QualifiedType,
+    /// Contains type passed as argument of parametric type.
+    TypeArgument,
 
     /// Contains message definition.
     Enum,
@@ -82,6 +98,8 @@ pub enum TreeKind {
     ExprBinary,
     /// Contains unary operation as expression.
     ExprUnary,
+    /// Contains call of builtin function as expression.
+    ExprCall,
     /// Contains type hole as expression.
     ExprHole,
 }
@@ -140,3 +158,15 @@ pub fn convert_to_ast(
 ) -> Module<Location<Offset>, LocatedName<InternedString, Offset>> {
     convert(tree)
 }
+
+/// Collect import declarations of CST.
+#[must_use]
+pub fn collect_imports(tree: &Tree) -> Vec<Import<Location<Offset>>> {
+    convert_imports(tree)
+}
+
+/// Collect package declaration of CST.
+#[must_use]
+pub fn collect_package(tree: &Tree) -> Option<Package<Location<Offset>>> {
+    convert_package(tree)
+}
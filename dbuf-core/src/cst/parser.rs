@@ -7,6 +7,7 @@ use chumsky::pratt::*;
 use chumsky::prelude::*;
 
 use super::{Child, Token, Tree, TreeKind};
+use crate::ast::operators::BinaryOp;
 use crate::cst::parser_utils::ChildFlatten;
 use crate::location::Location;
 use crate::location::Offset;
@@ -53,18 +54,21 @@ type ExtraData = Full<ParsingError, ParsingState, ()>;
 ///
 /// Pattern:
 /// ```dbuf
-/// (<message> | <enum> | /* comment */ | /* whitespace */)
+/// (<package> | <import> | <alias> | <message> | <enum> | /* comment */ | /* whitespace */)
 /// ```
 ///
 /// Recovery:
 /// ```dbuf
-/// [not (/* one comment */ message | /* one comment */ enum)]
+/// [not (/* one comment */ package | /* one comment */ import | /* one comment */ type | /* one comment */ message | /* one comment */ enum)]
 /// ```
 ///
 pub fn file_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
 where
     I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
 {
+    let package = package_parser().map_child().map(|c| vec![c]);
+    let import = import_parser().map_child().map(|c| vec![c]);
+    let alias = alias_parser().map_child().map(|c| vec![c]);
     let message = message_parser().map_child().map(|c| vec![c]);
     let enum_parser = enum_parser().map_child().map(|c| vec![c]);
     let comment = WhiteSpace::new()
@@ -73,10 +77,13 @@ where
         .parser();
     let ws = WhiteSpace::new().with_no_comment().with_progress().parser();
 
-    let recovery_on = comment
-        .clone()
-        .or_not()
-        .then(choice((just(Token::Message), just(Token::Enum))));
+    let recovery_on = comment.clone().or_not().then(choice((
+        just(Token::Package),
+        just(Token::Import),
+        just(Token::Type),
+        just(Token::Message),
+        just(Token::Enum),
+    )));
     let recovery_skip = any()
         .map_token()
         .and_is(recovery_on.not())
@@ -89,7 +96,8 @@ where
         .map_child()
         .map(|c| vec![c]);
 
-    let one_block = choice((ws, message, enum_parser, comment)).recover_with(via_parser(recovery));
+    let one_block = choice((ws, package, import, alias, message, enum_parser, comment))
+        .recover_with(via_parser(recovery));
 
     one_block
         .repeated()
@@ -97,6 +105,69 @@ where
         .map_tree(TreeKind::File)
 }
 
+/// Parses package declaration.
+///
+/// Pattern:
+/// ```dbuf
+/// /*one comment*/
+/// package /*comments*/ lcIdentifier [.lcIdentifier] /*comments*/ ;
+/// ```
+fn package_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let bind_comment = WhiteSpace::new().with_bind_comment().parser().or_not();
+    let ws = WhiteSpace::new().parser();
+
+    let package_kw = just(Token::Package).map_token();
+    let var_ident = var_identifier_parser();
+    let dot = just(Token::Dot).map_token();
+    let name = var_ident
+        .clone()
+        .then(dot.then(var_ident).repeated().collect::<Vec<_>>())
+        .labelled(PackageName);
+    let semicolon = just(Token::Semicolon).map_token();
+
+    bind_comment
+        .then(package_kw)
+        .then(ws.clone())
+        .then(name)
+        .then(ws)
+        .then(semicolon)
+        .map_tree(TreeKind::Package)
+}
+
+/// Parses one import declaration.
+///
+/// Pattern:
+/// ```dbuf
+/// /*one comment*/
+/// import /*comments*/ StringLiteral /*comments*/ ;
+/// ```
+fn import_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let bind_comment = WhiteSpace::new().with_bind_comment().parser().or_not();
+    let ws = WhiteSpace::new().parser();
+
+    let import_kw = just(Token::Import).map_token();
+    let path = select! {
+        Token::StringLiteral(path) => Token::StringLiteral(path)
+    }
+    .map_token()
+    .labelled(ImportPath);
+    let semicolon = just(Token::Semicolon).map_token();
+
+    bind_comment
+        .then(import_kw)
+        .then(ws.clone())
+        .then(path)
+        .then(ws)
+        .then(semicolon)
+        .map_tree(TreeKind::Import)
+}
+
 /// Parses one message.
 ///
 /// Pattern:
@@ -128,6 +199,50 @@ where
         .map_tree(TreeKind::Message)
 }
 
+/// Parses type alias.
+///
+/// Pattern:
+/// ```dbuf
+/// /*one comment*/
+/// type /*comments*/ UCIdentifier /*comments*/ [<dependency> /*comments*/]
+///   = /*comments*/ <type reference> [/*comments*/ <type argument>] /*comments*/ ;
+/// ```
+fn alias_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let bind_comment = WhiteSpace::new().with_bind_comment().parser().or_not();
+    let ws = WhiteSpace::new().parser();
+
+    let type_kw = just(Token::Type).map_token();
+    let type_ident = type_identifier_parser();
+    let dependency = dependency_parser().then(ws.clone());
+    let dependency_r = dependency.repeated().collect::<Vec<_>>();
+    let assign = just(Token::Assign).map_token();
+    let target = type_reference_parser();
+    let arguments = WhiteSpace::new()
+        .with_no_new_line()
+        .parser()
+        .then(type_arguments_parser(type_argument_parser()))
+        .repeated()
+        .collect::<Vec<_>>();
+    let semicolon = just(Token::Semicolon).map_token();
+
+    bind_comment
+        .then(type_kw)
+        .then(ws.clone())
+        .then(type_ident)
+        .then(ws.clone())
+        .then(dependency_r)
+        .then(assign)
+        .then(ws.clone())
+        .then(target)
+        .then(arguments)
+        .then(ws)
+        .then(semicolon)
+        .map_tree(TreeKind::Alias)
+}
+
 /// Parses one dependency.
 ///
 /// Pattern:
@@ -202,7 +317,7 @@ where
 /// Pattern:
 /// ```dbuf
 /// /* one comment */
-/// <definition> /* comments */;
+/// <definition> /* comments */ [<default value> /* comments */];
 /// ```
 fn field_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
 where
@@ -212,6 +327,7 @@ where
     let ws = WhiteSpace::new().with_no_new_line().parser();
 
     let definition = definition_parser();
+    let commented_default = default_value_parser().then(ws.clone()).or_not();
 
     let semicolon = just(Token::Semicolon).map_token().map(Option::Some);
     let no_semicolon = semicolon.clone().not().rewind().map_with(|(), extra| {
@@ -230,12 +346,17 @@ where
             d
         }))
         .then(ws)
+        .then(commented_default)
         .then(maybe_semicolon)
-        .map_with(|(((comm1, mut tree), comm2), t), extra| {
+        .map_with(|((((comm1, mut tree), comm2), default), t), extra| {
             let mut nchildren = vec![];
             nchildren.extend(comm1.into_iter().flatten());
             nchildren.extend(tree.children);
             nchildren.extend(comm2);
+            if let Some((default, comm3)) = default {
+                nchildren.push(Child::Tree(default));
+                nchildren.extend(comm3);
+            }
             nchildren.extend(t);
             tree.children = nchildren;
             tree.location = extra.span();
@@ -249,8 +370,9 @@ where
 ///
 /// Pattern:
 /// ```dbuf
-/// lcIdentifier /* comments */ UCIdentifier /* comments */
-///   [/* comments */ (<parened expression>|<var chain>|<literal>|<constructed value>|<hole>)]
+/// lcIdentifier /* comments */ <type reference> /* comments */
+///   [/* comments */ (<type argument>|<parened expression>|<var chain>|<literal>|<constructed value>|<hole>)]
+///   [/* comments */ <refinement>]
 /// ```
 fn definition_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
 where
@@ -259,8 +381,76 @@ where
     let ws = WhiteSpace::new().with_no_new_line().parser();
 
     let var_ident = var_identifier_parser();
-    let type_ident = type_identifier_parser();
+    let type_ident = type_reference_parser();
+
+    let commented_arguments = ws
+        .clone()
+        .then(type_arguments_parser(type_argument_parser()))
+        .repeated()
+        .collect::<Vec<_>>();
+    let commented_refinement = ws.clone().then(refinement_parser()).or_not();
+
+    var_ident
+        .then(ws.clone())
+        .then(type_ident)
+        .then(ws)
+        .then(commented_arguments)
+        .then(commented_refinement)
+        .map_tree(TreeKind::Definition)
+        .labelled(Definition)
+}
+
+/// Parses refinement of field or dependency.
+///
+/// Pattern:
+/// ```dbuf
+/// where <expression>
+/// ```
+fn refinement_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let where_kw = just(Token::Where).map_token();
+
+    where_kw
+        .then(expression_parser())
+        .map_tree(TreeKind::Refinement)
+        .labelled(Refinement)
+}
+
+/// Parses default value of field.
+///
+/// Pattern:
+/// ```dbuf
+/// = (<constructed value>|<expression>)
+/// ```
+fn default_value_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let ws = WhiteSpace::new().with_no_new_line().parser();
+    let assign = just(Token::Assign).map_token();
+
+    assign
+        .then(ws)
+        .then(constructed_value_parser().or(expression_parser()))
+        .map_tree(TreeKind::Default)
+        .labelled(DefaultValue)
+}
 
+/// Parses one argument of type.
+///
+/// Pattern:
+/// ```dbuf
+/// (<constructed value>|<type argument>|<parened expression>|<var chain>|<literal>|<hole>)
+/// ```
+fn type_arguments_parser<'src, I>(
+    type_argument: impl Parser<'src, I, Tree, ExtraData> + Clone,
+) -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let var_ident = var_identifier_parser();
     let expr = expression_parser();
     let paren_expr = parened_expression_parser(expr);
     let chain = var_chain_parser();
@@ -270,21 +460,56 @@ where
 
     let dot = just(Token::Dot).map_token();
     let constructed_value_chain = cv
-        .clone()
         .map_child()
-        .then(dot.then(var_ident.clone()).repeated().collect::<Vec<_>>())
+        .then(dot.then(var_ident).repeated().collect::<Vec<_>>())
         .map_tree(TreeKind::ConstructedValueChain);
 
-    let arguments = choice((paren_expr, chain, literal, constructed_value_chain, hole));
-    let commented_arguments = ws.clone().then(arguments).repeated().collect::<Vec<_>>();
+    // type argument goes before var chain, so qualified type is not taken for var chain.
+    choice((
+        constructed_value_chain,
+        type_argument,
+        paren_expr,
+        chain,
+        literal,
+        hole,
+    ))
+}
 
-    var_ident
-        .then(ws.clone())
-        .then(type_ident)
-        .then(ws)
-        .then(commented_arguments)
-        .map_tree(TreeKind::Definition)
-        .labelled(Definition)
+/// Parses type passed as argument of parametric type.
+///
+/// Pattern:
+/// ```dbuf
+/// <type reference> | (/* c */ <type reference> [/* c */ <type argument>] /* c */)
+/// ```
+fn type_argument_parser<'src, I>() -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    recursive(|type_argument| {
+        let ws = WhiteSpace::new().with_no_new_line().parser();
+        let l_paren = just(Token::LParen).map_token();
+        let r_paren = just(Token::RParen).map_token();
+
+        let arguments = ws
+            .clone()
+            .then(type_arguments_parser(type_argument))
+            .repeated()
+            .collect::<Vec<_>>();
+
+        let applied = l_paren
+            .then(ws.clone())
+            .then(type_reference_parser())
+            .then(arguments)
+            .then(ws)
+            .then(r_paren)
+            .map_tree(TreeKind::TypeArgument);
+
+        choice((
+            applied,
+            type_reference_parser().map_tree(TreeKind::TypeArgument),
+        ))
+        .labelled(TypeArgument)
+    })
 }
 
 /// Parses constructed value.
@@ -363,6 +588,7 @@ where
 /// /* comment */ <literal> /* comment */
 /// /* comment */ <var chain> /* comment */
 /// /* comment */ <typed hole> /* comment */
+/// /* comment */ <call> /* comment */
 /// /* comment */ (/* comment */ <expression> /* comment */) /* comment */
 /// <lhs_expression> (+|-|*|/|'|'|&) <rhs_expression>
 /// /* comment */ (-|!) <rhs_expression>
@@ -398,11 +624,18 @@ where
     let ws = WhiteSpace::new().with_no_new_line().parser();
 
     let literal_atom = literal_parser().map_tree(TreeKind::ExprLiteral);
+    let call_atom = call_parser(e_parser.clone());
     let identifier_atom = var_chain_parser();
     let parented_atom = parened_expression_parser(e_parser);
     let hole_atom = typed_hole_parser();
 
-    let atom = choice((literal_atom, identifier_atom, parented_atom, hole_atom));
+    let atom = choice((
+        literal_atom,
+        call_atom,
+        identifier_atom,
+        parented_atom,
+        hole_atom,
+    ));
 
     let commented_atom =
         ws.clone()
@@ -449,11 +682,22 @@ where
 
     commented_atom
         .pratt((
-            prefix(5, unary_op(Token::Minus), unary_fold!()),
-            prefix(5, unary_op(Token::Bang), unary_fold!()),
-            infix(left(4), binary_op(Token::Star), binary_fold!()),
-            infix(left(3), binary_op(Token::Plus), binary_fold!()),
-            infix(left(3), binary_op(Token::Minus), binary_fold!()),
+            prefix(8, unary_op(Token::Minus), unary_fold!()),
+            prefix(8, unary_op(Token::Bang), unary_fold!()),
+            infix(left(7), binary_op(Token::Star), binary_fold!()),
+            infix(left(7), binary_op(Token::Slash), binary_fold!()),
+            infix(left(7), binary_op(Token::Percent), binary_fold!()),
+            infix(left(6), binary_op(Token::Plus), binary_fold!()),
+            infix(left(6), binary_op(Token::Minus), binary_fold!()),
+            infix(left(5), binary_op(Token::LessLess), binary_fold!()),
+            infix(left(5), binary_op(Token::GreaterGreater), binary_fold!()),
+            infix(left(4), binary_op(Token::Caret), binary_fold!()),
+            infix(left(3), binary_op(Token::EqEq), binary_fold!()),
+            infix(left(3), binary_op(Token::BangEq), binary_fold!()),
+            infix(left(3), binary_op(Token::Less), binary_fold!()),
+            infix(left(3), binary_op(Token::LessEq), binary_fold!()),
+            infix(left(3), binary_op(Token::Greater), binary_fold!()),
+            infix(left(3), binary_op(Token::GreaterEq), binary_fold!()),
             infix(left(2), binary_op(Token::Amp), binary_fold!()),
             infix(left(1), binary_op(Token::Pipe), binary_fold!()),
         ))
@@ -485,6 +729,36 @@ where
         .labelled(ParenedExpression)
 }
 
+/// Parses call of builtin function.
+///
+/// Argument:
+///     * `e_parser` is an expression parser.
+///
+/// Pattern:
+/// ```dbuf
+/// startsWith(<expression>, <expression>)
+/// ```
+fn call_parser<'src, I>(
+    e_parser: impl Parser<'src, I, Tree, ExtraData> + Clone,
+) -> impl Parser<'src, I, Tree, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let function = just(Token::LCIdentifier(BinaryOp::StartsWith.symbol().into())).map_token();
+    let l_paren = just(Token::LParen).map_token();
+    let comma = just(Token::Comma).map_token();
+    let r_paren = just(Token::RParen).map_token();
+
+    function
+        .then(l_paren)
+        .then(e_parser.clone())
+        .then(comma)
+        .then(e_parser)
+        .then(r_paren)
+        .map_tree(TreeKind::ExprCall)
+        .labelled(FunctionCall)
+}
+
 /// Parses one enum.
 ///
 /// Pattern:
@@ -759,6 +1033,29 @@ where
     .labelled(TypeIndentifier)
 }
 
+/// Parses type reference, that can be qualified with package.
+///
+/// Pattern:
+/// ```dbuf
+/// [lcIdentifier.] UCIdentifier
+/// ```
+fn type_reference_parser<'src, I>() -> impl Parser<'src, I, Child, ExtraData> + Clone
+where
+    I: ValueInput<'src, Span = Location<Offset>, Token = Token>,
+{
+    let dot = just(Token::Dot).map_token();
+    let qualified = var_identifier_parser()
+        .then(dot)
+        .repeated()
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .then(type_identifier_parser())
+        .map_tree(TreeKind::QualifiedType)
+        .map_child();
+
+    choice((qualified, type_identifier_parser()))
+}
+
 /// Parses var chain.
 ///
 /// Pattern:
@@ -834,7 +1131,10 @@ where
         Token::BoolLiteral(b) => Token::BoolLiteral(b),
         Token::IntLiteral(i) => Token::IntLiteral(i),
         Token::UintLiteral(ui) => Token::UintLiteral(ui),
+        Token::DoubleLiteral(d) => Token::DoubleLiteral(d),
+        Token::SizedIntLiteral(s) => Token::SizedIntLiteral(s),
         Token::StringLiteral(s) => Token::StringLiteral(s),
+        Token::BytesLiteral(b) => Token::BytesLiteral(b),
     }
     .map_token()
     .labelled(Literal)
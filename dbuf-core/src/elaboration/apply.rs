@@ -1,6 +1,6 @@
 use crate::ast::elaborated as e;
 use crate::elaboration::builtins::BuiltinType;
-use crate::elaboration::{subst, type_of, unify};
+use crate::elaboration::{normalize, refinement, subst, type_of, unify};
 use crate::error::elaborating::Error::{self, ArityMismatch};
 use std::hash::Hash;
 
@@ -17,9 +17,11 @@ where
         implicits,
         fields,
         result_type,
+        refinements,
+        defaults,
     } = constructor;
 
-    let ((_var_name, field_type), rest_fields) = fields.split_first().ok_or(ArityMismatch {
+    let ((var_name, field_type), rest_fields) = fields.split_first().ok_or(ArityMismatch {
         expected: 1,
         found: 0,
     })?;
@@ -27,20 +29,38 @@ where
     let arg_type = type_of(arg);
     let implicit_bindings = unify::unify_type(&arg_type, field_type, module)?;
 
+    let new_result_type = subst::apply_bindings_to_type(result_type.clone(), &implicit_bindings);
+
+    let mut bindings = implicit_bindings;
+    bindings.push((var_name.clone(), arg.clone()));
+
+    // Types of the rest fields may depend on the applied field, e.g. through its field access,
+    // which is evaluated after substitution.
     let new_fields = rest_fields
         .iter()
         .map(|(name, ty)| {
-            let ty = subst::apply_bindings_to_type(ty.clone(), &implicit_bindings);
-            (name.clone(), ty)
+            let ty = subst::apply_bindings_to_type(ty.clone(), &bindings);
+            (name.clone(), normalize::evaluate_type(&ty, module))
+        })
+        .collect();
+    let new_refinements = refinement::apply_refinements(refinements, &bindings)?;
+    let new_defaults = defaults
+        .iter()
+        .filter(|(name, _)| name != var_name)
+        .map(|(name, value)| {
+            (
+                name.clone(),
+                subst::apply_bindings(value.clone(), &bindings),
+            )
         })
         .collect();
-
-    let new_result_type = subst::apply_bindings_to_type(result_type.clone(), &implicit_bindings);
 
     Ok(e::Constructor {
         implicits: implicits.clone(),
         fields: new_fields,
         result_type: new_result_type,
+        refinements: new_refinements,
+        defaults: new_defaults,
     })
 }
 
@@ -48,7 +68,9 @@ where
 mod tests {
     use super::*;
     use crate::ast::elaborated::{self as e, ConstructorNames};
-    use crate::error::elaborating::Error::ArityMismatch;
+    use crate::ast::operators::{BinaryOp, Literal, OpCall};
+    use crate::elaboration::builtins::get_builtin;
+    use crate::error::elaborating::Error::{ArityMismatch, RefinementViolated};
     use indexmap::IndexMap;
     use std::collections::BTreeMap;
 
@@ -84,12 +106,14 @@ mod tests {
 
     fn test_module() -> e::Module<String> {
         e::Module {
+            package: vec![],
             types: IndexMap::from([
                 (
                     "A".to_owned(),
                     e::Type {
                         dependencies: vec![],
                         constructor_names: ConstructorNames::OfMessage("A".to_owned()),
+                        refinements: vec![],
                     },
                 ),
                 (
@@ -97,10 +121,12 @@ mod tests {
                     e::Type {
                         dependencies: vec![("x".to_owned(), a_ty())],
                         constructor_names: ConstructorNames::OfMessage("B".to_owned()),
+                        refinements: vec![],
                     },
                 ),
             ]),
             constructors: BTreeMap::new(),
+            aliases: IndexMap::new(),
         }
     }
 
@@ -110,6 +136,8 @@ mod tests {
             implicits: vec![],
             fields: vec![],
             result_type: a_ty(),
+            refinements: vec![],
+            defaults: vec![],
         };
         assert_eq!(
             application(&ctor, &ctor_a(), &test_module()).unwrap_err(),
@@ -126,6 +154,8 @@ mod tests {
             implicits: vec![],
             fields: vec![("f".to_owned(), a_ty())],
             result_type: a_ty(),
+            refinements: vec![],
+            defaults: vec![],
         };
         let result = application(&ctor, &ctor_a(), &test_module()).unwrap();
         assert!(result.fields.is_empty());
@@ -141,6 +171,8 @@ mod tests {
                 ("g".to_owned(), b_ty(var("x"))),
             ],
             result_type: b_ty(var("x")),
+            refinements: vec![],
+            defaults: vec![],
         };
         let arg = e::ValueExpression::Variable {
             name: "v".to_owned(),
@@ -150,4 +182,67 @@ mod tests {
         assert_eq!(result.fields, vec![("g".to_owned(), b_ty(ctor_a()))]);
         assert_eq!(result.result_type, b_ty(ctor_a()));
     }
+
+    fn uint(n: u64) -> e::ValueExpression<String> {
+        e::ValueExpression::OpCall {
+            op_call: OpCall::Literal(Literal::UInt(n)),
+            result_type: get_builtin(&BuiltinType::UInt),
+        }
+    }
+
+    /// Constructor with single `UInt` field `f` refined with `f < 10`.
+    fn ctor_below_ten() -> e::Constructor<String> {
+        let f = e::ValueExpression::Variable {
+            name: "f".to_owned(),
+            ty: get_builtin(&BuiltinType::UInt),
+        };
+        let predicate = e::ValueExpression::OpCall {
+            op_call: OpCall::Binary(BinaryOp::Less, e::Rec::new(f), e::Rec::new(uint(10))),
+            result_type: get_builtin(&BuiltinType::Bool),
+        };
+        e::Constructor {
+            implicits: vec![],
+            fields: vec![("f".to_owned(), get_builtin(&BuiltinType::UInt))],
+            result_type: a_ty(),
+            refinements: vec![("f".to_owned(), predicate)],
+            defaults: vec![],
+        }
+    }
+
+    #[test]
+    fn satisfied_refinement_is_dropped() {
+        let result = application(&ctor_below_ten(), &uint(3), &test_module()).unwrap();
+        assert!(result.refinements.is_empty());
+    }
+
+    #[test]
+    fn violated_refinement_is_rejected() {
+        assert_eq!(
+            application(&ctor_below_ten(), &uint(10), &test_module()).unwrap_err(),
+            RefinementViolated("f".to_owned())
+        );
+    }
+
+    #[test]
+    fn refinement_of_variable_is_kept() {
+        let arg = e::ValueExpression::Variable {
+            name: "v".to_owned(),
+            ty: get_builtin(&BuiltinType::UInt),
+        };
+        let result = application(&ctor_below_ten(), &arg, &test_module()).unwrap();
+        assert_eq!(result.refinements.len(), 1);
+    }
+
+    #[test]
+    fn default_of_applied_field_is_dropped() {
+        let ctor = e::Constructor {
+            implicits: vec![],
+            fields: vec![("f".to_owned(), a_ty()), ("g".to_owned(), a_ty())],
+            result_type: a_ty(),
+            refinements: vec![],
+            defaults: vec![("f".to_owned(), ctor_a()), ("g".to_owned(), ctor_a())],
+        };
+        let result = application(&ctor, &ctor_a(), &test_module()).unwrap();
+        assert_eq!(result.defaults, vec![("g".to_owned(), ctor_a())]);
+    }
 }
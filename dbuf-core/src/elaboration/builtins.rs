@@ -9,11 +9,74 @@ pub enum BuiltinType {
     Bool,
     Int,
     UInt,
+    /// 8-bit signed integer.
+    Int8,
+    /// 16-bit signed integer.
+    Int16,
+    /// 32-bit signed integer.
+    Int32,
+    /// 64-bit signed integer.
+    Int64,
+    /// 8-bit unsigned integer.
+    UInt8,
+    /// 16-bit unsigned integer.
+    UInt16,
+    /// 32-bit unsigned integer.
+    UInt32,
+    /// 64-bit unsigned integer.
+    UInt64,
+    /// IEEE-754 double precision number.
+    Double,
     String,
+    /// Opaque binary payload.
+    Bytes,
+    /// Sequence of elements of type `T`: `List T`.
+    List,
+    /// Sequence of exactly `n` elements of type `T`: `Array T n`.
+    Array,
+    /// Kind of type arguments. It can't be named in source.
+    Type,
 }
 
 impl BuiltinType {
-    pub const ALL: &'static [Self] = &[Self::Bool, Self::Int, Self::UInt, Self::String];
+    pub const ALL: &'static [Self] = &[
+        Self::Bool,
+        Self::Int,
+        Self::UInt,
+        Self::Int8,
+        Self::Int16,
+        Self::Int32,
+        Self::Int64,
+        Self::UInt8,
+        Self::UInt16,
+        Self::UInt32,
+        Self::UInt64,
+        Self::Double,
+        Self::String,
+        Self::Bytes,
+        Self::List,
+        Self::Array,
+    ];
+
+    /// Builtin integer types.
+    pub const INTEGERS: &'static [Self] = &[
+        Self::Int,
+        Self::UInt,
+        Self::Int8,
+        Self::Int16,
+        Self::Int32,
+        Self::Int64,
+        Self::UInt8,
+        Self::UInt16,
+        Self::UInt32,
+        Self::UInt64,
+    ];
+
+    /// Builtin sequence types.
+    pub const SEQUENCES: &'static [Self] = &[Self::List, Self::Array];
+
+    /// Builtin types of strings, which are concatenated with `+` and have `length`.
+    pub const STRINGS: &'static [Self] = &[Self::String, Self::Bytes];
 
     #[must_use]
     pub const fn as_str(self) -> &'static str {
@@ -21,7 +84,34 @@ impl BuiltinType {
             Self::Bool => "Bool",
             Self::Int => "Int",
             Self::UInt => "UInt",
+            Self::Int8 => "Int8",
+            Self::Int16 => "Int16",
+            Self::Int32 => "Int32",
+            Self::Int64 => "Int64",
+            Self::UInt8 => "UInt8",
+            Self::UInt16 => "UInt16",
+            Self::UInt32 => "UInt32",
+            Self::UInt64 => "UInt64",
+            Self::Double => "Double",
             Self::String => "String",
+            Self::Bytes => "Bytes",
+            Self::List => "List",
+            Self::Array => "Array",
+            Self::Type => "Type",
+        }
+    }
+
+    /// Names and types of dependencies of builtin type.
+    #[must_use]
+    pub fn dependencies<Str>(self) -> Vec<(Str, e::TypeExpression<Str>)>
+    where
+        Str: From<BuiltinType> + From<&'static str>,
+    {
+        let element = || (Str::from("T"), get_builtin(&Self::Type));
+        match self {
+            Self::List => vec![element()],
+            Self::Array => vec![element(), (Str::from("n"), get_builtin(&Self::UInt))],
+            _ => vec![],
         }
     }
 }
@@ -44,7 +134,20 @@ impl From<&str> for BuiltinType {
             "Bool" => Self::Bool,
             "Int" => Self::Int,
             "UInt" => Self::UInt,
+            "Int8" => Self::Int8,
+            "Int16" => Self::Int16,
+            "Int32" => Self::Int32,
+            "Int64" => Self::Int64,
+            "UInt8" => Self::UInt8,
+            "UInt16" => Self::UInt16,
+            "UInt32" => Self::UInt32,
+            "UInt64" => Self::UInt64,
+            "Double" => Self::Double,
             "String" => Self::String,
+            "Bytes" => Self::Bytes,
+            "List" => Self::List,
+            "Array" => Self::Array,
+            "Type" => Self::Type,
             _ => unreachable!(),
         }
     }
@@ -56,21 +159,27 @@ pub fn builtins<Str: Eq + Hash + From<BuiltinType> + Clone>() -> Vec<e::TypeExpr
 }
 
 #[must_use]
-pub fn builtins_module<Str: Eq + Hash + From<BuiltinType> + Clone>() -> e::Module<Str> {
+pub fn builtins_module<Str>() -> e::Module<Str>
+where
+    Str: Eq + Hash + From<BuiltinType> + From<&'static str> + Clone,
+{
     e::Module {
-        types: builtins::<Str>()
-            .into_iter()
-            .map(|e::TypeExpression::TypeExpression { name, .. }| {
+        package: vec![],
+        types: BuiltinType::ALL
+            .iter()
+            .map(|bt| {
                 (
-                    name,
+                    Str::from(*bt),
                     e::Type {
-                        dependencies: vec![],
+                        dependencies: bt.dependencies(),
                         constructor_names: e::ConstructorNames::OfEnum(BTreeSet::new()),
+                        refinements: vec![],
                     },
                 )
             })
             .collect::<IndexMap<_, _>>(),
         constructors: BTreeMap::new(),
+        aliases: IndexMap::new(),
     }
 }
 
@@ -86,5 +195,24 @@ pub fn get_builtin<Str: From<BuiltinType>>(ty: &BuiltinType) -> e::TypeExpressio
 pub fn is_builtin_type<Str: Clone + PartialEq + From<BuiltinType>>(
     ty: &e::TypeExpression<Str>,
 ) -> bool {
-    BuiltinType::ALL.iter().any(|bt| ty == &get_builtin(bt))
+    let e::TypeExpression::TypeExpression { name, .. } = ty;
+    BuiltinType::ALL.iter().any(|bt| *name == Str::from(*bt))
+}
+
+/// Whether `ty` is one of `BuiltinType::STRINGS`.
+#[must_use]
+pub fn is_string<Str: PartialEq + From<BuiltinType>>(ty: &e::TypeExpression<Str>) -> bool {
+    BuiltinType::STRINGS.iter().any(|bt| *ty == get_builtin(bt))
+}
+
+/// Returns builtin sequence type of `ty` with its dependencies, if it is a sequence.
+#[must_use]
+pub fn as_sequence<Str: PartialEq + From<BuiltinType>>(
+    ty: &e::TypeExpression<Str>,
+) -> Option<(BuiltinType, &[e::ValueExpression<Str>])> {
+    let e::TypeExpression::TypeExpression { name, dependencies } = ty;
+    BuiltinType::SEQUENCES
+        .iter()
+        .find(|bt| *name == Str::from(**bt))
+        .map(|bt| (*bt, dependencies.as_ref()))
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 use std::hash::Hash;
 
 #[derive(Debug, Clone)]
@@ -9,8 +9,8 @@ where
     Alias: 'a,
 {
     parent: Option<&'a Context<'a, Key, Value, Alias>>,
-    pub terms: HashMap<Key, Value>,
-    pub aliases: HashMap<Key, Alias>,
+    pub terms: IndexMap<Key, Value>,
+    pub aliases: IndexMap<Key, Alias>,
 }
 
 impl<'a, Key, Value, Alias> Default for Context<'a, Key, Value, Alias>
@@ -34,8 +34,8 @@ where
     pub fn new() -> Self {
         Self {
             parent: None,
-            terms: HashMap::new(),
-            aliases: HashMap::new(),
+            terms: IndexMap::new(),
+            aliases: IndexMap::new(),
         }
     }
 
@@ -43,8 +43,8 @@ where
     pub fn new_layer(&'a self) -> Self {
         Context {
             parent: Some(self),
-            terms: HashMap::new(),
-            aliases: HashMap::new(),
+            terms: IndexMap::new(),
+            aliases: IndexMap::new(),
         }
     }
 
@@ -68,6 +68,24 @@ where
         self.aliases.insert(name, alias);
     }
 
+    /// Terms visible in the context in order of insertion, starting from the outermost layer,
+    /// with aliases they are refined to.
+    #[must_use]
+    pub fn visible_terms(&self) -> Vec<(&Key, &Value, Option<&Alias>)> {
+        let mut visible = self
+            .parent
+            .map(|parent| parent.visible_terms())
+            .unwrap_or_default();
+        for (name, term) in &self.terms {
+            visible.retain(|(shadowed, _, _)| *shadowed != name);
+            visible.push((name, term, None));
+        }
+        for (name, _, alias) in &mut visible {
+            *alias = self.get_alias(name);
+        }
+        visible
+    }
+
     pub fn lookup(&self, name: &Key) -> Option<Lookup<&Value, &Alias>> {
         if let Some(alias) = self.aliases.get(name) {
             return Some(Lookup::Alias(alias));
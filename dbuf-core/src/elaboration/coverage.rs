@@ -0,0 +1,335 @@
+//! Coverage checking of patterns in enum branches.
+//!
+//! Patterns of each branch form a row of a pattern matrix, which is checked as in
+//! "Warnings for pattern matching" by L. Maranget: a branch is unreachable if its row
+//! matches no value unmatched by previous rows, and patterns are exhaustive if every
+//! value is matched by some row.
+//!
+//! All values are known only for `Bool` and types declared in schema, so literals of
+//! other builtin types never cover a dependency without a wildcard. Indices and
+//! refinements of dependency types are not taken into account.
+
+use super::{DefRef, Loc, Mod, Name, Str, TypeExpr, builtins, operators};
+use crate::ast::{elaborated as e, operators as o, parsed as p};
+use crate::error::elaborating::{ElaboratingStage, Error};
+
+/// Maximal number of reported missing values.
+const MISSING_LIMIT: usize = 3;
+
+/// Literal or constructor, which a pattern starts with.
+#[derive(Clone, Debug, PartialEq)]
+enum Head {
+    Literal(o::Literal),
+    Constructor(Str),
+}
+
+#[derive(Clone, Debug)]
+enum Pattern {
+    Wildcard,
+    Head(Head, Vec<Pattern>),
+}
+
+type Row = Vec<Pattern>;
+
+/// Checks patterns of branches of enum `type_def`, which is elaborated as `ty`.
+///
+/// Returns warnings for unreachable branches and for values of dependencies matched by no branch.
+pub(super) fn check_enum(
+    module_ctx: &Mod,
+    type_def: DefRef<'_>,
+    ty: &e::Type<Str>,
+) -> Vec<ElaboratingStage> {
+    let p::TypeDefinition::Enum(branches) = &type_def.data.body else {
+        return vec![];
+    };
+    let types: Vec<TypeExpr> = ty.dependencies.iter().map(|(_, t)| t.clone()).collect();
+    if types.is_empty() {
+        return vec![];
+    }
+
+    let checker = Checker { module_ctx };
+    let mut warnings = vec![];
+    let mut rows = vec![];
+    for branch in branches {
+        let row: Row = branch
+            .patterns
+            .iter()
+            .zip(&types)
+            .map(|(pattern, ty)| checker.lower(pattern, ty))
+            .collect();
+        if !checker.is_useful(&rows, &row, &types) {
+            warnings.push(ElaboratingStage {
+                error: Error::UnreachableBranch,
+                loc: branch.patterns.first().map(|p| p.loc),
+            });
+        }
+        rows.push(row);
+    }
+
+    let missing = checker.missing(&rows, &types);
+    if !missing.is_empty() {
+        warnings.push(ElaboratingStage {
+            error: Error::NonExhaustive(missing.iter().map(|row| checker.show_row(row)).collect()),
+            loc: Some(Loc::from(&type_def.name)),
+        });
+    }
+    warnings
+}
+
+struct Checker<'a> {
+    module_ctx: &'a Mod,
+}
+
+impl Checker<'_> {
+    fn lower(&self, pattern: &p::Pattern<Loc, Name>, ty: &TypeExpr) -> Pattern {
+        match &pattern.node {
+            p::PatternNode::ConstructorCall { name, fields } => {
+                let field_types = self.field_types(&name.content);
+                let args = fields
+                    .iter()
+                    .zip(&field_types)
+                    .map(|(field, ty)| self.lower(&field.data, ty))
+                    .collect();
+                Pattern::Head(Head::Constructor(name.content.clone()), args)
+            }
+            p::PatternNode::Literal(literal) => {
+                let literal = operators::convert_literal(literal, ty)
+                    .and_then(Result::ok)
+                    .unwrap_or_else(|| literal.clone());
+                Pattern::Head(Head::Literal(literal), vec![])
+            }
+            p::PatternNode::Variable { .. } | p::PatternNode::Underscore => Pattern::Wildcard,
+        }
+    }
+
+    fn field_types(&self, constructor: &Str) -> Vec<TypeExpr> {
+        self.module_ctx
+            .constructors
+            .get(constructor)
+            .map(|ctor| ctor.fields.iter().map(|(_, ty)| ty.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// All heads of values of `ty` with types of their arguments,
+    /// or `None` if they can't be listed.
+    fn heads(&self, ty: &TypeExpr) -> Option<Vec<(Head, Vec<TypeExpr>)>> {
+        if *ty == builtins::get_builtin(&builtins::BuiltinType::Bool) {
+            return Some(vec![
+                (Head::Literal(o::Literal::Bool(true)), vec![]),
+                (Head::Literal(o::Literal::Bool(false)), vec![]),
+            ]);
+        }
+        if builtins::is_builtin_type(ty) {
+            return None;
+        }
+        let e::TypeExpression::TypeExpression { name, .. } = ty;
+        let constructors = match &self.module_ctx.types.get(name)?.constructor_names {
+            e::ConstructorNames::OfMessage(ctor) => vec![ctor.clone()],
+            e::ConstructorNames::OfEnum(ctors) => ctors.iter().cloned().collect(),
+        };
+        Some(
+            constructors
+                .into_iter()
+                .map(|ctor| {
+                    let fields = self.field_types(&ctor);
+                    (Head::Constructor(ctor), fields)
+                })
+                .collect(),
+        )
+    }
+
+    /// All heads of `ty` if each of them starts some row.
+    fn complete_heads(&self, rows: &[Row], ty: &TypeExpr) -> Option<Vec<(Head, Vec<TypeExpr>)>> {
+        let used = used_heads(rows);
+        self.heads(ty)
+            .filter(|heads| heads.iter().all(|(head, _)| used.contains(head)))
+    }
+
+    /// Whether some value matched by `row` is not matched by any of `rows`.
+    fn is_useful(&self, rows: &[Row], row: &[Pattern], types: &[TypeExpr]) -> bool {
+        let (Some((first, rest)), Some((ty, rest_types))) =
+            (row.split_first(), types.split_first())
+        else {
+            return rows.is_empty();
+        };
+        match first {
+            Pattern::Head(head, args) => {
+                let fields = self.head_fields(head);
+                self.is_useful(
+                    &specialize(rows, head, args.len()),
+                    &[args.as_slice(), rest].concat(),
+                    &[fields.as_slice(), rest_types].concat(),
+                )
+            }
+            Pattern::Wildcard => match self.complete_heads(rows, ty) {
+                Some(heads) => heads.iter().any(|(head, fields)| {
+                    self.is_useful(
+                        &specialize(rows, head, fields.len()),
+                        &[vec![Pattern::Wildcard; fields.len()].as_slice(), rest].concat(),
+                        &[fields.as_slice(), rest_types].concat(),
+                    )
+                }),
+                None => self.is_useful(&default(rows), rest, rest_types),
+            },
+        }
+    }
+
+    /// Examples of values of `types`, which are not matched by any of `rows`.
+    fn missing(&self, rows: &[Row], types: &[TypeExpr]) -> Vec<Row> {
+        let Some((ty, rest_types)) = types.split_first() else {
+            return if rows.is_empty() {
+                vec![vec![]]
+            } else {
+                vec![]
+            };
+        };
+
+        if let Some(heads) = self.complete_heads(rows, ty) {
+            return heads
+                .into_iter()
+                .flat_map(|(head, fields)| {
+                    let arity = fields.len();
+                    let missing = self.missing(
+                        &specialize(rows, &head, arity),
+                        &[fields.as_slice(), rest_types].concat(),
+                    );
+                    missing.into_iter().map(move |mut args| {
+                        let rest = args.split_off(arity);
+                        [vec![Pattern::Head(head.clone(), args)], rest].concat()
+                    })
+                })
+                .take(MISSING_LIMIT)
+                .collect();
+        }
+
+        let missing_rest = self.missing(&default(rows), rest_types);
+        if missing_rest.is_empty() {
+            return vec![];
+        }
+        let used = used_heads(rows);
+        let examples = match self.heads(ty) {
+            Some(heads) if !used.is_empty() => heads
+                .into_iter()
+                .filter(|(head, _)| !used.contains(head))
+                .map(|(head, fields)| Pattern::Head(head, vec![Pattern::Wildcard; fields.len()]))
+                .collect(),
+            None => vec![unused_literal(&used).map_or(Pattern::Wildcard, |literal| {
+                Pattern::Head(Head::Literal(literal), vec![])
+            })],
+            Some(_) => vec![Pattern::Wildcard],
+        };
+        examples
+            .iter()
+            .flat_map(|example| {
+                missing_rest
+                    .iter()
+                    .map(move |rest| [vec![example.clone()], rest.clone()].concat())
+            })
+            .take(MISSING_LIMIT)
+            .collect()
+    }
+
+    fn head_fields(&self, head: &Head) -> Vec<TypeExpr> {
+        match head {
+            Head::Constructor(ctor) => self.field_types(ctor),
+            Head::Literal(_) => vec![],
+        }
+    }
+
+    fn show_row(&self, row: &[Pattern]) -> String {
+        row.iter()
+            .map(|pattern| self.show_pattern(pattern))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Shows pattern in surface syntax, e.g. `Suc{pred: *}`.
+    fn show_pattern(&self, pattern: &Pattern) -> String {
+        match pattern {
+            Pattern::Wildcard => "*".to_owned(),
+            Pattern::Head(Head::Literal(literal), _) => show_literal(literal),
+            Pattern::Head(Head::Constructor(ctor), args) => {
+                let fields = self
+                    .module_ctx
+                    .constructors
+                    .get(ctor)
+                    .map(|ctor| ctor.fields.as_slice())
+                    .unwrap_or_default();
+                let args = fields
+                    .iter()
+                    .zip(args)
+                    .map(|((name, _), arg)| format!("{name}: {}", self.show_pattern(arg)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{ctor}{{{args}}}")
+            }
+        }
+    }
+}
+
+fn used_heads(rows: &[Row]) -> Vec<Head> {
+    let mut used = vec![];
+    for row in rows {
+        if let Some(Pattern::Head(head, _)) = row.first()
+            && !used.contains(head)
+        {
+            used.push(head.clone());
+        }
+    }
+    used
+}
+
+/// Rows matching values that start with `head`, with its arguments in place of the first pattern.
+fn specialize(rows: &[Row], head: &Head, arity: usize) -> Vec<Row> {
+    rows.iter()
+        .filter_map(|row| {
+            let (first, rest) = row.split_first()?;
+            match first {
+                Pattern::Wildcard => {
+                    Some([vec![Pattern::Wildcard; arity].as_slice(), rest].concat())
+                }
+                Pattern::Head(other, args) if other == head => {
+                    Some([args.as_slice(), rest].concat())
+                }
+                Pattern::Head(..) => None,
+            }
+        })
+        .collect()
+}
+
+/// Rows matching values that start with any head not used in the first column.
+fn default(rows: &[Row]) -> Vec<Row> {
+    rows.iter()
+        .filter_map(|row| match row.split_first()? {
+            (Pattern::Wildcard, rest) => Some(rest.to_vec()),
+            (Pattern::Head(..), _) => None,
+        })
+        .collect()
+}
+
+/// The least natural number not used in integer literals, if they are `Int` or `UInt`.
+fn unused_literal(used: &[Head]) -> Option<o::Literal> {
+    // One of first `used.len() + 1` numbers is not used.
+    let candidates = 0..=u64::try_from(used.len()).ok()?;
+    let is_unused = |literal: &o::Literal| !used.contains(&Head::Literal(literal.clone()));
+    match used.first()? {
+        Head::Literal(o::Literal::Int(_)) => candidates
+            .filter_map(|n| i64::try_from(n).ok())
+            .map(o::Literal::Int)
+            .find(is_unused),
+        Head::Literal(o::Literal::UInt(_)) => candidates.map(o::Literal::UInt).find(is_unused),
+        _ => None,
+    }
+}
+
+fn show_literal(literal: &o::Literal) -> String {
+    match literal {
+        o::Literal::Bool(b) => b.to_string(),
+        o::Literal::Int(n) => n.to_string(),
+        o::Literal::UInt(n) => n.to_string(),
+        o::Literal::Str(s) => format!("{s:?}"),
+        o::Literal::Bytes(b) => format!("b\"{}\"", b.escape_ascii()),
+        o::Literal::Double(d) => d.0.to_string(),
+        o::Literal::Sized(n) => n.to_string(),
+    }
+}
@@ -0,0 +1,236 @@
+//! Analysis of values that are determined by dependencies.
+//!
+//! Readers of values get dependencies separately, so a value that can be computed
+//! from them doesn't need to be encoded:
+//!  * Descriptor of an enum is derivable if patterns of every two of its constructors
+//!    start with different constructors or literals at some dependency, so values of
+//!    dependencies match patterns of at most one constructor. For example, `Nil` and
+//!    `Cons` of `Vec (n Nat)` are matched by `Zero` and `Suc p`.
+//!  * Field is derivable if it has a refinement `field == value` (possibly conjunct of `&`),
+//!    where `value` refers only to implicits and preceding fields. Only fields of builtin
+//!    types other than sequences are derived.
+//!
+//! Nested patterns and refinements are not solved, e.g. `a + b == n` doesn't derive `b`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::builtins::BuiltinType;
+use crate::ast::elaborated as e;
+use crate::ast::operators::{BinaryOp, OpCall, UnaryOp};
+
+/// Values of declarations of a module that are determined by dependencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Derivable<Str> {
+    /// Enums whose constructor is determined by values of dependencies.
+    pub descriptors: BTreeSet<Str>,
+    /// Derivable fields of constructors paired with values they are equal to,
+    /// which are expressed with implicits and preceding fields.
+    pub fields: BTreeMap<Str, Vec<(Str, e::ValueExpression<Str>)>>,
+}
+
+impl<Str> Default for Derivable<Str> {
+    fn default() -> Self {
+        Derivable {
+            descriptors: BTreeSet::new(),
+            fields: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Str: Ord> Derivable<Str> {
+    /// Whether descriptor of enum `ty` is determined by its dependencies.
+    #[must_use]
+    pub fn is_descriptor_derivable(&self, ty: &Str) -> bool {
+        self.descriptors.contains(ty)
+    }
+
+    /// Value of `field` of `constructor`, if it is derivable.
+    #[must_use]
+    pub fn field_value(&self, constructor: &Str, field: &Str) -> Option<&e::ValueExpression<Str>> {
+        self.fields
+            .get(constructor)?
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Finds derivable descriptors and fields of declarations of `module`.
+#[must_use]
+pub fn analyze<Str>(module: &e::Module<Str>) -> Derivable<Str>
+where
+    Str: Ord + Clone + AsRef<str>,
+{
+    let descriptors = module
+        .types
+        .iter()
+        .filter(|(_, ty)| is_descriptor_derivable(module, ty))
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    let fields = module
+        .constructors
+        .iter()
+        .filter_map(|(name, constructor)| {
+            let fields = derivable_fields(constructor);
+            (!fields.is_empty()).then(|| (name.clone(), fields))
+        })
+        .collect();
+
+    Derivable {
+        descriptors,
+        fields,
+    }
+}
+
+fn is_descriptor_derivable<Str: Ord>(module: &e::Module<Str>, ty: &e::Type<Str>) -> bool {
+    let e::ConstructorNames::OfEnum(names) = &ty.constructor_names else {
+        return false;
+    };
+    let patterns: Vec<&[e::ValueExpression<Str>]> = names
+        .iter()
+        .filter_map(|name| module.constructors.get(name))
+        .map(|constructor| {
+            let e::TypeExpression::TypeExpression { dependencies, .. } = &constructor.result_type;
+            dependencies.as_ref()
+        })
+        .collect();
+
+    patterns.iter().enumerate().all(|(index, lhs)| {
+        patterns[index + 1..]
+            .iter()
+            .all(|rhs| lhs.iter().zip(rhs.iter()).any(|(l, r)| heads_differ(l, r)))
+    })
+}
+
+/// Whether patterns start with different constructors or literals,
+/// so no value is matched by both of them.
+fn heads_differ<Str: PartialEq>(
+    lhs: &e::ValueExpression<Str>,
+    rhs: &e::ValueExpression<Str>,
+) -> bool {
+    match (lhs, rhs) {
+        (
+            e::ValueExpression::Constructor { name: lhs, .. },
+            e::ValueExpression::Constructor { name: rhs, .. },
+        ) => lhs != rhs,
+        (e::ValueExpression::OpCall { .. }, e::ValueExpression::OpCall { .. }) => {
+            is_literal(lhs) && is_literal(rhs) && lhs != rhs
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a literal, negative integers are negations of literals.
+fn is_literal<Str>(expr: &e::ValueExpression<Str>) -> bool {
+    match expr {
+        e::ValueExpression::OpCall {
+            op_call: OpCall::Literal(_),
+            ..
+        } => true,
+        e::ValueExpression::OpCall {
+            op_call: OpCall::Unary(UnaryOp::Minus, operand),
+            ..
+        } => is_literal(operand),
+        _ => false,
+    }
+}
+
+fn derivable_fields<Str>(constructor: &e::Constructor<Str>) -> Vec<(Str, e::ValueExpression<Str>)>
+where
+    Str: Ord + Clone + AsRef<str>,
+{
+    let mut derivable = vec![];
+    for (index, (field, ty)) in constructor.fields.iter().enumerate() {
+        // refinements of fields that shadow implicits can't be told from ones of dependencies
+        let shadows = constructor.implicits.iter().any(|(name, _)| name == field);
+        if shadows || !is_scalar_builtin(ty) {
+            continue;
+        }
+        // names of the field and later ones may shadow implicits and preceding fields
+        let unbound: BTreeSet<&Str> = constructor.fields[index..]
+            .iter()
+            .map(|(name, _)| name)
+            .collect();
+        let value = constructor
+            .refinements
+            .iter()
+            .filter(|(name, _)| name == field)
+            .flat_map(|(_, predicate)| conjuncts(predicate))
+            .find_map(|conjunct| equated_value(conjunct, field, &unbound));
+        if let Some(value) = value {
+            derivable.push((field.clone(), value.clone()));
+        }
+    }
+    derivable
+}
+
+/// Whether `ty` is builtin type other than sequences.
+fn is_scalar_builtin<Str: AsRef<str>>(ty: &e::TypeExpression<Str>) -> bool {
+    let e::TypeExpression::TypeExpression { name, .. } = ty;
+    BuiltinType::ALL
+        .iter()
+        .filter(|builtin| !BuiltinType::SEQUENCES.contains(builtin))
+        .any(|builtin| builtin.as_str() == name.as_ref())
+}
+
+fn conjuncts<Str>(predicate: &e::ValueExpression<Str>) -> Vec<&e::ValueExpression<Str>> {
+    match predicate {
+        e::ValueExpression::OpCall {
+            op_call: OpCall::Binary(BinaryOp::BinaryAnd, lhs, rhs),
+            ..
+        } => {
+            let mut all = conjuncts(lhs);
+            all.extend(conjuncts(rhs));
+            all
+        }
+        _ => vec![predicate],
+    }
+}
+
+/// Value that `conjunct` equates `field` to, if it mentions no `unbound` variables.
+fn equated_value<'a, Str: Ord>(
+    conjunct: &'a e::ValueExpression<Str>,
+    field: &Str,
+    unbound: &BTreeSet<&Str>,
+) -> Option<&'a e::ValueExpression<Str>> {
+    let e::ValueExpression::OpCall {
+        op_call: OpCall::Binary(BinaryOp::Equal, lhs, rhs),
+        ..
+    } = conjunct
+    else {
+        return None;
+    };
+    let is_field = |expr: &e::ValueExpression<Str>| matches!(expr, e::ValueExpression::Variable { name, .. } if name == field);
+    let value = if is_field(lhs) {
+        rhs.as_ref()
+    } else if is_field(rhs) {
+        lhs.as_ref()
+    } else {
+        return None;
+    };
+    (!mentions_any(value, unbound)).then_some(value)
+}
+
+fn mentions_any<Str: Ord>(expr: &e::ValueExpression<Str>, names: &BTreeSet<&Str>) -> bool {
+    match expr {
+        e::ValueExpression::OpCall { op_call, .. } => match op_call {
+            OpCall::Literal(_) => false,
+            OpCall::Unary(_, operand) => mentions_any(operand, names),
+            OpCall::Binary(_, lhs, rhs) => mentions_any(lhs, names) || mentions_any(rhs, names),
+        },
+        e::ValueExpression::Constructor {
+            implicits,
+            arguments,
+            ..
+        } => implicits
+            .iter()
+            .chain(arguments.iter())
+            .any(|expr| mentions_any(expr, names)),
+        e::ValueExpression::Variable { name, .. } => names.contains(name),
+        e::ValueExpression::Type { ty } => {
+            let e::TypeExpression::TypeExpression { dependencies, .. } = ty;
+            dependencies.iter().any(|expr| mentions_any(expr, names))
+        }
+    }
+}
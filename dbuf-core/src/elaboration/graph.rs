@@ -7,108 +7,301 @@ use crate::location::{Location, Offset};
 
 type ModuleRef<'a, Loc, Str> = Vec<&'a Definition<Loc, Str, p::TypeDeclaration<Loc, Str>>>;
 
-/// Topologically sorts declarations in a parsed module
+/// Topologically sorts groups of mutually recursive declarations in a parsed module
 /// # Errors
 ///  Returns `Err` containing one cycle names and locations
-pub fn topological_sort<'a, Loc, Str>(
-    module: &'a p::Module<Loc, Str>,
-) -> Result<ModuleRef<'a, Loc, Str>, Error>
+pub fn topological_sort<Loc, Str>(
+    module: &p::Module<Loc, Str>,
+) -> Result<Vec<ModuleRef<'_, Loc, Str>>, Error>
 where
     Loc: Into<Location<Offset>> + Copy,
     Str: Ord + Clone + ToString,
 {
-    let declared: BTreeSet<String> = module.iter().map(|def| def.name.to_string()).collect();
+    let (sorted, mut cycles) = topological_sort_partial(module);
+    if cycles.is_empty() {
+        Ok(sorted)
+    } else {
+        Err(cycles.swap_remove(0))
+    }
+}
 
+/// Splits declarations in a parsed module into groups of mutually recursive ones
+/// (strongly connected components) and topologically sorts the groups.
+///
+/// Fields may refer to any declaration of their group, but dependencies may not,
+/// since types of dependencies have to be elaborated before the group itself.
+/// Group that breaks this is left out together with groups that depend on it.
+///
+/// Returns sorted groups and a `Cycle` error for every group left out by itself.
+#[must_use]
+pub fn topological_sort_partial<'a, Loc, Str>(
+    module: &'a p::Module<Loc, Str>,
+) -> (Vec<ModuleRef<'a, Loc, Str>>, Vec<Error>)
+where
+    Loc: Into<Location<Offset>> + Copy,
+    Str: Ord + Clone + ToString,
+{
     let by_name: BTreeMap<String, &'a Definition<Loc, Str, p::TypeDeclaration<Loc, Str>>> = module
         .iter()
         .map(|def| (def.name.to_string(), def))
         .collect();
+    let deps = dependency_graph(module);
 
-    let deps: BTreeMap<String, BTreeSet<String>> = module
+    let mut sorted = vec![];
+    let mut cycles = vec![];
+    let mut rejected = BTreeSet::new();
+    for group in sort_components(&deps) {
+        let depends_on_rejected = group
+            .iter()
+            .any(|name| deps[name].iter().any(|dep| rejected.contains(dep)));
+        let cycle = dependency_cycle(&group, &deps, &by_name);
+        if depends_on_rejected || !cycle.is_empty() {
+            rejected.extend(group);
+            if !cycle.is_empty() {
+                let cycle = cycle
+                    .into_iter()
+                    .map(|name| {
+                        let loc = by_name[&name].loc.into();
+                        (name, loc)
+                    })
+                    .collect();
+                cycles.push(Error::Cycle(cycle));
+            }
+            continue;
+        }
+        sorted.push(group.iter().map(|name| by_name[name]).collect());
+    }
+    (sorted, cycles)
+}
+
+/// Returns names of declared types referenced by every declaration,
+/// except references to itself.
+fn dependency_graph<Loc, Str: ToString>(
+    module: &p::Module<Loc, Str>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    let declared: BTreeSet<String> = module.iter().map(|def| def.name.to_string()).collect();
+    module
         .iter()
         .map(|def| {
             let def_name = def.name.to_string();
-            let mut refs = BTreeSet::new();
-            for dep in &def.data.dependencies {
-                add_ref(&dep.data, &mut refs);
-            }
-            match &def.data.body {
-                p::TypeDefinition::Message(fields) => {
-                    for field in fields {
-                        add_ref(&field.data, &mut refs);
-                    }
-                }
-                p::TypeDefinition::Enum(branches) => {
-                    for branch in branches {
-                        for ctor in &branch.constructors {
-                            for field in &ctor.data {
-                                add_ref(&field.data, &mut refs);
-                            }
-                        }
-                    }
-                }
-            }
-            let node_deps = refs
+            let node_deps = references(def)
                 .into_iter()
                 .filter(|r| declared.contains(r) && *r != def_name)
                 .collect();
             (def_name, node_deps)
         })
-        .collect();
+        .collect()
+}
 
-    let mut in_degree: BTreeMap<String, usize> =
-        deps.iter().map(|(k, v)| (k.clone(), v.len())).collect();
+/// Returns strongly connected components of `deps` graph in the order of Kahn's
+/// algorithm, where a component is named after its first member.
+fn sort_components(deps: &BTreeMap<String, BTreeSet<String>>) -> Vec<Vec<String>> {
+    let components: BTreeMap<String, Vec<String>> = strongly_connected_components(deps)
+        .into_iter()
+        .map(|component| (component[0].clone(), component))
+        .collect();
+    let component_of: BTreeMap<&str, &str> = components
+        .iter()
+        .flat_map(|(head, members)| {
+            members
+                .iter()
+                .map(move |name| (name.as_str(), head.as_str()))
+        })
+        .collect();
 
-    let mut rdeps: BTreeMap<String, Vec<String>> = BTreeMap::new();
-    for (node, node_deps) in &deps {
-        for dep in node_deps {
-            rdeps.entry(dep.clone()).or_default().push(node.clone());
+    let mut component_deps: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut rdeps: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (head, members) in &components {
+        let head_deps: BTreeSet<&str> = members
+            .iter()
+            .flat_map(|name| &deps[name])
+            .map(|dep| component_of[dep.as_str()])
+            .filter(|dep| dep != head)
+            .collect();
+        for dep in &head_deps {
+            rdeps.entry(dep).or_default().push(head);
         }
+        component_deps.insert(head, head_deps);
     }
 
-    let mut queue: VecDeque<String> = in_degree
+    let mut in_degree: BTreeMap<&str, usize> =
+        component_deps.iter().map(|(&k, v)| (k, v.len())).collect();
+    let mut queue: VecDeque<&str> = in_degree
         .iter()
-        .filter_map(|(k, &v)| if v == 0 { Some(k.clone()) } else { None })
+        .filter_map(|(&k, &v)| if v == 0 { Some(k) } else { None })
         .collect();
 
-    let mut sorted: Vec<&'a Definition<Loc, Str, p::TypeDeclaration<Loc, Str>>> =
-        Vec::with_capacity(module.len());
+    let mut sorted = Vec::with_capacity(components.len());
+    while let Some(head) = queue.pop_front() {
+        sorted.push(components[head].clone());
 
-    while let Some(name) = queue.pop_front() {
-        sorted.push(by_name[&name]);
-
-        let Some(dependents) = rdeps.get(&name) else {
+        let Some(dependents) = rdeps.get(head) else {
             continue;
         };
-        let newly_free: Vec<_> = dependents
+        for &dependent in dependents {
+            let deg = in_degree
+                .get_mut(dependent)
+                .expect("every component has in-degree");
+            *deg -= 1;
+            if *deg == 0 {
+                queue.push_back(dependent);
+            }
+        }
+    }
+    sorted
+}
+
+/// Returns strongly connected components of `deps` graph, so that every
+/// component goes after the ones it depends on. Names inside a component are sorted.
+fn strongly_connected_components(deps: &BTreeMap<String, BTreeSet<String>>) -> Vec<Vec<String>> {
+    struct Tarjan<'a> {
+        deps: &'a BTreeMap<String, BTreeSet<String>>,
+        index: BTreeMap<&'a str, usize>,
+        low_link: BTreeMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<String>>,
+    }
+
+    impl<'a> Tarjan<'a> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.low_link.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            for dep in &self.deps[node] {
+                let dep = dep.as_str();
+                if !self.index.contains_key(dep) {
+                    self.visit(dep);
+                    let low = self.low_link[node].min(self.low_link[dep]);
+                    self.low_link.insert(node, low);
+                } else if self.on_stack.contains(dep) {
+                    let low = self.low_link[node].min(self.index[dep]);
+                    self.low_link.insert(node, low);
+                }
+            }
+
+            if self.low_link[node] == index {
+                let mut component = vec![];
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member.to_string());
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort();
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        deps,
+        index: BTreeMap::new(),
+        low_link: BTreeMap::new(),
+        stack: vec![],
+        on_stack: BTreeSet::new(),
+        components: vec![],
+    };
+    for node in deps.keys() {
+        if !tarjan.index.contains_key(node.as_str()) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+/// Returns cycle of `group` that goes through a dependency of some declaration,
+/// or empty vector if there is none.
+fn dependency_cycle<Loc, Str: ToString>(
+    group: &[String],
+    deps: &BTreeMap<String, BTreeSet<String>>,
+    by_name: &BTreeMap<String, &Definition<Loc, Str, p::TypeDeclaration<Loc, Str>>>,
+) -> Vec<String> {
+    for name in group {
+        let mut dependency_refs = BTreeSet::new();
+        for dep in &by_name[name].data.dependencies {
+            add_ref(&dep.data, &mut dependency_refs);
+        }
+        // alias is expanded where it is used, so it can't refer to its group, even to itself.
+        if let p::TypeDefinition::Alias(target) = &by_name[name].data.body {
+            add_ref(target, &mut dependency_refs);
+            if dependency_refs.contains(name) {
+                return vec![name.clone()];
+            }
+        }
+        let Some(target) = group
             .iter()
-            .filter_map(|dep| {
-                let deg = in_degree.get_mut(dep)?;
-                *deg -= 1;
-                if *deg == 0 { Some(dep.clone()) } else { None }
-            })
-            .collect();
-        queue.extend(newly_free);
+            .find(|member| *member != name && dependency_refs.contains(*member))
+        else {
+            continue;
+        };
+
+        // Shortest path from `target` back to `name` inside the group.
+        let mut previous: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut queue = VecDeque::from([target.as_str()]);
+        while let Some(node) = queue.pop_front() {
+            if node == name {
+                break;
+            }
+            for next in &deps[node] {
+                if group.contains(next) && next != target && !previous.contains_key(next.as_str()) {
+                    previous.insert(next, node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut cycle = vec![];
+        let mut node = name.as_str();
+        while let Some(&prev) = previous.get(node) {
+            cycle.push(prev.to_string());
+            node = prev;
+        }
+        cycle.push(name.clone());
+        cycle.reverse();
+        return cycle;
     }
+    vec![]
+}
 
-    if sorted.len() == module.len() {
-        Ok(sorted)
-    } else {
-        let cycle_names = find_cycle(&deps);
-        let cycle = cycle_names
-            .into_iter()
-            .map(|name| {
-                let loc = by_name
-                    .get(&name)
-                    .map_or_else(Location::default, |def| def.loc.into());
-                (name, loc)
-            })
-            .collect();
-        Err(Error::Cycle(cycle))
+/// Returns names of all types referenced by the declaration,
+/// including itself if it is recursive.
+#[must_use]
+pub fn references<Loc, Str: ToString>(
+    def: &Definition<Loc, Str, p::TypeDeclaration<Loc, Str>>,
+) -> BTreeSet<String> {
+    let mut refs = BTreeSet::new();
+    for dep in &def.data.dependencies {
+        add_ref(&dep.data, &mut refs);
+    }
+    match &def.data.body {
+        p::TypeDefinition::Message(fields) => {
+            for field in fields {
+                add_ref(&field.data, &mut refs);
+            }
+        }
+        p::TypeDefinition::Enum(branches) => {
+            for branch in branches {
+                for ctor in &branch.constructors {
+                    for field in &ctor.data {
+                        add_ref(&field.data, &mut refs);
+                    }
+                }
+            }
+        }
+        p::TypeDefinition::Alias(target) => add_ref(target, &mut refs),
     }
+    refs
 }
 
-/// Returns the names and locations of types that have no initial constructor
+/// Returns the names and locations of types that have no initial constructor.
+///
+/// Constructor is initial if none of its fields refer to the group of mutually
+/// recursive types it belongs to, except the types already known to have one.
+/// Aliases have no constructors and are never reported.
 #[must_use]
 pub fn check_initial_constructors<Loc, Str>(
     module: &p::Module<Loc, Str>,
@@ -117,87 +310,69 @@ where
     Loc: Into<Location<Offset>> + Copy,
     Str: ToString,
 {
+    let mut groups = BTreeMap::new();
+    for (index, group) in strongly_connected_components(&dependency_graph(module))
+        .into_iter()
+        .enumerate()
+    {
+        groups.extend(group.into_iter().map(|name| (name, index)));
+    }
+
+    let mut initial: BTreeSet<String> = BTreeSet::new();
+    loop {
+        let found: Vec<String> = module
+            .iter()
+            .filter(|def| !initial.contains(&def.name.to_string()))
+            .filter(|def| {
+                let group = groups[&def.name.to_string()];
+                let is_initial = |fields: &p::ConstructorBody<Loc, Str>| {
+                    fields.iter().all(|field| {
+                        field_type_name(field).is_none_or(|name| {
+                            groups.get(&name) != Some(&group) || initial.contains(&name)
+                        })
+                    })
+                };
+                match &def.data.body {
+                    p::TypeDefinition::Message(fields) => is_initial(fields),
+                    p::TypeDefinition::Enum(branches) => branches
+                        .iter()
+                        .flat_map(|branch| &branch.constructors)
+                        .any(|ctor| is_initial(&ctor.data)),
+                    p::TypeDefinition::Alias(_) => true,
+                }
+            })
+            .map(|def| def.name.to_string())
+            .collect();
+        if found.is_empty() {
+            break;
+        }
+        initial.extend(found);
+    }
+
     module
         .iter()
-        .filter(|def| match &def.data.body {
-            p::TypeDefinition::Message(fields) => !fields
-                .iter()
-                .all(|field| is_self_recursive(&def.name, field)),
-            p::TypeDefinition::Enum(branches) => !branches.iter().any(|branch| {
-                branch.constructors.iter().any(|ctor| {
-                    ctor.data
-                        .iter()
-                        .all(|field| is_self_recursive(&def.name, field))
-                })
-            }),
-        })
+        .filter(|def| !initial.contains(&def.name.to_string()))
         .map(|def| (def.name.to_string(), def.loc.into()))
         .collect()
 }
 
-fn is_self_recursive<Loc, Str: ToString>(
-    self_name: &Str,
-    field: &Definition<Loc, Str, p::TypeExpression<Loc, Str>>,
-) -> bool {
+fn field_type_name<Loc, Str: ToString>(
+    field: &Definition<Loc, Str, p::Typed<Loc, Str>>,
+) -> Option<String> {
     if let p::ExpressionNode::FunCall { fun, .. } = &field.data.node {
-        fun.to_string() != self_name.to_string()
+        Some(fun.to_string())
     } else {
-        true
+        None
     }
 }
 
 fn add_ref<Loc, Str: ToString>(expr: &p::Expression<Loc, Str>, refs: &mut BTreeSet<String>) {
-    if let p::ExpressionNode::FunCall { fun, .. } = &expr.node {
+    if let p::ExpressionNode::FunCall { fun, args } = &expr.node {
         refs.insert(fun.to_string());
-    }
-}
-
-fn find_cycle(deps: &BTreeMap<String, BTreeSet<String>>) -> Vec<String> {
-    let mut visited = BTreeSet::new();
-    let mut in_progress = BTreeSet::new();
-    let mut path = Vec::new();
-
-    for start in deps.keys() {
-        if let Some(cycle) = dfs(
-            start.as_str(),
-            deps,
-            &mut visited,
-            &mut in_progress,
-            &mut path,
-        ) {
-            return cycle;
+        for arg in args.iter() {
+            add_ref(arg, refs);
         }
     }
-    vec![]
-}
-
-fn dfs<'a>(
-    node: &'a str,
-    deps: &'a BTreeMap<String, BTreeSet<String>>,
-    visited: &mut BTreeSet<&'a str>,
-    in_progress: &mut BTreeSet<&'a str>,
-    path: &mut Vec<&'a str>,
-) -> Option<Vec<String>> {
-    if visited.contains(node) {
-        return None;
-    }
-    if in_progress.contains(node) {
-        let start = path.iter().position(|&n| n == node).unwrap_or(0);
-        return Some(path[start..].iter().map(ToString::to_string).collect());
-    }
-    in_progress.insert(node);
-    path.push(node);
-    if let Some(node_deps) = deps.get(node) {
-        for dep in node_deps {
-            if let Some(cycle) = dfs(dep.as_str(), deps, visited, in_progress, path) {
-                return Some(cycle);
-            }
-        }
-    }
-    path.pop();
-    in_progress.remove(node);
-    visited.insert(node);
-    None
 }
 
 #[cfg(test)]
@@ -212,13 +387,17 @@ mod tests {
     type Loc = Location<Offset>;
     type Str = String;
 
-    fn fun_call(name: &str) -> p::TypeExpression<Loc, Str> {
-        p::Expression {
-            loc: Loc::default(),
-            node: ExpressionNode::FunCall {
-                fun: name.to_string(),
-                args: Arc::from(vec![].into_boxed_slice()),
+    fn fun_call(name: &str) -> p::Typed<Loc, Str> {
+        p::Typed {
+            ty: p::Expression {
+                loc: Loc::default(),
+                node: ExpressionNode::FunCall {
+                    fun: name.to_string(),
+                    args: Arc::from(vec![].into_boxed_slice()),
+                },
             },
+            refinement: None,
+            default: None,
         }
     }
 
@@ -251,16 +430,22 @@ mod tests {
             data: TypeDeclaration {
                 dependencies,
                 body: TypeDefinition::Message(fields),
+                allowed_lints: vec![],
             },
         }
     }
 
-    fn names<'a>(sorted: &[&'a Definition<Loc, Str, TypeDeclaration<Loc, Str>>]) -> Vec<&'a str> {
-        sorted.iter().map(|d| d.name.as_str()).collect()
+    type Groups<'a> = [Vec<&'a Definition<Loc, Str, TypeDeclaration<Loc, Str>>>];
+
+    fn names<'a>(sorted: &Groups<'a>) -> Vec<&'a str> {
+        sorted.iter().flatten().map(|d| d.name.as_str()).collect()
     }
 
-    fn pos(sorted: &[&Definition<Loc, Str, TypeDeclaration<Loc, Str>>], name: &str) -> usize {
-        sorted.iter().position(|d| d.name == name).unwrap()
+    fn pos(sorted: &Groups<'_>, name: &str) -> usize {
+        sorted
+            .iter()
+            .position(|group| group.iter().any(|d| d.name == name))
+            .unwrap()
     }
 
     #[test]
@@ -312,7 +497,7 @@ mod tests {
 
     #[test]
     fn cycle_detected() {
-        let module = vec![make_decl("A", &[], &["B"]), make_decl("B", &[], &["A"])];
+        let module = vec![make_decl("A", &["B"], &[]), make_decl("B", &[], &["A"])];
         let Cycle(cycle) = topological_sort(&module).unwrap_err() else {
             panic!("expected Cycle");
         };
@@ -320,6 +505,59 @@ mod tests {
         assert!(cycle.iter().any(|(n, _)| n == "B"));
     }
 
+    #[test]
+    fn cycle_goes_through_dependency() {
+        let module = vec![
+            make_decl("A", &[], &["B"]),
+            make_decl("B", &[], &["C"]),
+            make_decl("C", &["A"], &["B"]),
+        ];
+        let Cycle(cycle) = topological_sort(&module).unwrap_err() else {
+            panic!("expected Cycle");
+        };
+        let cycle: Vec<_> = cycle.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(cycle, ["C", "A", "B"]);
+    }
+
+    #[test]
+    fn mutually_recursive_fields_form_group() {
+        let module = vec![
+            make_decl("Stmt", &[], &["Expr"]),
+            make_decl("Expr", &[], &["Stmt", "Int"]),
+            make_decl("Program", &[], &["Stmt"]),
+            make_decl("Int", &[], &[]),
+        ];
+        let sorted = topological_sort(&module).unwrap();
+        assert_eq!(sorted.len(), 3);
+        assert!(pos(&sorted, "Int") < pos(&sorted, "Expr"));
+        assert_eq!(pos(&sorted, "Expr"), pos(&sorted, "Stmt"));
+        assert!(pos(&sorted, "Stmt") < pos(&sorted, "Program"));
+    }
+
+    #[test]
+    fn partial_sort_reports_every_cycle() {
+        let module = vec![
+            make_decl("A", &["B"], &[]),
+            make_decl("B", &[], &["A"]),
+            make_decl("C", &[], &["D"]),
+            make_decl("D", &["C"], &[]),
+            make_decl("E", &[], &["A"]),
+            make_decl("F", &[], &[]),
+        ];
+        let (sorted, cycles) = topological_sort_partial(&module);
+        assert_eq!(names(&sorted), ["F"]);
+        let cycles: Vec<Vec<String>> = cycles
+            .into_iter()
+            .map(|error| {
+                let Cycle(cycle) = error else {
+                    panic!("expected Cycle error");
+                };
+                cycle.into_iter().map(|(name, _)| name).collect()
+            })
+            .collect();
+        assert_eq!(cycles, [["A", "B"], ["D", "C"]]);
+    }
+
     #[test]
     fn self_reference_not_a_cycle() {
         let module = vec![make_decl("List", &[], &["List"])];
@@ -370,10 +608,67 @@ mod tests {
             data: TypeDeclaration {
                 dependencies: vec![],
                 body: TypeDefinition::Enum(vec![branch]),
+                allowed_lints: vec![],
             },
         }
     }
 
+    fn make_alias_decl(
+        name: &str,
+        target: &str,
+    ) -> Definition<Loc, Str, TypeDeclaration<Loc, Str>> {
+        Definition {
+            loc: Loc::default(),
+            name: name.to_string(),
+            data: TypeDeclaration {
+                dependencies: vec![],
+                body: TypeDefinition::Alias(fun_call(target).ty),
+                allowed_lints: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn alias_goes_after_target() {
+        let module = vec![
+            make_decl("B", &[], &["Id"]),
+            make_alias_decl("Id", "A"),
+            make_decl("A", &[], &[]),
+        ];
+        let sorted = topological_sort(&module).unwrap();
+        assert!(pos(&sorted, "A") < pos(&sorted, "Id"));
+        assert!(pos(&sorted, "Id") < pos(&sorted, "B"));
+    }
+
+    #[test]
+    fn alias_referencing_itself_is_cycle() {
+        let module = vec![make_alias_decl("Loop", "Loop")];
+        let Cycle(cycle) = topological_sort(&module).unwrap_err() else {
+            panic!("expected Cycle");
+        };
+        let cycle: Vec<_> = cycle.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(cycle, ["Loop"]);
+    }
+
+    #[test]
+    fn alias_in_recursive_group_is_cycle() {
+        let module = vec![
+            make_alias_decl("Ref", "Node"),
+            make_decl("Node", &[], &["Ref"]),
+        ];
+        let Cycle(cycle) = topological_sort(&module).unwrap_err() else {
+            panic!("expected Cycle");
+        };
+        let cycle: Vec<_> = cycle.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(cycle, ["Ref", "Node"]);
+    }
+
+    #[test]
+    fn alias_has_no_initial_constructor_check() {
+        let module = vec![make_alias_decl("Id", "UInt")];
+        assert!(check_initial_constructors(&module).is_empty());
+    }
+
     #[test]
     fn message_no_fields_has_initial() {
         let module = vec![make_decl("Unit", &[], &[])];
@@ -415,6 +710,37 @@ mod tests {
         assert_eq!(errs[0].0, "Bad");
     }
 
+    #[test]
+    fn group_without_base_case_no_initial() {
+        let module = vec![
+            make_enum_decl("Expr", &[&["Stmt"]]),
+            make_enum_decl("Stmt", &[&["Expr"], &["Expr", "Stmt"]]),
+        ];
+        let errs = check_initial_constructors(&module);
+        let names: Vec<_> = errs.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["Expr", "Stmt"]);
+    }
+
+    #[test]
+    fn group_with_base_case_has_initial() {
+        let module = vec![
+            make_enum_decl("Expr", &[&["Int"], &["Stmt"]]),
+            make_enum_decl("Stmt", &[&["Expr", "Stmt"], &["Expr"]]),
+        ];
+        assert!(check_initial_constructors(&module).is_empty());
+    }
+
+    #[test]
+    fn reference_outside_group_is_initial() {
+        let module = vec![
+            make_decl("Bad", &[], &["Bad"]),
+            make_decl("UsesBad", &[], &["Bad"]),
+        ];
+        let errs = check_initial_constructors(&module);
+        let names: Vec<_> = errs.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["Bad"]);
+    }
+
     #[test]
     fn multiple_types_reports_all_bad() {
         let module = vec![
@@ -0,0 +1,279 @@
+//! Lints of parsed declarations, which report suspicious places of valid schemas.
+//!
+//! Lints are syntactic: a dependency is used if its name is referenced in
+//! types or refinements of later dependencies and fields, or if an enum
+//! branch matches it by a pattern other than a variable or `*`.
+//!
+//! Lints allowed by `dbuf:allow` pragma of a declaration are not reported for it.
+
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::iter::zip;
+
+use super::{DefRef, Loc, Name, Str, graph};
+use crate::ast::elaborated::split_qualified;
+use crate::ast::operators::OpCall;
+use crate::ast::parsed::{self as p, definition::Definitions};
+use crate::error::elaborating::{ElaboratingStage, Error, Lint};
+
+/// Checks dependencies and field names of `type_def` and lint names of its pragma.
+pub(super) fn check_declaration(type_def: DefRef<'_>) -> Vec<ElaboratingStage> {
+    let mut warnings = unknown_lints(type_def);
+    warnings.extend(unused_dependencies(type_def));
+    warnings.extend(shadowed_dependencies(type_def));
+    warnings
+}
+
+/// Reports declarations of `module` that are referenced neither by its other declarations
+/// nor by `importers`, unless they allow it.
+///
+/// References of `importers` aren't resolved, so a declaration is referenced by them
+/// if they mention either its qualified name or its bare name.
+pub(super) fn check_references(
+    module: &p::Module<Loc, Name>,
+    importers: &[&p::Module<Loc, Name>],
+) -> Vec<ElaboratingStage> {
+    let referenced: BTreeSet<String> = module
+        .iter()
+        .flat_map(|type_def| {
+            let name = type_def.name.to_string();
+            graph::references(type_def)
+                .into_iter()
+                .filter(move |reference| *reference != name)
+        })
+        .collect();
+    let referenced_by_importers: BTreeSet<String> = importers
+        .iter()
+        .flat_map(|importer| importer.iter())
+        .flat_map(graph::references)
+        .collect();
+    let is_referenced = |name: &str| {
+        let (_, bare) = split_qualified(name);
+        referenced.contains(name)
+            || referenced_by_importers.contains(name)
+            || referenced_by_importers.contains(bare)
+    };
+
+    module
+        .iter()
+        .filter(|type_def| !is_referenced(type_def.name.as_ref()))
+        .flat_map(|type_def| {
+            let warning = ElaboratingStage {
+                error: Error::UnreferencedType(type_def.name.to_string()),
+                loc: Some(Loc::from(&type_def.name)),
+            };
+            silence(type_def, vec![warning])
+        })
+        .collect()
+}
+
+/// Reports messages and enums of `module` which name in lower case is the name
+/// of an earlier one in lower case, unless their declarations allow it.
+///
+/// Aliases are skipped, as generated code declares no namespace for them.
+pub(super) fn check_type_names(module: &p::Module<Loc, Name>) -> Vec<ElaboratingStage> {
+    let mut namespaces: BTreeMap<String, String> = BTreeMap::new();
+    module
+        .iter()
+        .filter(|type_def| !matches!(type_def.body, p::TypeDefinition::Alias(_)))
+        .flat_map(|type_def| {
+            let name = type_def.name.to_string();
+            let warnings = match namespaces.entry(name.to_lowercase()) {
+                Entry::Occupied(other) => vec![ElaboratingStage {
+                    error: Error::NamespaceClash(name, other.get().clone()),
+                    loc: Some(Loc::from(&type_def.name)),
+                }],
+                Entry::Vacant(entry) => {
+                    entry.insert(name);
+                    vec![]
+                }
+            };
+            silence(type_def, warnings)
+        })
+        .collect()
+}
+
+/// Leaves out warnings of lints allowed for `type_def`.
+pub(super) fn silence(
+    type_def: DefRef<'_>,
+    warnings: Vec<ElaboratingStage>,
+) -> Vec<ElaboratingStage> {
+    warnings
+        .into_iter()
+        .filter(|warning| {
+            !warning.error.lint().is_some_and(|lint| {
+                type_def
+                    .allowed_lints
+                    .iter()
+                    .any(|name| name == lint.name())
+            })
+        })
+        .collect()
+}
+
+fn unknown_lints(type_def: DefRef<'_>) -> Vec<ElaboratingStage> {
+    type_def
+        .allowed_lints
+        .iter()
+        .filter(|name| Lint::from_name(name).is_none())
+        .map(|name| ElaboratingStage {
+            error: Error::UnknownLint(name.clone()),
+            loc: Some(Loc::from(&type_def.name)),
+        })
+        .collect()
+}
+
+fn unused_dependencies(type_def: DefRef<'_>) -> Vec<ElaboratingStage> {
+    type_def
+        .dependencies
+        .iter()
+        .enumerate()
+        .filter(|&(index, _)| !is_dependency_used(type_def, index))
+        .map(|(_, dependency)| ElaboratingStage {
+            error: Error::UnusedDependency(dependency.name.to_string()),
+            loc: Some(Loc::from(&dependency.name)),
+        })
+        .collect()
+}
+
+fn is_dependency_used(type_def: DefRef<'_>, index: usize) -> bool {
+    let name = &type_def.dependencies[index].name.content;
+    let in_dependencies = type_def.dependencies[index + 1..]
+        .iter()
+        .any(|dependency| typed_uses(&dependency.data, name));
+
+    in_dependencies
+        || match &type_def.body {
+            p::TypeDefinition::Message(fields) => fields_use(fields, name),
+            p::TypeDefinition::Alias(target) => uses(target, name),
+            p::TypeDefinition::Enum(branches) => branches.iter().any(|branch| {
+                let matched =
+                    branch
+                        .patterns
+                        .get(index)
+                        .is_some_and(|pattern| match &pattern.node {
+                            p::PatternNode::Variable { name: variable } => {
+                                constructors_use(branch, &variable.content)
+                            }
+                            p::PatternNode::Underscore => false,
+                            p::PatternNode::ConstructorCall { .. } | p::PatternNode::Literal(_) => {
+                                true
+                            }
+                        });
+                let shadowed = branch
+                    .patterns
+                    .iter()
+                    .flat_map(pattern_variables)
+                    .any(|(variable, _)| variable.content == *name);
+                matched || (!shadowed && constructors_use(branch, name))
+            }),
+        }
+}
+
+fn shadowed_dependencies(type_def: DefRef<'_>) -> Vec<ElaboratingStage> {
+    let mut bindings = vec![];
+    match &type_def.body {
+        p::TypeDefinition::Message(fields) => bindings.extend(field_names(fields)),
+        p::TypeDefinition::Alias(_) => {}
+        p::TypeDefinition::Enum(branches) => {
+            for branch in branches {
+                for (pattern, dependency) in zip(&branch.patterns, &type_def.dependencies) {
+                    // Variable named as the matched dependency binds the same value.
+                    if let p::PatternNode::Variable { name } = &pattern.node
+                        && name.content == dependency.name.content
+                    {
+                        continue;
+                    }
+                    bindings.extend(pattern_variables(pattern));
+                }
+                for ctor in &branch.constructors {
+                    bindings.extend(field_names(ctor));
+                }
+            }
+        }
+    }
+
+    bindings
+        .into_iter()
+        .filter(|(name, _)| {
+            type_def
+                .dependencies
+                .iter()
+                .any(|dependency| dependency.name.content == name.content)
+        })
+        .map(|(name, loc)| ElaboratingStage {
+            error: Error::ShadowedDependency(name.to_string()),
+            loc: Some(loc),
+        })
+        .collect()
+}
+
+fn field_names(fields: &p::ConstructorBody<Loc, Name>) -> Vec<(&Name, Loc)> {
+    fields
+        .iter()
+        .map(|field| (&field.name, Loc::from(&field.name)))
+        .collect()
+}
+
+/// Variables bound by `pattern` with their locations.
+fn pattern_variables(pattern: &p::Pattern<Loc, Name>) -> Vec<(&Name, Loc)> {
+    match &pattern.node {
+        p::PatternNode::Variable { name } => vec![(name, pattern.loc)],
+        p::PatternNode::ConstructorCall { fields, .. } => fields
+            .iter()
+            .flat_map(|field| pattern_variables(&field.data))
+            .collect(),
+        p::PatternNode::Literal(_) | p::PatternNode::Underscore => vec![],
+    }
+}
+
+/// Whether types of fields of `branch` use variable `name`.
+fn constructors_use(branch: &p::EnumBranch<Loc, Name>, name: &Str) -> bool {
+    branch
+        .constructors
+        .iter()
+        .any(|ctor| fields_use(&ctor.data, name))
+}
+
+/// Whether types of `fields` use variable `name` before a field shadows it.
+///
+/// Defaults only see dependencies, so fields never shadow them there.
+fn fields_use(fields: &Definitions<Loc, Name, p::Typed<Loc, Name>>, name: &Str) -> bool {
+    for field in fields {
+        if uses(&field.data.ty, name) || field.data.default.as_ref().is_some_and(|d| uses(d, name))
+        {
+            return true;
+        }
+        if field.name.content == *name {
+            return false;
+        }
+        if field
+            .data
+            .refinement
+            .as_ref()
+            .is_some_and(|r| uses(r, name))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+fn typed_uses(typed: &p::Typed<Loc, Name>, name: &Str) -> bool {
+    uses(&typed.ty, name) || typed.refinement.as_ref().is_some_and(|r| uses(r, name))
+}
+
+fn uses(expr: &p::Expression<Loc, Name>, name: &Str) -> bool {
+    match &expr.node {
+        p::ExpressionNode::OpCall(OpCall::Literal(_)) | p::ExpressionNode::TypedHole => false,
+        p::ExpressionNode::OpCall(OpCall::Unary(_, arg)) => uses(arg, name),
+        p::ExpressionNode::OpCall(OpCall::Binary(_, lhs, rhs)) => {
+            uses(lhs, name) || uses(rhs, name)
+        }
+        p::ExpressionNode::FunCall { args, .. } => args.iter().any(|arg| uses(arg, name)),
+        p::ExpressionNode::ConstructorCall { fields, .. } => {
+            fields.iter().any(|field| uses(&field.data, name))
+        }
+        p::ExpressionNode::Variable { name: variable } => variable.content == *name,
+    }
+}
@@ -1,6 +1,7 @@
 use crate::ast::elaborated::ConstructorNames;
 use crate::ast::elaborated::{
-    Constructor, Context, Module, Rec, Type, TypeExpression, ValueExpression, ValueExprs,
+    Alias, Constructor, Context, Module, Rec, Refinements, Type, TypeExpression, ValueExpression,
+    ValueExprs,
 };
 use crate::ast::operators::{OpCall, UnaryOp};
 use std::hash::Hash;
@@ -12,6 +13,7 @@ where
     B: Clone + Ord + Hash + Eq,
 {
     Module {
+        package: module.package.into_iter().map(f).collect(),
         constructors: module
             .constructors
             .into_iter()
@@ -22,6 +24,23 @@ where
             .into_iter()
             .map(|(name, ty)| (f(name.clone()), map_type(ty, f)))
             .collect(),
+        aliases: module
+            .aliases
+            .into_iter()
+            .map(|(name, alias)| (f(name), map_alias(alias, f)))
+            .collect(),
+    }
+}
+
+fn map_alias<A, B, F>(alias: Alias<A>, f: &F) -> Alias<B>
+where
+    F: Fn(A) -> B,
+    A: Clone,
+{
+    Alias {
+        dependencies: map_context(alias.dependencies, f),
+        refinements: map_refinements(alias.refinements, f),
+        target: map_type_expression(alias.target, f),
     }
 }
 
@@ -39,6 +58,7 @@ where
                 ConstructorNames::OfEnum(names.into_iter().map(f).collect())
             }
         },
+        refinements: map_refinements(ty.refinements, f),
     }
 }
 
@@ -51,6 +71,8 @@ where
         implicits: map_context(ctor.implicits, f),
         fields: map_context(ctor.fields, f),
         result_type: map_type_expression(ctor.result_type, f),
+        refinements: map_refinements(ctor.refinements, f),
+        defaults: map_refinements(ctor.defaults, f),
     }
 }
 
@@ -64,6 +86,17 @@ where
         .collect()
 }
 
+fn map_refinements<A, B, F>(refinements: Refinements<A>, f: &F) -> Refinements<B>
+where
+    F: Fn(A) -> B,
+    A: Clone,
+{
+    refinements
+        .into_iter()
+        .map(|(name, predicate)| (f(name), map_value_expression(predicate, f)))
+        .collect()
+}
+
 fn map_type_expression<A, B, F>(ty: TypeExpression<A>, f: &F) -> TypeExpression<B>
 where
     F: Fn(A) -> B,
@@ -119,6 +152,9 @@ where
             op_call: map_op_call(op_call, f),
             result_type: map_type_expression(result_type, f),
         },
+        ValueExpression::Type { ty } => ValueExpression::Type {
+            ty: map_type_expression(ty, f),
+        },
     }
 }
 
@@ -248,11 +284,13 @@ mod tests {
     #[test]
     fn module_maps_all_names() {
         let module = Module {
+            package: vec![],
             types: IndexMap::from([(
                 "Foo".to_owned(),
                 Type {
                     dependencies: vec![("n".to_owned(), ty("Nat"))],
                     constructor_names: ConstructorNames::OfMessage("Foo".to_owned()),
+                    refinements: vec![],
                 },
             )]),
             constructors: BTreeMap::from([(
@@ -261,6 +299,16 @@ mod tests {
                     implicits: vec![],
                     fields: vec![("x".to_owned(), ty("Int"))],
                     result_type: ty("Foo"),
+                    refinements: vec![],
+                    defaults: vec![],
+                },
+            )]),
+            aliases: IndexMap::from([(
+                "Foos".to_owned(),
+                Alias {
+                    dependencies: vec![],
+                    refinements: vec![],
+                    target: ty("Foo"),
                 },
             )]),
         };
@@ -276,5 +324,6 @@ mod tests {
             vec![("x_".to_owned(), ty("Int_"))]
         );
         assert_eq!(result.constructors["Foo_"].result_type, ty("Foo_"));
+        assert_eq!(result.aliases["Foos_"].target, ty("Foo_"));
     }
 }
@@ -7,11 +7,18 @@ use crate::location::{LocatedName, Location, Offset};
 pub mod apply;
 pub mod builtins;
 pub mod context;
+mod coverage;
+pub mod derivable;
 pub mod graph;
+mod lints;
 pub mod map_ast;
 pub mod normalize;
 pub mod operators;
+pub mod pretty;
+pub mod refinement;
+mod resolve;
 pub mod subst;
+mod suggest;
 pub mod typecheck;
 pub mod unify;
 
@@ -21,9 +28,12 @@ type Name = LocatedName<Str, Offset>;
 type TypeExpr = e::TypeExpression<Str>;
 type Value = e::ValueExpression<Str>;
 type ElaboratedCtx = e::Context<Str>;
+type Refinements = e::Refinements<Str>;
+type Defaults = e::Defaults<Str>;
 type Mod = e::Module<Str>;
 type Ctx<'a> = context::Context<'a, Str, TypeExpr, Value>;
 type ElaboratedDeclaration = (Str, e::Type<Str>, Vec<(Str, e::Constructor<Str>)>);
+type DefRef<'a> = &'a p::definition::Definition<Loc, Name, p::TypeDeclaration<Loc, Name>>;
 type Binds<Str> = Vec<(Str, e::ValueExpression<Str>)>;
 type Bindings = Binds<Str>;
 
@@ -37,33 +47,104 @@ impl From<&o::UnaryOp<Name>> for o::UnaryOp<Str> {
     }
 }
 
-/// # Errors
-pub fn elaborate(module: &p::Module<Loc, Name>) -> Result<Mod, ElaboratingError> {
-    let sorted = graph::topological_sort(module).map_err(|error| {
+/// Elaborates `module`.
+///
+/// Returns elaborated declarations and errors of the ones that failed, see
+/// [`elaborate_in_package`].
+#[must_use]
+pub fn elaborate(module: &p::Module<Loc, Name>) -> (Mod, Vec<ElaboratingError>) {
+    elaborate_with_imports(module, &[])
+}
+
+/// Elaborates `module` of a file that imports already elaborated `imports`.
+///
+/// Types and constructors of `imports` are visible in `module`, but the
+/// result contains only declarations of `module` itself.
+#[must_use]
+pub fn elaborate_with_imports(
+    module: &p::Module<Loc, Name>,
+    imports: &[&Mod],
+) -> (Mod, Vec<ElaboratingError>) {
+    elaborate_in_package(module, &[], imports)
+}
+
+/// Elaborates `module` of a file declared in `package`.
+///
+/// Declarations of `module` are qualified with `package`, and references
+/// are resolved against it and declarations of `imports`.
+///
+/// Elaboration doesn't stop at the first error: declaration that fails is
+/// left out of the result together with declarations that depend on it,
+/// and the rest is elaborated. So the module is complete only if there are no errors,
+/// except warnings (see [`crate::error::Error::is_warning`]), e.g. about unreachable enum branches.
+///
+/// Warnings of lints (see [`crate::error::elaborating::Lint`]) are silenced for a declaration
+/// by a comment right before it, e.g. `// dbuf:allow(unused_dependency)`.
+#[must_use]
+pub fn elaborate_in_package(
+    module: &p::Module<Loc, Name>,
+    package: &[Str],
+    imports: &[&Mod],
+) -> (Mod, Vec<ElaboratingError>) {
+    elaborate_in_graph(module, package, imports, &[])
+}
+
+/// Elaborates `module` of a file declared in `package`, which is imported by files
+/// with parsed modules `importers`.
+///
+/// Same as [`elaborate_in_package`], but types referenced by `importers` are not
+/// reported as never referenced, since types of a library file are used by its importers.
+#[must_use]
+pub fn elaborate_in_graph(
+    module: &p::Module<Loc, Name>,
+    package: &[Str],
+    imports: &[&Mod],
+    importers: &[&p::Module<Loc, Name>],
+) -> (Mod, Vec<ElaboratingError>) {
+    let module_ctx = imports
+        .iter()
+        .fold(builtins::builtins_module::<Str>(), |ctx, &imported| {
+            ctx.merge(imported.clone())
+        });
+
+    let module = &resolve::Resolver::new(module, package, &module_ctx).module(module);
+
+    let mut errors = vec![];
+
+    let (sorted, cycles) = graph::topological_sort_partial(module);
+    errors.extend(cycles.into_iter().map(|error| {
         let loc = match &error {
             Error::Cycle(entries) => entries.first().map(|(_, loc)| *loc),
             _ => None,
         };
         ElaboratingStage { error, loc }
-    })?;
+    }));
 
     let missing = graph::check_initial_constructors(module);
     if !missing.is_empty() {
         let loc = missing.first().map(|(_, loc)| *loc);
-        return Err(ElaboratingStage {
+        errors.push(ElaboratingStage {
             error: Error::NoInitialConstructor(missing),
             loc,
-        }
-        .into());
+        });
     }
 
-    Ok(typecheck::elaborate_sorted(&sorted)?)
+    let (mut elaborated, elaboration_errors) = typecheck::elaborate_sorted(module_ctx, &sorted);
+    errors.extend(elaboration_errors);
+    errors.extend(lints::check_references(module, importers));
+    errors.extend(lints::check_type_names(module));
+    elaborated.package = package.to_vec();
+
+    (elaborated, errors.into_iter().map(Into::into).collect())
 }
 
-fn type_of<Str: Clone>(expr: &e::ValueExpression<Str>) -> e::TypeExpression<Str> {
+fn type_of<Str: Clone + From<builtins::BuiltinType>>(
+    expr: &e::ValueExpression<Str>,
+) -> e::TypeExpression<Str> {
     match expr {
         e::ValueExpression::Variable { ty, .. } => ty.clone(),
         e::ValueExpression::Constructor { result_type, .. } => result_type.clone(),
         e::ValueExpression::OpCall { result_type, .. } => result_type.clone(),
+        e::ValueExpression::Type { .. } => builtins::get_builtin(&builtins::BuiltinType::Type),
     }
 }
@@ -1,5 +1,6 @@
 use crate::ast::elaborated::{TypeExpression, ValueExpression};
 use crate::ast::operators::{BinaryOp, UnaryOp};
+use crate::elaboration::builtins::BuiltinType;
 use crate::elaboration::operators;
 
 use super::types::{ArithCoeff, Mono, NormalForm};
@@ -9,26 +10,22 @@ pub fn poly_to_expr<Str, C>(
     result_type: TypeExpression<Str>,
 ) -> ValueExpression<Str>
 where
-    Str: Clone + PartialEq,
+    Str: Clone + PartialEq + From<BuiltinType>,
     C: ArithCoeff,
 {
     if nf.poly.is_empty() {
-        return operators::make_lit(C::zero().to_literal(), result_type);
+        return make_coeff(C::zero(), result_type);
     }
 
     let mut pos: Vec<ValueExpression<Str>> = Vec::new();
     let mut neg: Vec<ValueExpression<Str>> = Vec::new();
 
     for (mono, &coeff) in &nf.poly {
-        if coeff.is_neg() {
-            neg.push(mono_to_expr(
-                mono,
-                coeff.coeff_abs(),
-                &nf.vars,
-                &result_type,
-            ));
-        } else {
-            pos.push(mono_to_expr(mono, coeff, &nf.vars, &result_type));
+        match coeff.checked_abs() {
+            Some(abs) if coeff.is_neg() => {
+                neg.push(mono_to_expr(mono, abs, &nf.vars, &result_type));
+            }
+            _ => pos.push(mono_to_expr(mono, coeff, &nf.vars, &result_type)),
         }
     }
 
@@ -40,7 +37,7 @@ where
         .reduce(|acc, t| operators::make_binary(BinaryOp::Plus, acc, t, result_type.clone()));
 
     match (pos_sum, neg_sum) {
-        (None, None) => operators::make_lit(C::zero().to_literal(), result_type),
+        (None, None) => make_coeff(C::zero(), result_type),
         (Some(p), None) => p,
         (None, Some(n)) => operators::make_unary(UnaryOp::Minus, n, result_type),
         (Some(p), Some(n)) => operators::make_binary(BinaryOp::Minus, p, n, result_type),
@@ -54,7 +51,7 @@ fn mono_to_expr<Str, C>(
     result_type: &TypeExpression<Str>,
 ) -> ValueExpression<Str>
 where
-    Str: Clone + PartialEq,
+    Str: Clone + PartialEq + From<BuiltinType>,
     C: ArithCoeff,
 {
     let mut product: Option<ValueExpression<Str>> = None;
@@ -71,13 +68,24 @@ where
     }
 
     match (coeff == C::one(), product) {
-        (_, None) => operators::make_lit(coeff.to_literal(), result_type.clone()),
+        (_, None) => make_coeff(coeff, result_type.clone()),
         (true, Some(p)) => p,
         (false, Some(p)) => operators::make_binary(
             BinaryOp::Star,
-            operators::make_lit(coeff.to_literal(), result_type.clone()),
+            make_coeff(coeff, result_type.clone()),
             p,
             result_type.clone(),
         ),
     }
 }
+
+fn make_coeff<Str, C>(coeff: C, result_type: TypeExpression<Str>) -> ValueExpression<Str>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+    C: ArithCoeff,
+{
+    let literal = operators::convert_literal(&coeff.to_literal(), &result_type)
+        .and_then(Result::ok)
+        .expect("coefficient fits into its type");
+    operators::make_lit(literal, result_type)
+}
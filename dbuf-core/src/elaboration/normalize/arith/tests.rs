@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use super::{Mono, NormalForm, normalize};
 use crate::ast::elaborated::{TypeExpression, ValueExpression};
-use crate::ast::operators::{BinaryOp, Literal, OpCall, UnaryOp};
+use crate::ast::operators::{BinaryOp, Literal, OpCall, SizedInt, UnaryOp};
 use crate::elaboration::builtins::{BuiltinType, get_builtin};
 
 type Str = String;
@@ -65,13 +65,18 @@ fn mono(pairs: &[(usize, u32)]) -> Mono {
     pairs.iter().copied().collect()
 }
 
+#[track_caller]
+fn normalize_int(expr: &ValueExpression<Str>) -> NormalForm<Str, i64> {
+    normalize(expr).expect("normalization doesn't overflow")
+}
+
 fn poly_coeff(nf: &NormalForm<Str, i64>, m: &Mono) -> i64 {
     *nf.poly.get(m).unwrap_or(&0)
 }
 
 #[track_caller]
 fn assert_poly_eq(lhs: ValueExpression<Str>, rhs: ValueExpression<Str>) {
-    let diff = normalize::<Str, i64>(&sub(lhs, rhs));
+    let diff = normalize_int(&sub(lhs, rhs));
     assert!(
         diff.poly.is_empty(),
         "polynomials are not equal; expected: 0, but got: {:?}",
@@ -81,7 +86,7 @@ fn assert_poly_eq(lhs: ValueExpression<Str>, rhs: ValueExpression<Str>) {
 
 #[test]
 fn literal_constant() {
-    let nf = normalize::<Str, i64>(&lit(42));
+    let nf = normalize_int(&lit(42));
     assert!(nf.vars.is_empty());
     assert_eq!(poly_coeff(&nf, &mono(&[])), 42);
     assert_eq!(nf.poly.len(), 1);
@@ -89,14 +94,14 @@ fn literal_constant() {
 
 #[test]
 fn single_variable() {
-    let nf = normalize::<Str, i64>(&var("x"));
+    let nf = normalize_int(&var("x"));
     assert_eq!(nf.vars, vec![var("x")]);
     assert_eq!(poly_coeff(&nf, &mono(&[(0, 1)])), 1);
 }
 
 #[test]
 fn add_same_variable() {
-    let nf = normalize::<Str, i64>(&add(var("x"), var("x")));
+    let nf = normalize_int(&add(var("x"), var("x")));
     assert_eq!(nf.vars.len(), 1);
     assert_eq!(poly_coeff(&nf, &mono(&[(0, 1)])), 2);
     assert_eq!(nf.poly.len(), 1);
@@ -104,7 +109,7 @@ fn add_same_variable() {
 
 #[test]
 fn sub_cancels() {
-    let nf = normalize::<Str, i64>(&sub(var("x"), var("x")));
+    let nf = normalize_int(&sub(var("x"), var("x")));
     assert!(nf.poly.is_empty());
 }
 
@@ -234,7 +239,7 @@ fn square_of_difference() {
 
 #[test]
 fn constant_folding() {
-    let nf = normalize::<Str, i64>(&add(mul(lit(2), lit(3)), lit(1)));
+    let nf = normalize_int(&add(mul(lit(2), lit(3)), lit(1)));
     assert!(nf.vars.is_empty());
     assert_eq!(poly_coeff(&nf, &mono(&[])), 7);
     assert_eq!(nf.poly.len(), 1);
@@ -271,3 +276,135 @@ fn complex_product() {
         ),
     );
 }
+
+fn lit_i8(n: i8) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::Sized(SizedInt::I8(n))),
+        result_type: get_builtin(&BuiltinType::Int8),
+    }
+}
+
+fn add_i8(lhs: ValueExpression<Str>, rhs: ValueExpression<Str>) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Binary(BinaryOp::Plus, Arc::new(lhs), Arc::new(rhs)),
+        result_type: get_builtin(&BuiltinType::Int8),
+    }
+}
+
+#[test]
+fn sized_constant_folding() {
+    let nf = normalize::<Str, i8>(&add_i8(lit_i8(100), lit_i8(27))).expect("127 fits into i8");
+    assert_eq!(nf.poly.get(&mono(&[])), Some(&127));
+}
+
+#[test]
+fn sized_overflow_is_not_folded() {
+    assert!(normalize::<Str, i8>(&add_i8(lit_i8(100), lit_i8(28))).is_none());
+}
+
+#[test]
+fn int_overflow_is_not_folded() {
+    assert!(normalize::<Str, i64>(&mul(lit(i64::MAX), lit(2))).is_none());
+}
+
+fn opaque(
+    op: BinaryOp,
+    lhs: ValueExpression<Str>,
+    rhs: ValueExpression<Str>,
+) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Binary(op, Arc::new(lhs), Arc::new(rhs)),
+        result_type: int_ty(),
+    }
+}
+
+#[track_caller]
+fn assert_folds_to(expr: &ValueExpression<Str>, expected: i64) {
+    let nf = normalize_int(expr);
+    assert!(nf.vars.is_empty());
+    assert_eq!(poly_coeff(&nf, &mono(&[])), expected);
+}
+
+#[test]
+fn division_folding() {
+    assert_folds_to(&opaque(BinaryOp::Slash, lit(-7), lit(2)), -3);
+    assert_folds_to(&opaque(BinaryOp::Percent, lit(-7), lit(2)), -1);
+    assert_folds_to(&opaque(BinaryOp::Slash, lit(i64::MIN), lit(-1)), i64::MIN);
+    assert_folds_to(&opaque(BinaryOp::Percent, lit(i64::MIN), lit(-1)), 0);
+}
+
+#[test]
+fn shift_and_xor_folding() {
+    assert_folds_to(&opaque(BinaryOp::ShiftLeft, lit(3), lit(2)), 12);
+    assert_folds_to(&opaque(BinaryOp::ShiftRight, lit(-8), lit(1)), -4);
+    assert_folds_to(&opaque(BinaryOp::ShiftLeft, lit(1), lit(64)), 0);
+    assert_folds_to(&opaque(BinaryOp::ShiftLeft, lit(1), lit(-1)), 0);
+    assert_folds_to(&opaque(BinaryOp::ShiftRight, lit(-8), lit(70)), -1);
+    assert_folds_to(&opaque(BinaryOp::Caret, lit(6), lit(3)), 5);
+}
+
+#[test]
+fn sized_division_wraps() {
+    let div = ValueExpression::OpCall {
+        op_call: OpCall::Binary(
+            BinaryOp::Slash,
+            Arc::new(lit_i8(-128)),
+            Arc::new(lit_i8(-1)),
+        ),
+        result_type: get_builtin(&BuiltinType::Int8),
+    };
+    let nf = normalize::<Str, i8>(&div).expect("division is folded");
+    assert_eq!(nf.poly.get(&mono(&[])), Some(&-128));
+}
+
+#[test]
+fn division_by_zero_is_atom() {
+    let div = opaque(BinaryOp::Slash, lit(1), lit(0));
+    let nf = normalize_int(&div);
+    assert_eq!(nf.vars, vec![div]);
+}
+
+#[test]
+fn opaque_call_is_atom() {
+    let half = opaque(BinaryOp::Slash, var("x"), lit(2));
+    let nf = normalize_int(&add(half.clone(), half.clone()));
+    assert_eq!(nf.vars, vec![half]);
+    assert_eq!(poly_coeff(&nf, &mono(&[(0, 1)])), 2);
+
+    let third = opaque(BinaryOp::Slash, var("x"), lit(3));
+    let diff = normalize_int(&sub(opaque(BinaryOp::Slash, var("x"), lit(2)), third));
+    assert_eq!(diff.vars.len(), 2);
+}
+
+fn length(string: ValueExpression<Str>) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Unary(UnaryOp::Access("length".to_owned()), Arc::new(string)),
+        result_type: get_builtin(&BuiltinType::UInt),
+    }
+}
+
+#[test]
+fn string_length() {
+    let string = |s: &str| ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::Str(s.to_owned())),
+        result_type: get_builtin(&BuiltinType::String),
+    };
+    let s = ValueExpression::Variable {
+        name: "s".to_owned(),
+        ty: get_builtin(&BuiltinType::String),
+    };
+    let concat = |lhs, rhs| ValueExpression::OpCall {
+        op_call: OpCall::Binary(BinaryOp::Plus, Arc::new(lhs), Arc::new(rhs)),
+        result_type: get_builtin(&BuiltinType::String),
+    };
+
+    let folded = normalize::<Str, u64>(&length(string("ёж"))).expect("length fits");
+    assert!(folded.vars.is_empty());
+    assert_eq!(folded.poly.get(&mono(&[])), Some(&4));
+
+    let nf =
+        normalize::<Str, u64>(&length(concat(string("urn:"), s.clone()))).expect("length fits");
+    assert_eq!(nf.vars, vec![length(s)]);
+    assert_eq!(nf.poly.get(&mono(&[])), Some(&4));
+    assert_eq!(nf.poly.get(&mono(&[(0, 1)])), Some(&1));
+}
@@ -1,33 +1,43 @@
+use num_traits::NumCast;
+
 use super::types::{ArithCoeff, Mono, NormalForm, Poly};
-use crate::ast::elaborated::ValueExpression;
-use crate::ast::operators::{BinaryOp, OpCall, UnaryOp};
-use crate::elaboration::normalize::find_var;
+use crate::ast::elaborated::{TypeExpression, ValueExpression};
+use crate::ast::operators::{BinaryOp, Literal, OpCall, UnaryOp};
+use crate::elaboration::builtins::{self, BuiltinType};
+use crate::elaboration::normalize::{find_var, strings};
+use crate::elaboration::{operators, type_of};
 
-pub fn normalize<Str, C>(expr: &ValueExpression<Str>) -> NormalForm<Str, C>
+/// Builds polynomial normal form of `expr`.
+///
+/// Returns `None` if folding of constants overflows the coefficient type.
+pub fn normalize<Str, C>(expr: &ValueExpression<Str>) -> Option<NormalForm<Str, C>>
 where
-    Str: Clone + PartialEq,
+    Str: Clone + PartialEq + From<BuiltinType>,
     C: ArithCoeff,
 {
     let mut vars = Vec::new();
-    let poly = expr_to_poly(expr, &mut vars);
-    NormalForm { vars, poly }
+    let poly = expr_to_poly(expr, &mut vars)?;
+    Some(NormalForm { vars, poly })
 }
 
 fn expr_to_poly<Str, C>(
     expr: &ValueExpression<Str>,
     vars: &mut Vec<ValueExpression<Str>>,
-) -> Poly<C>
+) -> Option<Poly<C>>
 where
-    Str: Clone + PartialEq,
+    Str: Clone + PartialEq + From<BuiltinType>,
     C: ArithCoeff,
 {
-    match expr {
+    let poly = match expr {
         ValueExpression::Variable { .. } => {
             let idx = find_var(vars, expr.clone());
             var_poly(idx)
         }
 
-        ValueExpression::OpCall { op_call, .. } => match op_call {
+        ValueExpression::OpCall {
+            op_call,
+            result_type,
+        } => match op_call {
             OpCall::Literal(lit) => {
                 if let Some(c) = C::try_from_literal(lit) {
                     const_poly(c)
@@ -37,27 +47,82 @@ where
                 }
             }
 
-            OpCall::Unary(UnaryOp::Minus, arg) => poly_neg(expr_to_poly(arg, vars)),
+            OpCall::Unary(UnaryOp::Minus, arg) => poly_neg(expr_to_poly(arg, vars)?)?,
+            OpCall::Unary(access @ UnaryOp::Access(_), arg)
+                if builtins::is_string(&type_of(arg)) =>
+            {
+                length_to_poly(access, arg, result_type, vars)?
+            }
 
             OpCall::Binary(BinaryOp::Plus, lhs, rhs) => {
-                poly_add(expr_to_poly(lhs, vars), expr_to_poly(rhs, vars))
+                poly_add(expr_to_poly(lhs, vars)?, expr_to_poly(rhs, vars)?)?
             }
             OpCall::Binary(BinaryOp::Minus, lhs, rhs) => {
-                poly_sub(expr_to_poly(lhs, vars), expr_to_poly(rhs, vars))
+                poly_sub(expr_to_poly(lhs, vars)?, expr_to_poly(rhs, vars)?)?
             }
             OpCall::Binary(BinaryOp::Star, lhs, rhs) => {
-                poly_mul(&expr_to_poly(lhs, vars), &expr_to_poly(rhs, vars))
+                poly_mul(&expr_to_poly(lhs, vars)?, &expr_to_poly(rhs, vars)?)?
+            }
+            OpCall::Binary(op, lhs, rhs) if op.is_opaque() => {
+                // Operands are folded only to be checked for constants, call itself is atom.
+                let folded = match (
+                    poly_const(&expr_to_poly(lhs, &mut Vec::new())?),
+                    poly_const(&expr_to_poly(rhs, &mut Vec::new())?),
+                ) {
+                    (Some(lhs), Some(rhs)) => C::fold_opaque(*op, lhs, rhs),
+                    _ => None,
+                };
+                if let Some(c) = folded {
+                    const_poly(c)
+                } else {
+                    let idx = find_var(vars, expr.clone());
+                    var_poly(idx)
+                }
             }
             _ => {
                 let idx = find_var(vars, expr.clone());
                 var_poly(idx)
             }
         },
-        ValueExpression::Constructor { .. } => {
+        ValueExpression::Constructor { .. } | ValueExpression::Type { .. } => {
             let idx = find_var(vars, expr.clone());
             var_poly(idx)
         }
+    };
+    Some(poly)
+}
+
+/// Length is the only field of `String` and `Bytes`, which is sum of lengths of concatenated
+/// segments. Lengths of literals are folded, as they are counted in bytes.
+fn length_to_poly<Str, C>(
+    access: &UnaryOp<Str>,
+    string: &ValueExpression<Str>,
+    result_type: &TypeExpression<Str>,
+    vars: &mut Vec<ValueExpression<Str>>,
+) -> Option<Poly<C>>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+    C: ArithCoeff,
+{
+    let mut poly = Poly::new();
+    for segment in strings::normalize(string) {
+        let length = match &segment {
+            ValueExpression::OpCall {
+                op_call: OpCall::Literal(Literal::Str(s)),
+                ..
+            } => const_poly(<C as NumCast>::from(s.len())?),
+            ValueExpression::OpCall {
+                op_call: OpCall::Literal(Literal::Bytes(b)),
+                ..
+            } => const_poly(<C as NumCast>::from(b.len())?),
+            _ => {
+                let atom = operators::make_unary(access.clone(), segment, result_type.clone());
+                var_poly(find_var(vars, atom))
+            }
+        };
+        poly = poly_add(poly, length)?;
     }
+    Some(poly)
 }
 
 fn const_poly<C: ArithCoeff>(n: C) -> Poly<C> {
@@ -67,31 +132,41 @@ fn const_poly<C: ArithCoeff>(n: C) -> Poly<C> {
     [(Mono::new(), n)].into_iter().collect()
 }
 
+fn poly_const<C: ArithCoeff>(p: &Poly<C>) -> Option<C> {
+    match p.len() {
+        0 => Some(C::zero()),
+        1 => p.get(&Mono::new()).copied(),
+        _ => None,
+    }
+}
+
 fn var_poly<C: ArithCoeff>(idx: usize) -> Poly<C> {
     let mono: Mono = [(idx, 1)].into_iter().collect();
     [(mono, C::one())].into_iter().collect()
 }
 
-fn poly_neg<C: ArithCoeff>(p: Poly<C>) -> Poly<C> {
-    p.into_iter().map(|(m, c)| (m, c.coeff_neg())).collect()
+fn poly_neg<C: ArithCoeff>(p: Poly<C>) -> Option<Poly<C>> {
+    p.into_iter()
+        .map(|(m, c)| Some((m, c.checked_neg()?)))
+        .collect()
 }
 
-fn poly_add<C: ArithCoeff>(mut lhs: Poly<C>, rhs: Poly<C>) -> Poly<C> {
+fn poly_add<C: ArithCoeff>(mut lhs: Poly<C>, rhs: Poly<C>) -> Option<Poly<C>> {
     for (mono, coeff) in rhs {
         let entry = lhs.entry(mono).or_insert(C::zero());
-        *entry = *entry + coeff;
+        *entry = entry.checked_add(&coeff)?;
     }
     lhs.retain(|_, c| !c.is_zero());
-    lhs
+    Some(lhs)
 }
 
-fn poly_sub<C: ArithCoeff>(mut lhs: Poly<C>, rhs: Poly<C>) -> Poly<C> {
+fn poly_sub<C: ArithCoeff>(mut lhs: Poly<C>, rhs: Poly<C>) -> Option<Poly<C>> {
     for (mono, coeff) in rhs {
         let entry = lhs.entry(mono).or_insert(C::zero());
-        *entry = *entry - coeff;
+        *entry = entry.checked_sub(&coeff)?;
     }
     lhs.retain(|_, c| !c.is_zero());
-    lhs
+    Some(lhs)
 }
 
 fn mono_mul(lhs: &Mono, rhs: &Mono) -> Mono {
@@ -102,17 +177,17 @@ fn mono_mul(lhs: &Mono, rhs: &Mono) -> Mono {
     result
 }
 
-fn poly_mul<C: ArithCoeff>(lhs: &Poly<C>, rhs: &Poly<C>) -> Poly<C> {
+fn poly_mul<C: ArithCoeff>(lhs: &Poly<C>, rhs: &Poly<C>) -> Option<Poly<C>> {
     let mut result = Poly::new();
     for (lm, lc) in lhs {
         for (rm, rc) in rhs {
-            let coeff = *lc * *rc;
+            let coeff = lc.checked_mul(rc)?;
             if !coeff.is_zero() {
                 let entry = result.entry(mono_mul(lm, rm)).or_insert(C::zero());
-                *entry = *entry + coeff;
+                *entry = entry.checked_add(&coeff)?;
             }
         }
     }
     result.retain(|_, c| !c.is_zero());
-    result
+    Some(result)
 }
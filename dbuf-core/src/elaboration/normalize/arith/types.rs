@@ -1,45 +1,70 @@
-use num_traits::{One, Zero};
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, One, PrimInt, Zero};
 use std::collections::BTreeMap;
 
 use crate::ast::elaborated::ValueExpression;
-use crate::ast::operators::Literal;
+use crate::ast::operators::{BinaryOp, Literal, SizedInt};
 
+/// Coefficient of polynomial normal form of integer type.
+///
+/// Arithmetic is checked, so folding that overflows the type is detected.
 pub trait ArithCoeff:
     Clone
     + Copy
     + PartialEq
     + std::fmt::Debug
-    + std::ops::Add<Output = Self>
-    + std::ops::Sub<Output = Self>
-    + std::ops::Mul<Output = Self>
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedNeg
+    + CheckedRem
     + Zero
     + One
+    + PrimInt
 {
-    #[must_use]
-    fn coeff_neg(self) -> Self;
     fn is_neg(self) -> bool;
-    #[must_use]
-    fn coeff_abs(self) -> Self {
+    /// Absolute value, if it is representable.
+    fn checked_abs(self) -> Option<Self> {
         if self.is_neg() {
-            self.coeff_neg()
+            self.checked_neg()
         } else {
-            self
+            Some(self)
         }
     }
     fn try_from_literal(lit: &Literal) -> Option<Self>;
+    /// Literal of the coefficient. It may need conversion to the literal of expression type,
+    /// as e.g. both `Int` and `Int64` have `i64` coefficients.
     fn to_literal(self) -> Literal;
+    /// Folds call of opaque operator, see `BinaryOp::is_opaque`.
+    ///
+    /// Returns `None` on division by zero, which is left unfolded.
+    fn fold_opaque(op: BinaryOp, lhs: Self, rhs: Self) -> Option<Self> {
+        let width = Self::zero().count_zeros();
+        let shift = rhs.to_u32().filter(|&shift| shift < width);
+        match op {
+            // `checked_div` and `checked_rem` fail on zero and on `MIN / -1`.
+            BinaryOp::Slash if rhs.is_zero() => None,
+            BinaryOp::Slash => Some(lhs.checked_div(&rhs).unwrap_or(lhs)),
+            BinaryOp::Percent if rhs.is_zero() => None,
+            BinaryOp::Percent => Some(lhs.checked_rem(&rhs).unwrap_or(Self::zero())),
+            BinaryOp::ShiftLeft => Some(shift.map_or(Self::zero(), |shift| lhs << shift as usize)),
+            BinaryOp::ShiftRight => Some(match shift {
+                Some(shift) => lhs >> shift as usize,
+                None if lhs.is_neg() => !Self::zero(),
+                None => Self::zero(),
+            }),
+            BinaryOp::Caret => Some(lhs ^ rhs),
+            _ => unreachable!("{op:?} is not opaque"),
+        }
+    }
 }
 
 impl ArithCoeff for i64 {
-    fn coeff_neg(self) -> Self {
-        -self
-    }
     fn is_neg(self) -> bool {
         self < 0
     }
     fn try_from_literal(lit: &Literal) -> Option<Self> {
         match lit {
-            Literal::Int(n) => Some(*n),
+            Literal::Int(n) | Literal::Sized(SizedInt::I64(n)) => Some(*n),
             _ => None,
         }
     }
@@ -49,15 +74,12 @@ impl ArithCoeff for i64 {
 }
 
 impl ArithCoeff for u64 {
-    fn coeff_neg(self) -> Self {
-        unreachable!("negation is not defined for UInt")
-    }
     fn is_neg(self) -> bool {
         false
     }
     fn try_from_literal(lit: &Literal) -> Option<Self> {
         match lit {
-            Literal::UInt(n) => Some(*n),
+            Literal::UInt(n) | Literal::Sized(SizedInt::U64(n)) => Some(*n),
             _ => None,
         }
     }
@@ -66,6 +88,27 @@ impl ArithCoeff for u64 {
     }
 }
 
+macro_rules! sized_arith_coeff {
+    ($($coeff:ty => $width:ident),+ $(,)?) => {$(
+        impl ArithCoeff for $coeff {
+            fn is_neg(self) -> bool {
+                self < Self::zero()
+            }
+            fn try_from_literal(lit: &Literal) -> Option<Self> {
+                match lit {
+                    Literal::Sized(SizedInt::$width(n)) => Some(*n),
+                    _ => None,
+                }
+            }
+            fn to_literal(self) -> Literal {
+                Literal::Sized(SizedInt::$width(self))
+            }
+        }
+    )+};
+}
+
+sized_arith_coeff!(i8 => I8, i16 => I16, i32 => I32, u8 => U8, u16 => U16, u32 => U32);
+
 pub type Mono = BTreeMap<usize, u32>;
 pub type Poly<C> = BTreeMap<Mono, C>;
 
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use super::{Poly, normalize};
 use crate::ast::elaborated::{TypeExpression, ValueExpression};
-use crate::ast::operators::{BinaryOp, Literal, OpCall, UnaryOp};
+use crate::ast::operators::{BinaryOp, Double, Literal, OpCall, UnaryOp};
 use crate::elaboration::builtins::{BuiltinType, get_builtin};
 
 type Str = String;
@@ -229,3 +229,146 @@ fn distributivity_full_equality() {
         or(and(var("x"), var("y")), and(var("x"), var("z"))),
     );
 }
+
+fn uint(n: u64) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::UInt(n)),
+        result_type: get_builtin(&BuiltinType::UInt),
+    }
+}
+
+fn uint_var(name: &str) -> ValueExpression<Str> {
+    ValueExpression::Variable {
+        name: name.to_string(),
+        ty: get_builtin(&BuiltinType::UInt),
+    }
+}
+
+fn double_var(name: &str) -> ValueExpression<Str> {
+    ValueExpression::Variable {
+        name: name.to_string(),
+        ty: get_builtin(&BuiltinType::Double),
+    }
+}
+
+fn cmp(op: BinaryOp, lhs: ValueExpression<Str>, rhs: ValueExpression<Str>) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Binary(op, Arc::new(lhs), Arc::new(rhs)),
+        result_type: bool_ty(),
+    }
+}
+
+#[test]
+fn comparison_of_literals() {
+    assert_bool_eq(cmp(BinaryOp::Less, uint(1), uint(2)), lit(true));
+    assert_bool_eq(cmp(BinaryOp::GreaterEq, uint(1), uint(2)), lit(false));
+    assert_bool_eq(cmp(BinaryOp::NotEqual, uint(3), uint(3)), lit(false));
+}
+
+#[test]
+fn comparison_of_same_operands() {
+    assert_bool_eq(
+        cmp(BinaryOp::LessEq, uint_var("n"), uint_var("n")),
+        lit(true),
+    );
+    assert_bool_eq(
+        cmp(BinaryOp::Less, uint_var("n"), uint_var("n")),
+        lit(false),
+    );
+}
+
+#[test]
+fn comparison_of_same_doubles_is_folded() {
+    assert_bool_eq(
+        cmp(BinaryOp::Equal, double_var("d"), double_var("d")),
+        lit(true),
+    );
+    assert_bool_eq(
+        cmp(BinaryOp::Less, double_var("d"), double_var("d")),
+        lit(false),
+    );
+}
+
+#[test]
+fn doubles_are_totally_ordered() {
+    let double = |d: f64| ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::Double(Double(d))),
+        result_type: get_builtin(&BuiltinType::Double),
+    };
+    assert_bool_eq(cmp(BinaryOp::Less, double(-0.0), double(0.0)), lit(true));
+    assert_bool_eq(
+        cmp(BinaryOp::Equal, double(f64::NAN), double(f64::NAN)),
+        lit(true),
+    );
+}
+
+#[test]
+fn greater_is_swapped_less() {
+    assert_bool_eq(
+        cmp(BinaryOp::Greater, uint_var("a"), uint_var("b")),
+        cmp(BinaryOp::Less, uint_var("b"), uint_var("a")),
+    );
+    assert_bool_eq(
+        cmp(BinaryOp::GreaterEq, uint_var("a"), uint_var("b")),
+        cmp(BinaryOp::LessEq, uint_var("b"), uint_var("a")),
+    );
+}
+
+#[test]
+fn not_equal_is_negated_equal() {
+    assert_bool_eq(
+        cmp(BinaryOp::NotEqual, uint_var("a"), uint_var("b")),
+        not(cmp(BinaryOp::Equal, uint_var("a"), uint_var("b"))),
+    );
+}
+
+fn string(s: &str) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::Str(s.to_owned())),
+        result_type: get_builtin(&BuiltinType::String),
+    }
+}
+
+fn string_var(name: &str) -> ValueExpression<Str> {
+    ValueExpression::Variable {
+        name: name.to_string(),
+        ty: get_builtin(&BuiltinType::String),
+    }
+}
+
+fn concat(lhs: ValueExpression<Str>, rhs: ValueExpression<Str>) -> ValueExpression<Str> {
+    ValueExpression::OpCall {
+        op_call: OpCall::Binary(BinaryOp::Plus, Arc::new(lhs), Arc::new(rhs)),
+        result_type: get_builtin(&BuiltinType::String),
+    }
+}
+
+fn starts_with(string: ValueExpression<Str>, prefix: ValueExpression<Str>) -> ValueExpression<Str> {
+    cmp(BinaryOp::StartsWith, string, prefix)
+}
+
+#[test]
+fn starts_with_literals() {
+    assert_bool_eq(starts_with(string("urn:isbn"), string("urn:")), lit(true));
+    assert_bool_eq(starts_with(string("urn"), string("urn:")), lit(false));
+    assert_bool_eq(starts_with(string_var("s"), string("")), lit(true));
+    assert_bool_eq(starts_with(string_var("s"), string_var("s")), lit(true));
+}
+
+#[test]
+fn starts_with_leading_literal() {
+    let s = || string_var("s");
+    assert_bool_eq(
+        starts_with(concat(string("urn:"), s()), string("urn")),
+        lit(true),
+    );
+    assert_bool_eq(
+        starts_with(concat(string("http"), s()), string("urn:")),
+        lit(false),
+    );
+    assert_bool_eq(
+        starts_with(concat(string("ur"), s()), string("urn:")),
+        starts_with(s(), string("n:")),
+    );
+    assert_eq!(normalize(&starts_with(s(), string("urn:"))).vars.len(), 1);
+}
@@ -1,20 +1,31 @@
 use super::types::{Mono, NormalForm, Poly};
-use crate::ast::elaborated::ValueExpression;
+use crate::ast::elaborated::{Rec, TypeExpression, ValueExpression};
 use crate::ast::operators::{BinaryOp, Literal, OpCall, UnaryOp};
-use crate::elaboration::normalize::find_var;
+use crate::elaboration::builtins::BuiltinType;
+use crate::elaboration::normalize::{find_var, strings};
+use crate::elaboration::operators::make_lit;
+use std::cmp::Ordering;
 use std::collections::btree_map::Entry;
+use std::mem::discriminant;
 
-pub fn normalize<Str: Clone + PartialEq>(expr: &ValueExpression<Str>) -> NormalForm<Str> {
+pub fn normalize<Str>(expr: &ValueExpression<Str>) -> NormalForm<Str>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
     let mut vars = Vec::new();
     let poly = expr_to_poly(expr, &mut vars);
     NormalForm { vars, poly }
 }
 
-fn expr_to_poly<Str: Clone + PartialEq>(
-    expr: &ValueExpression<Str>,
-    vars: &mut Vec<ValueExpression<Str>>,
-) -> Poly {
-    if let ValueExpression::OpCall { op_call, .. } = expr {
+fn expr_to_poly<Str>(expr: &ValueExpression<Str>, vars: &mut Vec<ValueExpression<Str>>) -> Poly
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    if let ValueExpression::OpCall {
+        op_call,
+        result_type,
+    } = expr
+    {
         match op_call {
             OpCall::Literal(Literal::Bool(b)) => {
                 if *b {
@@ -30,6 +41,12 @@ fn expr_to_poly<Str: Clone + PartialEq>(
             OpCall::Binary(BinaryOp::BinaryOr, lhs, rhs) => {
                 poly_or(expr_to_poly(lhs, vars), expr_to_poly(rhs, vars))
             }
+            OpCall::Binary(op, lhs, rhs) if op.is_comparison() => {
+                comparison_to_poly(*op, lhs, rhs, result_type, vars)
+            }
+            OpCall::Binary(BinaryOp::StartsWith, lhs, rhs) => {
+                starts_with_to_poly(lhs, rhs, result_type, vars)
+            }
             _ => {
                 let idx = find_var(vars, expr.clone());
                 poly_var(idx)
@@ -41,6 +58,117 @@ fn expr_to_poly<Str: Clone + PartialEq>(
     }
 }
 
+/// Comparisons are atoms of normal form, which are expressed with `==`, `<` and `<=` only.
+///
+/// Comparison is folded if both operands are literals or if operands are the same.
+/// Doubles are totally ordered, so folding is valid for `NaN` too.
+fn comparison_to_poly<Str>(
+    op: BinaryOp,
+    lhs: &Rec<ValueExpression<Str>>,
+    rhs: &Rec<ValueExpression<Str>>,
+    result_type: &TypeExpression<Str>,
+    vars: &mut Vec<ValueExpression<Str>>,
+) -> Poly
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    let op = match op {
+        BinaryOp::NotEqual => {
+            return poly_not(comparison_to_poly(
+                BinaryOp::Equal,
+                lhs,
+                rhs,
+                result_type,
+                vars,
+            ));
+        }
+        BinaryOp::Greater => {
+            return comparison_to_poly(BinaryOp::Less, rhs, lhs, result_type, vars);
+        }
+        BinaryOp::GreaterEq => {
+            return comparison_to_poly(BinaryOp::LessEq, rhs, lhs, result_type, vars);
+        }
+        op => op,
+    };
+
+    let ordering = match (lhs.as_ref(), rhs.as_ref()) {
+        (
+            ValueExpression::OpCall {
+                op_call: OpCall::Literal(l),
+                ..
+            },
+            ValueExpression::OpCall {
+                op_call: OpCall::Literal(r),
+                ..
+            },
+        ) if discriminant(l) == discriminant(r) => Some(l.cmp(r)),
+        (l, r) if l == r => Some(Ordering::Equal),
+        _ => None,
+    };
+
+    match ordering {
+        Some(ordering) if op.holds(ordering) => poly_true(),
+        Some(_) => poly_false(),
+        None => {
+            let atom = ValueExpression::OpCall {
+                op_call: OpCall::Binary(op, lhs.clone(), rhs.clone()),
+                result_type: result_type.clone(),
+            };
+            poly_var(find_var(vars, atom))
+        }
+    }
+}
+
+/// Prefix check is folded if prefix is a literal and string starts with a literal,
+/// which is enough to decide it. Otherwise, the matched part of literals is dropped.
+fn starts_with_to_poly<Str>(
+    string: &Rec<ValueExpression<Str>>,
+    prefix: &Rec<ValueExpression<Str>>,
+    result_type: &TypeExpression<Str>,
+    vars: &mut Vec<ValueExpression<Str>>,
+) -> Poly
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    let literal = |expr: &ValueExpression<Str>| match expr {
+        ValueExpression::OpCall {
+            op_call: OpCall::Literal(Literal::Str(s)),
+            ..
+        } => Some(s.clone()),
+        _ => None,
+    };
+
+    let mut string = string.clone();
+    let mut prefix = prefix.clone();
+    if let Some(prefix_literal) = literal(&prefix) {
+        let mut segments = strings::normalize(&string);
+        let leading = segments.first().and_then(literal);
+        match leading {
+            _ if prefix_literal.is_empty() => return poly_true(),
+            Some(leading) if leading.starts_with(&prefix_literal) => return poly_true(),
+            Some(leading) if segments.len() > 1 && prefix_literal.starts_with(&leading) => {
+                let string_type = crate::elaboration::type_of(&string);
+                segments.remove(0);
+                string = Rec::new(strings::strings_to_expr(&segments, string_type.clone()));
+                prefix = Rec::new(make_lit(
+                    Literal::Str(prefix_literal[leading.len()..].to_owned()),
+                    string_type,
+                ));
+            }
+            Some(_) => return poly_false(),
+            None => {}
+        }
+    } else if string == prefix {
+        return poly_true();
+    }
+
+    let atom = ValueExpression::OpCall {
+        op_call: OpCall::Binary(BinaryOp::StartsWith, string, prefix),
+        result_type: result_type.clone(),
+    };
+    poly_var(find_var(vars, atom))
+}
+
 fn poly_false() -> Poly {
     Poly::new()
 }
@@ -2,7 +2,8 @@ pub mod arith;
 pub mod boolean;
 pub mod strings;
 
-use crate::ast::elaborated::ValueExpression;
+use crate::ast::elaborated::{Module, TypeExpression, ValueExpression};
+use crate::ast::operators::{OpCall, UnaryOp};
 use crate::elaboration::builtins::{self, BuiltinType};
 
 fn find_var<Str: Clone + PartialEq>(
@@ -17,24 +18,183 @@ fn find_var<Str: Clone + PartialEq>(
     idx
 }
 
+/// Evaluates `expr` to normal form, where constructors of `module` are known.
+///
+/// Accesses to fields of constructor calls are replaced by values of the fields, and
+/// operator calls are simplified, also inside arguments of constructors and types.
+/// So expressions equal by computation, like `Point{x: n, y: 0}.x` and `n`,
+/// have the same normal form.
+pub fn evaluate<Str>(expr: &ValueExpression<Str>, module: &Module<Str>) -> ValueExpression<Str>
+where
+    Str: Clone + Ord + From<BuiltinType>,
+{
+    match expr {
+        ValueExpression::OpCall {
+            op_call,
+            result_type,
+        } => {
+            let op_call = match op_call {
+                OpCall::Literal(literal) => OpCall::Literal(literal.clone()),
+                OpCall::Unary(UnaryOp::Access(field), operand) => {
+                    let operand = evaluate(operand, module);
+                    if let Some(value) = project(&operand, field, module) {
+                        return value;
+                    }
+                    OpCall::Unary(UnaryOp::Access(field.clone()), operand.into())
+                }
+                OpCall::Unary(op, operand) => {
+                    OpCall::Unary(op.clone(), evaluate(operand, module).into())
+                }
+                OpCall::Binary(op, lhs, rhs) => OpCall::Binary(
+                    *op,
+                    evaluate(lhs, module).into(),
+                    evaluate(rhs, module).into(),
+                ),
+            };
+            simplify(&ValueExpression::OpCall {
+                op_call,
+                result_type: result_type.clone(),
+            })
+        }
+        ValueExpression::Constructor {
+            name,
+            implicits,
+            arguments,
+            result_type,
+        } => ValueExpression::Constructor {
+            name: name.clone(),
+            implicits: implicits.iter().map(|i| evaluate(i, module)).collect(),
+            arguments: arguments.iter().map(|a| evaluate(a, module)).collect(),
+            result_type: evaluate_type(result_type, module),
+        },
+        ValueExpression::Variable { .. } => expr.clone(),
+        ValueExpression::Type { ty } => ValueExpression::Type {
+            ty: evaluate_type(ty, module),
+        },
+    }
+}
+
+/// Evaluates dependencies of `ty` to normal form, see [`evaluate`].
+pub fn evaluate_type<Str>(ty: &TypeExpression<Str>, module: &Module<Str>) -> TypeExpression<Str>
+where
+    Str: Clone + Ord + From<BuiltinType>,
+{
+    let TypeExpression::TypeExpression { name, dependencies } = ty;
+    TypeExpression::TypeExpression {
+        name: name.clone(),
+        dependencies: dependencies.iter().map(|d| evaluate(d, module)).collect(),
+    }
+}
+
+/// Value of `field` of `value`, if it is a constructor call with such field.
+fn project<Str>(
+    value: &ValueExpression<Str>,
+    field: &Str,
+    module: &Module<Str>,
+) -> Option<ValueExpression<Str>>
+where
+    Str: Clone + Ord,
+{
+    let ValueExpression::Constructor {
+        name, arguments, ..
+    } = value
+    else {
+        return None;
+    };
+    let index = module
+        .constructors
+        .get(name)?
+        .fields
+        .iter()
+        .position(|(name, _)| name == field)?;
+    arguments.get(index).cloned()
+}
+
 pub fn simplify<Str>(expr: &ValueExpression<Str>) -> ValueExpression<Str>
 where
     Str: Clone + PartialEq + From<BuiltinType>,
 {
     let result_type = crate::elaboration::type_of(expr);
-    if result_type == builtins::get_builtin(&BuiltinType::UInt) {
-        let nf = arith::normalize::<Str, u64>(expr);
-        arith::poly_to_expr(&nf, result_type)
-    } else if result_type == builtins::get_builtin(&BuiltinType::Int) {
-        let nf = arith::normalize::<Str, i64>(expr);
-        arith::poly_to_expr(&nf, result_type)
+    let integer = BuiltinType::INTEGERS
+        .iter()
+        .find(|ty| result_type == builtins::get_builtin(ty));
+    if let Some(integer) = integer {
+        simplify_integer(expr, *integer)
     } else if result_type == builtins::get_builtin(&BuiltinType::Bool) {
         let nf = boolean::normalize(expr);
         boolean::poly_to_expr(&nf, result_type)
-    } else if result_type == builtins::get_builtin(&BuiltinType::String) {
+    } else if builtins::is_string(&result_type) {
         let strings = strings::normalize(expr);
         strings::strings_to_expr(&strings, result_type)
     } else {
+        // `Double` arithmetic is not associative, so such expressions are kept
+        // as written and are equal only syntactically, with bitwise equal literals.
         expr.clone()
     }
 }
+
+/// Folds arithmetic expression of integer type `ty`.
+///
+/// Expression is left as is if folding overflows `ty`, so such expressions are equal
+/// only syntactically. Constant ones are reported by elaboration, see [`overflows`].
+fn simplify_integer<Str>(expr: &ValueExpression<Str>, ty: BuiltinType) -> ValueExpression<Str>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    fold_integer(expr, ty).unwrap_or_else(|| expr.clone())
+}
+
+/// Folded `expr` of integer type `ty`, or `None` if folding overflows `ty`.
+fn fold_integer<Str>(expr: &ValueExpression<Str>, ty: BuiltinType) -> Option<ValueExpression<Str>>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    fn fold<Str, C>(expr: &ValueExpression<Str>) -> Option<ValueExpression<Str>>
+    where
+        Str: Clone + PartialEq + From<BuiltinType>,
+        C: arith::ArithCoeff,
+    {
+        let nf = arith::normalize::<Str, C>(expr)?;
+        Some(arith::poly_to_expr(&nf, crate::elaboration::type_of(expr)))
+    }
+
+    match ty {
+        BuiltinType::Int | BuiltinType::Int64 => fold::<Str, i64>(expr),
+        BuiltinType::UInt | BuiltinType::UInt64 => fold::<Str, u64>(expr),
+        BuiltinType::Int8 => fold::<Str, i8>(expr),
+        BuiltinType::Int16 => fold::<Str, i16>(expr),
+        BuiltinType::Int32 => fold::<Str, i32>(expr),
+        BuiltinType::UInt8 => fold::<Str, u8>(expr),
+        BuiltinType::UInt16 => fold::<Str, u16>(expr),
+        BuiltinType::UInt32 => fold::<Str, u32>(expr),
+        _ => unreachable!("{ty:?} is not an integer type"),
+    }
+}
+
+/// Integer type of `expr`, if it is built of literals only and its value overflows
+/// the type, so evaluating it would fail in generated code.
+///
+/// Overflows of expressions with variables depend on their values, so they are not reported.
+pub fn overflows<Str>(expr: &ValueExpression<Str>) -> Option<BuiltinType>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    let result_type = crate::elaboration::type_of(expr);
+    let integer = *BuiltinType::INTEGERS
+        .iter()
+        .find(|ty| result_type == builtins::get_builtin(ty))?;
+    (is_constant(expr) && fold_integer(expr, integer).is_none()).then_some(integer)
+}
+
+/// Whether `expr` is built of literals with operators.
+fn is_constant<Str>(expr: &ValueExpression<Str>) -> bool {
+    match expr {
+        ValueExpression::OpCall { op_call, .. } => match op_call {
+            OpCall::Literal(_) => true,
+            OpCall::Unary(UnaryOp::Access(_), _) => false,
+            OpCall::Unary(_, operand) => is_constant(operand),
+            OpCall::Binary(_, lhs, rhs) => is_constant(lhs) && is_constant(rhs),
+        },
+        _ => false,
+    }
+}
@@ -5,31 +5,40 @@ use crate::elaboration::operators::{make_binary, make_lit};
 #[cfg(test)]
 mod tests;
 
+/// Flattens concatenation of strings or bytes into segments, merging adjacent literals.
 pub fn normalize<Str: Clone + PartialEq>(expr: &ValueExpression<Str>) -> Vec<ValueExpression<Str>> {
     match expr {
         ValueExpression::OpCall { op_call, .. } => match op_call {
-            OpCall::Literal(Literal::Str(_)) => vec![expr.clone()],
+            OpCall::Literal(Literal::Str(_) | Literal::Bytes(_)) => vec![expr.clone()],
             OpCall::Binary(BinaryOp::Plus, lhs, rhs) => {
                 let mut strings = normalize(lhs);
                 strings.extend(normalize(rhs));
                 let mut result = vec![];
                 for seg in strings {
-                    if let ValueExpression::OpCall {
-                        op_call: OpCall::Literal(Literal::Str(new_s)),
+                    let ValueExpression::OpCall {
+                        op_call: OpCall::Literal(new_lit),
                         ..
                     } = &seg
-                    {
-                        if let Some(ValueExpression::OpCall {
-                            op_call: OpCall::Literal(Literal::Str(prev_s)),
-                            ..
-                        }) = result.last_mut()
-                        {
-                            prev_s.push_str(new_s);
-                        } else {
-                            result.push(seg);
-                        }
-                    } else {
+                    else {
                         result.push(seg);
+                        continue;
+                    };
+                    match (new_lit, result.last_mut()) {
+                        (
+                            Literal::Str(new_s),
+                            Some(ValueExpression::OpCall {
+                                op_call: OpCall::Literal(Literal::Str(prev_s)),
+                                ..
+                            }),
+                        ) => prev_s.push_str(new_s),
+                        (
+                            Literal::Bytes(new_b),
+                            Some(ValueExpression::OpCall {
+                                op_call: OpCall::Literal(Literal::Bytes(prev_b)),
+                                ..
+                            }),
+                        ) => prev_b.extend_from_slice(new_b),
+                        _ => result.push(seg),
                     }
                 }
                 result
@@ -68,3 +68,15 @@ fn literals_not_merged() {
     let strings = normalize(&cat(cat(lit("a"), var("x")), lit("b")));
     assert_eq!(strings.len(), 3);
 }
+
+#[test]
+fn bytes_literals_merged() {
+    let bytes = |b: &[u8]| ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::Bytes(b.to_vec())),
+        result_type: get_builtin(&BuiltinType::Bytes),
+    };
+    let strings = normalize(&cat(cat(bytes(b"a"), bytes(b"\x00")), var("x")));
+    assert_eq!(strings.len(), 2);
+    assert_eq!(strings[0], bytes(b"a\x00"));
+    assert!(is_opaque(&strings[1]));
+}
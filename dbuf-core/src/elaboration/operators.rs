@@ -1,10 +1,12 @@
 use crate::ast::elaborated as e;
 use crate::ast::operators as o;
 use crate::elaboration::builtins::BuiltinType;
-use crate::elaboration::{builtins, subst};
+use crate::elaboration::pretty::show_type;
+use crate::elaboration::{builtins, normalize, subst, suggest};
 use crate::error::elaborating::Error;
 use crate::error::elaborating::Error::{
-    TypeMismatch, UnknownConstructor, UnknownField, UnknownType, UnsupportedSyntax,
+    DivisionByZero, IntegerOverflow, LiteralOutOfRange, TypeMismatch, UnknownConstructor,
+    UnknownField, UnknownType, UnsupportedSyntax,
 };
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -15,18 +17,87 @@ use std::sync::Arc;
 pub fn unary_accepted_types<S>(op: &o::UnaryOp<S>) -> &[BuiltinType] {
     match op {
         o::UnaryOp::Access(_) => &[],
-        o::UnaryOp::Minus => &[BuiltinType::Int],
+        o::UnaryOp::Minus => &[
+            BuiltinType::Int,
+            BuiltinType::Int8,
+            BuiltinType::Int16,
+            BuiltinType::Int32,
+            BuiltinType::Int64,
+            BuiltinType::Double,
+        ],
         o::UnaryOp::Bang => &[BuiltinType::Bool],
     }
 }
 
+/// Types of operands accepted by `op`.
+///
+/// Predicates result in `Bool`, other operators result in type of their operands.
 #[must_use]
 pub fn binary_accepted_types(op: &o::BinaryOp) -> &[BuiltinType] {
     match op {
-        o::BinaryOp::Plus => &[BuiltinType::UInt, BuiltinType::Int, BuiltinType::String],
-        o::BinaryOp::Minus => &[BuiltinType::Int],
-        o::BinaryOp::Star => &[BuiltinType::UInt, BuiltinType::Int],
+        o::BinaryOp::Plus => &[
+            BuiltinType::UInt,
+            BuiltinType::Int,
+            BuiltinType::Int8,
+            BuiltinType::Int16,
+            BuiltinType::Int32,
+            BuiltinType::Int64,
+            BuiltinType::UInt8,
+            BuiltinType::UInt16,
+            BuiltinType::UInt32,
+            BuiltinType::UInt64,
+            BuiltinType::Double,
+            BuiltinType::String,
+            BuiltinType::Bytes,
+        ],
+        o::BinaryOp::Minus => &[
+            BuiltinType::Int,
+            BuiltinType::Int8,
+            BuiltinType::Int16,
+            BuiltinType::Int32,
+            BuiltinType::Int64,
+            BuiltinType::Double,
+        ],
+        o::BinaryOp::Star
+        | o::BinaryOp::Less
+        | o::BinaryOp::LessEq
+        | o::BinaryOp::Greater
+        | o::BinaryOp::GreaterEq => &[
+            BuiltinType::UInt,
+            BuiltinType::Int,
+            BuiltinType::Int8,
+            BuiltinType::Int16,
+            BuiltinType::Int32,
+            BuiltinType::Int64,
+            BuiltinType::UInt8,
+            BuiltinType::UInt16,
+            BuiltinType::UInt32,
+            BuiltinType::UInt64,
+            BuiltinType::Double,
+        ],
+        o::BinaryOp::Slash
+        | o::BinaryOp::Percent
+        | o::BinaryOp::ShiftLeft
+        | o::BinaryOp::ShiftRight
+        | o::BinaryOp::Caret => BuiltinType::INTEGERS,
         o::BinaryOp::BinaryAnd | o::BinaryOp::BinaryOr => &[BuiltinType::Bool],
+        o::BinaryOp::Equal | o::BinaryOp::NotEqual => &[
+            BuiltinType::Bool,
+            BuiltinType::UInt,
+            BuiltinType::Int,
+            BuiltinType::Int8,
+            BuiltinType::Int16,
+            BuiltinType::Int32,
+            BuiltinType::Int64,
+            BuiltinType::UInt8,
+            BuiltinType::UInt16,
+            BuiltinType::UInt32,
+            BuiltinType::UInt64,
+            BuiltinType::Double,
+            BuiltinType::String,
+            BuiltinType::Bytes,
+        ],
+        o::BinaryOp::StartsWith => &[BuiltinType::String],
     }
 }
 
@@ -36,24 +107,116 @@ pub fn literal_to_type<Str: From<BuiltinType>>(literal: &o::Literal) -> e::TypeE
         o::Literal::Bool(_) => BuiltinType::Bool,
         o::Literal::Int(_) => BuiltinType::Int,
         o::Literal::UInt(_) => BuiltinType::UInt,
+        o::Literal::Double(_) => BuiltinType::Double,
         o::Literal::Str(_) => BuiltinType::String,
+        o::Literal::Bytes(_) => BuiltinType::Bytes,
+        o::Literal::Sized(o::SizedInt::I8(_)) => BuiltinType::Int8,
+        o::Literal::Sized(o::SizedInt::I16(_)) => BuiltinType::Int16,
+        o::Literal::Sized(o::SizedInt::I32(_)) => BuiltinType::Int32,
+        o::Literal::Sized(o::SizedInt::I64(_)) => BuiltinType::Int64,
+        o::Literal::Sized(o::SizedInt::U8(_)) => BuiltinType::UInt8,
+        o::Literal::Sized(o::SizedInt::U16(_)) => BuiltinType::UInt16,
+        o::Literal::Sized(o::SizedInt::U32(_)) => BuiltinType::UInt32,
+        o::Literal::Sized(o::SizedInt::U64(_)) => BuiltinType::UInt64,
     })
 }
 
+/// Converts integer `value` to literal of integer type `ty`, if it is in range of `ty`.
+#[must_use]
+pub fn integer_literal(value: i128, ty: BuiltinType) -> Option<o::Literal> {
+    let width = match ty {
+        BuiltinType::Int => return i64::try_from(value).ok().map(o::Literal::Int),
+        BuiltinType::UInt => return u64::try_from(value).ok().map(o::Literal::UInt),
+        BuiltinType::Int8 => o::SizedInt::I8(0),
+        BuiltinType::Int16 => o::SizedInt::I16(0),
+        BuiltinType::Int32 => o::SizedInt::I32(0),
+        BuiltinType::Int64 => o::SizedInt::I64(0),
+        BuiltinType::UInt8 => o::SizedInt::U8(0),
+        BuiltinType::UInt16 => o::SizedInt::U16(0),
+        BuiltinType::UInt32 => o::SizedInt::U32(0),
+        BuiltinType::UInt64 => o::SizedInt::U64(0),
+        _ => return None,
+    };
+    width.with_value(value).map(o::Literal::Sized)
+}
+
+/// Checks that `literal` can have `expected_type` and returns it as literal of that type.
+///
+/// Literals without width suffix, like `5` or `5u`, are accepted by every integer type
+/// which range contains them.
+///
 /// # Errors
-pub fn check_literal<Str: Eq + From<BuiltinType>>(
+/// `TypeMismatch` if `literal` can't have `expected_type`, where types are shown
+/// with constructors of `module`, and `LiteralOutOfRange` if its value doesn't fit.
+pub fn check_literal<Str: Clone + Ord + ToString + From<BuiltinType>>(
     literal: &o::Literal,
     expected_type: &e::TypeExpression<Str>,
-) -> Result<(), Error> {
-    match literal {
-        literal if *expected_type == literal_to_type(literal) => Ok(()),
-        o::Literal::UInt(_) if *expected_type == builtins::get_builtin(&BuiltinType::Int) => Ok(()),
-        o::Literal::Int(value)
-            if *expected_type == builtins::get_builtin(&BuiltinType::UInt) && *value >= 0 =>
-        {
-            Ok(())
-        }
-        _ => Err(TypeMismatch),
+    module: &e::Module<Str>,
+) -> Result<o::Literal, Error> {
+    convert_literal(literal, expected_type).unwrap_or_else(|| {
+        Err(TypeMismatch {
+            expected: show_type(expected_type, module),
+            found: show_type(&literal_to_type(literal), module),
+            origin: None,
+        })
+    })
+}
+
+/// Converts `literal` to literal of `expected_type` as [`check_literal`] does.
+///
+/// Returns `None` if `literal` can't have `expected_type`.
+pub fn convert_literal<Str: PartialEq + From<BuiltinType>>(
+    literal: &o::Literal,
+    expected_type: &e::TypeExpression<Str>,
+) -> Option<Result<o::Literal, Error>> {
+    if *expected_type == literal_to_type(literal) {
+        return Some(Ok(literal.clone()));
+    }
+    let value = match literal {
+        o::Literal::Int(value) => i128::from(*value),
+        o::Literal::UInt(value) => i128::from(*value),
+        _ => return None,
+    };
+    let ty = BuiltinType::INTEGERS
+        .iter()
+        .find(|ty| *expected_type == builtins::get_builtin(ty))?;
+    Some(integer_literal(value, *ty).ok_or(LiteralOutOfRange))
+}
+
+/// Checks that `op` with right operand `rhs` is not a division by constant zero.
+///
+/// # Errors
+/// `DivisionByZero` if `op` is `/` or `%` and `rhs` is zero literal.
+pub fn check_divisor<Str>(op: o::BinaryOp, rhs: &e::ValueExpression<Str>) -> Result<(), Error> {
+    let is_zero = match rhs {
+        e::ValueExpression::OpCall {
+            op_call: o::OpCall::Literal(literal),
+            ..
+        } => match literal {
+            o::Literal::Int(value) => *value == 0,
+            o::Literal::UInt(value) => *value == 0,
+            o::Literal::Sized(value) => value.value() == 0,
+            _ => false,
+        },
+        _ => false,
+    };
+    if is_zero && matches!(op, o::BinaryOp::Slash | o::BinaryOp::Percent) {
+        return Err(DivisionByZero);
+    }
+    Ok(())
+}
+
+/// Checks that constant `expr` doesn't overflow its integer type.
+///
+/// # Errors
+/// `IntegerOverflow` if `expr` consists of literals and its value doesn't fit its type.
+pub fn check_overflow<Str>(expr: &e::ValueExpression<Str>) -> Result<(), Error>
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    match normalize::overflows(expr) {
+        Some(ty) => Err(IntegerOverflow(ty.as_str().to_owned())),
+        None => Ok(()),
     }
 }
 
@@ -93,6 +256,45 @@ pub fn make_binary<Str: Clone>(
     }
 }
 
+/// Name of the only field of builtin sequences, `String` and `Bytes`.
+pub const LENGTH_FIELD: &str = "length";
+
+/// Resolves access to `field` of `operand_value` of builtin sequence, `String` or `Bytes` type.
+///
+/// Returns `None` if `operand_type` is none of them. Length of `Array T n` is `n`,
+/// length of `String` is number of bytes in its UTF-8 encoding.
+#[must_use]
+pub fn sequence_length<Str>(
+    operand_type: &e::TypeExpression<Str>,
+    operand_value: &e::ValueExpression<Str>,
+    field: &Str,
+) -> Option<Result<e::ValueExpression<Str>, Error>>
+where
+    Str: Clone + PartialEq + From<BuiltinType> + Display,
+{
+    let array_length = if builtins::is_string(operand_type) {
+        None
+    } else {
+        match builtins::as_sequence(operand_type)? {
+            (BuiltinType::Array, [_, length]) => Some(length.clone()),
+            _ => None,
+        }
+    };
+    if field.to_string() != LENGTH_FIELD {
+        return Some(Err(UnknownField {
+            name: field.to_string(),
+            suggestion: suggest::closest(&field.to_string(), [LENGTH_FIELD]),
+        }));
+    }
+    Some(Ok(array_length.unwrap_or_else(|| {
+        make_unary(
+            o::UnaryOp::Access(field.clone()),
+            operand_value.clone(),
+            builtins::get_builtin(&BuiltinType::UInt),
+        )
+    })))
+}
+
 /// # Errors
 pub fn resolve_field_access<Str: Debug + Clone + Hash + Eq + Ord + From<BuiltinType> + Display>(
     module_ctx: &e::Module<Str>,
@@ -105,10 +307,10 @@ pub fn resolve_field_access<Str: Debug + Clone + Hash + Eq + Ord + From<BuiltinT
         dependencies: type_deps,
     } = operand_type;
 
-    let ty = module_ctx
-        .types
-        .get(type_name)
-        .ok_or_else(|| UnknownType(type_name.to_string()))?;
+    let ty = module_ctx.types.get(type_name).ok_or_else(|| UnknownType {
+        name: type_name.to_string(),
+        suggestion: None,
+    })?;
 
     let ctor_name = match &ty.constructor_names {
         e::ConstructorNames::OfMessage(ctor_name) => ctor_name.clone(),
@@ -118,7 +320,10 @@ pub fn resolve_field_access<Str: Debug + Clone + Hash + Eq + Ord + From<BuiltinT
     let ctor = module_ctx
         .constructors
         .get(&ctor_name)
-        .ok_or_else(|| UnknownConstructor(ctor_name.to_string()))?
+        .ok_or_else(|| UnknownConstructor {
+            name: ctor_name.to_string(),
+            suggestion: None,
+        })?
         .clone();
 
     let (field_idx, (_, field_type)) = ctor
@@ -126,7 +331,13 @@ pub fn resolve_field_access<Str: Debug + Clone + Hash + Eq + Ord + From<BuiltinT
         .iter()
         .enumerate()
         .find(|(_, (n, _))| n == field)
-        .ok_or_else(|| UnknownField(field.to_string()))?;
+        .ok_or_else(|| UnknownField {
+            name: field.to_string(),
+            suggestion: suggest::closest(
+                &field.to_string(),
+                ctor.fields.iter().map(|(name, _)| name.to_string()),
+            ),
+        })?;
     let after_implicits = ctor.implicits.iter().zip(type_deps.iter()).fold(
         field_type.clone(),
         |ty, ((implicit_name, _), concrete_val)| subst::subst_type(ty, implicit_name, concrete_val),
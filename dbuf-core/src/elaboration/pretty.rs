@@ -0,0 +1,196 @@
+//! Printing of elaborated expressions in surface syntax, e.g. `Vec (n + 1u)`,
+//! which is used in error messages.
+
+use crate::ast::elaborated as e;
+use crate::ast::operators as o;
+
+/// Shows type expression `ty`, where constructors of `module` can be used.
+#[must_use]
+pub fn show_type<Str: ToString + Ord>(
+    ty: &e::TypeExpression<Str>,
+    module: &e::Module<Str>,
+) -> String {
+    let e::TypeExpression::TypeExpression { name, dependencies } = ty;
+    std::iter::once(name.to_string())
+        .chain(dependencies.iter().map(|dep| show_argument(dep, module)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Shows value expression `value`, where constructors of `module` can be used.
+///
+/// Fields of constructors unknown to `module` are shown without names.
+#[must_use]
+pub fn show_value<Str: ToString + Ord>(
+    value: &e::ValueExpression<Str>,
+    module: &e::Module<Str>,
+) -> String {
+    match value {
+        e::ValueExpression::OpCall { op_call, .. } => match op_call {
+            o::OpCall::Literal(literal) => literal.to_string(),
+            o::OpCall::Unary(o::UnaryOp::Access(field), operand) => {
+                format!("{}.{}", show_operand(operand, module), field.to_string())
+            }
+            o::OpCall::Unary(o::UnaryOp::Minus, operand) => {
+                format!("-{}", show_operand(operand, module))
+            }
+            o::OpCall::Unary(o::UnaryOp::Bang, operand) => {
+                format!("!{}", show_operand(operand, module))
+            }
+            o::OpCall::Binary(o::BinaryOp::StartsWith, lhs, rhs) => format!(
+                "{}({}, {})",
+                o::BinaryOp::StartsWith.symbol(),
+                show_value(lhs, module),
+                show_value(rhs, module)
+            ),
+            o::OpCall::Binary(op, lhs, rhs) => format!(
+                "{} {} {}",
+                show_operand(lhs, module),
+                op.symbol(),
+                show_operand(rhs, module)
+            ),
+        },
+        e::ValueExpression::Constructor {
+            name, arguments, ..
+        } => {
+            let fields = module.constructors.get(name).map(|ctor| &ctor.fields);
+            let arguments = arguments
+                .iter()
+                .enumerate()
+                .map(|(i, argument)| {
+                    let argument = show_value(argument, module);
+                    match fields.and_then(|fields| fields.get(i)) {
+                        Some((field, _)) => format!("{}: {argument}", field.to_string()),
+                        None => argument,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{}{{{arguments}}}", name.to_string())
+        }
+        e::ValueExpression::Variable { name, .. } => name.to_string(),
+        e::ValueExpression::Type { ty } => show_type(ty, module),
+    }
+}
+
+/// Shows argument of type application, which is parenthesized unless it is atomic.
+fn show_argument<Str: ToString + Ord>(
+    value: &e::ValueExpression<Str>,
+    module: &e::Module<Str>,
+) -> String {
+    match value {
+        e::ValueExpression::Type {
+            ty: e::TypeExpression::TypeExpression { dependencies, .. },
+        } if !dependencies.is_empty() => format!("({})", show_value(value, module)),
+        _ => show_operand(value, module),
+    }
+}
+
+/// Shows operand of operator, which is parenthesized if it is an operator call itself.
+fn show_operand<Str: ToString + Ord>(
+    value: &e::ValueExpression<Str>,
+    module: &e::Module<Str>,
+) -> String {
+    match value {
+        e::ValueExpression::OpCall {
+            op_call: o::OpCall::Unary(..) | o::OpCall::Binary(..),
+            ..
+        } => format!("({})", show_value(value, module)),
+        _ => show_value(value, module),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::elaborated::ConstructorNames;
+    use crate::ast::operators::{BinaryOp, Literal, OpCall};
+    use crate::elaboration::builtins::{BuiltinType, get_builtin};
+    use indexmap::IndexMap;
+    use std::collections::BTreeMap;
+
+    type Str = String;
+
+    fn module() -> e::Module<Str> {
+        let nat = e::TypeExpression::TypeExpression {
+            name: "Nat".to_owned(),
+            dependencies: e::Rec::from([]),
+        };
+        let suc = e::Constructor {
+            implicits: vec![],
+            fields: vec![("pred".to_owned(), nat.clone())],
+            result_type: nat,
+            refinements: vec![],
+            defaults: vec![],
+        };
+        e::Module {
+            package: vec![],
+            types: IndexMap::from([(
+                "Nat".to_owned(),
+                e::Type {
+                    dependencies: vec![],
+                    constructor_names: ConstructorNames::OfEnum(["Suc".to_owned()].into()),
+                    refinements: vec![],
+                },
+            )]),
+            constructors: BTreeMap::from([("Suc".to_owned(), suc)]),
+            aliases: IndexMap::new(),
+        }
+    }
+
+    fn var(name: &str) -> e::ValueExpression<Str> {
+        e::ValueExpression::Variable {
+            name: name.to_owned(),
+            ty: get_builtin(&BuiltinType::UInt),
+        }
+    }
+
+    fn uint(n: u64) -> e::ValueExpression<Str> {
+        e::ValueExpression::OpCall {
+            op_call: OpCall::Literal(Literal::UInt(n)),
+            result_type: get_builtin(&BuiltinType::UInt),
+        }
+    }
+
+    fn ty(name: &str, dependencies: Vec<e::ValueExpression<Str>>) -> e::TypeExpression<Str> {
+        e::TypeExpression::TypeExpression {
+            name: name.to_owned(),
+            dependencies: e::Rec::from(dependencies),
+        }
+    }
+
+    #[test]
+    fn operator_arguments_are_parenthesized() {
+        let sum = e::ValueExpression::OpCall {
+            op_call: OpCall::Binary(BinaryOp::Plus, var("n").into(), uint(1).into()),
+            result_type: get_builtin(&BuiltinType::UInt),
+        };
+        let vec = ty("Vec", vec![sum, var("m")]);
+        assert_eq!(show_type(&vec, &module()), "Vec (n + 1u) m");
+    }
+
+    #[test]
+    fn type_arguments_are_parenthesized() {
+        let list = ty(
+            "List",
+            vec![e::ValueExpression::Type {
+                ty: ty("Vec", vec![uint(2)]),
+            }],
+        );
+        assert_eq!(show_type(&list, &module()), "List (Vec 2u)");
+    }
+
+    #[test]
+    fn constructors_show_field_names() {
+        let one = e::ValueExpression::Constructor {
+            name: "Suc".to_owned(),
+            implicits: e::Rec::from([]),
+            arguments: e::Rec::from([var("zero")]),
+            result_type: ty("Nat", vec![]),
+        };
+        assert_eq!(
+            show_type(&ty("Parity", vec![one]), &module()),
+            "Parity Suc{pred: zero}"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Refinements are `Bool` predicates on fields and dependencies, written after `where`.
+//!
+//! They are checked at runtime by generated code, but predicates on literal values
+//! are folded during elaboration, so violations of such refinements are reported early.
+
+use crate::ast::elaborated as e;
+use crate::ast::operators::{Literal, OpCall};
+use crate::elaboration::builtins::BuiltinType;
+use crate::elaboration::subst;
+use crate::error::elaborating::Error::{self, RefinementViolated};
+
+/// Substitutes literal values of `bindings` into `refinements`.
+///
+/// Refinements that become always true are dropped.
+/// # Errors
+/// `RefinementViolated` if some refinement becomes always false.
+pub fn apply_refinements<Str>(
+    refinements: &e::Refinements<Str>,
+    bindings: &[(Str, e::ValueExpression<Str>)],
+) -> Result<e::Refinements<Str>, Error>
+where
+    Str: Clone + Eq + From<BuiltinType> + ToString,
+{
+    // Only literals are substituted, as other values may refer to variables,
+    // which are shadowed by names of refinement's context.
+    let literals: Vec<_> = bindings
+        .iter()
+        .filter(|(_, value)| {
+            matches!(
+                value,
+                e::ValueExpression::OpCall {
+                    op_call: OpCall::Literal(_),
+                    ..
+                }
+            )
+        })
+        .cloned()
+        .collect();
+
+    let mut applied = Vec::new();
+    for (name, predicate) in refinements {
+        let predicate = subst::apply_bindings(predicate.clone(), &literals);
+        if let Some(holds) = as_bool(&predicate) {
+            if !holds {
+                return Err(RefinementViolated(name.to_string()));
+            }
+        } else {
+            applied.push((name.clone(), predicate));
+        }
+    }
+    Ok(applied)
+}
+
+fn as_bool<Str>(predicate: &e::ValueExpression<Str>) -> Option<bool> {
+    match predicate {
+        e::ValueExpression::OpCall {
+            op_call: OpCall::Literal(Literal::Bool(value)),
+            ..
+        } => Some(*value),
+        _ => None,
+    }
+}
@@ -0,0 +1,253 @@
+//! Resolution of type and constructor names declared in packages.
+//!
+//! Types and constructors declared in a module are qualified with its package.
+//! Qualified references are kept as written, while a bare reference resolves to:
+//!   1. declaration of the same package;
+//!   2. declaration without package (builtins and files without package);
+//!   3. the only visible declaration of another package with such bare name.
+//!
+//! Names that can't be resolved are kept, so elaboration reports them as unknown.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::elaborated::{qualify, split_qualified};
+use crate::ast::operators::OpCall;
+use crate::ast::parsed::definition::{Definition, Definitions};
+use crate::ast::parsed::{self as p, Rec};
+
+use super::{Loc, Mod, Name, Str};
+
+pub(super) struct Resolver<'a> {
+    /// Package of resolved module.
+    package: &'a [Str],
+    /// Qualified names of every visible type and constructor.
+    known: HashSet<Str>,
+    /// Qualified names of declarations in packages by their bare name.
+    by_bare: HashMap<String, Vec<Str>>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Creates resolver for `module` declared in `package`,
+    /// where declarations of `visible` can be used.
+    pub(super) fn new(module: &p::Module<Loc, Name>, package: &'a [Str], visible: &Mod) -> Self {
+        let mut resolver = Resolver {
+            package,
+            known: HashSet::new(),
+            by_bare: HashMap::new(),
+        };
+
+        let imported = visible
+            .types
+            .keys()
+            .chain(visible.aliases.keys())
+            .chain(visible.constructors.keys())
+            .cloned();
+        let declared = module.iter().flat_map(|definition| {
+            let constructors: Vec<_> = match &definition.body {
+                p::TypeDefinition::Message(_) | p::TypeDefinition::Alias(_) => vec![],
+                p::TypeDefinition::Enum(branches) => branches
+                    .iter()
+                    .flat_map(|branch| branch.constructors.iter())
+                    .map(|constructor| resolver.qualify(&constructor.name.content))
+                    .collect(),
+            };
+            std::iter::once(resolver.qualify(&definition.name.content)).chain(constructors)
+        });
+        let names: Vec<_> = imported.chain(declared).collect();
+
+        // message and its constructor share the same name.
+        for name in names {
+            if !resolver.known.insert(name.clone()) {
+                continue;
+            }
+            let (package, bare) = split_qualified(name.as_ref());
+            if !package.is_empty() {
+                resolver
+                    .by_bare
+                    .entry(bare.to_owned())
+                    .or_default()
+                    .push(name);
+            }
+        }
+
+        resolver
+    }
+
+    /// Resolves every name in `module`.
+    pub(super) fn module(&self, module: &p::Module<Loc, Name>) -> p::Module<Loc, Name> {
+        module
+            .iter()
+            .map(|definition| Definition {
+                loc: definition.loc,
+                name: self.declare(&definition.name),
+                data: p::TypeDeclaration {
+                    dependencies: self.typed_definitions(&definition.dependencies),
+                    body: match &definition.body {
+                        p::TypeDefinition::Message(body) => {
+                            p::TypeDefinition::Message(self.typed_definitions(body))
+                        }
+                        p::TypeDefinition::Enum(branches) => p::TypeDefinition::Enum(
+                            branches.iter().map(|branch| self.branch(branch)).collect(),
+                        ),
+                        p::TypeDefinition::Alias(target) => {
+                            p::TypeDefinition::Alias(self.expression(target))
+                        }
+                    },
+                    allowed_lints: definition.allowed_lints.clone(),
+                },
+            })
+            .collect()
+    }
+
+    fn qualify(&self, name: &Str) -> Str {
+        qualify(self.package, name.as_ref()).into()
+    }
+
+    /// Name of declaration in resolved module.
+    fn declare(&self, name: &Name) -> Name {
+        Name {
+            content: self.qualify(&name.content),
+            start: name.start,
+        }
+    }
+
+    /// Name of referenced declaration.
+    fn resolve(&self, name: &Name) -> Name {
+        let (package, bare) = split_qualified(name.content.as_ref());
+        if !package.is_empty() {
+            return name.clone();
+        }
+
+        let local = self.qualify(&name.content);
+        let content = if self.known.contains(&local) {
+            local
+        } else if self.known.contains(&name.content) {
+            name.content.clone()
+        } else {
+            match self.by_bare.get(bare).map(Vec::as_slice) {
+                Some([only]) => only.clone(),
+                _ => name.content.clone(),
+            }
+        };
+
+        Name {
+            content,
+            start: name.start,
+        }
+    }
+
+    fn branch(&self, branch: &p::EnumBranch<Loc, Name>) -> p::EnumBranch<Loc, Name> {
+        p::EnumBranch {
+            patterns: branch
+                .patterns
+                .iter()
+                .map(|pattern| self.pattern(pattern))
+                .collect(),
+            constructors: branch
+                .constructors
+                .iter()
+                .map(|constructor| Definition {
+                    loc: constructor.loc,
+                    name: self.declare(&constructor.name),
+                    data: self.typed_definitions(&constructor.data),
+                })
+                .collect(),
+        }
+    }
+
+    fn typed_definitions(
+        &self,
+        definitions: &Definitions<Loc, Name, p::Typed<Loc, Name>>,
+    ) -> Definitions<Loc, Name, p::Typed<Loc, Name>> {
+        definitions
+            .iter()
+            .map(|definition| Definition {
+                loc: definition.loc,
+                name: definition.name.clone(),
+                data: p::Typed {
+                    ty: self.expression(&definition.ty),
+                    refinement: definition
+                        .refinement
+                        .as_ref()
+                        .map(|refinement| self.expression(refinement)),
+                    default: definition
+                        .default
+                        .as_ref()
+                        .map(|default| self.expression(default)),
+                },
+            })
+            .collect()
+    }
+
+    fn definitions(
+        &self,
+        definitions: &Definitions<Loc, Name, p::Expression<Loc, Name>>,
+    ) -> Definitions<Loc, Name, p::Expression<Loc, Name>> {
+        definitions
+            .iter()
+            .map(|definition| Definition {
+                loc: definition.loc,
+                name: definition.name.clone(),
+                data: self.expression(&definition.data),
+            })
+            .collect()
+    }
+
+    fn expression(&self, expression: &p::Expression<Loc, Name>) -> p::Expression<Loc, Name> {
+        let node = match &expression.node {
+            p::ExpressionNode::OpCall(op_call) => p::ExpressionNode::OpCall(match op_call {
+                OpCall::Literal(literal) => OpCall::Literal(literal.clone()),
+                OpCall::Unary(op, arg) => OpCall::Unary(op.clone(), Rec::new(self.expression(arg))),
+                OpCall::Binary(op, lhs, rhs) => OpCall::Binary(
+                    *op,
+                    Rec::new(self.expression(lhs)),
+                    Rec::new(self.expression(rhs)),
+                ),
+            }),
+            p::ExpressionNode::FunCall { fun, args } => p::ExpressionNode::FunCall {
+                fun: self.resolve(fun),
+                args: args
+                    .iter()
+                    .map(|arg| self.expression(arg))
+                    .collect::<Vec<_>>()
+                    .into(),
+            },
+            p::ExpressionNode::ConstructorCall { name, fields } => {
+                p::ExpressionNode::ConstructorCall {
+                    name: self.resolve(name),
+                    fields: self.definitions(fields),
+                }
+            }
+            node @ (p::ExpressionNode::Variable { .. } | p::ExpressionNode::TypedHole) => {
+                node.clone()
+            }
+        };
+
+        p::Expression {
+            loc: expression.loc,
+            node,
+        }
+    }
+
+    fn pattern(&self, pattern: &p::Pattern<Loc, Name>) -> p::Pattern<Loc, Name> {
+        let node = match &pattern.node {
+            p::PatternNode::ConstructorCall { name, fields } => p::PatternNode::ConstructorCall {
+                name: self.resolve(name),
+                fields: fields
+                    .iter()
+                    .map(|field| Definition {
+                        loc: field.loc,
+                        name: field.name.clone(),
+                        data: self.pattern(&field.data),
+                    })
+                    .collect(),
+            },
+            node => node.clone(),
+        };
+
+        p::Pattern {
+            loc: pattern.loc,
+            node,
+        }
+    }
+}
@@ -82,6 +82,11 @@ where
                 false,
             )
         }
+
+        e::ValueExpression::Type { ty } => {
+            let ty = subst_type(ty, var, replacement);
+            (e::ValueExpression::Type { ty }, false)
+        }
     }
 }
 
@@ -0,0 +1,70 @@
+//! Suggestions of known names for misspelled ones.
+
+/// Name of `candidates` closest to unknown `name`, if it is close enough to be misspelled.
+///
+/// Names are compared by edit distance, which may be up to a third of the length of `name`.
+pub(super) fn closest<S: AsRef<str>>(
+    name: &str,
+    candidates: impl IntoIterator<Item = S>,
+) -> Option<String> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.as_ref() != name)
+        .map(|candidate| (edit_distance(name, candidate.as_ref()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by(|(a, x), (b, y)| a.cmp(b).then_with(|| x.as_ref().cmp(y.as_ref())))
+        .map(|(_, candidate)| candidate.as_ref().to_owned())
+}
+
+/// Number of insertions, deletions, substitutions and transpositions of adjacent characters
+/// turning `a` into `b`, where no substring is edited twice.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // Distances between prefixes of `a` and `b`, where `d[i][j]` is for `a[..i]` and `b[..j]`.
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = substitution.min(d[i - 1][j] + 1).min(d[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("String", "String"), 0);
+        assert_eq!(edit_distance("Strng", "String"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("Piar", "Pair"), 1);
+    }
+
+    #[test]
+    fn closest_name() {
+        let names = ["String", "Int", "UInt", "Bytes"];
+        assert_eq!(closest("Strng", names), Some("String".to_owned()));
+        assert_eq!(closest("Uint", names), Some("UInt".to_owned()));
+        assert_eq!(closest("Double", names), None);
+    }
+
+    #[test]
+    fn exact_name_is_not_suggested() {
+        assert_eq!(closest("Int", ["Int"]), None);
+    }
+}
@@ -1,19 +1,27 @@
 use crate::ast::{elaborated as e, operators as o, parsed as p};
+use crate::elaboration::pretty::{show_type, show_value};
 use crate::elaboration::*;
 use crate::error::elaborating::{
     ElaboratingStage,
     Error::{
-        self, ArityMismatch, OperatorTypeMismatch, TypeMismatch, UnknownConstructor, UnknownField,
-        UnknownType, UnknownVariable, UnsupportedSyntax,
+        self, ArityMismatch, ConstructorMismatch, DoublePattern, LiteralMismatch,
+        OperatorTypeMismatch, TypeMismatch, UnknownConstructor, UnknownField, UnknownType,
+        UnknownVariable, UnsupportedSyntax, ValueMismatch,
     },
+    Goal, Hypothesis, Origin,
 };
 
 use indexmap::IndexMap;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
 use std::iter::zip;
 
 type EResult<T> = Result<T, ElaboratingStage>;
 
+/// Goals of typed holes found in a declaration, which are reported
+/// without stopping its elaboration.
+type Holes = RefCell<Vec<ElaboratingStage>>;
+
 trait AttachLoc<T> {
     fn at(self, loc: Loc) -> EResult<T>;
 }
@@ -36,66 +44,280 @@ impl<T> AttachLoc<T> for EResult<T> {
     }
 }
 
-type DefRef<'a> = &'a p::definition::Definition<Loc, Name, p::TypeDeclaration<Loc, Name>>;
-
-/// Elaborate top-sorted parsed definitions
-/// # Errors
-/// If the parsed module elaboration failed
-pub(super) fn elaborate_sorted(module: &[DefRef<'_>]) -> EResult<Mod> {
-    elaborate_with_module_ctx(builtins::builtins_module::<Str>(), module)
-}
-
-fn elaborate_with_module_ctx(mut module_ctx: Mod, module: &[DefRef<'_>]) -> EResult<Mod> {
+/// Elaborate top-sorted groups of mutually recursive parsed definitions
+/// in presence of `module_ctx` (builtins and imported declarations).
+///
+/// Declaration that fails to elaborate is left out of the result together with
+/// its group and poisons declarations that reference them, which are skipped without errors.
+/// Declaration with typed holes is left out of the result too, but declarations
+/// that reference it are elaborated, so their holes are reported as well.
+/// Returns elaborated declarations, errors of failed ones and warnings.
+pub(super) fn elaborate_sorted(
+    mut module_ctx: Mod,
+    groups: &[Vec<DefRef<'_>>],
+) -> (Mod, Vec<ElaboratingStage>) {
+    let module = groups.concat();
     let mut elaborated_module = e::Module {
+        package: vec![],
         types: IndexMap::new(),
         constructors: BTreeMap::new(),
+        aliases: IndexMap::new(),
     };
-    for &type_def in module {
-        let (name, elaborated_type, elaborated_constructors) =
-            elaborate_type_decl(&mut module_ctx, type_def)?;
+    let mut errors = vec![];
+    let mut poisoned = BTreeSet::new();
+    for group in groups {
+        let group_names = group.iter().map(|type_def| type_def.name.to_string());
+        if group.iter().any(|&type_def| {
+            graph::references(type_def)
+                .iter()
+                .any(|name| poisoned.contains(name))
+        }) {
+            poisoned.extend(group_names);
+            continue;
+        }
 
-        module_ctx
-            .types
-            .insert(name.clone(), elaborated_type.clone());
-        module_ctx
-            .constructors
-            .extend(elaborated_constructors.clone());
+        // alias can't be mutually recursive, so it is the only member of its group.
+        if let [type_def] = group.as_slice()
+            && let p::TypeDefinition::Alias(target) = &type_def.body
+        {
+            let holes = Holes::default();
+            let alias = elaborate_alias(&module_ctx, &holes, type_def, target);
+            let holes = holes.into_inner();
+            let has_holes = !holes.is_empty();
+            errors.extend(holes);
+            match alias {
+                Ok(alias) => {
+                    let name = type_def.name.content.clone();
+                    module_ctx.aliases.insert(name.clone(), alias.clone());
+                    errors.extend(lints::silence(type_def, lints::check_declaration(type_def)));
+                    if !has_holes {
+                        elaborated_module.aliases.insert(name, alias);
+                    }
+                }
+                Err(mut error) => {
+                    poisoned.extend(group_names);
+                    locate_origin(&mut error.error, &module);
+                    suggest_name(&mut error.error, &module_ctx, &module);
+                    errors.push(error);
+                }
+            }
+            continue;
+        }
 
-        elaborated_module.types.insert(name, elaborated_type);
-        elaborated_module
-            .constructors
-            .extend(elaborated_constructors);
+        let declarations = match elaborate_group(&mut module_ctx, &mut errors, group) {
+            Ok(declarations) => declarations,
+            Err(failed) => {
+                poisoned.extend(group_names);
+                for mut error in failed {
+                    locate_origin(&mut error.error, &module);
+                    suggest_name(&mut error.error, &module_ctx, &module);
+                    errors.push(error);
+                }
+                continue;
+            }
+        };
+
+        for ((name, elaborated_type, elaborated_constructors), _) in &declarations {
+            module_ctx
+                .types
+                .insert(name.clone(), elaborated_type.clone());
+            module_ctx
+                .constructors
+                .extend(elaborated_constructors.clone());
+        }
+
+        for (&type_def, (declaration, has_holes)) in zip(group, declarations) {
+            let (name, elaborated_type, elaborated_constructors) = declaration;
+            let mut warnings = coverage::check_enum(&module_ctx, type_def, &elaborated_type);
+            warnings.extend(lints::check_declaration(type_def));
+            errors.extend(lints::silence(type_def, warnings));
+            if has_holes {
+                continue;
+            }
+
+            elaborated_module.types.insert(name, elaborated_type);
+            elaborated_module
+                .constructors
+                .extend(elaborated_constructors);
+        }
     }
-    Ok(elaborated_module)
+    (elaborated_module, errors)
 }
 
-fn elaborate_type_decl(
+/// Elaborates declarations of a group with types of the whole group visible
+/// in their bodies, reporting goals of typed holes into `errors`.
+///
+/// Returns every declaration with whether it has typed holes,
+/// or errors of declarations that failed.
+fn elaborate_group(
     module_ctx: &mut Mod,
-    type_def: &p::definition::Definition<Loc, Name, p::TypeDeclaration<Loc, Name>>,
+    errors: &mut Vec<ElaboratingStage>,
+    group: &[DefRef<'_>],
+) -> Result<Vec<(ElaboratedDeclaration, bool)>, Vec<ElaboratingStage>> {
+    let known_types = module_ctx.types.len();
+    let mut failed = vec![];
+    for &type_def in group {
+        // Holes of dependencies are reported when the whole declaration is elaborated.
+        match type_header(module_ctx, &Holes::default(), type_def) {
+            Ok(header) => {
+                module_ctx
+                    .types
+                    .insert(type_def.name.content.clone(), header);
+            }
+            Err(error) => failed.push(error),
+        }
+    }
+
+    let mut declarations = vec![];
+    if failed.is_empty() {
+        for &type_def in group {
+            let holes = Holes::default();
+            let declaration = elaborate_type_decl(module_ctx, &holes, type_def);
+            let holes = holes.into_inner();
+            let has_holes = !holes.is_empty();
+            errors.extend(holes);
+            match declaration {
+                Ok(declaration) => declarations.push((declaration, has_holes)),
+                Err(error) => failed.push(error),
+            }
+        }
+    }
+    module_ctx.types.truncate(known_types);
+
+    if failed.is_empty() {
+        Ok(declarations)
+    } else {
+        Err(failed)
+    }
+}
+
+/// Finds declaration of field or dependency, which type is expected by `error`,
+/// among `module` definitions.
+fn locate_origin(error: &mut Error, module: &[DefRef<'_>]) {
+    let TypeMismatch {
+        origin: Some(origin),
+        ..
+    } = error
+    else {
+        return;
+    };
+    let find = |members: &p::definition::Definitions<Loc, Name, p::Typed<Loc, Name>>| {
+        members
+            .iter()
+            .find(|member| member.name.as_ref() == origin.member)
+            .map(|member| Loc::from(&member.name))
+    };
+    origin.loc = module.iter().find_map(|type_def| {
+        let in_dependencies = (type_def.name.as_ref() == origin.owner)
+            .then(|| find(&type_def.dependencies))
+            .flatten();
+        in_dependencies.or_else(|| match &type_def.body {
+            p::TypeDefinition::Message(fields) => (type_def.name.as_ref() == origin.owner)
+                .then(|| find(fields))
+                .flatten(),
+            p::TypeDefinition::Alias(_) => None,
+            p::TypeDefinition::Enum(branches) => branches
+                .iter()
+                .flat_map(|branch| &branch.constructors)
+                .find(|ctor| ctor.name.as_ref() == origin.owner)
+                .and_then(|ctor| find(&ctor.data)),
+        })
+    });
+}
+
+/// Suggests name similar to unknown type or constructor among known ones
+/// and ones declared in `module`, which may be not elaborated yet.
+fn suggest_name(error: &mut Error, module_ctx: &Mod, module: &[DefRef<'_>]) {
+    match error {
+        UnknownType { name, suggestion } => {
+            let declared = module.iter().map(|type_def| type_def.name.as_ref());
+            *suggestion = suggest::closest(
+                name,
+                module_ctx
+                    .types
+                    .keys()
+                    .chain(module_ctx.aliases.keys())
+                    .map(AsRef::as_ref)
+                    .chain(declared),
+            );
+        }
+        UnknownConstructor { name, suggestion } => {
+            let declared = module.iter().flat_map(|type_def| match &type_def.body {
+                p::TypeDefinition::Message(_) => vec![type_def.name.as_ref()],
+                p::TypeDefinition::Alias(_) => vec![],
+                p::TypeDefinition::Enum(branches) => branches
+                    .iter()
+                    .flat_map(|branch| &branch.constructors)
+                    .map(|ctor| ctor.name.as_ref())
+                    .collect(),
+            });
+            *suggestion = suggest::closest(
+                name,
+                module_ctx
+                    .constructors
+                    .keys()
+                    .map(AsRef::as_ref)
+                    .chain(declared),
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Elaborates dependencies of `type_def`, which are enough to refer to its type.
+fn type_header(module_ctx: &Mod, holes: &Holes, type_def: DefRef<'_>) -> EResult<e::Type<Str>> {
+    let local_ctx = Ctx::new();
+    let mut binding = local_ctx.new_layer();
+    let (_, dependencies, refinements) =
+        elaborate_deps(module_ctx, holes, &mut binding, &type_def.data.dependencies)
+            .at(type_def.loc)?;
+    let constructor_names = match &type_def.data.body {
+        p::TypeDefinition::Message(_) => {
+            e::ConstructorNames::OfMessage(type_def.name.content.clone())
+        }
+        p::TypeDefinition::Enum(_) => e::ConstructorNames::OfEnum(BTreeSet::new()),
+        p::TypeDefinition::Alias(_) => unreachable!("alias is elaborated by itself"),
+    };
+    Ok(e::Type {
+        dependencies,
+        constructor_names,
+        refinements,
+    })
+}
+
+/// Elaborates `type_def`, which type and types of its group are already in `module_ctx`.
+fn elaborate_type_decl(
+    module_ctx: &Mod,
+    holes: &Holes,
+    type_def: DefRef<'_>,
 ) -> EResult<ElaboratedDeclaration> {
     let local_ctx = Ctx::new();
 
     let p::definition::Definition {
         loc,
         name,
-        data: p::TypeDeclaration { dependencies, body },
+        data: p::TypeDeclaration {
+            dependencies, body, ..
+        },
     } = type_def;
 
     let mut binding = local_ctx.new_layer();
-    let (local_ctx_with_deps, elaborated_dependencies) =
-        elaborate_deps(module_ctx, &mut binding, dependencies).at(*loc)?;
+    let (local_ctx_with_deps, elaborated_dependencies, dependency_refinements) =
+        elaborate_deps(module_ctx, holes, &mut binding, dependencies).at(*loc)?;
 
     match body {
         p::TypeDefinition::Message(ctor_body) => {
             let name_str = name.content.clone();
-            let fields =
-                elaborate_constructor_body(module_ctx, &local_ctx_with_deps, ctor_body).at(*loc)?;
+            let (fields, field_refinements, defaults) =
+                elaborate_constructor_body(module_ctx, holes, &local_ctx_with_deps, ctor_body)
+                    .at(*loc)?;
 
             Ok((
                 name_str.clone(),
                 e::Type {
                     dependencies: elaborated_dependencies.clone(),
                     constructor_names: e::ConstructorNames::OfMessage(name_str.clone()),
+                    refinements: dependency_refinements.clone(),
                 },
                 vec![(
                     name_str.clone(),
@@ -106,78 +328,145 @@ fn elaborate_type_decl(
                             name: name_str,
                             dependencies: e::Rec::from(ctx_to_deps(&elaborated_dependencies)),
                         },
+                        refinements: [dependency_refinements, field_refinements].concat(),
+                        defaults,
                     },
                 )],
             ))
         }
         p::TypeDefinition::Enum(branches) => {
-            module_ctx.types.insert(
-                name.content.clone(),
-                e::Type {
-                    dependencies: elaborated_dependencies.clone(),
-                    constructor_names: e::ConstructorNames::OfEnum(BTreeSet::new()),
-                },
-            );
-            let result = elaborate_enum(
+            let (constructor_names, constructors) = elaborate_enum(
                 module_ctx,
+                holes,
                 &local_ctx_with_deps,
                 name,
                 branches,
                 &elaborated_dependencies,
-            );
-            module_ctx.types.swap_remove(&name.content);
-            let (constructor_names, constructors) = result.at(*loc)?;
+                &dependency_refinements,
+            )
+            .at(*loc)?;
             Ok((
                 name.content.clone(),
                 e::Type {
                     dependencies: elaborated_dependencies,
                     constructor_names: e::ConstructorNames::OfEnum(constructor_names),
+                    refinements: dependency_refinements,
                 },
                 constructors,
             ))
         }
+        p::TypeDefinition::Alias(_) => unreachable!("alias is elaborated by itself"),
     }
 }
 
+/// Elaborates alias `type_def` of `target` type expression over its dependencies.
+fn elaborate_alias(
+    module_ctx: &Mod,
+    holes: &Holes,
+    type_def: DefRef<'_>,
+    target: &p::TypeExpression<Loc, Name>,
+) -> EResult<e::Alias<Str>> {
+    let local_ctx = Ctx::new();
+    let mut binding = local_ctx.new_layer();
+    let (local_ctx_with_deps, dependencies, refinements) =
+        elaborate_deps(module_ctx, holes, &mut binding, &type_def.data.dependencies)
+            .at(type_def.loc)?;
+    let target = elaborate_type(module_ctx, holes, &local_ctx_with_deps, target).at(type_def.loc)?;
+    Ok(e::Alias {
+        dependencies,
+        refinements,
+        target,
+    })
+}
+
 fn elaborate_deps<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a mut Ctx<'a>,
-    deps: &p::definition::Definitions<Loc, Name, p::TypeExpression<Loc, Name>>,
-) -> EResult<(Ctx<'a>, ElaboratedCtx)> {
+    deps: &p::definition::Definitions<Loc, Name, p::Typed<Loc, Name>>,
+) -> EResult<(Ctx<'a>, ElaboratedCtx, Refinements)> {
     let mut dependencies = vec![];
+    let mut refinements = vec![];
     for p::definition::Definition {
-        name,
-        data: type_expr,
-        ..
+        name, data: typed, ..
     } in deps
     {
-        let elaborated_type_expr = elaborate_type(module_ctx, local_ctx, type_expr)?;
+        let elaborated_type_expr = elaborate_type(module_ctx, holes, local_ctx, typed)?;
 
         local_ctx.insert(name.content.clone(), elaborated_type_expr.clone());
         dependencies.push((name.content.clone(), elaborated_type_expr));
+        refinements.extend(elaborate_refinement(
+            module_ctx, holes, local_ctx, name, typed,
+        )?);
     }
-    Ok((local_ctx.new_layer(), dependencies))
+    Ok((local_ctx.new_layer(), dependencies, refinements))
 }
 
+/// Elaborates fields of constructor, their refinements and defaults.
+///
+/// Defaults are checked in `local_ctx`, so they can only refer to dependencies.
 fn elaborate_constructor_body<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     ctor_body: &p::ConstructorBody<Loc, Name>,
-) -> EResult<ElaboratedCtx> {
+) -> EResult<(ElaboratedCtx, Refinements, Defaults)> {
     let mut fields: ElaboratedCtx = Vec::new();
+    let mut refinements = vec![];
+    let mut defaults = vec![];
     let mut field_ctx = local_ctx.new_layer();
 
     for p::definition::Definition { name, data, .. } in ctor_body {
-        let elaborated_type = elaborate_type(module_ctx, &field_ctx, data)?;
+        // Only the end of input tells that fields are absent, so defaults are trailing.
+        if data.default.is_none() && !defaults.is_empty() {
+            return Err(Error::NonTrailingDefault(name.to_string())).at(Loc::from(name));
+        }
+        let elaborated_type = elaborate_type(module_ctx, holes, &field_ctx, data)?;
+        let default = match &data.default {
+            Some(default) => {
+                let (value, _) = check(module_ctx, holes, local_ctx, default, &elaborated_type)?;
+                Some((default.loc, value))
+            }
+            None => None,
+        };
         field_ctx.insert(name.content.clone(), elaborated_type.clone());
         fields.push((name.content.clone(), elaborated_type));
+        let field_refinements = elaborate_refinement(module_ctx, holes, &field_ctx, name, data)?;
+        if let Some((loc, value)) = default {
+            // Default must satisfy refinements of the field, which are folded if it is a literal.
+            refinement::apply_refinements(
+                &field_refinements,
+                &[(name.content.clone(), value.clone())],
+            )
+            .at(loc)?;
+            defaults.push((name.content.clone(), value));
+        }
+        refinements.extend(field_refinements);
     }
 
-    Ok(fields)
+    Ok((fields, refinements, defaults))
+}
+
+/// Elaborates refinement of variable `name` as `Bool` expression in context,
+/// where the variable is already bound.
+fn elaborate_refinement<'a>(
+    module_ctx: &Mod,
+    holes: &Holes,
+    local_ctx: &'a Ctx<'a>,
+    name: &Name,
+    typed: &p::Typed<Loc, Name>,
+) -> EResult<Refinements> {
+    let Some(refinement) = &typed.refinement else {
+        return Ok(vec![]);
+    };
+    let bool_type = builtins::get_builtin(&builtins::BuiltinType::Bool);
+    let (predicate, _) = check(module_ctx, holes, local_ctx, refinement, &bool_type)?;
+    refinement::apply_refinements(&vec![(name.content.clone(), predicate)], &[]).at(refinement.loc)
 }
 
 fn elaborate_type<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     expr: &p::TypeExpression<Loc, Name>,
 ) -> EResult<TypeExpr> {
@@ -187,37 +476,44 @@ fn elaborate_type<'a>(
             loc: Some(expr.loc),
         }),
         p::ExpressionNode::FunCall { fun, args } => {
-            let fun_loc = Loc::from(fun);
+            if let Some(alias) = module_ctx.aliases.get(&fun.content) {
+                let bindings = elaborate_type_arguments(
+                    module_ctx,
+                    holes,
+                    local_ctx,
+                    fun,
+                    args,
+                    &alias.dependencies,
+                    &alias.refinements,
+                )
+                .at(expr.loc)?;
+                return expand_alias(module_ctx, alias, &bindings).at(expr.loc);
+            }
+
             let ty = module_ctx
                 .types
                 .get(&fun.content)
                 .ok_or_else(|| ElaboratingStage {
-                    error: UnknownType(fun.content.to_string()),
-                    loc: Some(fun_loc),
+                    error: UnknownType {
+                        name: fun.content.to_string(),
+                        suggestion: None,
+                    },
+                    loc: Some(Loc::from(fun)),
                 })?;
-
-            let declared_deps = ty.dependencies.clone();
-
-            check_arity(declared_deps.len(), args.len(), Some(fun_loc))?;
-
-            let mut remaining_deps = declared_deps;
-            let mut elaborated_args = Vec::new();
-
-            for arg in args.iter() {
-                let (dep_name, dep_type) = remaining_deps.remove(0);
-
-                let (arg_value, bindings) = check(module_ctx, local_ctx, arg, &dep_type)?;
-                elaborated_args.push(arg_value.clone());
-
-                for (_, remaining_ty) in &mut remaining_deps {
-                    let after_name = subst::subst_type(remaining_ty.clone(), &dep_name, &arg_value);
-                    *remaining_ty = subst::apply_bindings_to_type(after_name, &bindings);
-                }
-            }
+            let bindings = elaborate_type_arguments(
+                module_ctx,
+                holes,
+                local_ctx,
+                fun,
+                args,
+                &ty.dependencies,
+                &ty.refinements,
+            )
+            .at(expr.loc)?;
 
             Ok(TypeExpr::TypeExpression {
                 name: fun.content.clone(),
-                dependencies: e::Rec::from(elaborated_args),
+                dependencies: bindings.into_iter().map(|(_, arg)| arg).collect(),
             })
         }
         p::ExpressionNode::ConstructorCall { .. } => Err(ElaboratingStage {
@@ -225,21 +521,89 @@ fn elaborate_type<'a>(
             loc: Some(expr.loc),
         }),
         p::ExpressionNode::Variable { name } => lookup_var(local_ctx, name),
-        p::ExpressionNode::TypedHole => Err(ElaboratingStage {
-            error: UnsupportedSyntax,
-            loc: Some(expr.loc),
-        }),
+        p::ExpressionNode::TypedHole => {
+            let type_type = builtins::get_builtin(&builtins::BuiltinType::Type);
+            Ok(TypeExpr::TypeExpression {
+                name: fill_hole(module_ctx, holes, local_ctx, &type_type, expr.loc),
+                dependencies: e::Rec::from([]),
+            })
+        }
     }
 }
 
+/// Checks `args` of type or alias `fun` against its `declared_deps`
+/// and literal ones against its `refinements`.
+///
+/// Returns arguments bound to names of dependencies.
+/// Violated refinement is located by the caller.
+fn elaborate_type_arguments<'a>(
+    module_ctx: &Mod,
+    holes: &Holes,
+    local_ctx: &'a Ctx<'a>,
+    fun: &Name,
+    args: &[p::TypeExpression<Loc, Name>],
+    declared_deps: &ElaboratedCtx,
+    refinements: &Refinements,
+) -> EResult<Bindings> {
+    check_arity(declared_deps.len(), args.len(), Some(Loc::from(fun)))?;
+
+    let mut remaining_deps = declared_deps.clone();
+    let mut elaborated_args = Vec::new();
+
+    for arg in args {
+        let (dep_name, dep_type) = remaining_deps.remove(0);
+
+        let (arg_value, bindings) =
+            if dep_type == builtins::get_builtin(&builtins::BuiltinType::Type) {
+                let ty = elaborate_type(module_ctx, holes, local_ctx, arg)?;
+                (Value::Type { ty }, vec![])
+            } else {
+                check(module_ctx, holes, local_ctx, arg, &dep_type)
+                    .map_err(|error| with_origin(error, &fun.content, &dep_name))?
+            };
+        elaborated_args.push(arg_value.clone());
+
+        for (_, remaining_ty) in &mut remaining_deps {
+            let after_name = subst::subst_type(remaining_ty.clone(), &dep_name, &arg_value);
+            *remaining_ty = subst::apply_bindings_to_type(after_name, &bindings);
+        }
+    }
+
+    let dep_bindings: Bindings = zip(declared_deps, elaborated_args)
+        .map(|((dep_name, _), arg)| (dep_name.clone(), arg))
+        .collect();
+    refinement::apply_refinements(refinements, &dep_bindings)
+        .map_err(|error| ElaboratingStage { error, loc: None })?;
+    Ok(dep_bindings)
+}
+
+/// Substitutes arguments of `bindings` into target of `alias`.
+///
+/// Refinements of the target type, that depended on the alias dependencies,
+/// are checked again with the arguments. Error is located by the caller.
+fn expand_alias(module_ctx: &Mod, alias: &e::Alias<Str>, bindings: &Bindings) -> EResult<TypeExpr> {
+    let target = subst::apply_bindings_to_type(alias.target.clone(), bindings);
+    let TypeExpr::TypeExpression { name, dependencies } = &target;
+    if let Some(ty) = module_ctx.types.get(name) {
+        let target_bindings: Bindings = zip(&ty.dependencies, dependencies.iter())
+            .map(|((dep_name, _), arg)| (dep_name.clone(), arg.clone()))
+            .collect();
+        refinement::apply_refinements(&ty.refinements, &target_bindings)
+            .map_err(|error| ElaboratingStage { error, loc: None })?;
+    }
+    Ok(target)
+}
+
 type EnumSpecification = (BTreeSet<Str>, Vec<(Str, e::Constructor<Str>)>);
 
 fn elaborate_enum<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     name: &Name,
     branches: &[p::EnumBranch<Loc, Name>],
     enum_deps: &ElaboratedCtx,
+    dep_refinements: &Refinements,
 ) -> EResult<EnumSpecification> {
     let result_type = TypeExpr::TypeExpression {
         name: name.content.clone(),
@@ -250,8 +614,15 @@ fn elaborate_enum<'a>(
     let mut constructors = vec![];
 
     for branch in branches {
-        let branch_ctors =
-            elaborate_branch(module_ctx, local_ctx, branch, enum_deps, &result_type)?;
+        let branch_ctors = elaborate_branch(
+            module_ctx,
+            holes,
+            local_ctx,
+            branch,
+            enum_deps,
+            dep_refinements,
+            &result_type,
+        )?;
 
         for (ctor_name, ctor) in branch_ctors {
             constructor_names.push(ctor_name.clone());
@@ -264,9 +635,11 @@ fn elaborate_enum<'a>(
 
 fn elaborate_branch<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     branch: &p::EnumBranch<Loc, Name>,
     enum_deps: &ElaboratedCtx,
+    dep_refinements: &Refinements,
     result_type: &TypeExpr,
 ) -> EResult<Vec<(Str, e::Constructor<Str>)>> {
     check_arity(
@@ -292,6 +665,22 @@ fn elaborate_branch<'a>(
 
     let result_type = subst::apply_bindings_to_type(result_type.clone(), &bindings);
 
+    // Refinements of dependencies matched by patterns are expressed with pattern variables.
+    let matched_refinements: Refinements = dep_refinements
+        .iter()
+        .map(|(dep_name, predicate)| {
+            let predicate = subst::apply_bindings(predicate.clone(), &bindings);
+            (dep_name.clone(), predicate)
+        })
+        .collect();
+    let matched_refinements =
+        refinement::apply_refinements(&matched_refinements, &[]).map_err(|error| {
+            ElaboratingStage {
+                error,
+                loc: branch.patterns.first().map(|p| p.loc),
+            }
+        })?;
+
     let mut implicits = Vec::new();
     for (dep_name, dep_type) in enum_deps {
         if !bindings.iter().any(|(n, _)| n == dep_name) {
@@ -311,13 +700,16 @@ fn elaborate_branch<'a>(
         ..
     } in &branch.constructors
     {
-        let fields = elaborate_constructor_body(module_ctx, &branch_ctx, ctor_body)?;
+        let (fields, field_refinements, defaults) =
+            elaborate_constructor_body(module_ctx, holes, &branch_ctx, ctor_body)?;
         ctors.push((
             ctor_name.content.clone(),
             e::Constructor {
                 implicits: implicits.clone(),
                 fields,
                 result_type: result_type.clone(),
+                refinements: [matched_refinements.clone(), field_refinements].concat(),
+                defaults,
             },
         ));
     }
@@ -340,7 +732,7 @@ fn elaborate_pattern(
                 .constructors
                 .get(&ctor_name.content)
                 .ok_or_else(|| ElaboratingStage {
-                    error: UnknownConstructor(ctor_name.content.to_string()),
+                    error: unknown_constructor(&ctor_name.content),
                     loc: Some(Loc::from(ctor_name)),
                 })?
                 .clone();
@@ -373,9 +765,13 @@ fn elaborate_pattern(
             branch_ctx.insert_alias(dep_name.clone(), value.clone());
             Ok(vec![(dep_name.clone(), value)])
         }
+        p::PatternNode::Literal(o::Literal::Double(_)) => Err(ElaboratingStage {
+            error: DoublePattern,
+            loc: Some(pattern.loc),
+        }),
         p::PatternNode::Literal(literal) => {
-            operators::check_literal(literal, dep_type).at(pattern.loc)?;
-            let value = operators::make_lit(literal.clone(), dep_type.clone());
+            let literal = operators::check_literal(literal, dep_type, module_ctx).at(pattern.loc)?;
+            let value = operators::make_lit(literal, dep_type.clone());
             branch_ctx.insert_alias(dep_name.clone(), value.clone());
             Ok(vec![(dep_name.clone(), value)])
         }
@@ -436,7 +832,10 @@ fn elaborate_pattern_constructor(
                 .find(|(n, _)| n == field_name)
                 .map(|(_, v)| v.clone())
                 .ok_or_else(|| ElaboratingStage {
-                    error: UnknownField(field_name.as_ref().to_string()),
+                    error: UnknownField {
+                        name: field_name.as_ref().to_string(),
+                        suggestion: None,
+                    },
                     loc: Some(sub_def.data.loc),
                 })?;
             (arg, subterm_bindings)
@@ -465,12 +864,13 @@ fn elaborate_pattern_constructor(
 
 fn infer<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     expr: &p::Expression<Loc, Name>,
 ) -> EResult<Value> {
     match &expr.node {
         p::ExpressionNode::OpCall(op_call) => {
-            infer_operator(module_ctx, local_ctx, op_call, expr.loc)
+            infer_operator(module_ctx, holes, local_ctx, op_call, expr.loc)
         }
         p::ExpressionNode::FunCall { .. } => Err(ElaboratingStage {
             error: UnsupportedSyntax,
@@ -479,7 +879,7 @@ fn infer<'a>(
         p::ExpressionNode::ConstructorCall {
             name,
             fields: constructor_args,
-        } => infer_constructor_call(module_ctx, local_ctx, name, constructor_args),
+        } => infer_constructor_call(module_ctx, holes, local_ctx, name, constructor_args),
         p::ExpressionNode::Variable { name } => match local_ctx.lookup(&name.content) {
             Some(context::Lookup::Alias(alias)) => Ok(alias.clone()),
             Some(context::Lookup::Term(ty)) => Ok(Value::Variable {
@@ -500,6 +900,7 @@ fn infer<'a>(
 
 fn infer_operator<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     op_expr: &o::OpCall<Name, e::Rec<p::Expression<Loc, Name>>>,
     loc: Loc,
@@ -514,30 +915,18 @@ fn infer_operator<'a>(
                 fields: ctor_args,
             } = &value.node
             {
-                let ctor = module_ctx
-                    .constructors
-                    .get(&ctor_name.content)
-                    .ok_or_else(|| ElaboratingStage {
-                        error: UnknownConstructor(ctor_name.content.to_string()),
-                        loc: Some(Loc::from(ctor_name)),
-                    })?;
-                let field_idx = ctor
-                    .fields
-                    .iter()
-                    .position(|(n, _)| *n == field.content)
-                    .ok_or_else(|| ElaboratingStage {
-                        error: UnknownField(field.content.to_string()),
-                        loc: Some(loc),
-                    })?;
-                let field_arg = ctor_args.get(field_idx).ok_or_else(|| ElaboratingStage {
-                    error: UnknownField(field.content.to_string()),
-                    loc: Some(loc),
-                })?;
-                return infer(module_ctx, local_ctx, &field_arg.data);
+                return infer_constructor_field(
+                    module_ctx, holes, local_ctx, ctor_name, ctor_args, field, loc,
+                );
             }
 
-            let elaborated_expr = infer(module_ctx, local_ctx, value)?;
+            let elaborated_expr = infer(module_ctx, holes, local_ctx, value)?;
             let operand_type = type_of(&elaborated_expr);
+            if let Some(length) =
+                operators::sequence_length(&operand_type, &elaborated_expr, &field.content)
+            {
+                return Ok(normalize::simplify(&length.at(value.loc)?));
+            }
             let (_, _, concrete_field_type) = operators::resolve_field_access(
                 module_ctx,
                 &operand_type,
@@ -555,13 +944,22 @@ fn infer_operator<'a>(
         o::OpCall::Unary(op @ (o::UnaryOp::Minus | o::UnaryOp::Bang), value) => {
             let accepted_types = operators::unary_accepted_types(op);
             let Some((elaborated_val, ty)) = accepted_types.iter().find_map(|&x| {
-                let builtin = builtins::get_builtin(&x);
-                let (v, bindings) = check(module_ctx, local_ctx, value, &builtin).ok()?;
-                debug_assert_eq!(bindings.len(), 0);
-                Some((v, builtin))
+                attempt(holes, || {
+                    let builtin = builtins::get_builtin(&x);
+                    let (v, bindings) =
+                        check(module_ctx, holes, local_ctx, value, &builtin).ok()?;
+                    debug_assert_eq!(bindings.len(), 0);
+                    Some((v, builtin))
+                })
             }) else {
+                let found = infer(module_ctx, holes, local_ctx, value)?;
                 return Err(ElaboratingStage {
-                    error: OperatorTypeMismatch,
+                    error: operator_mismatch(
+                        module_ctx,
+                        unary_symbol(op),
+                        accepted_types,
+                        show_type(&type_of(&found), module_ctx),
+                    ),
                     loc: Some(loc),
                 });
             };
@@ -570,29 +968,84 @@ fn infer_operator<'a>(
         o::OpCall::Binary(op, l, r) => {
             let binary_op = |accepted_types: &[builtins::BuiltinType]| -> EResult<Value> {
                 let Some((builtin, left, right)) = accepted_types.iter().find_map(|x| {
-                    let builtin = builtins::get_builtin(x);
-                    let (left, bindings) = check(module_ctx, local_ctx, l, &builtin).ok()?;
-                    debug_assert_eq!(bindings.len(), 0);
-
-                    let (right, bindings) = check(module_ctx, local_ctx, r, &builtin).ok()?;
-                    debug_assert_eq!(bindings.len(), 0);
-                    Some((builtin, left, right))
+                    attempt(holes, || {
+                        let builtin = builtins::get_builtin(x);
+                        let (left, bindings) =
+                            check(module_ctx, holes, local_ctx, l, &builtin).ok()?;
+                        debug_assert_eq!(bindings.len(), 0);
+
+                        let (right, bindings) =
+                            check(module_ctx, holes, local_ctx, r, &builtin).ok()?;
+                        debug_assert_eq!(bindings.len(), 0);
+                        Some((builtin, left, right))
+                    })
                 }) else {
+                    let left = infer(module_ctx, holes, local_ctx, l)?;
+                    let right = infer(module_ctx, holes, local_ctx, r)?;
+                    let found = format!(
+                        "{} and {}",
+                        show_type(&type_of(&left), module_ctx),
+                        show_type(&type_of(&right), module_ctx)
+                    );
                     return Err(ElaboratingStage {
-                        error: OperatorTypeMismatch,
+                        error: operator_mismatch(module_ctx, op.symbol(), accepted_types, found),
                         loc: Some(loc),
                     });
                 };
-                Ok(operators::make_binary(*op, left, right, builtin))
+                operators::check_divisor(*op, &right).at(r.loc)?;
+                let result_type = if op.is_predicate() {
+                    builtins::get_builtin(&builtins::BuiltinType::Bool)
+                } else {
+                    builtin
+                };
+                Ok(operators::make_binary(*op, left, right, result_type))
             };
             binary_op(operators::binary_accepted_types(op))?
         }
     };
+    operators::check_overflow(&value).at(loc)?;
     Ok(normalize::simplify(&value))
 }
 
+/// Infers access to `field` of constructor call, which is the accessed argument.
+fn infer_constructor_field<'a>(
+    module_ctx: &Mod,
+    holes: &Holes,
+    local_ctx: &'a Ctx<'a>,
+    ctor_name: &Name,
+    ctor_args: &p::definition::Definitions<Loc, Name, p::Expression<Loc, Name>>,
+    field: &Name,
+    loc: Loc,
+) -> EResult<Value> {
+    let ctor = module_ctx
+        .constructors
+        .get(&ctor_name.content)
+        .ok_or_else(|| ElaboratingStage {
+            error: unknown_constructor(&ctor_name.content),
+            loc: Some(Loc::from(ctor_name)),
+        })?;
+    let unknown_field = || ElaboratingStage {
+        error: UnknownField {
+            name: field.content.to_string(),
+            suggestion: suggest::closest(
+                field.content.as_ref(),
+                ctor.fields.iter().map(|(name, _)| name),
+            ),
+        },
+        loc: Some(loc),
+    };
+    let field_idx = ctor
+        .fields
+        .iter()
+        .position(|(n, _)| *n == field.content)
+        .ok_or_else(unknown_field)?;
+    let field_arg = ctor_args.get(field_idx).ok_or_else(unknown_field)?;
+    infer(module_ctx, holes, local_ctx, &field_arg.data)
+}
+
 fn infer_constructor_call<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     name: &Name,
     constructor_args: &p::definition::Definitions<Loc, Name, p::Expression<Loc, Name>>,
@@ -603,7 +1056,7 @@ fn infer_constructor_call<'a>(
             .constructors
             .get(&name.content)
             .ok_or_else(|| ElaboratingStage {
-                error: UnknownConstructor(name.content.to_string()),
+                error: unknown_constructor(&name.content),
                 loc: Some(name_loc),
             })?;
 
@@ -613,15 +1066,26 @@ fn infer_constructor_call<'a>(
     let mut applied_constructor = constructor.clone();
     let mut arguments_expression = Vec::new();
     let mut all_implicit_bindings = Vec::new();
-    for (p::definition::Definition { data, .. }, (_var_name, ty)) in
+    let mut field_bindings = Vec::new();
+    for (p::definition::Definition { data, .. }, (field_name, ty)) in
         zip(constructor_args, fields.iter())
     {
-        let (data_value, check_bindings) = check(module_ctx, local_ctx, data, ty)?;
+        // Type of field may depend on previous fields, which are known now.
+        let ty = subst::apply_bindings_to_type(ty.clone(), &field_bindings);
+        let ty = normalize::evaluate_type(&ty, module_ctx);
+        let (data_value, check_bindings) = check(module_ctx, holes, local_ctx, data, &ty)
+            .map_err(|error| with_origin(error, &name.content, field_name))?;
+        field_bindings.push((field_name.clone(), data_value.clone()));
 
         all_implicit_bindings.extend(check_bindings);
 
-        let new_ctor =
-            apply::application(&applied_constructor, &data_value, module_ctx).at(name_loc)?;
+        let new_ctor = apply::application(&applied_constructor, &data_value, module_ctx)
+            .map_err(|error| {
+                let (_, field_type) = &applied_constructor.fields[0];
+                explain_mismatch(module_ctx, field_type, &type_of(&data_value), error)
+            })
+            .at(name_loc)
+            .map_err(|error| with_origin(error, &name.content, field_name))?;
         arguments_expression.push(data_value.clone());
 
         applied_constructor = new_ctor;
@@ -655,6 +1119,63 @@ fn infer_constructor_call<'a>(
     })
 }
 
+/// Runs speculative elaboration, forgetting holes found by it if it fails.
+fn attempt<T>(holes: &Holes, elaborate: impl FnOnce() -> Option<T>) -> Option<T> {
+    let found = holes.borrow().len();
+    let result = elaborate();
+    if result.is_none() {
+        holes.borrow_mut().truncate(found);
+    }
+    result
+}
+
+/// Records goal of typed hole at `loc`, which is filled with a fresh variable of `expected_type`.
+///
+/// Returns name of the variable, e.g. `?0`.
+fn fill_hole<'a>(
+    module_ctx: &Mod,
+    holes: &Holes,
+    local_ctx: &'a Ctx<'a>,
+    expected_type: &TypeExpr,
+    loc: Loc,
+) -> Str {
+    let visible = local_ctx.visible_terms();
+    let matched: Bindings = visible
+        .iter()
+        .filter_map(|&(name, _, value)| Some((name.clone(), value?.clone())))
+        .collect();
+    let show = |ty: &TypeExpr| {
+        show_type(
+            &subst::apply_bindings_to_type(ty.clone(), &matched),
+            module_ctx,
+        )
+    };
+    let context = visible
+        .into_iter()
+        .map(|(name, ty, value)| Hypothesis {
+            name: name.to_string(),
+            ty: show(ty),
+            value: value
+                .filter(
+                    |value| !matches!(value, Value::Variable { name: alias, .. } if alias == name),
+                )
+                .map(|value| show_value(value, module_ctx)),
+        })
+        .collect();
+    let goal = Goal {
+        expected: show(expected_type),
+        context,
+    };
+
+    let mut holes = holes.borrow_mut();
+    let name = Str::from(format!("?{}", holes.len()));
+    holes.push(ElaboratingStage {
+        error: Error::TypeHole(Box::new(goal)),
+        loc: Some(loc),
+    });
+    name
+}
+
 fn check_arity(expected: usize, found: usize, loc: Option<Loc>) -> EResult<()> {
     if expected == found {
         Ok(())
@@ -675,7 +1196,77 @@ fn lookup_var<'a>(ctx: &'a Ctx<'a>, name: &Name) -> EResult<TypeExpr> {
         })
 }
 
+fn unknown_constructor(name: &Str) -> Error {
+    UnknownConstructor {
+        name: name.to_string(),
+        suggestion: None,
+    }
+}
+
+fn unary_symbol<S>(op: &o::UnaryOp<S>) -> &'static str {
+    match op {
+        o::UnaryOp::Minus => "-",
+        o::UnaryOp::Bang => "!",
+        o::UnaryOp::Access(_) => ".",
+    }
+}
+
+fn operator_mismatch(
+    module_ctx: &Mod,
+    operator: &str,
+    accepted: &[builtins::BuiltinType],
+    found: String,
+) -> Error {
+    OperatorTypeMismatch {
+        operator: operator.to_owned(),
+        expected: accepted
+            .iter()
+            .map(|t| show_type(&builtins::get_builtin(t), module_ctx))
+            .collect(),
+        found,
+    }
+}
+
+/// Replaces mismatch of parts of `expected` and `found` types with mismatch of whole types.
+fn explain_mismatch(
+    module_ctx: &Mod,
+    expected: &TypeExpr,
+    found: &TypeExpr,
+    error: Error,
+) -> Error {
+    match error {
+        TypeMismatch { origin: None, .. }
+        | ValueMismatch { .. }
+        | ConstructorMismatch(..)
+        | LiteralMismatch(..) => TypeMismatch {
+            expected: show_type(expected, module_ctx),
+            found: show_type(found, module_ctx),
+            origin: None,
+        },
+        error => error,
+    }
+}
+
+/// Records that type mismatch is caused by declared type of `member` of `owner`,
+/// unless the mismatch is already explained by an inner declaration.
+fn with_origin(mut stage: ElaboratingStage, owner: &Str, member: &Str) -> ElaboratingStage {
+    if let TypeMismatch {
+        origin: origin @ None,
+        ..
+    } = &mut stage.error
+    {
+        *origin = Some(Box::new(Origin {
+            owner: owner.to_string(),
+            member: member.to_string(),
+            loc: None,
+        }));
+    }
+    stage
+}
+
 fn require_op_type(
+    module_ctx: &Mod,
+    operator: &str,
     accepted: &[builtins::BuiltinType],
     expected_type: &TypeExpr,
     loc: Loc,
@@ -685,7 +1276,12 @@ fn require_op_type(
         .any(|t| *expected_type == builtins::get_builtin(t))
     {
         return Err(ElaboratingStage {
-            error: OperatorTypeMismatch,
+            error: operator_mismatch(
+                module_ctx,
+                operator,
+                accepted,
+                show_type(expected_type, module_ctx),
+            ),
             loc: Some(loc),
         });
     }
@@ -694,6 +1290,7 @@ fn require_op_type(
 
 fn check<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     expression: &p::Expression<Loc, Name>,
     expected_type: &TypeExpr,
@@ -702,64 +1299,95 @@ fn check<'a>(
     match &expression.node {
         p::ExpressionNode::OpCall(op) => match op {
             o::OpCall::Literal(literal) => {
-                operators::check_literal(literal, expected_type).at(loc)?;
-                return Ok((
-                    operators::make_lit(literal.clone(), expected_type.clone()),
-                    vec![],
-                ));
+                let literal =
+                    operators::check_literal(literal, expected_type, module_ctx).at(loc)?;
+                return Ok((operators::make_lit(literal, expected_type.clone()), vec![]));
             }
             o::OpCall::Unary(op @ (o::UnaryOp::Minus | o::UnaryOp::Bang), arg) => {
-                require_op_type(operators::unary_accepted_types(op), expected_type, loc)?;
-                let (checked_arg, bindings) = check(module_ctx, local_ctx, arg, expected_type)?;
+                require_op_type(
+                    module_ctx,
+                    unary_symbol(op),
+                    operators::unary_accepted_types(op),
+                    expected_type,
+                    loc,
+                )?;
+                let (checked_arg, bindings) =
+                    check(module_ctx, holes, local_ctx, arg, expected_type)?;
                 debug_assert_eq!(bindings.len(), 0);
                 let value = operators::make_unary(op.into(), checked_arg, expected_type.clone());
+                operators::check_overflow(&value).at(loc)?;
                 return Ok((normalize::simplify(&value), vec![]));
             }
-            o::OpCall::Binary(op, l, r) => {
-                require_op_type(operators::binary_accepted_types(op), expected_type, loc)?;
-                let (checked_l, bindings_l) = check(module_ctx, local_ctx, l, expected_type)?;
+            // Operands of comparison don't have expected type, so it is inferred.
+            o::OpCall::Binary(op, l, r) if !op.is_predicate() => {
+                require_op_type(
+                    module_ctx,
+                    op.symbol(),
+                    operators::binary_accepted_types(op),
+                    expected_type,
+                    loc,
+                )?;
+                let (checked_l, bindings_l) =
+                    check(module_ctx, holes, local_ctx, l, expected_type)?;
                 debug_assert_eq!(bindings_l.len(), 0);
-                let (checked_r, bindings_r) = check(module_ctx, local_ctx, r, expected_type)?;
+                let (checked_r, bindings_r) =
+                    check(module_ctx, holes, local_ctx, r, expected_type)?;
                 debug_assert_eq!(bindings_r.len(), 0);
+                operators::check_divisor(*op, &checked_r).at(r.loc)?;
                 let value =
                     operators::make_binary(*op, checked_l, checked_r, expected_type.clone());
+                operators::check_overflow(&value).at(loc)?;
                 return Ok((normalize::simplify(&value), vec![]));
             }
-            o::OpCall::Unary(..) => {}
+            o::OpCall::Unary(..) | o::OpCall::Binary(..) => {}
         },
 
         p::ExpressionNode::ConstructorCall { name, fields } => {
-            return check_constructor_call(module_ctx, local_ctx, name, fields, expected_type);
+            return check_constructor_call(
+                module_ctx,
+                holes,
+                local_ctx,
+                name,
+                fields,
+                expected_type,
+            );
         }
         p::ExpressionNode::TypedHole => {
-            return Err(ElaboratingStage {
-                error: Error::TypeHole(expected_type.clone()),
-                loc: Some(loc),
-            });
+            let name = fill_hole(module_ctx, holes, local_ctx, expected_type, loc);
+            let hole = Value::Variable {
+                name,
+                ty: expected_type.clone(),
+            };
+            return Ok((hole, vec![]));
         }
         _ => {}
     }
-    let elaborated = infer(module_ctx, local_ctx, expression)?;
+    let elaborated = infer(module_ctx, holes, local_ctx, expression)?;
     let inferred_type = type_of(&elaborated);
 
-    let right_bindings = unify::unify_type(&inferred_type, expected_type, module_ctx).at(loc)?;
+    let right_bindings = unify::unify_type(&inferred_type, expected_type, module_ctx)
+        .map_err(|error| explain_mismatch(module_ctx, expected_type, &inferred_type, error))
+        .at(loc)?;
     Ok((elaborated, right_bindings))
 }
 
 fn check_constructor_call<'a>(
     module_ctx: &Mod,
+    holes: &Holes,
     local_ctx: &'a Ctx<'a>,
     name: &Name,
     constructor_args: &p::definition::Definitions<Loc, Name, p::Expression<Loc, Name>>,
     expected_type: &TypeExpr,
 ) -> EResult<(Value, Bindings)> {
     let name_loc = Loc::from(name);
-    let elaborated = infer_constructor_call(module_ctx, local_ctx, name, constructor_args)?;
+    let elaborated = infer_constructor_call(module_ctx, holes, local_ctx, name, constructor_args)?;
 
+    let inferred_type = type_of(&elaborated);
     let TypeExpr::TypeExpression {
         name: inferred_name,
         dependencies: inferred_deps,
-    } = &type_of(&elaborated);
+    } = &inferred_type;
+    let mismatch = |error| explain_mismatch(module_ctx, expected_type, &inferred_type, error);
 
     let TypeExpr::TypeExpression {
         name: expected_name,
@@ -768,7 +1396,11 @@ fn check_constructor_call<'a>(
 
     if inferred_name != expected_name || inferred_deps.len() != expected_deps.len() {
         return Err(ElaboratingStage {
-            error: TypeMismatch,
+            error: TypeMismatch {
+                expected: show_type(expected_type, module_ctx),
+                found: show_type(&inferred_type, module_ctx),
+                origin: None,
+            },
             loc: Some(name_loc),
         });
     }
@@ -782,7 +1414,9 @@ fn check_constructor_call<'a>(
                 left_bindings.push((name.clone(), expected_dep.clone()));
             }
             concrete => {
-                let rb = unify::unify_value(concrete, expected_dep, module_ctx).at(name_loc)?;
+                let rb = unify::unify_value(concrete, expected_dep, module_ctx)
+                    .map_err(mismatch)
+                    .at(name_loc)?;
                 right_bindings.extend(rb);
             }
         }
@@ -1,16 +1,19 @@
 use crate::ast::elaborated as e;
 use crate::ast::operators as o;
-use crate::elaboration::builtins::BuiltinType;
-use crate::elaboration::subst;
+use crate::elaboration::builtins::{self, BuiltinType};
+use crate::elaboration::pretty::{show_type, show_value};
+use crate::elaboration::{normalize, subst, type_of};
 use crate::error::elaborating::Error;
 use crate::error::elaborating::Error::{
-    ArityMismatch, ConflictingBinding, ConstructorMismatch, LiteralMismatch, OperatorTypeMismatch,
-    TypeMismatch,
+    ArityMismatch, ConflictingBinding, ConstructorMismatch, LiteralMismatch, TypeMismatch,
+    ValueMismatch,
 };
 use std::hash::Hash;
 
 pub type Bindings<Str> = Vec<(Str, e::ValueExpression<Str>)>;
 
+/// Unifies `a` with `b`, where `a` is the found type and `b` is the expected one.
+///
 /// # Errors
 pub fn unify_type<Str>(
     a: &e::TypeExpression<Str>,
@@ -30,7 +33,11 @@ where
     } = b;
 
     if name_a != name_b {
-        return Err(TypeMismatch);
+        return Err(TypeMismatch {
+            expected: show_type(b, module),
+            found: show_type(a, module),
+            origin: None,
+        });
     }
 
     let declared_arity = module
@@ -52,6 +59,11 @@ where
     )
 }
 
+/// Unifies `a` with `b`, where `a` is the found value and `b` is the expected one.
+///
+/// Values are compared after evaluation (see [`normalize::evaluate`]), so accesses to fields
+/// of constructor calls are unified with values of the fields.
+///
 /// # Errors
 pub fn unify_value<Str>(
     a: &e::ValueExpression<Str>,
@@ -61,6 +73,13 @@ pub fn unify_value<Str>(
 where
     Str: Clone + Eq + Ord + Hash + From<BuiltinType> + ToString,
 {
+    // Values equal by computation are unified by their normal forms.
+    let a = &normalize::evaluate(a, module);
+    let b = &normalize::evaluate(b, module);
+    let mismatch = || ValueMismatch {
+        expected: show_value(b, module),
+        found: show_value(a, module),
+    };
     match (a, b) {
         (
             e::ValueExpression::Variable { name: x, ty: ty_x },
@@ -76,7 +95,7 @@ where
                 ty: ty_x.clone(),
             },
         )]),
-        (e::ValueExpression::Variable { .. }, _) => Err(TypeMismatch),
+        (e::ValueExpression::Variable { .. }, _) => Err(mismatch()),
 
         (other, e::ValueExpression::Variable { name, .. }) => {
             Ok(vec![(name.clone(), other.clone())])
@@ -107,9 +126,13 @@ where
         (
             e::ValueExpression::OpCall { op_call: op_a, .. },
             e::ValueExpression::OpCall { op_call: op_b, .. },
-        ) => unify_op_call(op_a, op_b, module),
+        ) => unify_op_call(op_a, op_b, module, mismatch),
 
-        _ => Err(TypeMismatch),
+        (e::ValueExpression::Type { ty: ty_a }, e::ValueExpression::Type { ty: ty_b }) => {
+            unify_type(ty_a, ty_b, module)
+        }
+
+        _ => Err(mismatch()),
     }
 }
 
@@ -140,6 +163,7 @@ fn unify_op_call<Str>(
     a: &o::OpCall<Str, e::Rec<e::ValueExpression<Str>>>,
     b: &o::OpCall<Str, e::Rec<e::ValueExpression<Str>>>,
     module: &e::Module<Str>,
+    mismatch: impl Fn() -> Error,
 ) -> Result<Bindings<Str>, Error>
 where
     Str: Clone + Eq + Ord + Hash + From<BuiltinType> + ToString,
@@ -154,13 +178,30 @@ where
         }
         (o::OpCall::Unary(op_a, expr_a), o::OpCall::Unary(op_b, expr_b)) => {
             if op_a != op_b {
-                return Err(OperatorTypeMismatch);
+                return Err(mismatch());
+            }
+            if is_length(op_a, expr_a) {
+                // Strings of the same length are not equal.
+                return if expr_a == expr_b {
+                    Ok(vec![])
+                } else {
+                    Err(mismatch())
+                };
             }
             unify_value(expr_a, expr_b, module)
         }
         (o::OpCall::Binary(op_a, la, ra), o::OpCall::Binary(op_b, lb, rb)) => {
             if op_a != op_b {
-                return Err(OperatorTypeMismatch);
+                return Err(mismatch());
+            }
+            if op_a.is_opaque() || *op_a == o::BinaryOp::StartsWith {
+                // Operands can not be recovered from the result, e.g. `n / 8` is equal to
+                // `(n + 1) / 8` for some `n`, so such calls are unified as atoms.
+                return if la == lb && ra == rb {
+                    Ok(vec![])
+                } else {
+                    Err(mismatch())
+                };
             }
             unify_args(
                 &[(**la).clone(), (**ra).clone()],
@@ -168,10 +209,18 @@ where
                 module,
             )
         }
-        _ => Err(OperatorTypeMismatch),
+        _ => Err(mismatch()),
     }
 }
 
+/// Whether `op` applied to `operand` is length of `String` or `Bytes`.
+fn is_length<Str>(op: &o::UnaryOp<Str>, operand: &e::ValueExpression<Str>) -> bool
+where
+    Str: Clone + PartialEq + From<BuiltinType>,
+{
+    matches!(op, o::UnaryOp::Access(_)) && builtins::is_string(&type_of(operand))
+}
+
 fn extend_bindings<Str>(acc: &mut Bindings<Str>, new: Bindings<Str>) -> Result<(), Error>
 where
     Str: Clone + Eq + ToString,
@@ -190,11 +239,12 @@ where
 mod tests {
     use super::*;
     use crate::ast::elaborated::{self as e, ConstructorNames};
-    use crate::ast::operators::Literal;
+    use crate::ast::operators::{Double, Literal};
     use crate::elaboration::builtins;
     use crate::error::elaborating::Error::{
-        ArityMismatch, ConstructorMismatch, LiteralMismatch, OperatorTypeMismatch, TypeMismatch,
+        ArityMismatch, ConstructorMismatch, LiteralMismatch, TypeMismatch, ValueMismatch,
     };
+    use indexmap::IndexMap;
     use std::collections::BTreeMap;
 
     fn nat_ty() -> e::TypeExpression<String> {
@@ -243,6 +293,13 @@ mod tests {
         }
     }
 
+    fn lit_double(v: f64) -> e::ValueExpression<String> {
+        e::ValueExpression::OpCall {
+            op_call: o::OpCall::Literal(Literal::Double(Double(v))),
+            result_type: builtins::get_builtin(&BuiltinType::Double),
+        }
+    }
+
     fn neg(val: e::ValueExpression<String>) -> e::ValueExpression<String> {
         e::ValueExpression::OpCall {
             op_call: o::OpCall::Unary(o::UnaryOp::Minus, e::Rec::new(val)),
@@ -285,6 +342,7 @@ mod tests {
 
     fn test_module() -> e::Module<String> {
         e::Module {
+            package: vec![],
             types: [
                 (
                     "Nat".to_owned(),
@@ -293,6 +351,7 @@ mod tests {
                         constructor_names: ConstructorNames::OfEnum(
                             ["Zero", "Suc"].map(str::to_owned).into_iter().collect(),
                         ),
+                        refinements: vec![],
                     },
                 ),
                 (
@@ -302,12 +361,14 @@ mod tests {
                         constructor_names: ConstructorNames::OfEnum(
                             ["Nil", "Cons"].map(str::to_owned).into_iter().collect(),
                         ),
+                        refinements: vec![],
                     },
                 ),
             ]
             .into_iter()
             .collect(),
             constructors: BTreeMap::new(),
+            aliases: IndexMap::new(),
         }
     }
 
@@ -320,7 +381,13 @@ mod tests {
     #[test]
     fn left_binds() {
         let m = test_module();
-        assert_eq!(unify_value(&var("x"), &zero(), &m), Err(TypeMismatch));
+        assert_eq!(
+            unify_value(&var("x"), &zero(), &m),
+            Err(ValueMismatch {
+                expected: "Zero{}".to_owned(),
+                found: "x".to_owned(),
+            })
+        );
     }
 
     #[test]
@@ -372,14 +439,14 @@ mod tests {
             result_type: nat_ty(),
         };
 
-        assert_eq!(
+        assert!(matches!(
             unify_value(
                 &tuple(suc(suc(var("x"))), var("y"), suc(suc(zero()))),
                 &tuple(suc(var("z")), suc(suc(suc(zero()))), suc(var("z"))),
                 &m,
             ),
-            Err(TypeMismatch)
-        );
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
@@ -391,12 +458,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn value_double_bitwise() {
+        let m = test_module();
+        assert_eq!(
+            unify_value(&lit_double(0.5), &lit_double(0.5), &m),
+            Ok(vec![])
+        );
+        assert_eq!(
+            unify_value(&lit_double(0.0), &lit_double(-0.0), &m),
+            Err(LiteralMismatch(
+                Literal::Double(Double(0.0)),
+                Literal::Double(Double(-0.0))
+            ))
+        );
+    }
+
     #[test]
     fn type_name_mismatch() {
         let module = test_module();
         assert_eq!(
             unify_type(&nat_ty(), &vec_ty(zero()), &module),
-            Err(TypeMismatch)
+            Err(TypeMismatch {
+                expected: "Vec Zero{}".to_owned(),
+                found: "Nat".to_owned(),
+                origin: None,
+            })
         );
     }
 
@@ -452,13 +539,19 @@ mod tests {
     #[test]
     fn ctor_vs_opcall_mismatch() {
         let m = test_module();
-        assert_eq!(unify_value(&zero(), &lit_int(1), &m), Err(TypeMismatch));
+        assert!(matches!(
+            unify_value(&zero(), &lit_int(1), &m),
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
     fn opcall_vs_ctor_mismatch() {
         let m = test_module();
-        assert_eq!(unify_value(&lit_int(1), &zero(), &m), Err(TypeMismatch));
+        assert!(matches!(
+            unify_value(&lit_int(1), &zero(), &m),
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
@@ -479,37 +572,37 @@ mod tests {
     #[test]
     fn unary_op_mismatch() {
         let m = test_module();
-        assert_eq!(
+        assert!(matches!(
             unify_value(&neg(zero()), &bang(zero()), &m),
-            Err(OperatorTypeMismatch)
-        );
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
     fn binary_op_mismatch() {
         let m = test_module();
-        assert_eq!(
+        assert!(matches!(
             unify_value(&add_op(zero(), zero()), &sub_op(zero(), zero()), &m),
-            Err(OperatorTypeMismatch)
-        );
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
     fn opcall_kind_mismatch_literal_vs_unary() {
         let m = test_module();
-        assert_eq!(
+        assert!(matches!(
             unify_value(&lit_int(1), &neg(zero()), &m),
-            Err(OperatorTypeMismatch)
-        );
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
     fn opcall_kind_mismatch_unary_vs_binary() {
         let m = test_module();
-        assert_eq!(
+        assert!(matches!(
             unify_value(&neg(zero()), &add_op(zero(), zero()), &m),
-            Err(OperatorTypeMismatch)
-        );
+            Err(ValueMismatch { .. })
+        ));
     }
 
     #[test]
@@ -520,4 +613,105 @@ mod tests {
             Ok(vec![("y".to_owned(), var("x"))])
         );
     }
+
+    #[test]
+    fn opaque_op_same_success() {
+        let m = test_module();
+        let div = |a| bin_op(o::BinaryOp::Slash, BuiltinType::Int, a, lit_int(8));
+        assert_eq!(unify_value(&div(var("x")), &div(var("x")), &m), Ok(vec![]));
+    }
+
+    #[test]
+    fn opaque_op_is_not_decomposed() {
+        let m = test_module();
+        let div = |a| bin_op(o::BinaryOp::Slash, BuiltinType::Int, a, lit_int(8));
+        assert!(matches!(
+            unify_value(&div(var("x")), &div(var("y")), &m),
+            Err(ValueMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn opaque_op_as_atom_binds() {
+        let m = test_module();
+        let div = bin_op(o::BinaryOp::Slash, BuiltinType::Int, var("x"), lit_int(8));
+        assert_eq!(
+            unify_value(
+                &add_op(div.clone(), lit_int(1)),
+                &add_op(var("y"), lit_int(1)),
+                &m
+            ),
+            Ok(vec![("y".to_owned(), div)])
+        );
+    }
+
+    #[test]
+    fn starts_with_is_not_decomposed() {
+        let m = test_module();
+        let string = |name: &str| e::ValueExpression::Variable {
+            name: name.to_owned(),
+            ty: builtins::get_builtin(&BuiltinType::String),
+        };
+        let starts_with = |a, b| bin_op(o::BinaryOp::StartsWith, BuiltinType::Bool, a, b);
+        assert_eq!(
+            unify_value(
+                &starts_with(string("x"), string("p")),
+                &starts_with(string("x"), string("p")),
+                &m
+            ),
+            Ok(vec![])
+        );
+        assert!(matches!(
+            unify_value(
+                &starts_with(string("x"), string("p")),
+                &starts_with(string("y"), string("p")),
+                &m
+            ),
+            Err(ValueMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn string_length_is_not_decomposed() {
+        let m = test_module();
+        let length = |name: &str| e::ValueExpression::OpCall {
+            op_call: o::OpCall::Unary(
+                o::UnaryOp::Access("length".to_owned()),
+                e::Rec::new(e::ValueExpression::Variable {
+                    name: name.to_owned(),
+                    ty: builtins::get_builtin(&BuiltinType::String),
+                }),
+            ),
+            result_type: builtins::get_builtin(&BuiltinType::UInt),
+        };
+        assert_eq!(unify_value(&length("x"), &length("x"), &m), Ok(vec![]));
+        assert!(matches!(
+            unify_value(&length("x"), &length("y"), &m),
+            Err(ValueMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn access_to_constructor_field_is_evaluated() {
+        let mut m = test_module();
+        m.constructors.insert(
+            "Suc".to_owned(),
+            e::Constructor {
+                implicits: vec![],
+                fields: vec![("pred".to_owned(), nat_ty())],
+                result_type: nat_ty(),
+                refinements: vec![],
+                defaults: vec![],
+            },
+        );
+        let pred = e::ValueExpression::OpCall {
+            op_call: o::OpCall::Unary(
+                o::UnaryOp::Access("pred".to_owned()),
+                e::Rec::new(suc(var("x"))),
+            ),
+            result_type: nat_ty(),
+        };
+        assert_eq!(unify_value(&var("x"), &pred, &m), Ok(vec![]));
+        assert_eq!(unify_type(&vec_ty(pred), &vec_ty(var("x")), &m), Ok(vec![]));
+    }
 }
@@ -1,35 +1,55 @@
 //! Module contains `elaborating::Error` - errors that appear
 //! during elaborating phase
 
-use super::ErrorStage;
-use crate::arena::InternedString;
-use crate::ast::elaborated;
+use super::{ErrorStage, Severity};
 use crate::ast::operators::Literal;
 use crate::location::{Location, Offset};
+use std::fmt::{self, Display};
 use thiserror::Error;
 
 /// Errors that can occur during type elaboration.
+///
+/// Types and values in errors are shown in surface syntax.
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum Error {
-    #[error("unknown type {0}")]
-    UnknownType(String),
+    #[error("unknown type {name}")]
+    UnknownType {
+        name: String,
+        suggestion: Option<String>,
+    },
     #[error("unknown variable {0}")]
     UnknownVariable(String),
-    #[error("unknown constructor {0}")]
-    UnknownConstructor(String),
-    #[error("unknown field {0}")]
-    UnknownField(String),
+    #[error("unknown constructor {name}")]
+    UnknownConstructor {
+        name: String,
+        suggestion: Option<String>,
+    },
+    #[error("unknown field {name}")]
+    UnknownField {
+        name: String,
+        suggestion: Option<String>,
+    },
     #[error("arity mismatch: expected {expected}, found {found}")]
     ArityMismatch { expected: usize, found: usize },
-    #[error("type mismatch")]
-    TypeMismatch,
-    #[error("operator type mismatch")]
-    OperatorTypeMismatch,
+    #[error("type mismatch: expected {expected}, found {found}")]
+    TypeMismatch {
+        expected: String,
+        found: String,
+        origin: Option<Box<Origin>>,
+    },
+    #[error("operator {operator} is not defined for {found}, expected {}", .expected.join(" or "))]
+    OperatorTypeMismatch {
+        operator: String,
+        expected: Vec<String>,
+        found: String,
+    },
+    #[error("value mismatch: expected {expected}, found {found}")]
+    ValueMismatch { expected: String, found: String },
     #[error("unsupported syntax")]
     UnsupportedSyntax,
     #[error("constructor mismatch: {0} vs {1}")]
     ConstructorMismatch(String, String),
-    #[error("literal mismatch: {0:?} vs {1:?}")]
+    #[error("literal mismatch: {0} vs {1}")]
     LiteralMismatch(Literal, Literal),
     #[error("conflicting binding for {0}")]
     ConflictingBinding(String),
@@ -37,8 +57,253 @@ pub enum Error {
     Cycle(Vec<(String, Location<Offset>)>),
     #[error("no initial constructor for: {}", .0.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>().join(", "))]
     NoInitialConstructor(Vec<(String, Location<Offset>)>),
-    #[error("type hole should have type {0:?}")]
-    TypeHole(elaborated::TypeExpression<InternedString>),
+    #[error("field {0} without default follows fields with defaults")]
+    NonTrailingDefault(String),
+    #[error("literal is out of range of its type")]
+    LiteralOutOfRange,
+    #[error("Double literal can't be used as pattern")]
+    DoublePattern,
+    #[error("refinement of {0} is violated")]
+    RefinementViolated(String),
+    #[error("division by zero")]
+    DivisionByZero,
+    #[error("constant expression overflows {0}")]
+    IntegerOverflow(String),
+    #[error("type hole should have type {}", .0.expected)]
+    TypeHole(Box<Goal>),
+    #[error("patterns are not exhaustive, missing: {}", .0.join("; "))]
+    NonExhaustive(Vec<String>),
+    #[error("branch is unreachable, as previous branches match all its values")]
+    UnreachableBranch,
+    #[error("dependency {0} is never used")]
+    UnusedDependency(String),
+    #[error("{0} shadows dependency with the same name")]
+    ShadowedDependency(String),
+    #[error("type {0} is never referenced by other types")]
+    UnreferencedType(String),
+    #[error("type {0} is generated into the same namespace as type {1}")]
+    NamespaceClash(String, String),
+    #[error("unknown lint {0}")]
+    UnknownLint(String),
+}
+
+impl Error {
+    /// Severity of the error, which is `Warning` for suspicious but valid schemas.
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::UnknownLint(_) => Severity::Warning,
+            _ if self.lint().is_some() => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Stable code of the error.
+    ///
+    /// Codes of errors start with `E`: `E01xx` are unknown names, `E02xx` are type errors,
+    /// `E03xx` are errors of declarations and `E04xx` are errors of refinements.
+    /// Codes of warnings start with `W`.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::UnknownType { .. } => "E0101",
+            Self::UnknownVariable(_) => "E0102",
+            Self::UnknownConstructor { .. } => "E0103",
+            Self::UnknownField { .. } => "E0104",
+            Self::ArityMismatch { .. } => "E0201",
+            Self::TypeMismatch { .. } => "E0202",
+            Self::OperatorTypeMismatch { .. } => "E0203",
+            Self::ValueMismatch { .. } => "E0204",
+            Self::ConstructorMismatch(..) => "E0205",
+            Self::LiteralMismatch(..) => "E0206",
+            Self::ConflictingBinding(_) => "E0207",
+            Self::LiteralOutOfRange => "E0208",
+            Self::DoublePattern => "E0209",
+            Self::TypeHole(_) => "E0210",
+            Self::UnsupportedSyntax => "E0211",
+            Self::Cycle(_) => "E0301",
+            Self::NoInitialConstructor(_) => "E0302",
+            Self::NonTrailingDefault(_) => "E0303",
+            Self::RefinementViolated(_) => "E0401",
+            Self::DivisionByZero => "E0402",
+            Self::IntegerOverflow(_) => "E0403",
+            Self::NonExhaustive(_) => "W0101",
+            Self::UnreachableBranch => "W0102",
+            Self::UnusedDependency(_) => "W0103",
+            Self::ShadowedDependency(_) => "W0104",
+            Self::UnreferencedType(_) => "W0105",
+            Self::UnknownLint(_) => "W0106",
+            Self::NamespaceClash(..) => "W0107",
+        }
+    }
+
+    /// Hint on fixing the error, e.g. a similar known name.
+    #[must_use]
+    pub fn help(&self) -> Option<String> {
+        match self {
+            Self::UnknownType { suggestion, .. }
+            | Self::UnknownConstructor { suggestion, .. }
+            | Self::UnknownField { suggestion, .. } => suggestion
+                .as_ref()
+                .map(|suggestion| format!("did you mean `{suggestion}`?")),
+            _ => None,
+        }
+    }
+
+    /// Secondary locations related to the error with their descriptions.
+    #[must_use]
+    pub fn labels(&self) -> Vec<(String, Location<Offset>)> {
+        match self {
+            Self::Cycle(entries) => entries
+                .iter()
+                .map(|(name, loc)| (format!("{name} is part of the cycle"), *loc))
+                .collect(),
+            Self::NoInitialConstructor(entries) => entries
+                .iter()
+                .map(|(name, loc)| (format!("{name} has no initial constructor"), *loc))
+                .collect(),
+            Self::TypeMismatch {
+                expected,
+                origin: Some(origin),
+                ..
+            } => origin
+                .loc
+                .map(|loc| {
+                    let Origin { owner, member, .. } = &**origin;
+                    (
+                        format!("{member} of {owner} is declared as {expected}"),
+                        loc,
+                    )
+                })
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Lint which reported the warning, if it can be silenced.
+    #[must_use]
+    pub const fn lint(&self) -> Option<Lint> {
+        match self {
+            Self::NonExhaustive(_) => Some(Lint::NonExhaustive),
+            Self::UnreachableBranch => Some(Lint::UnreachableBranch),
+            Self::UnusedDependency(_) => Some(Lint::UnusedDependency),
+            Self::ShadowedDependency(_) => Some(Lint::Shadowing),
+            Self::UnreferencedType(_) => Some(Lint::UnreferencedType),
+            Self::NamespaceClash(..) => Some(Lint::NamespaceClash),
+            _ => None,
+        }
+    }
+}
+
+/// Field or dependency, which declared type is expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Origin {
+    /// Type or constructor with the field or dependency.
+    pub owner: String,
+    /// Name of the field or dependency.
+    pub member: String,
+    /// Location of its declaration, unless it is declared in another file.
+    pub loc: Option<Location<Offset>>,
+}
+
+/// Goal of a typed hole: type expected in its place and variables in scope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Goal {
+    /// Expected type.
+    pub expected: String,
+    /// Dependencies, earlier fields and pattern variables in order of declaration.
+    pub context: Vec<Hypothesis>,
+}
+
+/// Variable in scope of a typed hole.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hypothesis {
+    pub name: String,
+    /// Type of the variable, where matched variables are substituted.
+    pub ty: String,
+    /// Value that the variable is matched with by a pattern.
+    pub value: Option<String>,
+}
+
+impl Display for Goal {
+    /// Shows goal as a type below variables in scope, e.g.
+    ///
+    /// ```text
+    /// n = Suc{pred: m} : Nat
+    /// m : Nat
+    /// ----------
+    /// Vec m
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for hypothesis in &self.context {
+            writeln!(f, "{hypothesis}")?;
+        }
+        writeln!(f, "----------")?;
+        write!(f, "{}", self.expected)
+    }
+}
+
+impl Display for Hypothesis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{} = {value} : {}", self.name, self.ty),
+            None => write!(f, "{} : {}", self.name, self.ty),
+        }
+    }
+}
+
+/// Checks of valid schemas, which report suspicious places as warnings.
+///
+/// Lints are silenced for a declaration by a comment right before it:
+/// `// dbuf:allow(unused_dependency, shadowing)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Lint {
+    /// Values of enum dependencies matched by no branch.
+    NonExhaustive,
+    /// Enum branch matching only values matched by previous branches.
+    UnreachableBranch,
+    /// Dependency used neither in types of fields nor in patterns.
+    UnusedDependency,
+    /// Pattern variable or field named as a dependency.
+    Shadowing,
+    /// Type referenced neither by other types of its file nor by files importing it.
+    UnreferencedType,
+    /// Type named as another type of its file up to case.
+    ///
+    /// Generated Rust and Swift put each message and enum into a namespace named
+    /// as the type in lower case, so namespaces of such types collide.
+    NamespaceClash,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 6] = [
+        Lint::NonExhaustive,
+        Lint::UnreachableBranch,
+        Lint::UnusedDependency,
+        Lint::Shadowing,
+        Lint::UnreferencedType,
+        Lint::NamespaceClash,
+    ];
+
+    /// Name of the lint used in `dbuf:allow` pragma.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Lint::NonExhaustive => "non_exhaustive",
+            Lint::UnreachableBranch => "unreachable_branch",
+            Lint::UnusedDependency => "unused_dependency",
+            Lint::Shadowing => "shadowing",
+            Lint::UnreferencedType => "unreferenced_type",
+            Lint::NamespaceClash => "namespace_clash",
+        }
+    }
+
+    /// Lint with the given name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
@@ -52,6 +317,10 @@ impl ErrorStage for ElaboratingStage {
     fn location(&self) -> Location<Offset> {
         self.loc.unwrap_or_default()
     }
+
+    fn severity(&self) -> Severity {
+        self.error.severity()
+    }
 }
 
 impl From<super::Error<ElaboratingStage>> for ElaboratingStage {
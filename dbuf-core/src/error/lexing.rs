@@ -43,8 +43,12 @@ pub enum ErrorKind {
     IntegerOverflow,
     /// Integer is incorrect.
     InvalidInteger,
+    /// Double is too huge.
+    DoubleOverflow,
     /// String literal is incorrect.
     InvalidStringLiteral,
+    /// Bytes literal is incorrect.
+    InvalidBytesLiteral,
     /// `LCIdentifier` is incorrect. May contain only [a-zA-Z0-9].
     InvalidLCIdentifier,
     /// `UCIdentifier` is incorrect. May contain only [a-zA-Z0-9].
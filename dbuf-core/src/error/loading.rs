@@ -0,0 +1,40 @@
+//! Module contains `loading::Error` - errors that appear
+//! while resolving imports between dbuf files.
+
+use std::path::PathBuf;
+
+use super::ErrorStage;
+use crate::location::{Location, Offset};
+use thiserror::Error;
+
+/// Errors that can occur while loading imported files.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Error {
+    #[error("can't read file {path}: {reason}", path = .0.display(), reason = .1)]
+    Unreadable(PathBuf, String),
+    #[error("can't find imported file {0}")]
+    UnresolvedImport(String),
+    #[error("import cycle: {}", .0.iter().map(|(p, _)| p.display().to_string()).collect::<Vec<_>>().join(" -> "))]
+    ImportCycle(Vec<(PathBuf, Location<Offset>)>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("loading error: {error}")]
+pub struct LoadingStage {
+    pub error: Error,
+    /// File containing the failed import declaration.
+    pub file: PathBuf,
+    pub loc: Option<Location<Offset>>,
+}
+
+impl ErrorStage for LoadingStage {
+    fn location(&self) -> Location<Offset> {
+        self.loc.unwrap_or_default()
+    }
+}
+
+impl From<super::Error<LoadingStage>> for LoadingStage {
+    fn from(value: super::Error<LoadingStage>) -> Self {
+        value.stage
+    }
+}
@@ -3,6 +3,7 @@
 
 pub mod elaborating;
 pub mod lexing;
+pub mod loading;
 pub mod parsing;
 
 use std::{fmt::Display, ops::Deref};
@@ -11,14 +12,29 @@ use thiserror::Error;
 
 use elaborating::ElaboratingStage;
 use lexing::LexingStage;
+use loading::LoadingStage;
 use parsing::ParsingStage;
 
 use crate::location::{Location, Offset};
 
+/// Severity of a reported error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Compilation fails.
+    Error,
+    /// Compilation succeeds, but the schema is probably wrong.
+    Warning,
+}
+
 /// Stages of project pipeline.
 pub trait ErrorStage: Display {
     /// location of error
     fn location(&self) -> Location<Offset>;
+
+    /// severity of error
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
 }
 
 /// Error struct for every project error.
@@ -30,6 +46,7 @@ pub struct Error<Stage> {
     pub stage: Stage,
 }
 
+pub type LoadingError = Error<LoadingStage>;
 pub type LexingError = Error<LexingStage>;
 pub type ParsingError = Error<ParsingStage>;
 pub type ElaboratingError = Error<ElaboratingStage>;
@@ -37,6 +54,8 @@ pub type ElaboratingError = Error<ElaboratingStage>;
 /// Error enum, that contains all possible errors.
 #[derive(Debug, Error)]
 pub enum GeneralError {
+    #[error("Loading error {}", .0)]
+    Loading(#[from] LoadingError),
     #[error("Lexing error {}", .0)]
     Lexing(#[from] LexingError),
     #[error("Parsing error {}", .0)]
@@ -51,6 +70,12 @@ impl<Stage: ErrorStage> Display for Error<Stage> {
     }
 }
 
+impl From<LoadingStage> for Error<LoadingStage> {
+    fn from(value: LoadingStage) -> Self {
+        Error { stage: value }
+    }
+}
+
 impl From<LexingStage> for Error<LexingStage> {
     fn from(value: LexingStage) -> Self {
         Error { stage: value }
@@ -69,6 +94,13 @@ impl From<ElaboratingStage> for Error<ElaboratingStage> {
     }
 }
 
+impl<Stage: ErrorStage> Error<Stage> {
+    /// Whether the error doesn't fail compilation.
+    pub fn is_warning(&self) -> bool {
+        self.stage.severity() == Severity::Warning
+    }
+}
+
 impl<T: ErrorStage> Deref for Error<T> {
     type Target = T;
 
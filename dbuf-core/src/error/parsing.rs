@@ -8,7 +8,7 @@ use std::fmt::Display;
 use strum_macros::EnumMessage;
 use thiserror::Error;
 
-use super::ErrorStage;
+use super::{ErrorStage, Severity};
 
 use crate::cst::Label;
 use crate::cst::Token;
@@ -29,6 +29,14 @@ impl ErrorStage for ParsingStage {
     fn location(&self) -> Location<Offset> {
         self.at
     }
+
+    /// Typed holes are warnings, since their goals are reported by elaboration.
+    fn severity(&self) -> Severity {
+        match self.extra {
+            Some(ErrorExtra::TypedHole(_)) => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
 }
 
 impl From<super::Error<ParsingStage>> for ParsingStage {
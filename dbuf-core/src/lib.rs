@@ -3,4 +3,5 @@ pub mod ast;
 pub mod cst;
 pub mod elaboration;
 pub mod error;
+pub mod loader;
 pub mod location;
@@ -0,0 +1,153 @@
+//! Module exports:
+//!   * `Loader` struct, which reads dbuf file together with every file it imports.
+//!   * `SourceFile` struct, representing one loaded file.
+//!
+//! Import path is resolved relative to directory of importing file first and
+//! then relative to each include root in order they were given.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::ast::parsed::Import;
+use crate::cst::{collect_imports, parse_to_cst};
+use crate::error::LoadingError;
+use crate::error::loading::{Error, LoadingStage};
+use crate::location::{Location, Offset};
+
+/// One loaded dbuf file.
+#[derive(Debug)]
+pub struct SourceFile {
+    /// Canonical path of file.
+    pub path: PathBuf,
+    /// Content of file.
+    pub content: String,
+    /// Indices of directly imported files in list returned by `Loader::load`.
+    pub imports: Vec<usize>,
+}
+
+/// Loader of dbuf files with their imports.
+#[derive(Debug, Default)]
+pub struct Loader {
+    /// Directories to search imported files in.
+    include_roots: Vec<PathBuf>,
+}
+
+/// State of depth-first traversal over imports.
+#[derive(Default)]
+struct LoadingState {
+    /// Already loaded files.
+    files: Vec<SourceFile>,
+    /// Index of loaded file by its path.
+    loaded: HashMap<PathBuf, usize>,
+    /// Files that are being loaded with location of import currently followed.
+    in_progress: Vec<(PathBuf, Location<Offset>)>,
+}
+
+impl SourceFile {
+    /// Name of file without extension.
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(include_roots: Vec<PathBuf>) -> Self {
+        Loader { include_roots }
+    }
+
+    /// Resolves `import` declared in file `from`.
+    #[must_use]
+    pub fn resolve(&self, from: &Path, import: &str) -> Option<PathBuf> {
+        let importer_dir = from.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        std::iter::once(&importer_dir)
+            .chain(self.include_roots.iter())
+            .map(|root| root.join(import))
+            .find(|candidate| candidate.is_file())
+            .and_then(|path| path.canonicalize().ok())
+    }
+
+    /// Loads `entry` file and all files it transitively imports.
+    ///
+    /// Files are returned in order where every file goes after
+    /// files it imports, so `entry` is always the last one.
+    ///
+    /// # Errors
+    ///  * `Error::Unreadable` when some file can't be read.
+    ///  * `Error::UnresolvedImport` when imported file is not found.
+    ///  * `Error::ImportCycle` when files import each other.
+    pub fn load(&self, entry: &Path) -> Result<Vec<SourceFile>, LoadingError> {
+        let entry = entry.canonicalize().map_err(|err| LoadingStage {
+            error: Error::Unreadable(entry.to_path_buf(), err.to_string()),
+            file: entry.to_path_buf(),
+            loc: None,
+        })?;
+
+        let mut state = LoadingState::default();
+        self.visit(entry, &mut state)?;
+        Ok(state.files)
+    }
+
+    fn visit(&self, path: PathBuf, state: &mut LoadingState) -> Result<usize, LoadingError> {
+        if let Some(&index) = state.loaded.get(&path) {
+            return Ok(index);
+        }
+
+        let content = fs::read_to_string(&path).map_err(|err| LoadingStage {
+            error: Error::Unreadable(path.clone(), err.to_string()),
+            file: path.clone(),
+            loc: None,
+        })?;
+
+        let mut imports = vec![];
+        for Import { loc, path: import } in declared_imports(&content) {
+            let resolved = self.resolve(&path, &import).ok_or_else(|| LoadingStage {
+                error: Error::UnresolvedImport(import.clone()),
+                file: path.clone(),
+                loc: Some(loc),
+            })?;
+
+            state.in_progress.push((path.clone(), loc));
+            if let Some(start) = state
+                .in_progress
+                .iter()
+                .position(|(file, _)| *file == resolved)
+            {
+                let cycle = state.in_progress.split_off(start);
+                let (file, loc) = cycle[0].clone();
+                return Err(LoadingStage {
+                    error: Error::ImportCycle(cycle),
+                    file,
+                    loc: Some(loc),
+                }
+                .into());
+            }
+            imports.push(self.visit(resolved, state)?);
+            state.in_progress.pop();
+        }
+
+        let index = state.files.len();
+        state.loaded.insert(path.clone(), index);
+        state.files.push(SourceFile {
+            path,
+            content,
+            imports,
+        });
+        Ok(index)
+    }
+}
+
+/// Returns imports declared in `content`.
+///
+/// Syntax errors are ignored here, since they are reported
+/// when file is being compiled.
+fn declared_imports(content: &str) -> Vec<Import<Location<Offset>>> {
+    let (tree, _) = parse_to_cst(content);
+    tree.as_ref().map(collect_imports).unwrap_or_default()
+}
@@ -0,0 +1,51 @@
+use super::parse_file;
+use dbuf_core::arena::InternedString;
+use dbuf_core::elaboration::derivable::{self, Derivable};
+use dbuf_core::elaboration::elaborate;
+use std::path::Path;
+
+fn analyze(path: &str) -> Derivable<InternedString> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/elaboration/derivable_dbufs")
+        .join(path);
+    let ast = parse_file(&path);
+    let (elaborated, mut errors) = elaborate(&ast);
+    errors.retain(|error| !error.is_warning());
+    assert!(errors.is_empty(), "Elaboration failed: {errors:?}");
+    derivable::analyze(&elaborated)
+}
+
+#[test]
+fn test_derivable_descriptors() {
+    let derivable = analyze("derivable.dbuf");
+    let name = |name: &str| InternedString::from(name.to_owned());
+
+    assert!(derivable.is_descriptor_derivable(&name("Vec")));
+    assert!(derivable.is_descriptor_derivable(&name("Sign")));
+    assert!(!derivable.is_descriptor_derivable(&name("Nat")));
+    // `Other` matches `0` too, and the first matching constructor is chosen
+    assert!(!derivable.is_descriptor_derivable(&name("Tagged")));
+}
+
+#[test]
+fn test_derivable_fields() {
+    let derivable = analyze("derivable.dbuf");
+    let name = |name: &str| InternedString::from(name.to_owned());
+
+    assert!(
+        derivable
+            .field_value(&name("Range"), &name("end"))
+            .is_some()
+    );
+    assert!(
+        derivable
+            .field_value(&name("Range"), &name("length"))
+            .is_none()
+    );
+    // equations are not solved
+    assert!(
+        derivable
+            .field_value(&name("Bounded"), &name("spare"))
+            .is_none()
+    );
+}
@@ -17,7 +17,13 @@ fn get_setting(path: &Path) -> Settings {
 fn test_file(path: &Path) {
     get_setting(path).bind(|| {
         let ast = parse_file(path);
-        let elaborated = elaborate(&ast);
+        let (elaborated, mut errors) = elaborate(&ast);
+        errors.retain(|error| !error.is_warning());
+        assert!(
+            errors.is_empty(),
+            "Elaboration of '{}' failed: {errors:?}",
+            path.display()
+        );
         insta::assert_debug_snapshot!("elaborated", elaborated);
     });
 }
@@ -177,7 +183,67 @@ fn test_name_shadowing() {
     insta::glob!("correct_dbufs/shadow_dep_name.dbuf", test_file);
 }
 
+#[test]
+fn test_definitional_equality() {
+    insta::glob!("correct_dbufs/definitional_equality.dbuf", test_file);
+}
+
 #[test]
 fn test_correct_elaboration() {
     insta::glob!("correct_dbufs/*.dbuf", test_file);
 }
+
+#[test]
+fn test_sequences() {
+    insta::glob!("correct_dbufs/sequences.dbuf", test_file);
+}
+
+#[test]
+fn test_bytes() {
+    insta::glob!("correct_dbufs/bytes.dbuf", test_file);
+}
+
+#[test]
+fn test_doubles() {
+    insta::glob!("correct_dbufs/doubles.dbuf", test_file);
+}
+
+#[test]
+fn test_sized_ints() {
+    insta::glob!("correct_dbufs/sized_ints.dbuf", test_file);
+}
+
+#[test]
+fn test_comparisons() {
+    insta::glob!("correct_dbufs/comparisons.dbuf", test_file);
+}
+
+#[test]
+fn test_refinements() {
+    insta::glob!("correct_dbufs/refinements.dbuf", test_file);
+}
+
+#[test]
+fn test_division() {
+    insta::glob!("correct_dbufs/division.dbuf", test_file);
+}
+
+#[test]
+fn test_strings() {
+    insta::glob!("correct_dbufs/strings.dbuf", test_file);
+}
+
+#[test]
+fn test_mutual_recursion() {
+    insta::glob!("correct_dbufs/mutual_recursion.dbuf", test_file);
+}
+
+#[test]
+fn test_aliases() {
+    insta::glob!("correct_dbufs/aliases.dbuf", test_file);
+}
+
+#[test]
+fn test_defaults() {
+    insta::glob!("correct_dbufs/defaults.dbuf", test_file);
+}
@@ -1,15 +1,182 @@
 use super::parse_file;
 use dbuf_core::elaboration::elaborate;
+use dbuf_core::error::ElaboratingError;
+use dbuf_core::error::elaborating::Error;
 
 #[test]
 fn test_incorrect_elaboration() {
     insta::glob!("incorrect_dbufs/*.dbuf", |path| {
         let ast = parse_file(path);
-        let result = elaborate(&ast);
+        let (_, errors) = elaborate(&ast);
         assert!(
-            result.is_err(),
+            errors.iter().any(|error| !error.is_warning()),
             "Expected elaboration to fail for '{}'",
             path.display()
         );
     });
 }
+
+#[test]
+fn test_all_errors_reported() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/elaboration/incorrect_dbufs/multiple_errors.dbuf");
+    let ast = parse_file(&path);
+    let (module, errors) = elaborate(&ast);
+
+    let mut reported: Vec<_> = errors
+        .iter()
+        .filter(|e| !e.is_warning())
+        .map(|e| e.stage.error.to_string())
+        .collect();
+    reported.sort();
+    assert_eq!(
+        reported,
+        [
+            "cycle in type dependencies: First -> Second",
+            "operator - is not defined for UInt, expected Int or Int8 or Int16 or Int32 or Int64 or Double",
+            "unknown type Unknown",
+        ]
+    );
+    assert!(errors.iter().all(|e| e.stage.loc.is_some()));
+
+    let names: Vec<_> = module.types.keys().map(AsRef::as_ref).collect();
+    assert_eq!(names, ["Good", "Sized"]);
+}
+
+fn first_error(name: &str) -> ElaboratingError {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/elaboration/incorrect_dbufs")
+        .join(name);
+    let ast = parse_file(&path);
+    let (_, errors) = elaborate(&ast);
+    errors
+        .into_iter()
+        .find(|error| !error.is_warning())
+        .expect("elaboration should fail")
+}
+
+#[test]
+fn test_misspelled_name_is_suggested() {
+    let error = first_error("misspelled_type.dbuf").stage.error;
+    assert_eq!(error.code(), "E0101");
+    assert_eq!(error.to_string(), "unknown type Piar");
+    assert_eq!(error.help().as_deref(), Some("did you mean `Pair`?"));
+}
+
+#[test]
+fn test_mismatch_shows_declared_type() {
+    let error = first_error("wrong_constructor_field_type.dbuf").stage.error;
+    assert_eq!(error.code(), "E0202");
+    assert_eq!(error.to_string(), "type mismatch: expected Int, found Bool");
+    let labels: Vec<_> = error.labels().into_iter().map(|(label, _)| label).collect();
+    assert_eq!(labels, ["x of Pair is declared as Int"]);
+}
+
+#[test]
+fn test_projection_is_evaluated_in_mismatch() {
+    let error = first_error("projection_mismatch.dbuf").stage.error;
+    assert_eq!(
+        error.to_string(),
+        "type mismatch: expected Sized 1u, found Sized k"
+    );
+}
+
+#[test]
+fn test_operator_mismatch_shows_operand_types() {
+    let error = first_error("binary_string_minus.dbuf").stage.error;
+    assert_eq!(error.code(), "E0203");
+    assert_eq!(
+        error.to_string(),
+        "operator - is not defined for String, expected Int or Int8 or Int16 or Int32 or Int64 or Double"
+    );
+}
+
+#[test]
+fn test_typed_holes_report_goals() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/elaboration/incorrect_dbufs/typed_holes.dbuf");
+    let ast = parse_file(&path);
+    let (module, errors) = elaborate(&ast);
+
+    let goals: Vec<_> = errors
+        .iter()
+        .filter_map(|error| match &error.stage.error {
+            Error::TypeHole(goal) => Some(goal.to_string()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        goals,
+        [
+            "n = Suc{pred: m} : Nat\nv : Vec Suc{pred: m}\nm : Nat\nlen : UInt\n----------\nNat",
+            "n = Suc{pred: m} : Nat\nv : Vec Suc{pred: m}\nm : Nat\nlen : UInt\nrest : Vec ?0\n----------\nUInt",
+        ]
+    );
+    let names: Vec<_> = module.types.keys().map(AsRef::as_ref).collect();
+    assert!(!names.contains(&"Tail"));
+}
+
+#[test]
+fn test_mutual_recursion_needs_initial_constructor() {
+    let error = first_error("cycle.dbuf").stage.error;
+    assert_eq!(error.code(), "E0302");
+    assert_eq!(
+        error.to_string(),
+        "no initial constructor for: Wrapper, Inner"
+    );
+}
+
+#[test]
+fn test_alias_can_not_be_recursive() {
+    let error = first_error("alias_cycle.dbuf").stage.error;
+    assert_eq!(error.code(), "E0301");
+    assert_eq!(
+        error.to_string(),
+        "cycle in type dependencies: Loop -> Node"
+    );
+}
+
+#[test]
+fn test_alias_target_refinement_is_checked() {
+    let error = first_error("alias_refinement_violated.dbuf").stage.error;
+    assert_eq!(error.code(), "E0401");
+}
+
+#[test]
+fn test_default_is_checked_against_field_type() {
+    let error = first_error("default_type_mismatch.dbuf").stage.error;
+    assert_eq!(error.code(), "E0202");
+    assert_eq!(
+        error.to_string(),
+        "type mismatch: expected UInt, found String"
+    );
+}
+
+#[test]
+fn test_default_refinement_is_checked() {
+    let error = first_error("default_refinement_violated.dbuf").stage.error;
+    assert_eq!(error.code(), "E0401");
+}
+
+#[test]
+fn test_default_can_not_refer_to_fields() {
+    let error = first_error("default_refers_to_field.dbuf").stage.error;
+    assert_eq!(error.code(), "E0102");
+}
+
+#[test]
+fn test_constant_overflow_is_reported() {
+    let error = first_error("sized_overflow.dbuf").stage.error;
+    assert_eq!(error.code(), "E0403");
+    assert_eq!(error.to_string(), "constant expression overflows Int8");
+}
+
+#[test]
+fn test_defaults_must_be_trailing() {
+    let error = first_error("default_not_trailing.dbuf").stage.error;
+    assert_eq!(error.code(), "E0303");
+    assert_eq!(
+        error.to_string(),
+        "field level without default follows fields with defaults"
+    );
+}
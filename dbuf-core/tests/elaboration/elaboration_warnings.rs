@@ -0,0 +1,36 @@
+use super::parse_file;
+use dbuf_core::elaboration::elaborate;
+use dbuf_core::error::ElaboratingError;
+use insta::Settings;
+use std::path::Path;
+
+fn get_setting(path: &Path) -> Settings {
+    let mut settings = Settings::new();
+    settings.set_snapshot_path(format!(
+        "snapshots/warning/{}",
+        path.file_stem().unwrap().display()
+    ));
+    settings.set_prepend_module_to_snapshot(false);
+    settings.set_snapshot_suffix("");
+    settings
+}
+
+#[test]
+fn test_elaboration_warnings() {
+    insta::glob!("warning_dbufs/*.dbuf", |path| {
+        get_setting(path).bind(|| {
+            let ast = parse_file(path);
+            let (_, warnings) = elaborate(&ast);
+            assert!(
+                !warnings.is_empty() && warnings.iter().all(ElaboratingError::is_warning),
+                "Expected only warnings for '{}': {warnings:?}",
+                path.display()
+            );
+            let messages: Vec<_> = warnings
+                .iter()
+                .map(|warning| (warning.error.to_string(), warning.loc))
+                .collect();
+            insta::assert_debug_snapshot!("warnings", messages);
+        });
+    });
+}
@@ -0,0 +1,176 @@
+use super::fixture;
+
+use dbuf_core::arena::InternedString;
+use dbuf_core::ast::elaborated::TypeExpression;
+use dbuf_core::cst::{collect_package, convert_to_ast, parse_to_cst};
+use dbuf_core::elaboration::{
+    elaborate, elaborate_in_graph, elaborate_in_package, elaborate_with_imports,
+};
+use dbuf_core::error::ElaboratingError;
+use dbuf_core::error::elaborating::Error;
+use dbuf_core::loader::{Loader, SourceFile};
+
+#[test]
+fn test_load_order() {
+    let loader = Loader::new(vec![fixture("chain/include")]);
+    let files = loader.load(&fixture("chain/main.dbuf")).unwrap();
+
+    let names: Vec<_> = files.iter().map(SourceFile::name).collect();
+    assert_eq!(names, ["base", "types", "main"]);
+
+    assert!(files[0].imports.is_empty());
+    assert_eq!(files[1].imports, [0]);
+    assert_eq!(files[2].imports, [1, 0]);
+}
+
+#[test]
+fn test_resolve() {
+    let loader = Loader::new(vec![fixture("chain/include")]);
+    let from = fixture("chain/main.dbuf");
+
+    assert_eq!(
+        loader.resolve(&from, "types.dbuf"),
+        fixture("chain/types.dbuf").canonicalize().ok()
+    );
+    assert_eq!(
+        loader.resolve(&from, "base.dbuf"),
+        fixture("chain/include/base.dbuf").canonicalize().ok()
+    );
+    assert_eq!(loader.resolve(&from, "absent.dbuf"), None);
+}
+
+#[test]
+fn test_elaborate_with_imports() {
+    let loader = Loader::new(vec![fixture("chain/include")]);
+    let files = loader.load(&fixture("chain/main.dbuf")).unwrap();
+
+    let mut elaborated = vec![];
+    for file in &files {
+        let (tree, errors) = parse_to_cst(&file.content);
+        assert!(errors.is_empty(), "Parse errors in '{}'", file.name());
+        let ast = convert_to_ast(&tree.unwrap());
+
+        let imports: Vec<_> = elaborated.iter().collect();
+        let (module, mut errors) = elaborate_with_imports(&ast, &imports);
+        errors.retain(|error| !error.is_warning());
+        assert!(
+            errors.is_empty(),
+            "Elaboration of '{}' failed: {errors:?}",
+            file.name()
+        );
+        elaborated.push(module);
+    }
+
+    let main = elaborated.last().unwrap();
+    assert!(main.types.iter().any(|(name, _)| name.as_ref() == "User"));
+    assert!(!main.types.iter().any(|(name, _)| name.as_ref() == "Flags"));
+}
+
+#[test]
+fn test_elaborate_without_imports() {
+    let content = std::fs::read_to_string(fixture("chain/no_import.dbuf")).unwrap();
+    let (tree, _) = parse_to_cst(&content);
+    let ast = convert_to_ast(&tree.unwrap());
+
+    let (_, errors) = elaborate(&ast);
+    assert!(!errors.is_empty());
+}
+
+#[test]
+fn test_elaborate_in_packages() {
+    let loader = Loader::default();
+    let files = loader.load(&fixture("packages/billing.dbuf")).unwrap();
+
+    let mut elaborated = vec![];
+    for file in &files {
+        let (tree, errors) = parse_to_cst(&file.content);
+        assert!(errors.is_empty(), "Parse errors in '{}'", file.name());
+        let tree = tree.unwrap();
+        let package: Vec<InternedString> = collect_package(&tree)
+            .unwrap()
+            .path
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let ast = convert_to_ast(&tree);
+
+        let imports: Vec<_> = elaborated.iter().collect();
+        let (module, mut errors) = elaborate_in_package(&ast, &package, &imports);
+        errors.retain(|error| !error.is_warning());
+        assert!(
+            errors.is_empty(),
+            "Elaboration of '{}' failed: {errors:?}",
+            file.name()
+        );
+        elaborated.push(module);
+    }
+
+    let billing = elaborated.last().unwrap();
+    assert_eq!(billing.package, ["acme".into(), "billing".into()]);
+
+    let names: Vec<_> = billing.types.keys().map(AsRef::as_ref).collect();
+    assert_eq!(names, ["acme.billing.Header", "acme.billing.Invoice"]);
+
+    let invoice = &billing.constructors[&"acme.billing.Invoice".into()];
+    let fields: Vec<_> = invoice
+        .fields
+        .iter()
+        .map(|(_, TypeExpression::TypeExpression { name, .. })| name.as_ref())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "acme.common.Money",
+            "acme.billing.Header",
+            "acme.common.Header"
+        ]
+    );
+}
+
+#[test]
+fn test_types_referenced_by_importers() {
+    let loader = Loader::default();
+    let files = loader.load(&fixture("packages/billing.dbuf")).unwrap();
+
+    let parsed: Vec<_> = files
+        .iter()
+        .map(|file| {
+            let (tree, errors) = parse_to_cst(&file.content);
+            assert!(errors.is_empty(), "Parse errors in '{}'", file.name());
+            tree.unwrap()
+        })
+        .collect();
+    let packages: Vec<Vec<InternedString>> = parsed
+        .iter()
+        .map(|tree| {
+            collect_package(tree)
+                .unwrap()
+                .path
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        })
+        .collect();
+    let asts: Vec<_> = parsed.iter().map(convert_to_ast).collect();
+    let unreferenced = |errors: Vec<ElaboratingError>| -> Vec<String> {
+        errors
+            .into_iter()
+            .filter_map(|error| match &error.error {
+                Error::UnreferencedType(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let (common, errors) = elaborate_in_package(&asts[0], &packages[0], &[]);
+    assert_eq!(
+        unreferenced(errors),
+        ["acme.common.Money", "acme.common.Header"]
+    );
+
+    let (_, errors) = elaborate_in_graph(&asts[0], &packages[0], &[], &[&asts[1]]);
+    assert!(unreferenced(errors).is_empty());
+
+    let (_, errors) = elaborate_in_graph(&asts[1], &packages[1], &[&common], &[]);
+    assert_eq!(unreferenced(errors), ["acme.billing.Invoice"]);
+}
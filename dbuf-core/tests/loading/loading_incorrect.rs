@@ -0,0 +1,44 @@
+use super::fixture;
+
+use dbuf_core::error::loading::Error;
+use dbuf_core::loader::Loader;
+
+#[test]
+fn test_import_cycle() {
+    let loader = Loader::default();
+    let err = loader.load(&fixture("cycle/a.dbuf")).unwrap_err();
+
+    let Error::ImportCycle(cycle) = &err.error else {
+        panic!("Expected import cycle, got: {err}");
+    };
+    let names: Vec<_> = cycle
+        .iter()
+        .map(|(path, _)| path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(names, ["a.dbuf", "b.dbuf"]);
+    assert!(err.loc.is_some());
+}
+
+#[test]
+fn test_unresolved_import() {
+    let loader = Loader::default();
+    let err = loader.load(&fixture("missing/main.dbuf")).unwrap_err();
+
+    assert_eq!(
+        err.error,
+        Error::UnresolvedImport("absent.dbuf".to_string())
+    );
+    assert_eq!(
+        err.file,
+        fixture("missing/main.dbuf").canonicalize().unwrap()
+    );
+}
+
+#[test]
+fn test_unreadable_entry() {
+    let loader = Loader::default();
+    let err = loader.load(&fixture("missing/absent.dbuf")).unwrap_err();
+
+    assert!(matches!(err.error, Error::Unreadable(..)));
+    assert!(err.loc.is_none());
+}
@@ -0,0 +1,12 @@
+pub mod loading_correct;
+pub mod loading_incorrect;
+
+use std::path::PathBuf;
+
+/// Returns path of file from `loading/dbufs` directory.
+#[must_use]
+pub fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/loading/dbufs")
+        .join(path)
+}
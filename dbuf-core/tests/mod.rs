@@ -1,2 +1,3 @@
 pub mod elaboration;
+pub mod loading;
 pub mod parsing;
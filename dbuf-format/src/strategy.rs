@@ -7,6 +7,7 @@ use std::ops::BitAnd;
 
 use super::utils::{Event, PrettyStrategy};
 
+use dbuf_core::ast::operators::{bytes_literal, double_literal};
 use dbuf_core::cst::{Token, TreeKind};
 use dbuf_core::location::{Location, Offset};
 
@@ -18,15 +19,23 @@ fn token_to_string<'a>(t: &'a Token, l: &'a Location<Offset>) -> Cow<'a, str> {
     match t {
         Token::Message => "message".into(),
         Token::Enum => "enum".into(),
+        Token::Package => "package".into(),
+        Token::Import => "import".into(),
+        Token::Where => "where".into(),
+        Token::Type => "type".into(),
         Token::BoolLiteral(l) => l.to_string().into(),
         Token::IntLiteral(l) => l.to_string().into(),
         Token::UintLiteral(l) => format!("{l}u").into(),
+        Token::DoubleLiteral(l) => double_literal(*l).into(),
+        Token::SizedIntLiteral(l) => l.to_string().into(),
         Token::StringLiteral(l) => format!("\"{l}\"").into(),
+        Token::BytesLiteral(l) => bytes_literal(l).into(),
         Token::UCIdentifier(l) => l.into(),
         Token::LCIdentifier(l) => l.into(),
         Token::Arrow => "=>".into(),
         Token::Colon => ":".into(),
         Token::Semicolon => ";".into(),
+        Token::Assign => "=".into(),
         Token::Comma => ",".into(),
         Token::Dot => ".".into(),
         Token::LParen => "(".into(),
@@ -39,6 +48,17 @@ fn token_to_string<'a>(t: &'a Token, l: &'a Location<Offset>) -> Cow<'a, str> {
         Token::Amp => "&".into(),
         Token::Pipe => "|".into(),
         Token::Bang => "!".into(),
+        Token::EqEq => "==".into(),
+        Token::BangEq => "!=".into(),
+        Token::Less => "<".into(),
+        Token::LessEq => "<=".into(),
+        Token::Greater => ">".into(),
+        Token::GreaterEq => ">=".into(),
+        Token::Slash => "/".into(),
+        Token::Percent => "%".into(),
+        Token::LessLess => "<<".into(),
+        Token::GreaterGreater => ">>".into(),
+        Token::Caret => "^".into(),
         Token::Underscore => "_".into(),
         Token::NewLine => "\n".into(),
         Token::Space => " ".repeat(l.length.columns).into(),
@@ -134,6 +154,8 @@ pub struct Strategy<'a> {
     user_newline: bool,
     /// scopes for current tree node.
     scope: TreeKind,
+    /// last top level tree node.
+    top_level: Option<TreeKind>,
 }
 
 impl<'a> Strategy<'a> {
@@ -151,6 +173,7 @@ impl<'a> Strategy<'a> {
             },
             user_newline: false,
             scope: TreeKind::File,
+            top_level: None,
         }
     }
 
@@ -213,6 +236,7 @@ impl<'a> Strategy<'a> {
         let space = match t {
             Token::LBrace if ctr_scope => SpacePolicy::NoSpace,
             Token::RBrace if ctr_scope => SpacePolicy::NoSpace,
+            Token::LParen if matches!(self.scope, TreeKind::ExprCall) => SpacePolicy::NoSpace,
             Token::RParen => SpacePolicy::NoSpace,
             Token::Semicolon => SpacePolicy::NoSpace,
             Token::Colon => SpacePolicy::NoSpace,
@@ -320,6 +344,18 @@ impl<'a, D> PrettyStrategy<'a, D> for Strategy<'a> {
                 doc
             }
             Event::NewScope(tree_kind) => {
+                if matches!(self.scope, TreeKind::File) {
+                    // separate package, imports, aliases and definitions from each other.
+                    if matches!(
+                        self.top_level,
+                        Some(TreeKind::Package | TreeKind::Import | TreeKind::Alias)
+                    ) && self.top_level.as_ref() != Some(tree_kind)
+                    {
+                        self.last_policy.line = LinePolicy::TwoLine;
+                    }
+                    self.top_level = Some(tree_kind.clone());
+                }
+
                 self.scope = tree_kind.clone();
                 allocator.nil()
             }
@@ -327,7 +363,15 @@ impl<'a, D> PrettyStrategy<'a, D> for Strategy<'a> {
                 self.scope = tree_kind.clone();
 
                 if matches!(tree_kind, TreeKind::File) {
-                    self.last_policy.line = LinePolicy::TwoLine;
+                    // consecutive declarations of package, imports and aliases are not separated.
+                    self.last_policy.line = if matches!(
+                        self.top_level,
+                        Some(TreeKind::Package | TreeKind::Import | TreeKind::Alias)
+                    ) {
+                        LinePolicy::MaybeLine
+                    } else {
+                        LinePolicy::TwoLine
+                    };
                 }
 
                 allocator.nil()
@@ -1,18 +1,20 @@
 use super::{
-    Constructor, Module, OpCall, Str, Symbol, Type, TypeExpression, TypeKind, UnaryOp,
-    ValueExpression,
+    Alias, BYTES_NAME, Constructor, Module, OpCall, SEQUENCE_NAMES, STRING_NAME, Str, Symbol, Type,
+    TypeExpression, TypeKind, UnaryOp, ValueExpression,
 };
+use crate::import::Import;
 use crate::scope::Scope;
+use std::collections::{HashMap, HashSet};
 use std::rc::{Rc, Weak};
 
 pub use dbuf_core::ast::{elaborated, operators};
+use dbuf_core::elaboration::derivable::{self, Derivable};
 
-// maybe temporary
-#[allow(dead_code, reason = "maybe temporary")]
 type ElaboratedType = elaborated::Type<Str>;
 type ElaboratedValueExpression = elaborated::ValueExpression<Str>;
 type ElaboratedTypeExpression = elaborated::TypeExpression<Str>;
 type ElaboratedModule = elaborated::Module<Str>;
+type ElaboratedAlias = elaborated::Alias<Str>;
 #[allow(dead_code, reason = "maybe temporary")]
 type ElaboratedContext = elaborated::Context<Str>;
 type ElaboratedConstructor = elaborated::Constructor<Str>;
@@ -36,12 +38,21 @@ struct ASTContext<'a> {
     constructors: &'a Scope<'a, Str, Rc<Constructor>>,
 }
 
-const BUILTIN_NAMES: &[&str] = &["Bool", "Int", "UInt", "String"];
+const BUILTIN_NAMES: &[&str] = &[
+    "Bool", "Int", "UInt", "Int8", "Int16", "Int32", "Int64", "UInt8", "UInt16", "UInt32",
+    "UInt64", "Double", "String", "Bytes", "List", "Array",
+];
 
 impl Module {
-    pub(crate) fn from_elaborated(module: &ElaboratedModule) -> Self {
+    /// Converts `module` whose types may reference types of `imports`.
+    ///
+    /// `imports` must contain every transitively imported module, each one
+    /// after the modules it imports itself.
+    pub(crate) fn from_elaborated_with_imports(
+        module: &ElaboratedModule,
+        imports: &[Import<'_>],
+    ) -> Self {
         let mut all_constructors = Scope::<Str, Rc<Constructor>>::empty();
-        let mut types = Vec::with_capacity(module.types.len());
         let mut known_types = Scope::<Str, Weak<Type>>::empty();
 
         let builtins: Vec<Rc<Type>> = BUILTIN_NAMES
@@ -49,10 +60,12 @@ impl Module {
             .map(|&name| {
                 let ty = Rc::new(Type {
                     name: Str::from(name),
+                    package: vec![],
                     dependencies: vec![],
                     constructors: vec![],
                     kind: TypeKind::Enum,
                     is_builtin: true,
+                    derivable_descriptor: false,
                 });
                 assert!(
                     known_types.try_insert(Str::from(name), Rc::downgrade(&ty)),
@@ -62,79 +75,250 @@ impl Module {
             })
             .collect();
 
-        for (name, ty) in &module.types {
-            // all this scope constructs Rc<Type> and can be become Type::from_elaborated
-            // but I do not really want this, because I do not feel like it will simplify logic
-            let mut variables = Scope::<Str, Rc<Symbol>>::empty();
+        let imported = imports
+            .iter()
+            .flat_map(|import| {
+                let origin = Str::from(import.name);
+                Self::convert_types(import.module, &mut known_types, &mut all_constructors)
+                    .into_iter()
+                    .map(move |ty| (origin.clone(), ty))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let types = Self::convert_types(module, &mut known_types, &mut all_constructors);
+        let aliases = module
+            .aliases
+            .iter()
+            .map(|(name, alias)| {
+                let context = ASTContext {
+                    known_types: &known_types,
+                    variables: &Scope::empty(),
+                    constructors: &all_constructors,
+                };
+                Alias::from_elaborated(context, name, alias)
+            })
+            .collect();
+
+        Module {
+            package: module.package.clone(),
+            types,
+            aliases,
+            imported,
+            _builtins: builtins,
+        }
+    }
+
+    fn convert_types(
+        module: &ElaboratedModule,
+        known_types: &mut Scope<'_, Str, Weak<Type>>,
+        all_constructors: &mut Scope<'_, Str, Rc<Constructor>>,
+    ) -> Vec<Rc<Type>> {
+        let mut types = Vec::with_capacity(module.types.len());
+        let declarations: Vec<_> = module.types.iter().collect();
+        let derivable = derivable::analyze(module);
+        for group in recursive_groups(module, &declarations) {
+            Self::convert_group(
+                module,
+                &derivable,
+                group,
+                known_types,
+                all_constructors,
+                &mut types,
+            );
+        }
+        types
+    }
+
+    /// Converts mutually recursive types of `group` and appends them to `types`.
+    ///
+    /// Every type is constructed inside of `Rc::new_cyclic` of the previous one,
+    /// so types of the group can reference each other before they are complete.
+    fn convert_group(
+        module: &ElaboratedModule,
+        derivable: &Derivable<Str>,
+        group: &[(&Str, &ElaboratedType)],
+        known_types: &mut Scope<'_, Str, Weak<Type>>,
+        all_constructors: &mut Scope<'_, Str, Rc<Constructor>>,
+        types: &mut Vec<Rc<Type>>,
+    ) {
+        let Some((&(name, ty), rest)) = group.split_first() else {
+            return;
+        };
+        let position = types.len();
+
+        // all this scope constructs Rc<Type> and can be become Type::from_elaborated
+        // but I do not really want this, because I do not feel like it will simplify logic
+        let mut variables = Scope::<Str, Rc<Symbol>>::empty();
+
+        let dependencies = ty
+            .dependencies
+            .iter()
+            .map(|(name, expr)| {
+                let context = ASTContext {
+                    known_types,
+                    variables: &variables,
+                    constructors: all_constructors,
+                };
+
+                let symbol = Rc::new(Symbol::from_elaborated(context, name.clone(), expr));
+                assert!(variables.try_insert(name.clone(), symbol.clone()));
+                symbol
+            })
+            .collect();
+
+        let variables = variables;
+
+        let ty = Rc::new_cyclic(|me| {
+            use elaborated::ConstructorNames;
+            assert!(
+                known_types.try_insert(name.clone(), me.clone()),
+                "codegen expects valid elaborated ast: two types can not have same name"
+            );
+
+            Self::convert_group(
+                module,
+                derivable,
+                rest,
+                known_types,
+                all_constructors,
+                types,
+            );
+
+            let (constructors, kind) = match &ty.constructor_names {
+                ConstructorNames::OfMessage(name) => (vec![name], TypeKind::Message),
+                ConstructorNames::OfEnum(constructors) => {
+                    (constructors.iter().collect(), TypeKind::Enum)
+                }
+            };
 
-            let dependencies = ty
-                .dependencies
-                .iter()
-                .map(|(name, expr)| {
+            let constructors = constructors
+                .into_iter()
+                .map(|constructor_name| {
                     let context = ASTContext {
-                        known_types: &known_types,
+                        known_types,
                         variables: &variables,
-                        constructors: &all_constructors,
+                        constructors: all_constructors,
                     };
 
-                    let symbol = Rc::new(Symbol::from_elaborated(context, name.clone(), expr));
-                    assert!(variables.try_insert(name.clone(), symbol.clone()));
-                    symbol
+                    let elaborated_constructor = module
+                        .constructors
+                        .get(constructor_name)
+                        .expect("codegen expects valid elaborated ast: unknown constructor");
+
+                    let (_, bare_name) = elaborated::split_qualified(constructor_name.as_ref());
+                    let constructor = Constructor::from_elaborated(context, Str::from(bare_name), elaborated_constructor, derivable.fields.get(constructor_name), me.clone());
+
+                    let constructor = Rc::new(constructor);
+                    assert!(all_constructors.try_insert(constructor_name.clone(), constructor.clone()), "codegen expects valid elaborated ast: two constructors can not have same name");
+                    constructor
                 })
                 .collect();
 
-            let variables = variables;
+            let (package, bare_name) = elaborated::split_qualified(name.as_ref());
+            Type {
+                name: Str::from(bare_name),
+                package: package.into_iter().map(Str::from).collect(),
+                dependencies,
+                constructors,
+                kind,
+                is_builtin: false,
+                derivable_descriptor: derivable.is_descriptor_derivable(name),
+            }
+        });
 
-            let ty = Rc::new_cyclic(|me| {
-                use elaborated::ConstructorNames;
-                assert!(
-                    known_types.try_insert(name.clone(), me.clone()),
-                    "codegen expects valid elaborated ast: two types can not have same name"
-                );
+        types.insert(position, ty);
+    }
+}
 
-                let (constructors, kind) = match &ty.constructor_names {
-                    ConstructorNames::OfMessage(name) => (vec![name], TypeKind::Message),
-                    ConstructorNames::OfEnum(constructors) => {
-                        (constructors.iter().collect(), TypeKind::Enum)
-                    }
+impl Alias {
+    fn from_elaborated(context: ASTContext<'_>, name: &Str, alias: &ElaboratedAlias) -> Self {
+        let mut variables = Scope::nested_in(context.variables);
+        let dependencies = alias
+            .dependencies
+            .iter()
+            .map(|(name, expr)| {
+                let dependency_context = ASTContext {
+                    variables: &variables,
+                    ..context
                 };
+                let symbol = Rc::new(Symbol::from_elaborated(
+                    dependency_context,
+                    name.clone(),
+                    expr,
+                ));
+                assert!(variables.try_insert(name.clone(), symbol.clone()));
+                symbol
+            })
+            .collect();
 
-                let constructors = constructors
-                    .into_iter()
-                    .map(|constructor_name| {
-                        let context = ASTContext {
-                            known_types: &known_types,
-                            variables: &variables,
-                            constructors: &all_constructors,
-                        };
-
-                        let elaborated_constructor = module
-                            .constructors
-                            .get(constructor_name)
-                            .expect("codegen expects valid elaborated ast: unknown constructor");
-
-                        let constructor = Constructor::from_elaborated(context, constructor_name.clone(), elaborated_constructor, me.clone());
-
-                        let constructor = Rc::new(constructor);
-                        assert!(all_constructors.try_insert(constructor.name.clone(), constructor.clone()), "codegen expects valid elaborated ast: two constructors can not have same name");
-                        constructor
-                    })
-                    .collect();
+        let target_context = ASTContext {
+            variables: &variables,
+            ..context
+        };
+        let (_, bare_name) = elaborated::split_qualified(name.as_ref());
+        Alias {
+            name: Str::from(bare_name),
+            _dependencies: dependencies,
+            target: TypeExpression::from_elaborated(target_context, &alias.target),
+        }
+    }
+}
 
-                Type {
-                    name: name.clone(),
-                    dependencies,
-                    constructors,
-                    kind,
-                    is_builtin: false,
-                }
-            });
+/// Splits topologically sorted `types` of `module` into groups of mutually recursive ones.
+///
+/// Group ends where none of its types reference types after it.
+fn recursive_groups<'a, 'b>(
+    module: &'a ElaboratedModule,
+    types: &'b [(&'a Str, &'a ElaboratedType)],
+) -> Vec<&'b [(&'a Str, &'a ElaboratedType)]> {
+    use elaborated::ConstructorNames;
+
+    let position: HashMap<&str, usize> = types
+        .iter()
+        .enumerate()
+        .map(|(index, (name, _))| (name.as_ref(), index))
+        .collect();
+
+    let mut groups = vec![];
+    let mut start = 0;
+    let mut end = 0;
+    for (index, (_, ty)) in types.iter().enumerate() {
+        let mut referenced = HashSet::new();
+        for (_, expr) in &ty.dependencies {
+            referenced_types(expr, &mut referenced);
+        }
+        let constructor_names = match &ty.constructor_names {
+            ConstructorNames::OfMessage(name) => vec![name],
+            ConstructorNames::OfEnum(names) => names.iter().collect(),
+        };
+        for constructor in constructor_names
+            .into_iter()
+            .filter_map(|name| module.constructors.get(name))
+        {
+            for (_, expr) in constructor.implicits.iter().chain(&constructor.fields) {
+                referenced_types(expr, &mut referenced);
+            }
+        }
 
-            types.push(ty);
+        end = referenced
+            .into_iter()
+            .filter_map(|name| position.get(name))
+            .fold(end.max(index), |end, &referenced| end.max(referenced));
+        if end == index {
+            groups.push(&types[start..=index]);
+            start = index + 1;
         }
-        Module {
-            types,
-            _builtins: builtins,
+    }
+    groups
+}
+
+fn referenced_types<'a>(expr: &'a ElaboratedTypeExpression, referenced: &mut HashSet<&'a str>) {
+    let ElaboratedTypeExpression::TypeExpression { name, dependencies } = expr;
+    referenced.insert(name.as_ref());
+    for dependency in dependencies.iter() {
+        if let ElaboratedValueExpression::Type { ty } = dependency {
+            referenced_types(ty, referenced);
         }
     }
 }
@@ -146,8 +330,11 @@ impl Constructor {
         ElaboratedConstructor {
             implicits,
             fields,
+            refinements,
             result_type,
+            defaults,
         }: &ElaboratedConstructor,
+        derivable: Option<&Vec<(Str, ElaboratedValueExpression)>>,
         this: Weak<Type>,
     ) -> Constructor {
         let mut all_params = Scope::nested_in(type_context.variables);
@@ -166,6 +353,22 @@ impl Constructor {
             })
             .collect();
 
+        // defaults only refer to implicits, which may be shadowed by fields
+        let defaults_context = ASTContext {
+            known_types: type_context.known_types,
+            variables: &all_params,
+            constructors: type_context.constructors,
+        };
+        let defaults = defaults
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.clone(),
+                    ValueExpression::from_elaborated(defaults_context, value),
+                )
+            })
+            .collect();
+
         let fields: Vec<_> = fields
             .iter()
             .map(|(name, expr)| {
@@ -186,6 +389,27 @@ impl Constructor {
             constructors: type_context.constructors,
         };
 
+        let refinements = refinements
+            .iter()
+            .map(|(name, predicate)| {
+                (
+                    name.clone(),
+                    ValueExpression::from_elaborated(constructor_context, predicate),
+                )
+            })
+            .collect();
+
+        let derivable = derivable
+            .into_iter()
+            .flatten()
+            .map(|(name, value)| {
+                (
+                    name.clone(),
+                    ValueExpression::from_elaborated(constructor_context, value),
+                )
+            })
+            .collect();
+
         // this is if statement not needed now
         let result_type = match result_type {
             ElaboratedTypeExpression::TypeExpression {
@@ -208,17 +432,27 @@ impl Constructor {
             name,
             implicits,
             fields,
+            refinements,
             result_type,
+            defaults,
+            derivable,
         }
     }
 }
 
+/// Whether `+` concatenates values of `ty` instead of adding them.
+fn is_concatenated(ty: &ElaboratedTypeExpression) -> bool {
+    let elaborated::TypeExpression::TypeExpression { name, .. } = ty;
+    name.as_ref() == BYTES_NAME || name.as_ref() == STRING_NAME
+}
+
 impl ValueExpression {
+    #[allow(clippy::too_many_lines, reason = "??? (108/100)")]
     fn from_elaborated(context: ASTContext<'_>, expr: &ElaboratedValueExpression) -> Self {
         match expr {
             ElaboratedValueExpression::OpCall {
                 op_call,
-                result_type: _,
+                result_type,
             } => {
                 let op_call = match op_call {
                     operators::OpCall::Literal(literal) => OpCall::Literal(literal.clone()),
@@ -240,6 +474,9 @@ impl ValueExpression {
                                         result_type,
                                     } => result_type,
                                     elaborated::ValueExpression::Variable { name: _, ty } => ty,
+                                    elaborated::ValueExpression::Type { .. } => {
+                                        panic!("access to type")
+                                    }
                                 };
                                 let ty = match ty {
                                     elaborated::TypeExpression::TypeExpression {
@@ -256,6 +493,22 @@ impl ValueExpression {
                                         .expect("access to unknown type"),
                                 };
 
+                                if ty.is_builtin && ty.name.as_ref() == STRING_NAME {
+                                    return ValueExpression::OpCall(OpCall::Unary(
+                                        UnaryOp::StringLength,
+                                        Box::new(ValueExpression::from_elaborated(context, expr)),
+                                    ));
+                                }
+                                if ty.is_builtin
+                                    && (SEQUENCE_NAMES.contains(&ty.name.as_ref())
+                                        || ty.name.as_ref() == BYTES_NAME)
+                                {
+                                    return ValueExpression::OpCall(OpCall::Unary(
+                                        UnaryOp::Length,
+                                        Box::new(ValueExpression::from_elaborated(context, expr)),
+                                    ));
+                                }
+
                                 assert!(
                                     ty.constructors.len() == 1 && ty.kind == TypeKind::Message,
                                     "access to enum"
@@ -279,6 +532,14 @@ impl ValueExpression {
                             Box::new(ValueExpression::from_elaborated(context, expr)),
                         )
                     }
+                    operators::OpCall::Binary(operators::BinaryOp::Plus, lhs, rhs)
+                        if is_concatenated(result_type) =>
+                    {
+                        OpCall::Concat(
+                            Box::new(Self::from_elaborated(context, lhs)),
+                            Box::new(Self::from_elaborated(context, rhs)),
+                        )
+                    }
                     operators::OpCall::Binary(binary_op, lhs, rhs) => OpCall::Binary(
                         *binary_op,
                         Box::new(Self::from_elaborated(context, lhs)),
@@ -323,6 +584,9 @@ impl ValueExpression {
                     ));
                 ValueExpression::Variable(symbol)
             }
+            ElaboratedValueExpression::Type { ty } => {
+                ValueExpression::Type(TypeExpression::from_elaborated(context, ty))
+            }
         }
     }
 }
@@ -332,9 +596,8 @@ impl TypeExpression {
         match expr {
             ElaboratedTypeExpression::TypeExpression { name, dependencies } => {
                 // types in module must be in top sorted order (top sort over types and theirs dependencies)
-                // we iterate over them in the same order
-                // we can encounter type expression only in dependencies (either when calling or declaring)
-                // in both cases top sort ensures following check
+                // we iterate over them in the same order, and types of mutually recursive group are
+                // registered before any of them is complete, so top sort ensures following check
                 let call = context.known_types.get(name).expect("codegen expects valid elaborated ast: expression contains call to unknown type");
                 let dependencies = dependencies
                     .iter()
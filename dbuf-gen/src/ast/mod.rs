@@ -15,7 +15,13 @@ use std::rc::{Rc, Weak};
 pub type Str = InternedString;
 
 pub struct Module {
+    /// Package of the module, outermost segment first.
+    pub package: Vec<Str>,
     pub types: Vec<Rc<Type>>,
+    /// Type aliases, which go after all types, since they only refer to them.
+    pub aliases: Vec<Alias>,
+    /// Types declared in imported files together with name of the file.
+    pub imported: Vec<(Str, Rc<Type>)>,
     pub _builtins: Vec<Rc<Type>>,
 }
 
@@ -28,10 +34,23 @@ pub enum TypeKind {
 #[derive(Clone)]
 pub struct Type {
     pub name: Str,
+    /// Package the type is declared in.
+    pub package: Vec<Str>,
     pub dependencies: Vec<Rc<Symbol>>,
     pub constructors: Vec<Rc<Constructor>>,
     pub kind: TypeKind,
     pub is_builtin: bool,
+    /// Whether constructor of enum is determined by values of dependencies.
+    pub derivable_descriptor: bool,
+}
+
+/// Named type expression over dependencies, that are only used in the target.
+#[derive(Clone)]
+pub struct Alias {
+    pub name: Str,
+    /// Dependencies referenced by variables of the target.
+    pub _dependencies: Vec<Rc<Symbol>>,
+    pub target: TypeExpression,
 }
 
 #[derive(Clone)]
@@ -39,7 +58,13 @@ pub struct Constructor {
     pub name: Str,
     pub implicits: Vec<Rc<Symbol>>,
     pub fields: Vec<Rc<Symbol>>,
+    /// `Bool` predicates checked on construction, paired with name of the refined variable.
+    pub refinements: Vec<(Str, ValueExpression)>,
     pub result_type: TypeExpression,
+    /// Values of fields used when they are not provided, expressed with implicits.
+    pub defaults: Vec<(Str, ValueExpression)>,
+    /// Values of fields determined by refinements, expressed with implicits and preceding fields.
+    pub derivable: Vec<(Str, ValueExpression)>,
 }
 
 #[derive(Clone)]
@@ -51,6 +76,8 @@ pub enum ValueExpression {
         arguments: Vec<ValueExpression>,
     },
     Variable(Weak<Symbol>),
+    /// Element type of builtin sequence.
+    Type(TypeExpression),
 }
 
 #[derive(Clone)]
@@ -72,22 +99,59 @@ pub enum OpCall {
     Literal(Literal),
     Unary(UnaryOp, Box<ValueExpression>),
     Binary(BinaryOp, Box<ValueExpression>, Box<ValueExpression>),
+    /// Concatenation of `String` or `Bytes` values.
+    Concat(Box<ValueExpression>, Box<ValueExpression>),
 }
 
 #[derive(Clone)]
 pub enum UnaryOp {
-    Access { to: Weak<Type>, field: Weak<Symbol> },
+    Access {
+        to: Weak<Type>,
+        field: Weak<Symbol>,
+    },
+    /// Length of builtin sequence or `Bytes`.
+    Length,
+    /// Length of `String`, which is number of bytes in its UTF-8 encoding.
+    StringLength,
     Minus,
     Bang,
 }
 
 impl Node for Module {}
 impl Node for Type {}
+impl Node for Alias {}
 impl Node for Constructor {}
 impl Node for ValueExpression {}
 impl Node for TypeExpression {}
 impl Node for Symbol {}
 
+impl Type {
+    /// Name of the type qualified with its package.
+    #[cfg(any(feature = "kotlin", feature = "swift"))]
+    pub fn qualified_name(&self) -> String {
+        elaborated::qualify(&self.package, self.name.as_ref())
+    }
+}
+
+impl Constructor {
+    /// Default value of `field`, if it has one.
+    pub fn default_of(&self, field: &Symbol) -> Option<&ValueExpression> {
+        self.defaults
+            .iter()
+            .find(|(name, _)| *name == field.name)
+            .map(|(_, value)| value)
+    }
+
+    /// Value of `field`, if it is determined by implicits and preceding fields.
+    #[cfg(feature = "rust")]
+    pub fn derivable_of(&self, field: &Symbol) -> Option<&ValueExpression> {
+        self.derivable
+            .iter()
+            .find(|(name, _)| *name == field.name)
+            .map(|(_, value)| value)
+    }
+}
+
 impl TypeExpression {
     pub fn get_type(&self) -> Rc<Type> {
         match self {
@@ -106,4 +170,65 @@ impl TypeExpression {
             } => dependencies,
         }
     }
+
+    /// Element type if this is builtin sequence (`List T` or `Array T n`).
+    #[cfg(any(feature = "rust", feature = "kotlin", feature = "swift"))]
+    pub fn sequence_element(&self) -> Option<&TypeExpression> {
+        let ty = self.get_type();
+        if !ty.is_builtin || !SEQUENCE_NAMES.contains(&ty.name.as_ref()) {
+            return None;
+        }
+        match self.get_dependencies().first() {
+            Some(ValueExpression::Type(element)) => Some(element),
+            _ => panic!("sequence type expects element type as first dependency"),
+        }
+    }
+
+    /// Length dependency if this is builtin `Array T n`.
+    #[cfg(feature = "rust")]
+    pub fn array_length(&self) -> Option<&ValueExpression> {
+        let ty = self.get_type();
+        if !ty.is_builtin || ty.name.as_ref() != "Array" {
+            return None;
+        }
+        self.get_dependencies().get(1)
+    }
+}
+
+impl ValueExpression {
+    /// Whether `symbol` occurs in the expression.
+    #[cfg(feature = "rust")]
+    pub fn mentions(&self, symbol: &Rc<Symbol>) -> bool {
+        match self {
+            ValueExpression::OpCall(OpCall::Literal(_)) => false,
+            ValueExpression::OpCall(OpCall::Unary(_, operand)) => operand.mentions(symbol),
+            ValueExpression::OpCall(OpCall::Binary(_, lhs, rhs) | OpCall::Concat(lhs, rhs)) => {
+                lhs.mentions(symbol) || rhs.mentions(symbol)
+            }
+            ValueExpression::Constructor {
+                implicits,
+                arguments,
+                ..
+            } => implicits
+                .iter()
+                .chain(arguments)
+                .any(|expr| expr.mentions(symbol)),
+            ValueExpression::Variable(variable) => {
+                std::ptr::eq(variable.as_ptr(), Rc::as_ptr(symbol))
+            }
+            ValueExpression::Type(ty) => ty
+                .get_dependencies()
+                .iter()
+                .any(|expr| expr.mentions(symbol)),
+        }
+    }
 }
+
+/// Names of builtin sequence types.
+pub const SEQUENCE_NAMES: &[&str] = &["List", "Array"];
+
+/// Name of builtin type of binary payloads.
+pub const BYTES_NAME: &str = "Bytes";
+
+/// Name of builtin type of UTF-8 strings.
+pub const STRING_NAME: &str = "String";
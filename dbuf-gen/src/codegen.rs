@@ -1,18 +1,75 @@
 use std::io;
 
 use crate::ast::Str;
+use crate::import::Import;
 use crate::{ast, format::BoxAllocator, generate::GlobalContext, rust_gen};
 
+/// Options of Rust code generation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options {
+    /// Whether types implement `DbufMessageAsync` to be encoded over tokio I/O,
+    /// which needs `tokio` feature of `dbuf-rust-runtime`.
+    pub async_io: bool,
+    /// Encoding of integers and lengths of sequences in values of generated types.
+    pub encoding: Encoding,
+    /// Whether descriptors and fields that are determined by dependencies are left out
+    /// of encoding, see `dbuf_core::elaboration::derivable`. Views are not generated then,
+    /// since they are read without dependencies.
+    pub omit_derivable: bool,
+}
+
+/// Wire encoding of integers and lengths of sequences,
+/// which is `Fixed` or `Compact` encoding of `dbuf-rust-runtime`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// Little-endian bytes of width of integers, and 8 bytes of lengths.
+    #[default]
+    Fixed,
+    /// LEB128 varints of unsigned integers and lengths, and zig-zag varints of signed ones.
+    Compact,
+}
+
 /// # Errors
 ///
 /// TODO: explain when `Err` is returned.
 pub fn generate_module<Writer: io::Write>(
     module: &ast::elaborated::Module<Str>,
     w: &mut Writer,
+) -> io::Result<()> {
+    generate_module_with_imports(module, &[], w)
+}
+
+/// Generates module that uses types of `imports` from their own outputs,
+/// which are expected to be sibling Rust modules named after imported files.
+///
+/// # Errors
+///
+/// Returns `Err` if writing generated code to `w` fails.
+pub fn generate_module_with_imports<Writer: io::Write>(
+    module: &ast::elaborated::Module<Str>,
+    imports: &[Import<'_>],
+    w: &mut Writer,
+) -> io::Result<()> {
+    generate_module_with_options(module, imports, Options::default(), w)
+}
+
+/// Generates module like [`generate_module_with_imports`] with `options`.
+///
+/// # Errors
+///
+/// Returns `Err` if writing generated code to `w` fails.
+pub fn generate_module_with_options<Writer: io::Write>(
+    module: &ast::elaborated::Module<Str>,
+    imports: &[Import<'_>],
+    options: Options,
+    w: &mut Writer,
 ) -> io::Result<()> {
     let allocator = BoxAllocator;
-    let ctx = GlobalContext { alloc: &allocator };
-    let module = ast::Module::from_elaborated(module);
+    let ctx = GlobalContext {
+        alloc: &allocator,
+        options,
+    };
+    let module = ast::Module::from_elaborated_with_imports(module, imports);
     let doc = rust_gen::generate_module(&module, ctx);
     doc.render(40, w)
 }
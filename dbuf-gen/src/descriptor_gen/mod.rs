@@ -0,0 +1,215 @@
+//! Generates descriptors of elaborated modules (see [`dbuf_rust_runtime::descriptor`]),
+//! which describe schemas to tools that don't link `dbuf-core`.
+use dbuf_core::ast::elaborated as e;
+use dbuf_core::ast::operators::{BinaryOp, Literal, OpCall, SizedInt, UnaryOp};
+use dbuf_rust_runtime::Double;
+use dbuf_rust_runtime::descriptor as d;
+
+use crate::ast::Str;
+use crate::import::Import;
+
+/// Generates descriptor of declarations of `module`.
+#[must_use]
+pub fn generate_module(module: &e::Module<Str>) -> d::ModuleDescriptor {
+    generate_module_with_imports(module, &[])
+}
+
+/// Generates descriptor of declarations of `module` and of `imports`, which its types
+/// may reference, so the descriptor describes every type referenced by it.
+///
+/// Imports go first, as files go after files they import.
+#[must_use]
+pub fn generate_module_with_imports(
+    module: &e::Module<Str>,
+    imports: &[Import<'_>],
+) -> d::ModuleDescriptor {
+    let modules: Vec<&e::Module<Str>> = imports
+        .iter()
+        .map(|import| import.module)
+        .chain([module])
+        .collect();
+    d::ModuleDescriptor {
+        version: d::FORMAT_VERSION,
+        package_path: module.package.iter().map(ToString::to_string).collect(),
+        types: modules
+            .iter()
+            .flat_map(|module| {
+                module
+                    .types
+                    .iter()
+                    .map(|(name, ty)| type_descriptor(module, name, ty))
+            })
+            .collect(),
+        aliases: modules
+            .iter()
+            .flat_map(|module| &module.aliases)
+            .map(|(name, alias)| d::AliasDescriptor {
+                name: name.to_string(),
+                dependencies: bindings(&alias.dependencies),
+                refinements: refinements(&alias.refinements),
+                target: type_expr(&alias.target),
+            })
+            .collect(),
+    }
+}
+
+fn type_descriptor(module: &e::Module<Str>, name: &Str, ty: &e::Type<Str>) -> d::TypeDescriptor {
+    let (kind, constructor_names) = match &ty.constructor_names {
+        e::ConstructorNames::OfMessage(name) => (d::TypeKind::MessageType, vec![name]),
+        e::ConstructorNames::OfEnum(names) => (d::TypeKind::EnumType, names.iter().collect()),
+    };
+    d::TypeDescriptor {
+        name: name.to_string(),
+        kind,
+        dependencies: bindings(&ty.dependencies),
+        refinements: refinements(&ty.refinements),
+        constructors: constructor_names
+            .into_iter()
+            .map(|name| {
+                let constructor = &module.constructors[name];
+                d::ConstructorDescriptor {
+                    name: name.to_string(),
+                    implicits: bindings(&constructor.implicits),
+                    fields: bindings(&constructor.fields),
+                    result_type: type_expr(&constructor.result_type),
+                    refinements: refinements(&constructor.refinements),
+                    defaults: constructor
+                        .defaults
+                        .iter()
+                        .map(|(name, value)| d::FieldDefault {
+                            name: name.to_string(),
+                            value: value_expr(value),
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+    }
+}
+
+fn bindings(context: &e::Context<Str>) -> Vec<d::Binding> {
+    context
+        .iter()
+        .map(|(name, ty)| d::Binding {
+            name: name.to_string(),
+            ty: type_expr(ty),
+        })
+        .collect()
+}
+
+fn refinements(refinements: &e::Refinements<Str>) -> Vec<d::Refinement> {
+    refinements
+        .iter()
+        .map(|(name, predicate)| d::Refinement {
+            name: name.to_string(),
+            predicate: value_expr(predicate),
+        })
+        .collect()
+}
+
+fn type_expr(expr: &e::TypeExpression<Str>) -> d::TypeExpr {
+    let e::TypeExpression::TypeExpression { name, dependencies } = expr;
+    d::TypeExpr {
+        name: name.to_string(),
+        dependencies: dependencies.iter().map(value_expr).collect(),
+    }
+}
+
+fn value_expr(expr: &e::ValueExpression<Str>) -> d::ValueExpr {
+    match expr {
+        e::ValueExpression::OpCall {
+            op_call,
+            result_type,
+        } => {
+            let result_type = type_expr(result_type);
+            match op_call {
+                OpCall::Literal(literal) => d::ValueExpr::LiteralExpr {
+                    literal: literal_descriptor(literal),
+                    result_type,
+                },
+                OpCall::Unary(op, operand) => d::ValueExpr::UnaryExpr {
+                    op: match op {
+                        UnaryOp::Access(field) => d::UnaryOp::Access {
+                            field: field.to_string(),
+                        },
+                        UnaryOp::Minus => d::UnaryOp::Negate,
+                        UnaryOp::Bang => d::UnaryOp::Not,
+                    },
+                    operand: Box::new(value_expr(operand)),
+                    result_type,
+                },
+                OpCall::Binary(op, lhs, rhs) => d::ValueExpr::BinaryExpr {
+                    op: binary_op(*op),
+                    lhs: Box::new(value_expr(lhs)),
+                    rhs: Box::new(value_expr(rhs)),
+                    result_type,
+                },
+            }
+        }
+        e::ValueExpression::Constructor {
+            name,
+            implicits,
+            arguments,
+            result_type,
+        } => d::ValueExpr::ConstructorExpr {
+            name: name.to_string(),
+            implicits: implicits.iter().map(value_expr).collect(),
+            arguments: arguments.iter().map(value_expr).collect(),
+            result_type: type_expr(result_type),
+        },
+        e::ValueExpression::Variable { name, ty } => d::ValueExpr::VariableExpr {
+            name: name.to_string(),
+            ty: type_expr(ty),
+        },
+        e::ValueExpression::Type { ty } => d::ValueExpr::TypeArgument { ty: type_expr(ty) },
+    }
+}
+
+fn literal_descriptor(literal: &Literal) -> d::Literal {
+    match literal {
+        Literal::Bool(value) => d::Literal::BoolLiteral { value: *value },
+        Literal::Int(value) => d::Literal::IntLiteral { value: *value },
+        Literal::UInt(value) => d::Literal::UIntLiteral { value: *value },
+        Literal::Str(value) => d::Literal::StringLiteral {
+            value: value.clone(),
+        },
+        Literal::Bytes(value) => d::Literal::BytesLiteral {
+            value: value.clone(),
+        },
+        Literal::Double(value) => d::Literal::DoubleLiteral {
+            value: Double(value.0),
+        },
+        Literal::Sized(sized) => match *sized {
+            SizedInt::I8(value) => d::Literal::Int8Literal { value },
+            SizedInt::I16(value) => d::Literal::Int16Literal { value },
+            SizedInt::I32(value) => d::Literal::Int32Literal { value },
+            SizedInt::I64(value) => d::Literal::Int64Literal { value },
+            SizedInt::U8(value) => d::Literal::UInt8Literal { value },
+            SizedInt::U16(value) => d::Literal::UInt16Literal { value },
+            SizedInt::U32(value) => d::Literal::UInt32Literal { value },
+            SizedInt::U64(value) => d::Literal::UInt64Literal { value },
+        },
+    }
+}
+
+fn binary_op(op: BinaryOp) -> d::BinaryOp {
+    match op {
+        BinaryOp::Plus => d::BinaryOp::Plus,
+        BinaryOp::Minus => d::BinaryOp::Minus,
+        BinaryOp::Star => d::BinaryOp::Star,
+        BinaryOp::Slash => d::BinaryOp::Slash,
+        BinaryOp::Percent => d::BinaryOp::Percent,
+        BinaryOp::ShiftLeft => d::BinaryOp::ShiftLeft,
+        BinaryOp::ShiftRight => d::BinaryOp::ShiftRight,
+        BinaryOp::Caret => d::BinaryOp::Caret,
+        BinaryOp::BinaryAnd => d::BinaryOp::BinaryAnd,
+        BinaryOp::BinaryOr => d::BinaryOp::BinaryOr,
+        BinaryOp::Equal => d::BinaryOp::Equal,
+        BinaryOp::NotEqual => d::BinaryOp::NotEqual,
+        BinaryOp::Less => d::BinaryOp::Less,
+        BinaryOp::LessEq => d::BinaryOp::LessEq,
+        BinaryOp::Greater => d::BinaryOp::Greater,
+        BinaryOp::GreaterEq => d::BinaryOp::GreaterEq,
+        BinaryOp::StartsWith => d::BinaryOp::StartsWith,
+    }
+}
@@ -2,11 +2,12 @@ pub mod lookup;
 pub mod namespace;
 pub mod node;
 
-use crate::format::BoxAllocator;
+use crate::{codegen::Options, format::BoxAllocator};
 
 #[derive(Clone, Copy)]
 pub struct GlobalContext<'a> {
     pub alloc: &'a BoxAllocator,
+    pub options: Options,
 }
 
 #[cfg(test)]
@@ -0,0 +1,14 @@
+//! Module exports `Import` - elaborated module of a file imported by generated one.
+
+use crate::ast::{Str, elaborated};
+
+/// Elaborated module of an imported file.
+///
+/// Generated code references types of imports instead of duplicating them.
+#[derive(Clone, Copy)]
+pub struct Import<'a> {
+    /// Name of imported file without extension, also used as name of its generated output.
+    pub name: &'a str,
+    /// Elaborated module of imported file.
+    pub module: &'a elaborated::Module<Str>,
+}
@@ -1,6 +1,8 @@
+use std::collections::BTreeSet;
+
 use pretty::{BoxAllocator, BoxDoc, DocAllocator};
 
-use crate::ast::{Module, Type, TypeKind};
+use crate::ast::{Alias, Module, Type, TypeExpression, TypeKind};
 use crate::kotlin_gen::target as kotlin;
 
 fn generate_class<'a>(t: &'a Type, alloc: &'a BoxAllocator) -> BoxDoc<'a> {
@@ -30,6 +32,7 @@ fn generate_class<'a>(t: &'a Type, alloc: &'a BoxAllocator) -> BoxDoc<'a> {
                     .map(|field| kotlin::Field::new(field))
                     .collect(),
                 result_type: constructor.result_type.clone(),
+                defaults: constructor.defaults.clone(),
             })
             .collect(),
     };
@@ -37,15 +40,55 @@ fn generate_class<'a>(t: &'a Type, alloc: &'a BoxAllocator) -> BoxDoc<'a> {
     class.generate(alloc)
 }
 
+fn generate_alias<'a>(alias: &'a Alias, alloc: &'a BoxAllocator) -> BoxDoc<'a> {
+    let alias = kotlin::TypeAlias {
+        name: alias.name.clone(),
+        target: alias.target.clone(),
+    };
+
+    alias.generate(alloc)
+}
+
+/// Helpers of integer operators used by generated expressions, with their operand types.
+fn integer_helpers(module: &Module) -> BTreeSet<(kotlin::IntegerOp, String)> {
+    let mut helpers = BTreeSet::new();
+    for constructor in module.types.iter().flat_map(|t| &t.constructors) {
+        let TypeExpression::Type { dependencies, .. } = &constructor.result_type;
+        let defaults = constructor.defaults.iter().map(|(_, value)| value);
+        for expr in dependencies.iter().chain(defaults) {
+            kotlin::collect_integer_helpers(expr, &mut helpers);
+        }
+    }
+    helpers
+}
+
 pub fn generate_module(module: &Module) -> String {
     let alloc = &BoxAllocator;
     let mut writer = Vec::new();
+    if !module.package.is_empty() {
+        let package = module
+            .package
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(".");
+        writer.extend_from_slice(format!("package {package}\n\n").as_bytes());
+    }
     for t in &module.types {
         generate_class(t, alloc)
             .append(alloc.hardline())
             .render(40, &mut writer)
             .expect("To be ok");
     }
+    for alias in &module.aliases {
+        generate_alias(alias, alloc)
+            .append(alloc.hardline())
+            .render(40, &mut writer)
+            .expect("To be ok");
+    }
+    for (op, ty) in integer_helpers(module) {
+        writer.extend_from_slice(op.helper(&ty).as_bytes());
+    }
 
     String::from_utf8(writer).expect("generated code must be correct utf8")
 }
@@ -1,6 +1,7 @@
 use crate::ast;
 
 use crate::ast::Str;
+use crate::import::Import;
 
 mod generate;
 mod target;
@@ -8,7 +9,19 @@ mod target;
 /// FIXME: remove expensive clones.
 #[must_use]
 pub fn generate_module(module: &ast::elaborated::Module<Str>) -> String {
-    let module = ast::Module::from_elaborated(module);
+    generate_module_with_imports(module, &[])
+}
+
+/// Generates module whose types may reference types of `imports`.
+///
+/// Imported types are declared in the same package by their own outputs,
+/// so they are not generated again.
+#[must_use]
+pub fn generate_module_with_imports(
+    module: &ast::elaborated::Module<Str>,
+    imports: &[Import<'_>],
+) -> String {
+    let module = ast::Module::from_elaborated_with_imports(module, imports);
 
     generate::generate_module(&module)
 }
@@ -1,5 +1,8 @@
 use core::panic;
+use std::collections::BTreeSet;
+use std::fmt::Write;
 
+use dbuf_core::ast::operators::SizedInt;
 use pretty::{BoxAllocator, BoxDoc, DocAllocator, DocBuilder};
 
 use crate::ast::Str;
@@ -20,8 +23,17 @@ pub struct InnerClass {
     pub fields: Vec<Field>,
     pub parent_params: Vec<Field>,
     pub result_type: ast::TypeExpression,
+    /// Default values of fields, which can refer to parent params.
+    pub defaults: Vec<(Str, ast::ValueExpression)>,
 }
 
+/// Kotlin's type alias names the target type, dependencies are not part of Kotlin types
+pub struct TypeAlias {
+    pub name: Str,
+    pub target: ast::TypeExpression,
+}
+
+#[allow(clippy::too_many_lines, reason = "??? (101/100)")]
 fn compile_value_expression<'a>(
     alloc: &'a BoxAllocator,
     expr: &ast::ValueExpression,
@@ -34,24 +46,67 @@ fn compile_value_expression<'a>(
             ast::OpCall::Literal(literal) => alloc.text(match literal {
                 ast::Literal::Bool(bool) => bool.to_string(),
                 ast::Literal::Int(i64) => i64.to_string(),
-                ast::Literal::UInt(u64) => u64.to_string(),
-                ast::Literal::Str(_string) => panic!("Not implemented"),
+                ast::Literal::UInt(u64) => format!("{u64}u"),
+                ast::Literal::Sized(sized) => sized_literal(*sized),
+                ast::Literal::Double(double) => format!("{:?}", double.0),
+                ast::Literal::Str(string) => string_literal(string),
+                ast::Literal::Bytes(bytes) => format!(
+                    "byteArrayOf({})",
+                    bytes
+                        .iter()
+                        .map(|byte| byte.cast_signed().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
             }),
 
+            ast::OpCall::Binary(ast::BinaryOp::StartsWith, left, right) => {
+                let receiver = compile_value_expression(alloc, left);
+                let receiver = if matches!(left.as_ref(), ast::ValueExpression::Variable(_)) {
+                    receiver
+                } else {
+                    receiver.parens()
+                };
+                receiver
+                    .append(".startsWith")
+                    .append(compile_value_expression(alloc, right).parens())
+            }
             ast::OpCall::Binary(op, left, right) => {
                 let left = compile_value_expression(alloc, left);
                 let right = compile_value_expression(alloc, right);
 
+                if let Some(op) = IntegerOp::of(*op) {
+                    return alloc
+                        .text(op.function())
+                        .append(left.append(", ").append(right).parens());
+                }
                 let op_str = match op {
+                    ast::BinaryOp::Caret => {
+                        return left.append(" xor ").append(right).parens();
+                    }
                     ast::BinaryOp::Plus => alloc.text("+"),
                     ast::BinaryOp::Minus => alloc.text("-"),
                     ast::BinaryOp::Star => alloc.text("*"),
                     ast::BinaryOp::BinaryAnd => alloc.text("&"),
                     ast::BinaryOp::BinaryOr => alloc.text("|"),
+                    comparison => alloc.text(comparison.symbol()),
                 };
 
                 left.append(op_str).append(right)
             }
+            ast::OpCall::Concat(left, right) => compile_value_expression(alloc, left)
+                .append("+")
+                .append(compile_value_expression(alloc, right)),
+            // Negation of `Byte` or `Short` is `Int` in Kotlin, so negative literal is built
+            // directly.
+            ast::OpCall::Unary(ast::UnaryOp::Minus, arg)
+                if let ast::ValueExpression::OpCall(ast::OpCall::Literal(ast::Literal::Sized(
+                    sized,
+                ))) = arg.as_ref()
+                    && let Some(negated) = sized.with_value(-sized.value()) =>
+            {
+                alloc.text(sized_literal(negated))
+            }
             ast::OpCall::Unary(op, arg) => {
                 let arg = compile_value_expression(alloc, arg);
 
@@ -63,6 +118,8 @@ fn compile_value_expression<'a>(
 
                         arg.append(".").append(field_name.to_string())
                     }
+                    ast::UnaryOp::Length => arg.append(".size.toUInt()"),
+                    ast::UnaryOp::StringLength => arg.append(".encodeToByteArray().size.toUInt()"),
                 }
             }
         }
@@ -79,7 +136,7 @@ fn compile_value_expression<'a>(
             let ty = constructor.result_type.get_type();
 
             let class_name = alloc
-                .text(ty.name.to_string())
+                .text(ty.qualified_name())
                 .append(".")
                 .append(constructor.name.to_string());
 
@@ -96,6 +153,219 @@ fn compile_value_expression<'a>(
             let name = symbol.upgrade().expect("Value to be present").name.clone();
             alloc.text(name.to_string())
         }
+        ast::ValueExpression::Type(_) => panic!("type can not be used as value"),
+    }
+}
+
+/// Integer operators, which are total in schemas unless divisor is zero, unlike Kotlin ones.
+///
+/// They are generated as calls of private helpers with semantics of `DbufInteger` of
+/// Rust runtime, which throw `IllegalArgumentException` on division by zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntegerOp {
+    Div,
+    Rem,
+    Shl,
+    Shr,
+}
+
+impl IntegerOp {
+    fn of(op: ast::BinaryOp) -> Option<Self> {
+        match op {
+            ast::BinaryOp::Slash => Some(Self::Div),
+            ast::BinaryOp::Percent => Some(Self::Rem),
+            ast::BinaryOp::ShiftLeft => Some(Self::Shl),
+            ast::BinaryOp::ShiftRight => Some(Self::Shr),
+            _ => None,
+        }
+    }
+
+    fn function(self) -> &'static str {
+        match self {
+            Self::Div => "dbufDiv",
+            Self::Rem => "dbufRem",
+            Self::Shl => "dbufShl",
+            Self::Shr => "dbufShr",
+        }
+    }
+
+    /// Helper of operator on values of Kotlin integer type `ty`.
+    ///
+    /// Operands are widened to `Long` or `ULong`, so narrow results wrap like in Rust.
+    pub fn helper(self, ty: &str) -> String {
+        let (wide, literal) = if ty.starts_with('U') {
+            ("ULong", "uL")
+        } else {
+            ("Long", "L")
+        };
+        let bits = match ty {
+            "Byte" | "UByte" => 8,
+            "Short" | "UShort" => 16,
+            "Int" | "UInt" => 32,
+            _ => 64,
+        };
+        let header = format!(
+            "private fun {}(lhs: {ty}, rhs: {ty}): {ty}",
+            self.function()
+        );
+        let out_of_range = format!("rhs.to{wide}() !in 0{literal} until {bits}{literal}");
+        match self {
+            Self::Div | Self::Rem => {
+                let op = if self == Self::Div { "/" } else { "%" };
+                format!(
+                    "{header} {{\n    require(rhs.to{wide}() != 0{literal}) {{ \"division by zero\" }}\n    return (lhs.to{wide}() {op} rhs.to{wide}()).to{ty}()\n}}\n"
+                )
+            }
+            Self::Shl => format!(
+                "{header} =\n    (if ({out_of_range}) 0{literal} else lhs.to{wide}() shl rhs.toInt()).to{ty}()\n"
+            ),
+            // All bits are shifted out, only sign of signed integers remains.
+            Self::Shr => {
+                let shifted_out = if wide == "Long" {
+                    "lhs.toLong() shr 63"
+                } else {
+                    "0uL"
+                };
+                format!(
+                    "{header} =\n    (if ({out_of_range}) {shifted_out} else lhs.to{wide}() shr rhs.toInt()).to{ty}()\n"
+                )
+            }
+        }
+    }
+}
+
+/// Adds helpers of integer operators of `expr` paired with their operand types to `helpers`.
+pub fn collect_integer_helpers(
+    expr: &ast::ValueExpression,
+    helpers: &mut BTreeSet<(IntegerOp, String)>,
+) {
+    match expr {
+        ast::ValueExpression::OpCall(op_call) => match op_call {
+            ast::OpCall::Literal(_) => {}
+            ast::OpCall::Unary(_, arg) => collect_integer_helpers(arg, helpers),
+            ast::OpCall::Binary(op, lhs, rhs) => {
+                if let Some(op) = IntegerOp::of(*op) {
+                    helpers.insert((op, integer_type(lhs)));
+                }
+                collect_integer_helpers(lhs, helpers);
+                collect_integer_helpers(rhs, helpers);
+            }
+            ast::OpCall::Concat(lhs, rhs) => {
+                collect_integer_helpers(lhs, helpers);
+                collect_integer_helpers(rhs, helpers);
+            }
+        },
+        ast::ValueExpression::Constructor {
+            implicits,
+            arguments,
+            ..
+        } => {
+            for arg in implicits.iter().chain(arguments) {
+                collect_integer_helpers(arg, helpers);
+            }
+        }
+        ast::ValueExpression::Variable(_) | ast::ValueExpression::Type(_) => {}
+    }
+}
+
+/// Kotlin type of integer expression `expr`.
+fn integer_type(expr: &ast::ValueExpression) -> String {
+    match expr {
+        ast::ValueExpression::OpCall(op_call) => match op_call {
+            ast::OpCall::Literal(ast::Literal::Int(_)) => "Int".to_owned(),
+            ast::OpCall::Literal(ast::Literal::UInt(_))
+            | ast::OpCall::Unary(ast::UnaryOp::Length | ast::UnaryOp::StringLength, _) => {
+                "UInt".to_owned()
+            }
+            ast::OpCall::Literal(ast::Literal::Sized(sized)) => sized_kotlin_type(match sized {
+                SizedInt::I8(_) => "Int8",
+                SizedInt::I16(_) => "Int16",
+                SizedInt::I32(_) => "Int32",
+                SizedInt::I64(_) => "Int64",
+                SizedInt::U8(_) => "UInt8",
+                SizedInt::U16(_) => "UInt16",
+                SizedInt::U32(_) => "UInt32",
+                SizedInt::U64(_) => "UInt64",
+            })
+            .expect("sized integer has Kotlin type")
+            .to_owned(),
+            ast::OpCall::Unary(ast::UnaryOp::Access { field, .. }, _) => {
+                compile_type_expression(&field.upgrade().expect("value to be present").ty)
+            }
+            ast::OpCall::Unary(_, arg) | ast::OpCall::Binary(_, arg, _) => integer_type(arg),
+            ast::OpCall::Literal(_) | ast::OpCall::Concat(..) => {
+                panic!("expression is not integer")
+            }
+        },
+        ast::ValueExpression::Variable(symbol) => {
+            compile_type_expression(&symbol.upgrade().expect("value to be present").ty)
+        }
+        ast::ValueExpression::Constructor { .. } | ast::ValueExpression::Type(_) => {
+            panic!("expression is not integer")
+        }
+    }
+}
+
+/// Kotlin string literal, where `$` is escaped as it starts string templates.
+fn string_literal(string: &str) -> String {
+    let mut literal = String::from('"');
+    for c in string.chars() {
+        match c {
+            '"' | '\\' | '$' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => {
+                write!(literal, "\\u{:04x}", u32::from(c))
+                    .expect("Writing into String is always ok");
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+fn sized_literal(sized: SizedInt) -> String {
+    match sized {
+        SizedInt::I8(value) => format!("({value}).toByte()"),
+        SizedInt::I16(value) => format!("({value}).toShort()"),
+        SizedInt::I32(value) => value.to_string(),
+        SizedInt::I64(value) => format!("{value}L"),
+        SizedInt::U8(value) => format!("{value}u.toUByte()"),
+        SizedInt::U16(value) => format!("{value}u.toUShort()"),
+        SizedInt::U32(value) => format!("{value}u"),
+        SizedInt::U64(value) => format!("{value}uL"),
+    }
+}
+
+/// Kotlin names of fixed-width integer builtins.
+fn sized_kotlin_type(name: &str) -> Option<&'static str> {
+    match name {
+        "Int8" => Some("Byte"),
+        "Int16" => Some("Short"),
+        "Int32" => Some("Int"),
+        "Int64" => Some("Long"),
+        "UInt8" => Some("UByte"),
+        "UInt16" => Some("UShort"),
+        "UInt32" => Some("UInt"),
+        "UInt64" => Some("ULong"),
+        _ => None,
+    }
+}
+
+fn compile_type_expression(expr: &ast::TypeExpression) -> String {
+    let ty = expr.get_type();
+    match expr.sequence_element() {
+        Some(element) => format!("List<{}>", compile_type_expression(element)),
+        None if ty.is_builtin && ty.name.as_ref() == ast::BYTES_NAME => "ByteArray".to_owned(),
+        None if ty.is_builtin => {
+            sized_kotlin_type(ty.name.as_ref()).map_or_else(|| ty.qualified_name(), str::to_owned)
+        }
+        None => ty.qualified_name(),
     }
 }
 
@@ -193,6 +463,13 @@ impl SealedClass {
 }
 
 impl InnerClass {
+    fn default_of(&self, field: &Field) -> Option<&ast::ValueExpression> {
+        self.defaults
+            .iter()
+            .find(|(name, _)| *name == field.0.name)
+            .map(|(_, value)| value)
+    }
+
     pub fn generate<'a>(&self, parent_name: &str, alloc: &'a BoxAllocator) -> BoxDoc<'a> {
         let build_field_declarations = |fields: &Vec<Field>| {
             alloc.concat(fields.iter().map(|field| {
@@ -207,13 +484,16 @@ impl InnerClass {
 
         let build_constructor =
             |fields: &Vec<Field>, parent_params: &Vec<Field>, result_type: &ast::TypeExpression| {
-                let constructor_params = alloc.intersperse(
-                    parent_params
-                        .iter()
-                        .chain(fields.iter())
-                        .map(|param| param.generate(alloc)),
-                    alloc.text(", "),
-                );
+                let constructor_params =
+                    alloc.intersperse(
+                        parent_params
+                            .iter()
+                            .map(|param| param.generate(alloc))
+                            .chain(fields.iter().map(|field| {
+                                field.generate_parameter(self.default_of(field), alloc)
+                            })),
+                        alloc.text(", "),
+                    );
 
                 let parent_params = match result_type {
                     ast::TypeExpression::Type {
@@ -299,6 +579,18 @@ impl InnerClass {
     }
 }
 
+impl TypeAlias {
+    pub fn generate<'a>(&self, alloc: &'a BoxAllocator) -> BoxDoc<'a> {
+        alloc
+            .text("typealias")
+            .append(alloc.space())
+            .append(self.name.to_string())
+            .append(" = ")
+            .append(compile_type_expression(&self.target))
+            .into_doc()
+    }
+}
+
 impl Field {
     pub fn new(symbol: &ast::Symbol) -> Self {
         Self(symbol.clone())
@@ -308,7 +600,24 @@ impl Field {
             .text(self.0.name.to_string())
             .append(":")
             .append(alloc.space())
-            .append(self.0.ty.get_type().name.to_string())
+            .append(compile_type_expression(&self.0.ty))
             .into_doc()
     }
+
+    /// Generates constructor parameter, which is optional if it has `default` value.
+    pub fn generate_parameter<'a>(
+        &self,
+        default: Option<&ast::ValueExpression>,
+        alloc: &'a BoxAllocator,
+    ) -> BoxDoc<'a> {
+        match default {
+            Some(default) => alloc
+                .nil()
+                .append(self.generate(alloc))
+                .append(" = ")
+                .append(compile_value_expression(alloc, default))
+                .into_doc(),
+            None => self.generate(alloc),
+        }
+    }
 }
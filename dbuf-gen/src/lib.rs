@@ -1,4 +1,9 @@
-#![cfg(any(feature = "rust", feature = "kotlin", feature = "swift"))]
+#![cfg(any(
+    feature = "rust",
+    feature = "kotlin",
+    feature = "swift",
+    feature = "descriptor"
+))]
 #![cfg_attr(
     not(feature = "rust"),
     allow(
@@ -16,6 +21,7 @@
 
 mod ast;
 mod format;
+pub mod import;
 mod scope;
 
 #[cfg(feature = "rust")]
@@ -29,3 +35,6 @@ mod rust_gen;
 pub mod kotlin_gen;
 #[cfg(feature = "swift")]
 pub mod swift_gen;
+
+#[cfg(feature = "descriptor")]
+pub mod descriptor_gen;
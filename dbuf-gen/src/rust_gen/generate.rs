@@ -1,4 +1,6 @@
 use std::{
+    collections::HashSet,
+    iter,
     rc::Rc,
     sync::atomic::{AtomicU64, Ordering},
 };
@@ -20,27 +22,189 @@ impl<'a> Module {
     pub(super) fn generate(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
         let alloc = ctx.alloc;
 
-        let types = self
+        // imported types are renamed if their names are already taken
+        let mut taken: HashSet<String> = self.types.iter().map(|ty| ty.name.to_string()).collect();
+        let imported = self
+            .imported
+            .iter()
+            .map(|(origin, ty)| {
+                let mut alias = ty.name.to_string();
+                if !taken.insert(alias.clone()) {
+                    alias = ty
+                        .package
+                        .iter()
+                        .map(|segment| capitalize(segment.as_ref()))
+                        .chain(iter::once(alias))
+                        .collect();
+                    taken.insert(alias.clone());
+                }
+                ty.generate_import(origin, alias, self.package.len(), (ctx, namespace))
+            })
+            .collect::<Vec<_>>();
+
+        // mutually recursive types reference each other, so the ones referenced
+        // before they are generated are declared in advance
+        let mut referenced = HashSet::new();
+        for ty in &self.types {
+            if referenced.contains(&NodeId::id_rc(ty)) {
+                ty.declare((ctx, namespace));
+            }
+            referenced.extend(ty.referenced_types());
+        }
+
+        let mut types = self
             .types
             .iter()
             .map(|ty| (*ty).clone().generate((ctx, namespace)))
             .collect::<Vec<_>>();
+        types.extend(
+            self.aliases
+                .iter()
+                .map(|alias| alias.generate((ctx, namespace))),
+        );
 
-        alloc
-            .intersperse(
-                [
-                    "use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufPrimitive};",
-                    "use std::io::{Write, Read, Error};",
-                    "use std::slice;",
-                ],
-                alloc.hardline(),
-            )
+        let async_prelude = [
+            "use dbuf_rust_runtime::{BoxFuture, DbufMessageAsync, DbufPrimitiveAsync, defaulted_field_reader_async};",
+            "use dbuf_rust_runtime::tokio::io::{AsyncRead, AsyncWrite, AsyncReadExt, AsyncWriteExt};",
+        ];
+        let runtime_prelude = if ctx.options.omit_derivable {
+            // views are not generated
+            "use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, deserialize_or_default, refinement_holds};"
+        } else {
+            "use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};"
+        };
+        let prelude = [
+            runtime_prelude,
+            "use std::io::{Write, Read, Error};",
+            "use std::slice;",
+        ]
+        .into_iter()
+        .chain(async_prelude.into_iter().filter(|_| ctx.options.async_io))
+        .map(|line| alloc.text(line).into_doc())
+        .chain(imported);
+
+        let mut module = alloc.intersperse(prelude, alloc.hardline());
+        if !types.is_empty() {
+            module = module
+                .append(alloc.hardline())
+                .append(alloc.intersperse(types, alloc.hardline().append(alloc.hardline())));
+        }
+
+        self.package
+            .iter()
+            .rev()
+            .fold(module, |inner, segment| {
+                alloc
+                    .text("pub mod")
+                    .append(alloc.space())
+                    .append(segment.to_string())
+                    .append(alloc.space())
+                    .append("{")
+                    .append(alloc.hardline().append(inner).nest(NEST_UNIT))
+                    .append(alloc.hardline())
+                    .append("}")
+            })
             .append(alloc.hardline())
-            .append(alloc.intersperse(types, alloc.hardline()))
             .into_doc()
     }
 }
 
+/// Makes first letter of `segment` uppercase.
+fn capitalize(segment: &str) -> String {
+    let mut chars = segment.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Type argument of runtime traits and views for `encoding`,
+/// which is omitted for the default encoding.
+fn encoding_argument(encoding: Encoding) -> Option<&'static str> {
+    match encoding {
+        Encoding::Fixed => None,
+        Encoding::Compact => Some("dbuf_rust_runtime::Compact"),
+    }
+}
+
+/// I/O, which values of generated types are encoded and decoded over.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Io {
+    /// `std::io`, implementing `DbufMessage`.
+    Blocking,
+    /// tokio I/O, implementing `DbufMessageAsync`, whose functions return boxed futures.
+    Async,
+}
+
+impl Io {
+    fn message_trait(self) -> &'static str {
+        match self {
+            Io::Blocking => "super::DbufMessage",
+            Io::Async => "super::DbufMessageAsync",
+        }
+    }
+
+    fn primitive_trait(self, encoding: Encoding) -> String {
+        let primitive_trait = match self {
+            Io::Blocking => "super::DbufPrimitive",
+            Io::Async => "super::DbufPrimitiveAsync",
+        };
+        match encoding_argument(encoding) {
+            Some(argument) => format!("{primitive_trait}<{argument}>"),
+            None => primitive_trait.to_owned(),
+        }
+    }
+
+    /// Suffix of names of functions of the traits.
+    fn suffix(self) -> &'static str {
+        match self {
+            Io::Blocking => "",
+            Io::Async => "_async",
+        }
+    }
+
+    /// Suffix of calls of functions of the traits, which completes them.
+    fn completion(self) -> &'static str {
+        match self {
+            Io::Blocking => "",
+            Io::Async => ".await",
+        }
+    }
+
+    /// Wraps body of function of the traits.
+    fn wrap_body<'a>(self, body: BoxDoc<'a>, alloc: &'a crate::format::BoxAllocator) -> BoxDoc<'a> {
+        match self {
+            Io::Blocking => body,
+            Io::Async => alloc
+                .text("Box::pin(async move {")
+                .append(alloc.hardline().append(body).nest(NEST_UNIT))
+                .append(alloc.hardline())
+                .append("})")
+                .into_doc(),
+        }
+    }
+}
+
+/// What generated deserialization code reads.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Reading {
+    /// Values decoded from `Io`.
+    Values(Io),
+    /// Encodings in `SliceReader`, which are checked by reading views of fields.
+    /// Only fields, which checks of constructors mention, are decoded.
+    Views,
+}
+
+impl Reading {
+    /// I/O of decoded values. `SliceReader` implements `Read`.
+    fn io(self) -> Io {
+        match self {
+            Reading::Values(io) => io,
+            Reading::Views => Io::Blocking,
+        }
+    }
+}
+
 impl<'a> Type {
     pub fn builtin_rust_type(&self) -> Option<&'static str> {
         if !self.is_builtin {
@@ -50,17 +214,90 @@ impl<'a> Type {
             "Bool" => Some("bool"),
             "Int" => Some("i64"),
             "UInt" => Some("u64"),
+            "Int8" => Some("i8"),
+            "Int16" => Some("i16"),
+            "Int32" => Some("i32"),
+            "Int64" => Some("i64"),
+            "UInt8" => Some("u8"),
+            "UInt16" => Some("u16"),
+            "UInt32" => Some("u32"),
+            "UInt64" => Some("u64"),
+            "Double" => Some("dbuf_rust_runtime::Double"),
             "String" => Some("String"),
+            "Bytes" => Some("std::vec::Vec<u8>"),
             _ => None,
         }
     }
     pub fn generate(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
+        self.generate_named(self.name.to_string(), (ctx, namespace))
+    }
+
+    /// Whether descriptor of the enum is derived from dependencies instead of being encoded.
+    fn omits_descriptor(&self, options: Options) -> bool {
+        options.omit_derivable && self.derivable_descriptor
+    }
+
+    /// Declares type module with the type, its `Body`, `Dependencies` and view, and public alias,
+    /// so the type can be referenced before it is generated.
+    /// Module is replaced by generated one, alias is kept.
+    fn declare(&self, (_, namespace): MutContext<'a, '_, '_>) {
+        let (_, mut type_namespace) = namespace
+            .insert_object_preserve_name(objects::Module::from_object(
+                ObjectId(NodeId::id(self), Tag::String("module")),
+                self.name.to_string().to_lowercase(),
+            ))
+            .expect("couldn't declare type module");
+
+        let message_type_object = objects::Type::from_object(
+            ObjectId(NodeId::id(self), Tag::String("type")),
+            self.name.to_string(),
+        );
+        let name = type_namespace.name_object(&message_type_object);
+        let _ = type_namespace.insert_object(message_type_object, &name);
+
+        let (_, mut body_namespace) =
+            type_namespace.insert_object_auto_name(objects::Type::from_name("Body".to_owned()));
+        if self.kind == ast::TypeKind::Message {
+            for symbol in &self.constructors[0].fields {
+                let _ = body_namespace.insert_object_auto_name(objects::Variable::from_object(
+                    ObjectId(NodeId::id_rc(symbol), Tag::None),
+                    symbol.name.to_string(),
+                ));
+            }
+        }
+        drop(body_namespace);
+
+        let (_, mut dependencies_namespace) = type_namespace
+            .insert_object_auto_name(objects::Type::from_name("Dependencies".to_owned()));
+        for symbol in &self.dependencies {
+            let _ = dependencies_namespace.insert_object_auto_name(objects::Variable::from_object(
+                ObjectId(NodeId::id_rc(symbol), Tag::None),
+                symbol.name.to_string(),
+            ));
+        }
+        drop(dependencies_namespace);
+
+        let _ = type_namespace.insert_object_auto_name(objects::Type::from_object(
+            ObjectId(NodeId::id(self), Tag::String("view")),
+            format!("{}Ref", self.name),
+        ));
+        drop(type_namespace);
+
+        let _ = namespace.insert_object_auto_name(objects::Type::from_object(
+            ObjectId(NodeId::id(self), Tag::String("type")),
+            self.name.to_string(),
+        ));
+    }
+
+    /// Generates type with module and public alias named after `name`.
+    fn generate_named(&self, name: String, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
         let alloc = ctx.alloc;
 
+        let _ = namespace.remove_tree(&ObjectId(NodeId::id(self), Tag::String("module")));
         let (type_module, mut type_namespace) = namespace
             .insert_object_preserve_name(objects::Module::from_object(
                 ObjectId(NodeId::id(self), Tag::String("module")),
-                self.name.to_string().to_lowercase(),
+                name.to_lowercase(),
             ))
             .expect("couldn't generate type module");
 
@@ -72,7 +309,17 @@ impl<'a> Type {
             module_parts.push(descriptor_module);
         }
         module_parts.push(self.generate_declaration((ctx, &mut type_namespace)));
+        if !ctx.options.omit_derivable {
+            self.declare_view((ctx, &mut type_namespace));
+        }
         module_parts.push(self.generate_inherent_impl((ctx, &mut type_namespace)));
+        module_parts.push(self.generate_message_impl(Io::Blocking, (ctx, &mut type_namespace)));
+        if ctx.options.async_io {
+            module_parts.push(self.generate_message_impl(Io::Async, (ctx, &mut type_namespace)));
+        }
+        if !ctx.options.omit_derivable {
+            module_parts.push(self.generate_view((ctx, &mut type_namespace)));
+        }
 
         let module = alloc.intersperse(module_parts, alloc.hardline());
 
@@ -108,10 +355,15 @@ impl<'a> Type {
                 );
             });
 
-        let (use_alias_name, _) = namespace.insert_object_auto_name(objects::Type::from_object(
-            ObjectId(NodeId::id(self), Tag::String("type")),
-            self.name.to_string(),
-        ));
+        let alias_id = ObjectId(NodeId::id(self), Tag::String("type"));
+        let declared = namespace
+            .get_generated::<objects::Type>(alias_id.clone())
+            .map(|(declared, _)| declared);
+        let use_alias_name = declared.unwrap_or_else(|| {
+            namespace
+                .insert_object_auto_name(objects::Type::from_object(alias_id, name))
+                .0
+        });
 
         alloc
             .text("pub mod")
@@ -137,7 +389,121 @@ impl<'a> Type {
             .append(alloc.space())
             .append(use_alias_name.to_doc(ctx))
             .append(";")
-            .append(alloc.hardline())
+            .into_doc()
+    }
+}
+
+impl Constructor {
+    /// Value of `field` that is derived instead of being encoded, if it is omitted.
+    fn omitted_value(&self, field: &Symbol, options: Options) -> Option<&ValueExpression> {
+        self.derivable_of(field).filter(|_| options.omit_derivable)
+    }
+
+    /// Whether checks of the constructor on reading need value of `symbol`: refinements
+    /// or dependencies of types of fields mention it, or defaults of fields, which are needed.
+    /// Defaults mention only preceding fields, so the recursion ends.
+    fn checks_mention(&self, symbol: &Rc<Symbol>) -> bool {
+        self.refinements
+            .iter()
+            .any(|(_, predicate)| predicate.mentions(symbol))
+            || self.fields.iter().any(|field| {
+                field
+                    .ty
+                    .get_dependencies()
+                    .iter()
+                    .any(|dependency| dependency.mentions(symbol))
+                    || self
+                        .default_of(field)
+                        .is_some_and(|default| default.mentions(symbol))
+                        && self.checks_mention(field)
+            })
+    }
+}
+
+impl<'a> Alias {
+    /// Generates public type alias of the target type. Dependencies of the alias
+    /// are not part of Rust types, they are checked when target is constructed.
+    fn generate(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
+        let alloc = ctx.alloc;
+
+        let target = self
+            .target
+            .generate_as_top_level_rust_type((ctx, namespace));
+        let (name, _) = namespace.insert_object_auto_name(objects::Type::from_object(
+            ObjectId(NodeId::id(self), Tag::String("alias")),
+            self.name.to_string(),
+        ));
+
+        alloc
+            .text("pub type")
+            .append(alloc.space())
+            .append(name.to_doc(ctx))
+            .append(" = ")
+            .append(target)
+            .append(";")
+            .into_doc()
+    }
+}
+
+impl<'a> Type {
+    /// Registers type declared in `origin` file and imports it from there.
+    ///
+    /// Type is generated as usual under `alias`, so every inner object gets the
+    /// same name as in the output of `origin`, but only `use` declaration is emitted.
+    /// `depth` is the number of packages current module is nested in.
+    fn generate_import(
+        &self,
+        origin: &Str,
+        alias: String,
+        depth: usize,
+        (ctx, namespace): MutContext<'a, '_, '_>,
+    ) -> BoxDoc<'a> {
+        let alloc = ctx.alloc;
+
+        let renamed = alias != self.name.as_ref();
+        let _ = self.generate_named(alias, (ctx, namespace));
+
+        let (type_module, _) = namespace
+            .get_generated::<objects::Module>(ObjectId(NodeId::id(self), Tag::String("module")))
+            .expect("couldn't get imported type module");
+        let (type_alias, _) = namespace
+            .get_generated::<objects::Type>(ObjectId(NodeId::id(self), Tag::String("type")))
+            .expect("couldn't get imported type");
+
+        let path = iter::repeat_n("super".to_owned(), depth + 1)
+            .chain(iter::once(origin.to_string()))
+            .chain(self.package.iter().map(ToString::to_string))
+            .collect::<Vec<_>>()
+            .join("::");
+
+        let item = |original: String, generated: BoxDoc<'a>| {
+            if renamed {
+                alloc
+                    .text(original)
+                    .append(alloc.space())
+                    .append("as")
+                    .append(alloc.space())
+                    .append(generated)
+            } else {
+                alloc.nil().append(generated)
+            }
+        };
+
+        alloc
+            .text("use")
+            .append(alloc.space())
+            .append(path)
+            .append("::")
+            .append("{")
+            .append(item(
+                self.name.to_string().to_lowercase(),
+                type_module.to_doc(ctx),
+            ))
+            .append(",")
+            .append(alloc.space())
+            .append(item(self.name.to_string(), type_alias.to_doc(ctx)))
+            .append("}")
+            .append(";")
             .into_doc()
     }
 }
@@ -148,7 +514,6 @@ mod type_dependencies_import {
     use std::{collections::HashSet, iter, rc::Rc};
 
     impl<'a> Type {
-        #[allow(clippy::too_many_lines, reason = "??? (144/100)")]
         pub(super) fn generate_dependencies_import(
             &self,
             (ctx, namespace): MutContext<'a, '_, '_>,
@@ -160,29 +525,7 @@ mod type_dependencies_import {
             let namespace = &mut deps_namespace;
             let alloc = ctx.alloc;
 
-            let mut dependencies = self
-                .constructors
-                .iter()
-                .map(|constructor| {
-                    let Constructor {
-                        name: _,
-                        implicits,
-                        fields,
-                        result_type,
-                    } = constructor.as_ref();
-                    let implicits = implicits.iter().map(Self::symbol_dependencies);
-                    let fields = fields.iter().map(Self::symbol_dependencies);
-                    iter::once(Self::type_expression_dependencies(result_type))
-                        .chain(implicits)
-                        .chain(fields)
-                        .flatten()
-                        .collect()
-                })
-                .chain(self.dependencies.iter().map(Self::symbol_dependencies))
-                .flatten()
-                .collect::<HashSet<_>>();
-
-            dependencies.remove(&NodeId::id(self)); // don't need to return self name
+            let dependencies = self.referenced_types();
 
             let other_type_deps = if dependencies.is_empty() {
                 alloc.text("// ")
@@ -190,65 +533,74 @@ mod type_dependencies_import {
                 alloc.nil()
             };
 
+            let mut imports = dependencies
+                .into_iter()
+                .map(|node_id| {
+                    let global_namespace = namespace
+                        .cursor()
+                        .lookup_module_root()
+                        .go_back()
+                        .expect("deps module expected to be non-root")
+                        .lookup_module_root()
+                        .go_back()
+                        .expect("message module expected to be non-root");
+
+                    let message_module = global_namespace
+                        .clone()
+                        .lookup_generated::<objects::Module>(ObjectId(
+                            node_id.clone(),
+                            Tag::String("module"),
+                        ))
+                        .expect("couldn't get message module");
+
+                    let message_type = global_namespace
+                        .lookup_generated::<objects::Type>(ObjectId(
+                            node_id.clone(),
+                            Tag::String("type"),
+                        ))
+                        .expect("couldn't get message type");
+
+                    let message_module_generated =
+                        objects::GeneratedModule::try_from(message_module.value())
+                            .expect("expected module");
+                    let message_type_generated =
+                        objects::GeneratedType::try_from(message_type.value())
+                            .expect("expected type");
+
+                    // TODO: those should not be copies. References are enough, because they are non-modifiable parts of tree.
+                    let message_module_node = message_module.node().clone();
+                    let message_type_node = message_type.node().clone();
+                    drop(message_module);
+                    drop(message_type);
+
+                    namespace.insert_tree(
+                        &ObjectId(node_id.clone(), Tag::String("module")),
+                        message_module_node,
+                    );
+                    namespace.insert_tree(
+                        &ObjectId(node_id.clone(), Tag::String("type")),
+                        message_type_node,
+                    );
+
+                    (message_module_generated, message_type_generated)
+                })
+                .collect::<Vec<_>>();
+            // ids of types are their addresses, so imports are sorted by names
+            imports.sort_by_key(|(_, message_type)| message_type.name());
+
             let other_type_deps = other_type_deps
                 .append(alloc.text("pub(super) use"))
                 .append(alloc.space())
                 .append("super::super::")
                 .append("{")
                 .append(alloc.intersperse(
-                    dependencies.into_iter().map(|node_id| {
-                        let global_namespace = namespace
-                            .cursor()
-                            .lookup_module_root()
-                            .go_back()
-                            .expect("deps module expected to be non-root")
-                            .lookup_module_root()
-                            .go_back()
-                            .expect("message module expected to be non-root");
-
-                        let message_module = global_namespace
-                            .clone()
-                            .lookup_generated::<objects::Module>(ObjectId(
-                                node_id.clone(),
-                                Tag::String("module"),
-                            ))
-                            .expect("couldn't get message module");
-
-                        let message_type = global_namespace
-                            .lookup_generated::<objects::Type>(ObjectId(
-                                node_id.clone(),
-                                Tag::String("type"),
-                            ))
-                            .expect("couldn't get message type");
-
-                        let message_module_generated =
-                            objects::GeneratedModule::try_from(message_module.value())
-                                .expect("expected module");
-                        let message_type_generated =
-                            objects::GeneratedType::try_from(message_type.value())
-                                .expect("expected type");
-
-                        // TODO: those should not be copies. References are enough, because they are non-modifiable parts of tree.
-                        let message_module_node = message_module.node().clone();
-                        let message_type_node = message_type.node().clone();
-                        drop(message_module);
-                        drop(message_type);
-
-                        namespace.insert_tree(
-                            &ObjectId(node_id.clone(), Tag::String("module")),
-                            message_module_node,
-                        );
-                        namespace.insert_tree(
-                            &ObjectId(node_id.clone(), Tag::String("type")),
-                            message_type_node,
-                        );
-
+                    imports.into_iter().map(|(message_module, message_type)| {
                         alloc
                             .text("{")
-                            .append(message_module_generated.to_doc(ctx))
+                            .append(message_module.to_doc(ctx))
                             .append(",")
                             .append(alloc.space())
-                            .append(message_type_generated.to_doc(ctx))
+                            .append(message_type.to_doc(ctx))
                             .append("}")
                     }),
                     alloc.text(",").append(alloc.space()),
@@ -270,6 +622,43 @@ mod type_dependencies_import {
                 .into_doc()
         }
 
+        /// Returns ids of other types referenced by the type.
+        pub(super) fn referenced_types(&self) -> HashSet<NodeId<'a>> {
+            let mut dependencies = self
+                .constructors
+                .iter()
+                .map(|constructor| {
+                    let Constructor {
+                        name: _,
+                        implicits,
+                        fields,
+                        refinements,
+                        result_type,
+                        defaults,
+                        derivable,
+                    } = constructor.as_ref();
+                    let implicits = implicits.iter().map(Self::symbol_dependencies);
+                    let fields = fields.iter().map(Self::symbol_dependencies);
+                    let refinements = refinements
+                        .iter()
+                        .chain(defaults)
+                        .chain(derivable)
+                        .map(|(_, value)| Self::value_expression_dependencies(value));
+                    iter::once(Self::type_expression_dependencies(result_type))
+                        .chain(implicits)
+                        .chain(fields)
+                        .chain(refinements)
+                        .flatten()
+                        .collect()
+                })
+                .chain(self.dependencies.iter().map(Self::symbol_dependencies))
+                .flatten()
+                .collect::<HashSet<_>>();
+
+            dependencies.remove(&NodeId::id(self)); // don't need to return self name
+            dependencies
+        }
+
         fn type_expression_dependencies(expr: &TypeExpression) -> HashSet<NodeId<'a>> {
             match expr {
                 TypeExpression::Type { call, dependencies } => {
@@ -293,7 +682,7 @@ mod type_dependencies_import {
                     match op_call {
                         OpCall::Literal(_) => vec![], // no deps in literal
                         OpCall::Unary(_, expr) => vec![Self::value_expression_dependencies(expr)],
-                        OpCall::Binary(_, lhs, rhs) => {
+                        OpCall::Binary(_, lhs, rhs) | OpCall::Concat(lhs, rhs) => {
                             vec![
                                 Self::value_expression_dependencies(lhs),
                                 Self::value_expression_dependencies(rhs),
@@ -333,6 +722,7 @@ mod type_dependencies_import {
                         &symbol.upgrade().expect("missing type in symbol"),
                     )] // this is maybe unnecessary
                 }
+                ValueExpression::Type(ty) => vec![Self::type_expression_dependencies(ty)],
             };
             results.into_iter().flatten().collect()
         }
@@ -343,7 +733,25 @@ mod type_dependencies_import {
 
         // TODO: move this to more appropriate place
         fn is_primitive_type(name: &str) -> bool {
-            matches!(name, "Bool" | "Int" | "UInt" | "String")
+            matches!(
+                name,
+                "Bool"
+                    | "Int"
+                    | "UInt"
+                    | "Int8"
+                    | "Int16"
+                    | "Int32"
+                    | "Int64"
+                    | "UInt8"
+                    | "UInt16"
+                    | "UInt32"
+                    | "UInt64"
+                    | "Double"
+                    | "String"
+                    | "Bytes"
+                    | "List"
+                    | "Array"
+            )
         }
     }
 }
@@ -740,6 +1148,7 @@ impl<'a> Type {
 
 mod type_inherent_impl {
     use std::{
+        collections::HashSet,
         iter,
         rc::{Rc, Weak},
     };
@@ -747,7 +1156,9 @@ mod type_inherent_impl {
     use crate::rust_gen::generate::value_from_expression::Locator;
 
     use super::super::prelude::*;
-    use super::wrap_with_deferred_checks;
+    use super::{
+        Io, Reading, encoding_argument, generate_refinement_check, wrap_with_deferred_checks,
+    };
 
     struct ConstructorObjectsLocator {}
 
@@ -767,16 +1178,20 @@ mod type_inherent_impl {
             let constructors = self
                 .constructors
                 .iter()
-                .map(|constructor| {
-                    constructor
-                        .generate_constructor_declaration((ctx, &mut inherent_impl_namespace))
+                .flat_map(|constructor| {
+                    let declaration = constructor
+                        .generate_constructor_declaration((ctx, &mut inherent_impl_namespace));
+                    let builder = (!constructor.defaults.is_empty()).then(|| {
+                        constructor
+                            .generate_builder_declaration((ctx, &mut inherent_impl_namespace))
+                    });
+                    iter::once(declaration).chain(builder)
                 })
                 .collect::<Vec<_>>();
-
-            let serialize_function =
-                self.generate_serialize_function_declaration((ctx, &mut inherent_impl_namespace));
-            let deserilize_function =
-                self.generate_deserialize_function_declaration((ctx, &mut inherent_impl_namespace));
+            // views check encodings, since they are not constructed
+            let encoding_check = (!ctx.options.omit_derivable).then(|| {
+                self.generate_encoding_check_declaration((ctx, &mut inherent_impl_namespace))
+            });
 
             drop(inherent_impl_namespace);
 
@@ -798,46 +1213,170 @@ mod type_inherent_impl {
                 .append(
                     alloc
                         .hardline()
-                        .append(
-                            alloc.intersperse(
-                                constructors
-                                    .into_iter()
-                                    .chain(iter::once(serialize_function))
-                                    .chain(iter::once(deserilize_function)),
-                                alloc.hardline(),
-                            ),
-                        )
+                        .append(alloc.intersperse(
+                            constructors.into_iter().chain(encoding_check),
+                            alloc.hardline(),
+                        ))
                         .nest(NEST_UNIT)
                         .append(alloc.hardline()),
                 )
                 .append("}")
                 .into_doc()
         }
-    }
 
-    impl<'a> Constructor {
-        #[allow(clippy::too_many_lines, reason = "??? (128/100)")]
-        fn generate_constructor_declaration(
+        /// Generates function checking encoding of value in `SliceReader` like `deserialize`,
+        /// but reading views of fields, which values checks don't need, instead of decoding them.
+        fn generate_encoding_check_declaration(
             &self,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
 
-            let (constructor_func, mut constructor_namespace) =
+            let (check_function, mut check_function_namespace) =
                 namespace.insert_object_auto_name(objects::Function::from_object(
-                    ObjectId(NodeId::id(self), Tag::None),
-                    self.name.to_string().to_lowercase(),
+                    ObjectId(NodeId::id(self), Tag::String("encoding_check")),
+                    "check_encoding".to_owned(),
                 ));
-            let namespace = &mut constructor_namespace;
+            let (reader_parameter, _) = check_function_namespace
+                .insert_object_preserve_name(objects::Variable::from_name("reader".to_owned()))
+                .expect("couldn't generate reader function parameter");
+            let (dependencies_type, _) = check_function_namespace
+                .get_generated::<objects::Type>(ObjectId::from_name("Dependencies".to_owned()))
+                .expect("couldn't get Dependencies type");
+            let (dependencies_parameter, _) = check_function_namespace
+                .insert_object_preserve_name(objects::Variable::from_name(
+                    "dependencies".to_owned(),
+                ))
+                .expect("couldn't generate dependencies function parameter");
 
-            let params = self
-                .implicits
-                .iter()
-                .map(|symbol| {
-                    symbol
-                        .clone()
-                        .generate_as_field_declaration((ctx, namespace))
-                })
+            let function_body = match self.kind {
+                ast::TypeKind::Message => self.generate_deserialize_function_body_for_message(
+                    Reading::Views,
+                    (ctx, &mut check_function_namespace),
+                ),
+                ast::TypeKind::Enum => self.generate_deserialize_function_body_for_enum(
+                    Reading::Views,
+                    (ctx, &mut check_function_namespace),
+                ),
+            };
+
+            alloc
+                .text("fn ")
+                .append(check_function.to_doc(ctx))
+                .append("<'a>(")
+                .append(dependencies_parameter.to_doc(ctx))
+                .append(": ")
+                .append(dependencies_type.to_doc(ctx))
+                .append(", ")
+                .append(reader_parameter.to_doc(ctx))
+                .append(": &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(function_body)
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc()
+        }
+
+        /// Generates implementation of `DbufMessage` or `DbufMessageAsync` for `io`,
+        /// whose methods can't clash with constructors.
+        pub(super) fn generate_message_impl(
+            &self,
+            io: Io,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (_, mut message_impl_namespace) =
+                namespace.insert_object_auto_name(objects::Scope::new(ObjectId(
+                    NodeId::id(self),
+                    Tag::String(match io {
+                        Io::Blocking => "message_impl",
+                        Io::Async => "message_async_impl",
+                    }),
+                )));
+
+            // dependencies are declared once, by `DbufMessage`
+            let dependencies_declaration = (io == Io::Blocking).then(|| {
+                let (dependencies_type, _) = message_impl_namespace
+                    .get_generated::<objects::Type>(ObjectId::from_name("Dependencies".to_owned()))
+                    .expect("couldn't get Dependencies type");
+                alloc
+                    .text("type Dependencies = ")
+                    .append(dependencies_type.to_doc(ctx))
+                    .append(";")
+                    .into_doc()
+            });
+
+            let serialize_function = self
+                .generate_serialize_function_declaration(io, (ctx, &mut message_impl_namespace));
+            let deserialize_function = self
+                .generate_deserialize_function_declaration(io, (ctx, &mut message_impl_namespace));
+
+            drop(message_impl_namespace);
+
+            alloc
+                .text("impl ")
+                .append(io.message_trait())
+                .append(" for")
+                .append(alloc.space())
+                .append(
+                    namespace
+                        .get_generated::<objects::Type>(ObjectId(
+                            NodeId::id(self),
+                            Tag::String("type"),
+                        ))
+                        .expect("couldn't get message type")
+                        .0
+                        .to_doc(ctx),
+                )
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(
+                            alloc.intersperse(
+                                dependencies_declaration
+                                    .into_iter()
+                                    .chain([serialize_function, deserialize_function]),
+                                alloc.hardline(),
+                            ),
+                        )
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc()
+        }
+    }
+
+    impl<'a> Constructor {
+        #[allow(clippy::too_many_lines, reason = "??? (165/100)")]
+        fn generate_constructor_declaration(
+            &self,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (constructor_func, mut constructor_namespace) =
+                namespace.insert_object_auto_name(objects::Function::from_object(
+                    ObjectId(NodeId::id(self), Tag::None),
+                    self.name.to_string().to_lowercase(),
+                ));
+            let namespace = &mut constructor_namespace;
+
+            let params = self
+                .implicits
+                .iter()
+                .map(|symbol| {
+                    symbol
+                        .clone()
+                        .generate_as_field_declaration((ctx, namespace))
+                })
                 .collect::<Vec<_>>()
                 .into_iter()
                 .chain(self.fields.iter().map(|symbol| {
@@ -862,6 +1401,20 @@ mod type_inherent_impl {
             let (dependencies_var, _) = namespace
                 .insert_object_auto_name(objects::Variable::from_name("dependencies".to_owned()));
 
+            let refinement_checks =
+                alloc.concat(self.refinements.iter().map(|(name, predicate)| {
+                    generate_refinement_check(
+                        name,
+                        predicate,
+                        predicate.generate_as_value(
+                            (ctx, namespace.cursor()),
+                            &ConstructorObjectsLocator {},
+                        ),
+                        false,
+                        alloc,
+                    )
+                }));
+
             let body_initialization = alloc
                 .text("let ")
                 .append(body_var.to_doc(ctx))
@@ -958,7 +1511,8 @@ mod type_inherent_impl {
                     })
                     .append(";");
 
-            let constructor_body = body_initialization
+            let constructor_body = refinement_checks
+                .append(body_initialization)
                 .append(alloc.hardline())
                 .append(dependencies_initialization)
                 .append(alloc.hardline())
@@ -986,6 +1540,85 @@ mod type_inherent_impl {
                 .into_doc()
         }
 
+        /// Generates constructor, which takes only fields without defaults.
+        fn generate_builder_declaration(
+            &self,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (builder_func, mut builder_namespace) =
+                namespace.insert_object_auto_name(objects::Function::from_object(
+                    ObjectId(NodeId::id(self), Tag::String("with_defaults")),
+                    format!("{}_with_defaults", self.name.to_string().to_lowercase()),
+                ));
+            let namespace = &mut builder_namespace;
+
+            let params = self
+                .implicits
+                .iter()
+                .chain(
+                    self.fields
+                        .iter()
+                        .filter(|field| self.default_of(field).is_none()),
+                )
+                .map(|symbol| {
+                    symbol
+                        .clone()
+                        .generate_as_field_declaration((ctx, namespace))
+                })
+                .collect::<Vec<_>>();
+
+            let values = self
+                .implicits
+                .iter()
+                .map(|implicit| {
+                    ConstructorObjectsLocator {}
+                        .locate_variable((ctx, namespace.cursor()), &Rc::downgrade(implicit))
+                })
+                .chain(self.fields.iter().map(|field| {
+                    let Some(default) = self.default_of(field) else {
+                        return ConstructorObjectsLocator {}
+                            .locate_variable((ctx, namespace.cursor()), &Rc::downgrade(field));
+                    };
+                    let value = default.generate_as_value(
+                        (ctx, namespace.cursor()),
+                        &ConstructorObjectsLocator {},
+                    );
+                    if field.ty.get_type().is_builtin
+                        || matches!(default, ValueExpression::Variable(_))
+                    {
+                        value
+                    } else {
+                        alloc.text("Box::new(").append(value).append(")").into_doc()
+                    }
+                }))
+                .collect();
+
+            alloc
+                .text("pub fn")
+                .append(alloc.space())
+                .append(builder_func.to_doc(ctx))
+                .append("(")
+                .append(alloc.intersperse(params, alloc.text(", ")))
+                .append(") -> Result<Self, super::ConstructorError> {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(
+                            self.generate_constructor_construction(
+                                (ctx, namespace.cursor()),
+                                values,
+                            ),
+                        )
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc()
+        }
+
+        #[allow(clippy::too_many_lines, reason = "??? (106/100)")]
         fn generate_type_checker_if(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
 
@@ -994,6 +1627,16 @@ mod type_inherent_impl {
                 .append(alloc.intersperse(
                     self.fields.iter().map(|symbol| match &symbol.ty {
                         TypeExpression::Type { call, dependencies } => {
+                            // arrays are checked to have declared length
+                            if let Some(length) = symbol.ty.array_length() {
+                                return alloc
+                                    .text("(&")
+                                    .append(length.generate_as_value(
+                                        (ctx, namespace.cursor()),
+                                        &ConstructorObjectsLocator {},
+                                    ))
+                                    .append(")");
+                            }
                             let field_type = call.upgrade().expect("call to unknown type");
                             alloc
                                 .text("(")
@@ -1038,6 +1681,13 @@ mod type_inherent_impl {
                             } => call.upgrade().expect("call to unknown type"),
                         };
 
+                        if symbol.ty.array_length().is_some() {
+                            return alloc
+                                .text("(&(")
+                                .append(field_var.to_doc(ctx))
+                                .append(".len() as u64))")
+                                .into_doc();
+                        }
                         if symbol_ty.is_builtin {
                             return alloc.text("()").into_doc();
                         }
@@ -1173,13 +1823,16 @@ mod type_inherent_impl {
     impl<'a> Type {
         fn generate_serialize_function_declaration(
             &self,
+            io: Io,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
 
-            // TODO: serialize function could overlap with other methods, in future need to separate serilialize/deserialize into separate trait
             let (serialize_function, mut serialize_function_namespace) = namespace
-                .insert_object_preserve_name(objects::Function::from_name("serialize".to_owned()))
+                .insert_object_preserve_name(objects::Function::from_name(format!(
+                    "serialize{}",
+                    io.suffix()
+                )))
                 .expect("couldn't generate serialize function");
 
             let (writer_type_parameter, _) = serialize_function_namespace
@@ -1194,41 +1847,65 @@ mod type_inherent_impl {
                 .expect("couldn't generate writer function parameter");
 
             let function_body = match self.kind {
-                ast::TypeKind::Message => self.generate_serialize_function_body_for_message((
-                    ctx,
-                    &mut serialize_function_namespace,
-                )),
-                ast::TypeKind::Enum => self.generate_serialize_function_body_for_enum((
-                    ctx,
-                    &mut serialize_function_namespace,
-                )),
+                ast::TypeKind::Message => self.generate_serialize_function_body_for_message(
+                    io,
+                    (ctx, &mut serialize_function_namespace),
+                ),
+                ast::TypeKind::Enum => self.generate_serialize_function_body_for_enum(
+                    io,
+                    (ctx, &mut serialize_function_namespace),
+                ),
+            };
+
+            // futures borrow both `self` and writer
+            let (lifetime_parameter, reference, writer_bound) = match io {
+                Io::Blocking => ("", "&", "super::Write"),
+                Io::Async => ("'a, ", "&'a ", "super::AsyncWrite + Unpin + Send"),
+            };
+            let result = alloc
+                .text("Result")
+                .append("<")
+                .append(alloc.intersperse(
+                    ["()", "super::Error"],
+                    alloc.text(",").append(alloc.space()),
+                ))
+                .append(">");
+            let result = match io {
+                Io::Blocking => result,
+                Io::Async => alloc
+                    .text("super::BoxFuture<'a, ")
+                    .append(result)
+                    .append(">"),
             };
 
             alloc
-                .text("pub")
-                .append(alloc.space())
-                .append("fn")
+                .text("fn")
                 .append(alloc.space())
                 .append(serialize_function.to_doc(ctx))
                 .append("<")
+                .append(lifetime_parameter)
                 .append(
                     writer_type_parameter
                         .to_doc(ctx)
                         .append(":")
                         .append(alloc.space())
-                        .append("super::Write"),
+                        .append(writer_bound),
                 )
                 .append(">") // TODO
                 .append("(")
                 .append(
                     alloc.intersperse(
                         [
-                            self_parameter.to_doc(ctx),
+                            alloc
+                                .text(reference)
+                                .append(self_parameter.to_doc(ctx))
+                                .into_doc(),
                             writer_parameter
                                 .to_doc(ctx)
                                 .append(":")
                                 .append(alloc.space())
-                                .append("&mut")
+                                .append(reference)
+                                .append("mut")
                                 .append(alloc.space())
                                 .append(writer_type_parameter.to_doc(ctx)),
                         ],
@@ -1239,19 +1916,13 @@ mod type_inherent_impl {
                 .append(alloc.space())
                 .append("->")
                 .append(alloc.space())
-                .append("Result")
-                .append("<")
-                .append(alloc.intersperse(
-                    ["()", "super::Error"],
-                    alloc.text(",").append(alloc.space()),
-                ))
-                .append(">")
+                .append(result)
                 .append(alloc.space())
                 .append("{")
                 .append(
                     alloc
                         .hardline()
-                        .append(function_body)
+                        .append(io.wrap_body(function_body, alloc))
                         .nest(NEST_UNIT)
                         .append(alloc.hardline()),
                 )
@@ -1262,6 +1933,7 @@ mod type_inherent_impl {
         #[allow(clippy::too_many_lines, reason = "??? (104/100)")]
         fn generate_serialize_function_body_for_enum(
             &self,
+            io: Io,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
@@ -1287,6 +1959,7 @@ mod type_inherent_impl {
             alloc
                 .text("match")
                 .append(alloc.space())
+                .append("&")
                 .append(self_parameter.to_doc(ctx))
                 .append(".")
                 .append(message_type_body_field.to_doc(ctx))
@@ -1317,6 +1990,40 @@ mod type_inherent_impl {
                                     objects::ObjectId::from_name(constructor.name.to_string()),
                                 ));
 
+                            let fields_pattern = constructor
+                                .generate_fields_pattern(ctx, &mut variant_scope_namespace);
+
+                            // descriptor derived from dependencies is not written
+                            let descriptor_serialization = if self.omits_descriptor(ctx.options) {
+                                alloc.nil().into_doc()
+                            } else {
+                                // extension methods of tokio are not in scope
+                                match io {
+                                    Io::Blocking => writer_parameter
+                                        .to_doc(ctx)
+                                        .append(".")
+                                        .append("write_all")
+                                        .append("("),
+                                    Io::Async => alloc
+                                        .text("super::AsyncWriteExt::write_all(")
+                                        .append(writer_parameter.to_doc(ctx))
+                                        .append(", ")
+                                        .into_doc(),
+                                }
+                                .append("&")
+                                .append("[")
+                                .append(constructor.generate_enum_descriptor((
+                                    ctx,
+                                    variant_scope_namespace.cursor(),
+                                )))
+                                .append("]")
+                                .append(")")
+                                .append(io.completion())
+                                .append("?")
+                                .append(";")
+                                .append(alloc.hardline())
+                            };
+
                             body_type
                                 .to_doc(ctx)
                                 .append("::")
@@ -1324,17 +2031,7 @@ mod type_inherent_impl {
                                 .append(alloc.space())
                                 .append("{")
                                 .append(alloc.space())
-                                .append(alloc.intersperse(
-                                    constructor.fields.iter().map(|field| {
-                                        variant_scope_namespace
-                                            .insert_object_auto_name(objects::Variable::from_name(
-                                                field.name.to_string(),
-                                            ))
-                                            .0
-                                            .to_doc(ctx)
-                                    }),
-                                    alloc.text(",").append(alloc.space()),
-                                ))
+                                .append(fields_pattern)
                                 .append(alloc.space())
                                 .append("}")
                                 .append(alloc.space())
@@ -1344,28 +2041,11 @@ mod type_inherent_impl {
                                 .append(
                                     alloc
                                         .hardline()
-                                        .append(
-                                            writer_parameter
-                                                .to_doc(ctx)
-                                                .append(".")
-                                                .append("write_all")
-                                                .append("(")
-                                                .append("&")
-                                                .append("[")
-                                                .append(constructor.generate_enum_descriptor((
-                                                    ctx,
-                                                    variant_scope_namespace.cursor(),
-                                                )))
-                                                .append("]")
-                                                .append(")")
-                                                .append("?")
-                                                .append(";"),
-                                        )
-                                        .append(alloc.hardline())
-                                        .append(constructor.generate_constructor_serialization((
-                                            ctx,
-                                            &mut variant_scope_namespace,
-                                        )))
+                                        .append(descriptor_serialization)
+                                        .append(constructor.generate_constructor_serialization(
+                                            io,
+                                            (ctx, &mut variant_scope_namespace),
+                                        ))
                                         .nest(NEST_UNIT),
                                 )
                                 .append("}")
@@ -1382,6 +2062,7 @@ mod type_inherent_impl {
 
         fn generate_serialize_function_body_for_message(
             &self,
+            io: Io,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
@@ -1412,36 +2093,54 @@ mod type_inherent_impl {
                 .append(body_type.to_doc(ctx))
                 .append("{")
                 .append(alloc.space())
-                .append(alloc.intersperse(
-                    constructor.fields.iter().map(|field| {
-                        namespace
-                            .insert_object_auto_name(objects::Variable::from_name(
-                                field.name.to_string(),
-                            ))
-                            .0
-                            .to_doc(ctx)
-                    }),
-                    alloc.text(",").append(alloc.space()),
-                ))
+                .append(constructor.generate_fields_pattern(ctx, namespace))
                 .append(alloc.space())
                 .append("}")
                 .append(alloc.space())
                 .append("=")
                 .append(alloc.space())
+                .append("&")
                 .append(self_parameter.to_doc(ctx))
                 .append(".")
                 .append(message_type_body_field.to_doc(ctx))
                 .append(";")
                 .append(alloc.hardline())
-                .append(constructor.generate_constructor_serialization((ctx, namespace)))
+                .append(constructor.generate_constructor_serialization(io, (ctx, namespace)))
                 .append("Ok(())")
                 .into_doc()
         }
     }
 
     impl<'a> Constructor {
+        /// Generates pattern binding fields of body to variables named after them,
+        /// omitted fields are ignored.
+        fn generate_fields_pattern(
+            &self,
+            ctx: crate::generate::GlobalContext<'a>,
+            namespace: &mut context::NamingContext<'a, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+            alloc
+                .intersperse(
+                    self.fields.iter().map(|field| {
+                        if self.omitted_value(field, ctx.options).is_some() {
+                            return alloc.text(format!("{}: _", field.name)).into_doc();
+                        }
+                        namespace
+                            .insert_object_auto_name(objects::Variable::from_name(
+                                field.name.to_string(),
+                            ))
+                            .0
+                            .to_doc(ctx)
+                    }),
+                    alloc.text(",").append(alloc.space()),
+                )
+                .into_doc()
+        }
+
         fn generate_constructor_serialization(
             &self,
+            io: Io,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
@@ -1453,77 +2152,165 @@ mod type_inherent_impl {
                 .expect("couldn't get generated writer parameter");
 
             alloc
-                .concat(self.fields.iter().map(|field| {
-                    let field_ty = field.ty.get_type();
-                    let field_var = namespace
-                        .get_generated::<objects::Variable>(objects::ObjectId::from_name(
-                            field.name.to_string(),
-                        ))
-                        .expect("couldn't get generated constructor field")
-                        .0
-                        .to_doc(ctx);
-
-                    if let Some(rust_ty) = field_ty.builtin_rust_type() {
-                        alloc
-                            .text(format!(
-                                "<{rust_ty} as super::DbufPrimitive>::dbuf_serialize(&"
-                            ))
-                            .append(field_var)
-                            .append(",")
-                            .append(alloc.space())
-                            .append(writer_parameter.to_doc(ctx))
-                            .append(")")
-                            .append("?")
-                            .append(";")
-                            .append(alloc.hardline())
-                    } else {
-                        alloc
-                            .nil()
-                            .append(field_var)
-                            .append(".")
-                            .append("serialize")
-                            .append("(")
-                            .append(writer_parameter.to_doc(ctx))
-                            .append(")")
-                            .append("?")
-                            .append(";")
+                .concat(
+                    self.fields
+                        .iter()
+                        .filter(|field| self.omitted_value(field, ctx.options).is_none())
+                        .map(|field| {
+                            let field_var = namespace
+                                .get_generated::<objects::Variable>(objects::ObjectId::from_name(
+                                    field.name.to_string(),
+                                ))
+                                .expect("couldn't get generated constructor field")
+                                .0
+                                .to_doc(ctx);
+
+                            Self::generate_value_serialization(
+                                &field.ty,
+                                field_var,
+                                writer_parameter.to_doc(ctx),
+                                io,
+                                (ctx, namespace),
+                            )
                             .append(alloc.hardline())
-                    }
-                }))
+                        }),
+                )
                 .into_doc()
         }
-    }
-
-    struct EnumConstructorDeserializationObjectsLocator {}
 
-    impl<'a> super::value_from_expression::Locator<'a>
-        for EnumConstructorDeserializationObjectsLocator
-    {
-        fn locate_variable<'cursor>(
-            &self,
-            (ctx, namespace): Context<
-                'a,
-                'cursor,
-                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
-            >,
-            symbol: &Weak<Symbol>,
+        /// Generates statement serializing `value` of type `ty`.
+        ///
+        /// Sequences are prefixed with their length and elements are serialized one by one.
+        fn generate_value_serialization(
+            ty: &TypeExpression,
+            value: BoxDoc<'a>,
+            writer: BoxDoc<'a>,
+            io: Io,
+            (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
-            namespace
-                .get_generated::<objects::Variable>(ObjectId(NodeId::id_weak(symbol), Tag::None))
-                .expect("couldn't get variable")
-                .0
-                .to_doc(ctx)
-                .append(".")
-                .append("clone")
-                .append("()")
-        }
-    }
-
-    struct MessageConstructorDeserializationObjectsLocator {}
+            let alloc = ctx.alloc;
 
-    impl<'a> super::value_from_expression::Locator<'a>
-        for MessageConstructorDeserializationObjectsLocator
-    {
+            if let Some(rust_ty) = ty.primitive_rust_type() {
+                return alloc
+                    .text(format!(
+                        "<{rust_ty} as {}>::dbuf_serialize{}(&",
+                        io.primitive_trait(ctx.options.encoding),
+                        io.suffix()
+                    ))
+                    .append(value)
+                    .append(",")
+                    .append(alloc.space())
+                    .append(writer)
+                    .append(")")
+                    .append(io.completion())
+                    .append("?")
+                    .append(";")
+                    .into_doc();
+            }
+
+            let Some(element) = ty.sequence_element() else {
+                let (field_type, _) = ty
+                    .get_type()
+                    .lookup_type_type((ctx, namespace.cursor()))
+                    .expect("couldn't lookup type type");
+                return alloc
+                    .text("<")
+                    .append(field_type)
+                    .append(" as ")
+                    .append(io.message_trait())
+                    .append(">::serialize")
+                    .append(io.suffix())
+                    .append("(&")
+                    .append(value)
+                    .append(",")
+                    .append(alloc.space())
+                    .append(writer)
+                    .append(")")
+                    .append(io.completion())
+                    .append("?")
+                    .append(";")
+                    .into_doc();
+            };
+
+            let (element_var, _) =
+                namespace.insert_object_auto_name(objects::Variable::from_object(
+                    ObjectId(NodeId::id(element), Tag::String("element")),
+                    "element".to_owned(),
+                ));
+            let element_serialization = Self::generate_value_serialization(
+                element,
+                element_var.to_doc(ctx),
+                writer.clone(),
+                io,
+                (ctx, namespace),
+            );
+
+            alloc
+                .text(format!(
+                    "<u64 as {}>::dbuf_serialize{}(&(",
+                    io.primitive_trait(ctx.options.encoding),
+                    io.suffix()
+                ))
+                .append(value.clone())
+                .append(".len() as u64),")
+                .append(alloc.space())
+                .append(writer)
+                .append(")")
+                .append(io.completion())
+                .append("?")
+                .append(";")
+                .append(alloc.hardline())
+                .append("for")
+                .append(alloc.space())
+                .append(element_var.to_doc(ctx))
+                .append(alloc.space())
+                .append("in")
+                .append(alloc.space())
+                .append(value)
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(element_serialization)
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}")
+                .into_doc()
+        }
+    }
+
+    struct EnumConstructorDeserializationObjectsLocator {}
+
+    impl<'a> super::value_from_expression::Locator<'a>
+        for EnumConstructorDeserializationObjectsLocator
+    {
+        fn locate_variable<'cursor>(
+            &self,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+            symbol: &Weak<Symbol>,
+        ) -> BoxDoc<'a> {
+            namespace
+                .get_generated::<objects::Variable>(ObjectId(NodeId::id_weak(symbol), Tag::None))
+                .expect("couldn't get variable")
+                .0
+                .to_doc(ctx)
+                .append(".")
+                .append("clone")
+                .append("()")
+        }
+    }
+
+    struct MessageConstructorDeserializationObjectsLocator {}
+
+    impl<'a> super::value_from_expression::Locator<'a>
+        for MessageConstructorDeserializationObjectsLocator
+    {
         fn locate_variable<'cursor>(
             &self,
             (ctx, namespace): Context<
@@ -1548,15 +2335,19 @@ mod type_inherent_impl {
     }
 
     impl<'a> Type {
+        #[allow(clippy::too_many_lines, reason = "??? (105/100)")]
         fn generate_deserialize_function_declaration(
             &self,
+            io: Io,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
 
-            // TODO: deserialize function could overlap with other methods, in future need to separate serilialize/deserialize into separate trait
             let (deserialize_function, mut deserialize_function_namespace) = namespace
-                .insert_object_preserve_name(objects::Function::from_name("deserialize".to_owned()))
+                .insert_object_preserve_name(objects::Function::from_name(format!(
+                    "deserialize{}",
+                    io.suffix()
+                )))
                 .expect("couldn't generate deserialize function");
 
             let (reader_type_parameter, _) = deserialize_function_namespace
@@ -1580,20 +2371,41 @@ mod type_inherent_impl {
                 .expect("couldn't generate dependencies function parameter");
 
             let function_body = match self.kind {
-                ast::TypeKind::Message => self.generate_deserialize_function_body_for_message((
-                    ctx,
-                    &mut deserialize_function_namespace,
-                )),
-                ast::TypeKind::Enum => self.generate_deserialize_function_body_for_enum((
-                    ctx,
-                    &mut deserialize_function_namespace,
-                )),
+                ast::TypeKind::Message => self.generate_deserialize_function_body_for_message(
+                    Reading::Values(io),
+                    (ctx, &mut deserialize_function_namespace),
+                ),
+                ast::TypeKind::Enum => self.generate_deserialize_function_body_for_enum(
+                    Reading::Values(io),
+                    (ctx, &mut deserialize_function_namespace),
+                ),
+            };
+
+            let reader_bound = match io {
+                Io::Blocking => "Read",
+                Io::Async => "AsyncRead + Unpin + Send",
+            };
+            let result = alloc
+                .text("Result")
+                .append("<")
+                .append(alloc.intersperse(
+                    [
+                        alloc.text("Self"),
+                        alloc.text("super").append("::").append("DeserializeError"),
+                    ],
+                    alloc.text(",").append(alloc.space()),
+                ))
+                .append(">");
+            let result = match io {
+                Io::Blocking => result,
+                Io::Async => alloc
+                    .text("super::BoxFuture<'_, ")
+                    .append(result)
+                    .append(">"),
             };
 
             alloc
-                .text("pub")
-                .append(alloc.space())
-                .append("fn")
+                .text("fn")
                 .append(alloc.space())
                 .append(deserialize_function.to_doc(ctx))
                 .append("<")
@@ -1602,7 +2414,7 @@ mod type_inherent_impl {
                 .append(alloc.space())
                 .append("super")
                 .append("::")
-                .append("Read")
+                .append(reader_bound)
                 .append(">")
                 .append("(")
                 .append(
@@ -1630,22 +2442,13 @@ mod type_inherent_impl {
                 .append(alloc.space())
                 .append("->")
                 .append(alloc.space())
-                .append("Result")
-                .append("<")
-                .append(alloc.intersperse(
-                    [
-                        alloc.text("Self"),
-                        alloc.text("super").append("::").append("DeserializeError"),
-                    ],
-                    alloc.text(",").append(alloc.space()),
-                ))
-                .append(">")
+                .append(result)
                 .append(alloc.space())
                 .append("{")
                 .append(
                     alloc
                         .hardline()
-                        .append(function_body)
+                        .append(io.wrap_body(function_body, alloc))
                         .nest(NEST_UNIT)
                         .append(alloc.hardline()),
                 )
@@ -1655,6 +2458,7 @@ mod type_inherent_impl {
 
         fn generate_deserialize_function_body_for_message(
             &self,
+            reading: Reading,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
@@ -1669,6 +2473,10 @@ mod type_inherent_impl {
                     .implicits
                     .iter()
                     .zip(self.dependencies.iter())
+                    // views are not constructed, so only implicits that checks need are bound
+                    .filter(|(implicit, _)| {
+                        reading != Reading::Views || constructor.checks_mention(implicit)
+                    })
                     .map(|(implicit, type_dep)| {
                         let (field, _) = deps_type_cursor
                             .clone()
@@ -1709,13 +2517,165 @@ mod type_inherent_impl {
             alloc
                 .nil()
                 .append(implicit_bindings)
-                .append(constructor.generate_constructor_deserialization((ctx, namespace), false))
+                .append(constructor.generate_constructor_deserialization(
+                    reading,
+                    (ctx, namespace),
+                    false,
+                ))
+                .into_doc()
+        }
+
+        /// Generates accesses to dependencies that some constructor pattern starts with
+        /// a literal or enum constructor at, paired with indices of the dependencies.
+        fn generate_descriptor_scrutinees<'cursor>(
+            &self,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> Vec<(usize, BoxDoc<'a>)> {
+            let alloc = ctx.alloc;
+
+            let (dependencies_param, _) = namespace
+                .clone()
+                .get_generated::<objects::Variable>(ObjectId::from_name("dependencies".to_owned()))
+                .expect("couldn't get generated dependencies parameter");
+            let (_, dependencies_type_cursor) = namespace
+                .get_generated::<objects::Type>(ObjectId::from_name("Dependencies".to_owned()))
+                .expect("couldn't get generated Dependencies type");
+
+            let mut matched = vec![];
+            for (index, dependency) in self.dependencies.iter().enumerate() {
+                let Some(head) = self.constructors.iter().find_map(|constructor| {
+                    constructor.result_type.get_dependencies()[index].pattern_head()
+                }) else {
+                    continue;
+                };
+                let field_access = dependencies_param.to_doc(ctx).append(".").append(
+                    dependencies_type_cursor
+                        .clone()
+                        .get_generated::<objects::Variable>(ObjectId(
+                            NodeId::id_rc(dependency),
+                            Tag::None,
+                        ))
+                        .expect("couldn't get generated dependency")
+                        .0
+                        .to_doc(ctx),
+                );
+                let scrutinee = match head {
+                    ValueExpression::Constructor { .. } => alloc
+                        .text("&")
+                        .append(field_access)
+                        .append(".body")
+                        .into_doc(),
+                    ValueExpression::OpCall(OpCall::Literal(Literal::Str(_))) => {
+                        field_access.append(".as_str()")
+                    }
+                    ValueExpression::OpCall(OpCall::Literal(Literal::Bytes(_))) => {
+                        field_access.append(".as_slice()")
+                    }
+                    _ => alloc.text("&").append(field_access).into_doc(),
+                };
+                matched.push((index, scrutinee));
+            }
+            matched
+        }
+
+        /// Generates declaration of `descriptor_variable` with descriptor of constructor,
+        /// whose patterns match dependencies. Only literals and enum constructors that
+        /// patterns start with are matched, since they tell constructors apart.
+        fn generate_descriptor_derivation(
+            &self,
+            descriptor_variable: &objects::GeneratedVariable,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let declaration = alloc
+                .text("let")
+                .append(alloc.space())
+                .append(descriptor_variable.to_doc(ctx))
+                .append(alloc.space())
+                .append("=")
+                .append(alloc.space());
+
+            let descriptors = self
+                .constructors
+                .iter()
+                .map(|constructor| {
+                    constructor
+                        .generate_enum_descriptor((ctx, namespace.cursor()))
+                        .expect("couldn't generate enum descriptor")
+                })
+                .collect::<Vec<_>>();
+
+            if let [descriptor] = descriptors.as_slice() {
+                return declaration
+                    .append(descriptor.clone())
+                    .append(";")
+                    .append(alloc.hardline())
+                    .into_doc();
+            }
+
+            let matched = self.generate_descriptor_scrutinees((ctx, namespace.cursor()));
+
+            let arms = self
+                .constructors
+                .iter()
+                .zip(descriptors)
+                .map(|(constructor, descriptor)| {
+                    let patterns = matched.iter().map(|(index, _)| {
+                        constructor.result_type.get_dependencies()[*index]
+                            .generate_as_head_pattern((ctx, namespace.cursor()))
+                    });
+                    alloc
+                        .text("(")
+                        .append(alloc.intersperse(patterns, alloc.text(",").append(alloc.space())))
+                        .append(")")
+                        .append(alloc.space())
+                        .append("=>")
+                        .append(alloc.space())
+                        .append(descriptor)
+                        .append(",")
+                        .append(alloc.hardline())
+                })
+                .collect::<Vec<_>>();
+
+            declaration
+                .append("match")
+                .append(alloc.space())
+                .append("(")
+                .append(alloc.intersperse(
+                    matched.into_iter().map(|(_, scrutinee)| scrutinee),
+                    alloc.text(",").append(alloc.space()),
+                ))
+                .append(")")
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(alloc.concat(arms))
+                        // patterns may cover all values of dependencies
+                        .append("#[allow(unreachable_patterns)]")
+                        .append(alloc.hardline())
+                        .append(
+                            "_ => return Err(super::DeserializeError::DependenciesDescriptorMismatch),",
+                        )
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .append(";")
+                .append(alloc.hardline())
                 .into_doc()
         }
 
         #[allow(clippy::too_many_lines, reason = "??? (103/100)")]
         fn generate_deserialize_function_body_for_enum(
             &self,
+            reading: Reading,
             (ctx, namespace): MutContext<'a, '_, '_>,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
@@ -1735,34 +2695,63 @@ mod type_inherent_impl {
                     "descriptor".to_owned(),
                 ));
 
+            let descriptor_reading = if self.omits_descriptor(ctx.options) {
+                self.generate_descriptor_derivation(&descriptor_variable, (ctx, namespace))
+            } else if let Reading::Values(io) = reading {
+                alloc
+                    .text("let")
+                    .append(alloc.space())
+                    .append("mut")
+                    .append(alloc.space())
+                    .append(descriptor_variable.to_doc(ctx))
+                    .append(alloc.space())
+                    .append("=")
+                    .append(alloc.space())
+                    .append("0")
+                    .append(";")
+                    .append(alloc.hardline())
+                    // extension methods of tokio are not in scope
+                    .append(match io {
+                        Io::Blocking => reader_parameter
+                            .clone()
+                            .to_doc(ctx)
+                            .append(".")
+                            .append("read")
+                            .append("("),
+                        Io::Async => alloc
+                            .text("super::AsyncReadExt::read(")
+                            .append(reader_parameter.clone().to_doc(ctx))
+                            .append(", ")
+                            .into_doc(),
+                    })
+                    .append("super::slice::from_mut(")
+                    .append("&mut")
+                    .append(alloc.space())
+                    .append(descriptor_variable.to_doc(ctx))
+                    .append(")")
+                    .append(")")
+                    .append(io.completion())
+                    .append(".")
+                    .append("map_err")
+                    .append("(|e| super::DeserializeError::IoError(e))")
+                    .append("?")
+                    .append(";")
+                    .append(alloc.hardline())
+                    .into_doc()
+            } else {
+                alloc
+                    .text("let ")
+                    .append(descriptor_variable.to_doc(ctx))
+                    .append(" = <u8 as super::DbufView>::read_view((), ")
+                    .append(reader_parameter.to_doc(ctx))
+                    .append(")?;")
+                    .append(alloc.hardline())
+                    .into_doc()
+            };
+
             alloc
-                .text("let")
-                .append(alloc.space())
-                .append("mut")
-                .append(alloc.space())
-                .append(descriptor_variable.to_doc(ctx))
-                .append(alloc.space())
-                .append("=")
-                .append(alloc.space())
-                .append("0")
-                .append(";")
-                .append(alloc.hardline())
-                .append(reader_parameter.clone().to_doc(ctx))
-                .append(".")
-                .append("read")
-                .append("(")
-                .append("super::slice::from_mut(")
-                .append("&mut")
-                .append(alloc.space())
-                .append(descriptor_variable.to_doc(ctx))
-                .append(")")
-                .append(")")
-                .append(".")
-                .append("map_err")
-                .append("(|e| super::DeserializeError::IoError(e))")
-                .append("?")
-                .append(";")
-                .append(alloc.hardline())
+                .nil()
+                .append(descriptor_reading)
                 .append("match")
                 .append(alloc.space())
                 .append(descriptor_variable.to_doc(ctx))
@@ -1799,6 +2788,7 @@ mod type_inherent_impl {
                                                     .append(
                                                         constructor
                                                             .generate_constructor_deserialization(
+                                                                reading,
                                                                 (ctx, &mut variant_scope_namespace),
                                                                 true,
                                                             ),
@@ -1829,8 +2819,10 @@ mod type_inherent_impl {
     }
 
     impl<'a> Constructor {
+        #[allow(clippy::too_many_lines, reason = "??? (103/100)")]
         fn generate_constructor_deserialization(
             &self,
+            reading: Reading,
             (ctx, namespace): MutContext<'a, '_, '_>,
             is_enum_constructor: bool,
         ) -> BoxDoc<'a> {
@@ -1871,6 +2863,9 @@ mod type_inherent_impl {
                             ValueExpression::OpCall(OpCall::Literal(Literal::Str(_))) => {
                                 field_access.append(".").append("as_str()")
                             }
+                            ValueExpression::OpCall(OpCall::Literal(Literal::Bytes(_))) => {
+                                field_access.append(".").append("as_slice()")
+                            }
                             _ => field_access,
                         }
                     })
@@ -1896,8 +2891,11 @@ mod type_inherent_impl {
                     })
                     .collect::<Vec<_>>();
 
-                let constructor_call =
-                    self.generate_constructor_call((ctx, &mut implicits_extractor_if_scope), true);
+                let constructor_call = self.generate_constructor_call(
+                    reading,
+                    (ctx, &mut implicits_extractor_if_scope),
+                    true,
+                );
 
                 let wrapped_body = wrap_with_deferred_checks(
                     constructor_call,
@@ -1941,226 +2939,1433 @@ mod type_inherent_impl {
                     .append("}")
                     .into_doc()
             } else {
-                self.generate_constructor_call((ctx, namespace), false)
+                self.generate_constructor_call(reading, (ctx, namespace), false)
             }
         }
 
-        #[allow(clippy::too_many_lines, reason = "??? (114/100)")]
+        /// Generates reading of fields of the constructor with checks of its refinements.
+        /// Values are then passed to the constructor, which checks their dependencies.
+        /// Views are not constructed: dependencies of fields are checked on reading them.
         fn generate_constructor_call(
             &self,
+            reading: Reading,
             (ctx, namespace): MutContext<'a, '_, '_>,
             is_enum_constructor: bool,
         ) -> BoxDoc<'a> {
             let alloc = ctx.alloc;
 
-            let (reader_parameter, _) = namespace
-                .get_generated::<objects::Variable>(objects::ObjectId::from_name(
-                    "reader".to_owned(),
-                ))
-                .expect("couldn't get generated reader parameter");
-
-            // This is dirty, because I can't type erase Locator as its methods take as argument Context<..., impl Cursor>
-            // So cleaning this up requires type erased Cursor
-            // TODO: cleanup when proper Cursor will be implemented
-
             // Collect raw pointers of field symbols so we can detect when a type dependency
             // references a previously-deserialized field value (which is T, not Box<T>).
-            let field_symbol_ptrs: std::collections::HashSet<usize> =
+            let field_symbol_ptrs: HashSet<usize> =
                 self.fields.iter().map(|f| Rc::as_ptr(f) as usize).collect();
 
-            let fields_deserialization = alloc.concat(self.fields.iter().map(|field| {
-                let field_ty = field.ty.get_type();
+            // refinement is checked right after the last field it mentions is deserialized
+            let last_mentioned_fields: Vec<_> = self
+                .refinements
+                .iter()
+                .map(|(_, predicate)| {
+                    self.fields
+                        .iter()
+                        .rposition(|field| predicate.mentions(field))
+                })
+                .collect();
 
-                if let Some(rust_ty) = field_ty.builtin_rust_type() {
-                    let (reader_parameter, _) = namespace
-                        .get_generated::<objects::Variable>(objects::ObjectId::from_name(
-                            "reader".to_owned(),
-                        ))
-                        .expect("couldn't get generated reader parameter");
-                    return alloc
-                        .text("let")
-                        .append(alloc.space())
-                        .append(
-                            namespace
-                                .insert_object_auto_name(objects::Variable::from_object(
-                                    objects::ObjectId(
-                                        ast::NodeId::id_rc(field),
-                                        objects::Tag::None,
-                                    ),
-                                    field.name.to_string(),
-                                ))
-                                .0
-                                .to_doc(ctx),
+            let dependency_checks = self.generate_deserialized_refinement_checks(
+                (ctx, namespace.cursor()),
+                &last_mentioned_fields,
+                None,
+            );
+
+            let fields_deserialization =
+                alloc.concat(self.fields.iter().enumerate().map(|(field_idx, field)| {
+                    let reading = if reading == Reading::Views && !self.checks_mention(field) {
+                        self.generate_field_view_check(
+                            field,
+                            (ctx, namespace),
+                            is_enum_constructor,
+                            &field_symbol_ptrs,
                         )
-                        .append(alloc.space())
-                        .append("=")
-                        .append(alloc.space())
-                        .append(format!(
-                            "<{rust_ty} as super::DbufPrimitive>::dbuf_deserialize("
-                        ))
-                        .append(reader_parameter.to_doc(ctx))
-                        .append(")")
-                        .append("?")
-                        .append(";")
-                        .append(alloc.hardline());
+                    } else {
+                        let declaration = self.generate_field_deserialization(
+                            field,
+                            reading.io(),
+                            (ctx, namespace),
+                            is_enum_constructor,
+                            &field_symbol_ptrs,
+                        );
+                        match reading {
+                            Reading::Values(_) => declaration,
+                            Reading::Views => declaration.append(
+                                Self::generate_array_length_check(field, (ctx, namespace.cursor())),
+                            ),
+                        }
+                    };
+
+                    reading.append(self.generate_deserialized_refinement_checks(
+                        (ctx, namespace.cursor()),
+                        &last_mentioned_fields,
+                        Some(field_idx),
+                    ))
+                }));
+
+            let ending = match reading {
+                Reading::Values(_) => self
+                    .generate_deserialized_construction((ctx, namespace), is_enum_constructor)
+                    .append(".")
+                    .append("map_err")
+                    .append("(|e| super::DeserializeError::ConstructorError(e))"),
+                Reading::Views => alloc.text("Ok(())").into_doc(),
+            };
+
+            alloc
+                .nil()
+                .append(dependency_checks)
+                .append(fields_deserialization)
+                .append(ending)
+                .append(alloc.hardline())
+                .into_doc()
+        }
+
+        /// Generates declaration of local variable with deserialized value of `field`.
+        fn generate_field_deserialization(
+            &self,
+            field: &Rc<Symbol>,
+            io: Io,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+            is_enum_constructor: bool,
+            field_symbol_ptrs: &HashSet<usize>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let value = if let Some(value) = self.omitted_value(field, ctx.options) {
+                // implicits and preceding fields are already bound to local variables
+                value.generate_as_value((ctx, namespace.cursor()), &ConstructorObjectsLocator {})
+            } else {
+                let value = Self::generate_value_deserialization(
+                    &field.ty,
+                    io,
+                    (ctx, namespace),
+                    is_enum_constructor,
+                    field_symbol_ptrs,
+                );
+                match self.default_of(field) {
+                    Some(default) => Self::generate_defaulted_value_deserialization(
+                        field,
+                        default,
+                        value,
+                        io,
+                        (ctx, namespace.cursor()),
+                    ),
+                    None => value,
+                }
+            };
+
+            alloc
+                .text("let")
+                .append(alloc.space())
+                .append(Self::generate_field_variable(field, namespace).to_doc(ctx))
+                .append(alloc.space())
+                .append("=")
+                .append(alloc.space())
+                .append(value)
+                .append(";")
+                .append(alloc.hardline())
+                .into_doc()
+        }
+
+        /// Generates reading of view of `field`, which value checks don't need.
+        /// Views of arrays are bound to local variables to check their lengths.
+        fn generate_field_view_check(
+            &self,
+            field: &Rc<Symbol>,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+            is_enum_constructor: bool,
+            field_symbol_ptrs: &HashSet<usize>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (reader_parameter, _) = namespace
+                .get_generated::<objects::Variable>(ObjectId::from_name("reader".to_owned()))
+                .expect("couldn't get generated reader parameter");
+
+            let view = alloc
+                .text("<")
+                .append(field.ty.generate_as_view_type((ctx, namespace.cursor())))
+                .append(" as super::DbufView")
+                .append(
+                    encoding_argument(ctx.options.encoding)
+                        .map(|argument| format!("<'_, {argument}>"))
+                        .unwrap_or_default(),
+                )
+                .append(">::read_view(")
+                .append(Self::generate_view_dependencies_value(
+                    &field.ty,
+                    (ctx, namespace),
+                    is_enum_constructor,
+                    field_symbol_ptrs,
+                ))
+                .append(", ")
+                .append(reader_parameter.to_doc(ctx))
+                .append(")?");
+
+            let check = if field.ty.array_length().is_some() {
+                alloc
+                    .text("let ")
+                    .append(Self::generate_field_variable(field, namespace).to_doc(ctx))
+                    .append(" = ")
+                    .append(view)
+                    .append(";")
+                    .append(alloc.hardline())
+                    .append(Self::generate_array_length_check(
+                        field,
+                        (ctx, namespace.cursor()),
+                    ))
+                    .into_doc()
+            } else {
+                view.append(";").append(alloc.hardline()).into_doc()
+            };
+
+            // defaulted fields are absent if the input ends before them
+            if self.default_of(field).is_some() {
+                alloc
+                    .text("if !")
+                    .append(reader_parameter.to_doc(ctx))
+                    .append(".is_empty() {")
+                    .append(alloc.hardline().append(check).nest(NEST_UNIT))
+                    .append("}")
+                    .append(alloc.hardline())
+                    .into_doc()
+            } else {
+                check
+            }
+        }
+
+        /// Generates dependencies of view of value of type `ty`.
+        fn generate_view_dependencies_value(
+            ty: &TypeExpression,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+            is_enum_constructor: bool,
+            field_symbol_ptrs: &HashSet<usize>,
+        ) -> BoxDoc<'a> {
+            // views of sequences are read with dependencies of their elements
+            if let Some(element) = ty.sequence_element() {
+                return Self::generate_view_dependencies_value(
+                    element,
+                    (ctx, namespace),
+                    is_enum_constructor,
+                    field_symbol_ptrs,
+                );
+            }
+            if ty.get_type().is_builtin {
+                return ctx.alloc.text("()").into_doc();
+            }
+            Self::generate_dependencies_struct_value(
+                ty,
+                (ctx, namespace),
+                is_enum_constructor,
+                field_symbol_ptrs,
+            )
+        }
+
+        /// Generates check that array `field`, which is bound to local variable,
+        /// has declared length. Constructors check that, when values are decoded.
+        fn generate_array_length_check<'cursor>(
+            field: &Rc<Symbol>,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+            let Some(length) = field.ty.array_length() else {
+                return alloc.nil().into_doc();
+            };
+            let (field_var, _) = namespace
+                .clone()
+                .get_generated::<objects::Variable>(ObjectId(NodeId::id_rc(field), Tag::None))
+                .expect("couldn't get generated field variable");
+            alloc
+                .text("if ")
+                .append(length.generate_as_value((ctx, namespace), &ConstructorObjectsLocator {}))
+                .append(" != (")
+                .append(field_var.to_doc(ctx))
+                .append(".len() as u64) {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(
+                            "return Err(super::DeserializeError::ConstructorError(\
+                             super::ConstructorError::MismatchedDependencies));",
+                        )
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}")
+                .append(alloc.hardline())
+                .into_doc()
+        }
+
+        fn generate_field_variable(
+            field: &Rc<Symbol>,
+            namespace: &mut context::NamingContext<'a, '_>,
+        ) -> objects::GeneratedVariable {
+            namespace
+                .insert_object_auto_name(objects::Variable::from_object(
+                    ObjectId(NodeId::id_rc(field), Tag::None),
+                    field.name.to_string(),
+                ))
+                .0
+        }
+
+        /// Generates call of the constructor with deserialized implicits and fields.
+        fn generate_deserialized_construction(
+            &self,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+            is_enum_constructor: bool,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+            self.generate_constructor_construction(
+                (ctx, namespace.cursor()),
+                self.implicits
+                    .iter()
+                    .map(|implicit| {
+                        if is_enum_constructor {
+                            EnumConstructorDeserializationObjectsLocator {}.locate_variable(
+                                (ctx, namespace.cursor()),
+                                &Rc::downgrade(implicit),
+                            )
+                        } else {
+                            MessageConstructorDeserializationObjectsLocator {}.locate_variable(
+                                (ctx, namespace.cursor()),
+                                &Rc::downgrade(implicit),
+                            )
+                        }
+                    })
+                    .chain(self.fields.iter().map(|field| {
+                        let field_var = namespace
+                            .get_generated::<objects::Variable>(objects::ObjectId(
+                                ast::NodeId::id_rc(field),
+                                objects::Tag::None,
+                            ))
+                            .expect("couldn't get generated field variable")
+                            .0
+                            .to_doc(ctx);
+
+                        if field.ty.get_type().is_builtin {
+                            field_var
+                        } else {
+                            alloc
+                                .text("Box")
+                                .append("::")
+                                .append("new")
+                                .append("(")
+                                .append(field_var)
+                                .append(")")
+                                .into_doc()
+                        }
+                    }))
+                    .collect(),
+            )
+        }
+
+        /// Wraps deserialization `value` of `field`, so that `default` is used if
+        /// the input ends before the field.
+        fn generate_defaulted_value_deserialization<'cursor>(
+            field: &Symbol,
+            default: &ValueExpression,
+            value: BoxDoc<'a>,
+            io: Io,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (reader_parameter, _) = namespace
+                .clone()
+                .get_generated::<objects::Variable>(objects::ObjectId::from_name(
+                    "reader".to_owned(),
+                ))
+                .expect("couldn't get generated reader parameter");
+
+            // implicits of message types are boxed, while deserialized fields are not
+            let default_value =
+                default.generate_as_value((ctx, namespace), &ConstructorObjectsLocator {});
+            let default_value = if !field.ty.get_type().is_builtin
+                && matches!(default, ValueExpression::Variable(_))
+            {
+                alloc.text("*").append(default_value).into_doc()
+            } else {
+                default_value
+            };
+
+            match io {
+                Io::Blocking => alloc
+                    .text("super::deserialize_or_default(")
+                    .append(reader_parameter.to_doc(ctx))
+                    .append(", |")
+                    .append(reader_parameter.to_doc(ctx))
+                    .append("| Ok(")
+                    .append(value)
+                    .append("), || Ok(")
+                    .append(default_value)
+                    .append("))?")
+                    .into_doc(),
+                // futures can't be returned from closures borrowing their readers,
+                // so the field is read in place from the reader lent by the runtime
+                Io::Async => alloc
+                    .text("match super::defaulted_field_reader_async(")
+                    .append(reader_parameter.to_doc(ctx))
+                    .append(").await? {")
+                    .append(
+                        alloc
+                            .hardline()
+                            .append("Some(mut ")
+                            .append(reader_parameter.to_doc(ctx))
+                            .append(") => {")
+                            .append(
+                                alloc
+                                    .hardline()
+                                    .append("let ")
+                                    .append(reader_parameter.to_doc(ctx))
+                                    .append(" = &mut ")
+                                    .append(reader_parameter.to_doc(ctx))
+                                    .append(";")
+                                    .append(alloc.hardline())
+                                    .append(value)
+                                    .nest(NEST_UNIT),
+                            )
+                            .append(alloc.hardline())
+                            .append("}")
+                            .append(alloc.hardline())
+                            .append("None => ")
+                            .append(default_value)
+                            .append(",")
+                            .nest(NEST_UNIT),
+                    )
+                    .append(alloc.hardline())
+                    .append("}")
+                    .into_doc(),
+            }
+        }
+
+        /// Generates checks of refinements whose last mentioned field is `after`, so that
+        /// invalid input is rejected before the rest of the constructor is read.
+        fn generate_deserialized_refinement_checks<'cursor>(
+            &self,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+            last_mentioned_fields: &[Option<usize>],
+            after: Option<usize>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+            alloc
+                .concat(
+                    self.refinements
+                        .iter()
+                        .zip(last_mentioned_fields)
+                        .filter(|(_, last)| **last == after)
+                        .map(|((name, predicate), _)| {
+                            // implicits and fields are already bound to local variables
+                            let predicate_doc = predicate.generate_as_value(
+                                (ctx, namespace.clone()),
+                                &ConstructorObjectsLocator {},
+                            );
+                            generate_refinement_check(name, predicate, predicate_doc, true, alloc)
+                        }),
+                )
+                .into_doc()
+        }
+
+        /// Generates expression deserializing value of type `ty` from reader.
+        ///
+        /// Sequences are read as their length followed by elements.
+        #[allow(clippy::too_many_lines, reason = "??? (113/100)")]
+        fn generate_value_deserialization(
+            ty: &TypeExpression,
+            io: Io,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+            is_enum_constructor: bool,
+            field_symbol_ptrs: &HashSet<usize>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (reader_parameter, _) = namespace
+                .get_generated::<objects::Variable>(objects::ObjectId::from_name(
+                    "reader".to_owned(),
+                ))
+                .expect("couldn't get generated reader parameter");
+
+            if let Some(rust_ty) = ty.primitive_rust_type() {
+                return alloc
+                    .text(format!(
+                        "<{rust_ty} as {}>::dbuf_deserialize{}(",
+                        io.primitive_trait(ctx.options.encoding),
+                        io.suffix()
+                    ))
+                    .append(reader_parameter.to_doc(ctx))
+                    .append(")")
+                    .append(io.completion())
+                    .append("?")
+                    .into_doc();
+            }
+
+            let Some(element) = ty.sequence_element() else {
+                let field_ty = ty.get_type();
+                let dependencies_struct = Self::generate_dependencies_struct_value(
+                    ty,
+                    (ctx, namespace),
+                    is_enum_constructor,
+                    field_symbol_ptrs,
+                );
+                let (field_type, _) = field_ty
+                    .lookup_type_type((ctx, namespace.cursor()))
+                    .expect("couldn't lookup type type");
+
+                return alloc
+                    .text("<")
+                    .append(field_type)
+                    .append(" as ")
+                    .append(io.message_trait())
+                    .append(">::deserialize")
+                    .append(io.suffix())
+                    .append("(")
+                    .append(alloc.intersperse(
+                        [dependencies_struct, reader_parameter.to_doc(ctx)],
+                        alloc.text(",").append(alloc.space()),
+                    ))
+                    .append(")")
+                    .append(io.completion())
+                    .append("?")
+                    .into_doc();
+            };
+
+            let (length_var, _) =
+                namespace.insert_object_auto_name(objects::Variable::from_object(
+                    ObjectId(NodeId::id(element), Tag::String("length")),
+                    "length".to_owned(),
+                ));
+            let (elements_var, _) =
+                namespace.insert_object_auto_name(objects::Variable::from_object(
+                    ObjectId(NodeId::id(element), Tag::String("elements")),
+                    "elements".to_owned(),
+                ));
+            let element_value = Self::generate_value_deserialization(
+                element,
+                io,
+                (ctx, namespace),
+                is_enum_constructor,
+                field_symbol_ptrs,
+            );
+
+            let block = alloc
+                .text("let")
+                .append(alloc.space())
+                .append(length_var.to_doc(ctx))
+                .append(alloc.space())
+                .append("=")
+                .append(alloc.space())
+                .append(format!(
+                    "<u64 as {}>::dbuf_deserialize{}(",
+                    io.primitive_trait(ctx.options.encoding),
+                    io.suffix()
+                ))
+                .append(reader_parameter.to_doc(ctx))
+                .append(")")
+                .append(io.completion())
+                .append("?")
+                .append(";")
+                .append(alloc.hardline())
+                .append("let mut")
+                .append(alloc.space())
+                .append(elements_var.to_doc(ctx))
+                .append(alloc.space())
+                .append("=")
+                .append(alloc.space())
+                .append("std::vec::Vec::new();")
+                .append(alloc.hardline())
+                .append("for _ in 0..")
+                .append(length_var.to_doc(ctx))
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(elements_var.to_doc(ctx))
+                        .append(".push(")
+                        .append(element_value)
+                        .append(");")
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}")
+                .append(alloc.hardline())
+                .append(elements_var.to_doc(ctx));
+
+            alloc
+                .text("{")
+                .append(alloc.hardline().append(block).nest(NEST_UNIT))
+                .append(alloc.hardline())
+                .append("}")
+                .into_doc()
+        }
+
+        /// Generates `Dependencies` struct of message type `ty` with values of its dependencies.
+        fn generate_dependencies_struct_value(
+            ty: &TypeExpression,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+            is_enum_constructor: bool,
+            field_symbol_ptrs: &HashSet<usize>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+            let field_ty = ty.get_type();
+
+            let (field_type_module_prefix, field_type_module_cursor) = field_ty
+                .lookup_type_module((ctx, namespace.cursor()))
+                .expect("couldn't lookup type module");
+
+            let field_dep_types: Vec<_> = field_ty
+                .dependencies
+                .iter()
+                .map(|s| s.ty.get_type())
+                .collect();
+
+            field_type_module_prefix.append(
+                field_ty.generate_type_dependencies_struct(
+                    (ctx, field_type_module_cursor),
+                    ty.get_dependencies()
+                        .iter()
+                        .enumerate()
+                        .map(|(dep_idx, dep)| {
+                            // This is dirty, because I can't type erase Locator as its methods take as argument Context<..., impl Cursor>
+                            // So cleaning this up requires type erased Cursor
+                            // TODO: cleanup when proper Cursor will be implemented
+                            let val = if is_enum_constructor {
+                                let val = dep.generate_as_value(
+                                    (ctx, namespace.cursor()),
+                                    &EnumConstructorDeserializationObjectsLocator {},
+                                );
+                                if field_dep_types.get(dep_idx).is_some_and(|t| t.is_builtin)
+                                    || matches!(dep, ValueExpression::Variable(_))
+                                {
+                                    val
+                                } else {
+                                    ctx.alloc
+                                        .text("Box::new(")
+                                        .append(val)
+                                        .append(")")
+                                        .into_doc()
+                                }
+                            } else {
+                                let val = dep.generate_as_value(
+                                    (ctx, namespace.cursor()),
+                                    &MessageConstructorDeserializationObjectsLocator {},
+                                );
+                                if field_dep_types.get(dep_idx).is_some_and(|t| t.is_builtin)
+                                    || matches!(dep, ValueExpression::Variable(_))
+                                {
+                                    val
+                                } else {
+                                    ctx.alloc
+                                        .text("Box::new(")
+                                        .append(val)
+                                        .append(")")
+                                        .into_doc()
+                                }
+                            };
+                            // Field variables are plain T (not Box<T>), so they need wrapping
+                            // when used as type dependencies (which expect Box<T>).
+                            // Skip wrapping for primitive dep types.
+                            let dep_ty = field_dep_types.get(dep_idx).is_some_and(|t| t.is_builtin);
+                            if !dep_ty
+                                && let ValueExpression::Variable(weak) = dep
+                                && field_symbol_ptrs.contains(&(Weak::as_ptr(weak) as usize))
+                            {
+                                return alloc.text("Box::new(").append(val).append(")").into_doc();
+                            }
+                            val
+                        })
+                        .collect(),
+                ),
+            )
+        }
+    }
+}
+
+mod type_view {
+    use super::super::prelude::*;
+    use super::encoding_argument;
+
+    impl<'a> Type {
+        /// Declares `Ref` view of the type, which encoding check of the type reads
+        /// before the view is generated.
+        pub(super) fn declare_view(&self, (_, namespace): MutContext<'a, '_, '_>) {
+            let (_, mut view_type_namespace) =
+                namespace.insert_object_auto_name(objects::Type::from_object(
+                    ObjectId(NodeId::id(self), Tag::String("view")),
+                    format!("{}Ref", self.name),
+                ));
+            view_type_namespace
+                .insert_object_preserve_name(objects::Variable::from_name("encoding".to_owned()))
+                .expect("couldn't insert encoding field");
+        }
+
+        /// Generates declared `Ref` view of encoded values of the type, its `BodyRef` with
+        /// views of fields, and implementation of `DbufView`.
+        pub(super) fn generate_view(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (view_type, view_type_cursor) = namespace
+                .get_generated::<objects::Type>(ObjectId(NodeId::id(self), Tag::String("view")))
+                .expect("couldn't get declared view type");
+            let (encoding_field, _) = view_type_cursor
+                .get_generated::<objects::Variable>(ObjectId::from_name("encoding".to_owned()))
+                .expect("couldn't get encoding field");
+
+            let body_view = self.generate_body_view((ctx, namespace));
+
+            let view_struct = alloc
+                .text("#[derive(Clone, Copy, Debug)]")
+                .append(alloc.hardline())
+                .append("pub struct")
+                .append(alloc.space())
+                .append(view_type.to_doc(ctx))
+                .append("<'a>")
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(encoding_field.to_doc(ctx))
+                        .append(": &'a [u8]")
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc();
+
+            let inherent_impl =
+                self.generate_view_inherent_impl(&view_type, &encoding_field, (ctx, namespace));
+            let view_impl = self.generate_view_impl(&view_type, &encoding_field, (ctx, namespace));
+
+            alloc
+                .intersperse(
+                    [body_view, view_struct, inherent_impl, view_impl],
+                    alloc.hardline(),
+                )
+                .into_doc()
+        }
+
+        /// Whether views of the type borrow the input, so `BodyRef` has a lifetime.
+        fn body_view_borrows(&self) -> bool {
+            self.constructors
+                .iter()
+                .flat_map(|constructor| &constructor.fields)
+                .any(|field| field.ty.view_borrows())
+        }
+
+        fn generate_body_view_type(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
+            let (body_view_type, _) = namespace
+                .get_generated::<objects::Type>(ObjectId::from_name("BodyRef".to_owned()))
+                .expect("couldn't get BodyRef type");
+            let lifetime = if self.body_view_borrows() { "<'a>" } else { "" };
+            body_view_type.to_doc(ctx).append(lifetime)
+        }
+
+        fn generate_body_view(&self, (ctx, namespace): MutContext<'a, '_, '_>) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (body_view_type, mut body_view_namespace) =
+                namespace.insert_object_auto_name(objects::Type::from_name("BodyRef".to_owned()));
+
+            let generate_fields =
+                |constructor: &Constructor, namespace: &mut context::NamingContext<'a, '_>| {
+                    constructor
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let view_type = constructor
+                                .generate_field_view_type(field, (ctx, namespace.cursor()));
+                            namespace
+                                .insert_object_auto_name(objects::Variable::from_object(
+                                    ObjectId(NodeId::id_rc(field), Tag::None),
+                                    field.name.to_string(),
+                                ))
+                                .0
+                                .to_doc(ctx)
+                                .append(": ")
+                                .append(view_type)
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+            let fields = match self.kind {
+                ast::TypeKind::Message => alloc.intersperse(
+                    generate_fields(&self.constructors[0], &mut body_view_namespace)
+                        .into_iter()
+                        .map(|field| alloc.text("pub ").append(field)),
+                    alloc.text(",").append(alloc.hardline()),
+                ),
+                ast::TypeKind::Enum => alloc.intersperse(
+                    self.constructors
+                        .iter()
+                        .map(|constructor| {
+                            let (branch_type, mut branch_namespace) = body_view_namespace
+                                .insert_object_auto_name(objects::Type::from_object(
+                                    ObjectId(
+                                        NodeId::id_rc(constructor),
+                                        Tag::String("enum_branch"),
+                                    ),
+                                    constructor.name.to_string(),
+                                ));
+                            branch_type
+                                .to_doc(ctx)
+                                .append(alloc.space())
+                                .append("{")
+                                .append(
+                                    alloc
+                                        .hardline()
+                                        .append(alloc.intersperse(
+                                            generate_fields(constructor, &mut branch_namespace),
+                                            alloc.text(",").append(alloc.hardline()),
+                                        ))
+                                        .nest(NEST_UNIT)
+                                        .append(alloc.hardline()),
+                                )
+                                .append("}")
+                        })
+                        .collect::<Vec<_>>(),
+                    alloc.text(",").append(alloc.hardline()),
+                ),
+            };
+
+            let holder = match self.kind {
+                ast::TypeKind::Message => "struct",
+                ast::TypeKind::Enum => "enum",
+            };
+            let lifetime = if self.body_view_borrows() { "<'a>" } else { "" };
+            alloc
+                .text("#[derive(Clone, Copy, Debug)]")
+                .append(alloc.hardline())
+                .append(format!("pub {holder}"))
+                .append(alloc.space())
+                .append(body_view_type.to_doc(ctx))
+                .append(lifetime)
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(fields)
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc()
+        }
+
+        fn generate_view_inherent_impl(
+            &self,
+            view_type: &objects::GeneratedType,
+            encoding_field: &objects::GeneratedVariable,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (_, mut impl_namespace) = namespace.insert_object_auto_name(objects::Scope::new(
+                ObjectId(NodeId::id(self), Tag::String("view_inherent_impl")),
+            ));
+
+            let body_view_type = self.generate_body_view_type((ctx, &mut impl_namespace));
+
+            let mut insert_function = |name: &str| {
+                impl_namespace
+                    .insert_object_preserve_name(objects::Function::from_name(name.to_owned()))
+                    .expect("couldn't insert view function")
+                    .0
+            };
+            let encoding_function = insert_function("encoding");
+            let body_function = insert_function("body");
+            let read_body_function = insert_function("read_body");
+
+            let encoding_declaration = alloc
+                .text("pub fn ")
+                .append(encoding_function.to_doc(ctx))
+                .append("(&self) -> &'a [u8] {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append("self.")
+                        .append(encoding_field.to_doc(ctx))
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}")
+                .into_doc();
+
+            let body_declaration = alloc
+                .text("pub fn ")
+                .append(body_function.to_doc(ctx))
+                .append("(&self) -> ")
+                .append(body_view_type.clone())
+                .append(" {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append("Self::")
+                        .append(read_body_function.to_doc(ctx))
+                        .append("(&mut super::SliceReader::new(self.")
+                        .append(encoding_field.to_doc(ctx))
+                        .append("))")
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}")
+                .into_doc();
+
+            // fields of messages are also accessible directly
+            let accessors = match self.kind {
+                ast::TypeKind::Message => (0..self.constructors[0].fields.len())
+                    .map(|index| {
+                        self.constructors[0].generate_view_accessor(
+                            index,
+                            encoding_field,
+                            (ctx, &mut impl_namespace),
+                        )
+                    })
+                    .collect(),
+                ast::TypeKind::Enum => vec![],
+            };
+
+            let read_body_declaration = self.generate_body_reading(
+                &read_body_function,
+                &body_view_type,
+                (ctx, &mut impl_namespace),
+            );
+
+            drop(impl_namespace);
+
+            let functions = [encoding_declaration, body_declaration]
+                .into_iter()
+                .chain(accessors)
+                .chain([read_body_declaration]);
+
+            alloc
+                .text("impl<'a>")
+                .append(alloc.space())
+                .append(view_type.to_doc(ctx))
+                .append("<'a>")
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(alloc.intersperse(functions, alloc.hardline()))
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc()
+        }
+
+        /// Generates function reading `BodyRef` from reader, which encoding is already checked.
+        #[allow(clippy::too_many_lines, reason = "??? (105/100)")]
+        fn generate_body_reading(
+            &self,
+            function: &objects::GeneratedFunction,
+            body_view_type: &BoxDoc<'a>,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (_, mut function_namespace) = namespace.insert_object_auto_name(
+                objects::Scope::new(ObjectId(NodeId::id(self), Tag::String("read_body"))),
+            );
+            let (reader_parameter, _) = function_namespace
+                .insert_object_preserve_name(objects::Variable::from_name("reader".to_owned()))
+                .expect("couldn't generate reader function parameter");
+            let reader = reader_parameter.to_doc(ctx);
+
+            let constructions = self
+                .constructors
+                .iter()
+                .map(|constructor| {
+                    let (body_view, body_view_cursor) = function_namespace
+                        .get_generated::<objects::Type>(ObjectId::from_name("BodyRef".to_owned()))
+                        .expect("couldn't get BodyRef type");
+                    let (path, fields_cursor) = match self.kind {
+                        ast::TypeKind::Message => (body_view.to_doc(ctx), body_view_cursor),
+                        ast::TypeKind::Enum => {
+                            let (branch, branch_cursor) = body_view_cursor
+                                .get_generated::<objects::Type>(ObjectId(
+                                    NodeId::id_rc(constructor),
+                                    Tag::String("enum_branch"),
+                                ))
+                                .expect("couldn't get BodyRef variant for constructor");
+                            (
+                                body_view
+                                    .to_doc(ctx)
+                                    .append("::")
+                                    .append(branch.to_doc(ctx)),
+                                branch_cursor,
+                            )
+                        }
+                    };
+
+                    let fields = constructor
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let (field_name, _) = fields_cursor
+                                .clone()
+                                .get_generated::<objects::Variable>(ObjectId(
+                                    NodeId::id_rc(field),
+                                    Tag::None,
+                                ))
+                                .expect("couldn't get BodyRef field");
+                            let reading = constructor.generate_field_view_reading(
+                                field,
+                                reader.clone(),
+                                (ctx, function_namespace.cursor()),
+                            );
+                            field_name.to_doc(ctx).append(": ").append(reading)
+                        })
+                        .collect::<Vec<_>>();
+
+                    let construction =
+                        path.append(alloc.space())
+                            .append("{")
+                            .append(
+                                alloc
+                                    .hardline()
+                                    .append(alloc.intersperse(
+                                        fields,
+                                        alloc.text(",").append(alloc.hardline()),
+                                    ))
+                                    .nest(NEST_UNIT)
+                                    .append(alloc.hardline()),
+                            )
+                            .append("}");
+                    let descriptor =
+                        constructor.generate_enum_descriptor((ctx, function_namespace.cursor()));
+                    (descriptor, construction)
+                })
+                .collect::<Vec<_>>();
+
+            let body = match self.kind {
+                ast::TypeKind::Message => {
+                    let (_, construction) = constructions
+                        .into_iter()
+                        .next()
+                        .expect("message has constructor");
+                    construction
                 }
+                ast::TypeKind::Enum => alloc
+                    .text("match <u8 as super::DbufView>::read_checked(")
+                    .append(reader.clone())
+                    .append(") {")
+                    .append(
+                        alloc
+                            .hardline()
+                            .append(
+                                alloc.concat(
+                                    constructions
+                                        .into_iter()
+                                        .map(|(descriptor, construction)| {
+                                            descriptor
+                                                .expect("enum constructor has descriptor")
+                                                .append(" => ")
+                                                .append(construction)
+                                        })
+                                        .chain([alloc
+                                            .text(
+                                                "_ => unreachable!(\"view is checked when read\")",
+                                            )
+                                            .into_doc()])
+                                        .map(|arm| arm.append(",").append(alloc.hardline())),
+                                ),
+                            )
+                            .nest(NEST_UNIT),
+                    )
+                    .append("}")
+                    .into_doc(),
+            };
+
+            drop(function_namespace);
+
+            alloc
+                .text("fn ")
+                .append(function.to_doc(ctx))
+                .append("(")
+                .append(reader)
+                .append(": &mut super::SliceReader<'a>) -> ")
+                .append(body_view_type.clone())
+                .append(" {")
+                .append(alloc.hardline().append(body).nest(NEST_UNIT))
+                .append(alloc.hardline())
+                .append("}")
+                .into_doc()
+        }
+
+        /// Generates implementation of `DbufView`. Views are checked by the encoding check
+        /// of the type, which reads views of fields instead of decoding them.
+        #[allow(clippy::too_many_lines, reason = "??? (112/100)")]
+        fn generate_view_impl(
+            &self,
+            view_type: &objects::GeneratedType,
+            encoding_field: &objects::GeneratedVariable,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+
+            let (_, mut impl_namespace) = namespace.insert_object_auto_name(objects::Scope::new(
+                ObjectId(NodeId::id(self), Tag::String("view_impl")),
+            ));
+
+            let (dependencies_type, _) = impl_namespace
+                .get_generated::<objects::Type>(ObjectId::from_name("Dependencies".to_owned()))
+                .expect("couldn't get Dependencies type");
+            let (message_type, _) = impl_namespace
+                .get_generated::<objects::Type>(ObjectId(NodeId::id(self), Tag::String("type")))
+                .expect("couldn't get message type");
+            let (_, impl_cursor) = impl_namespace
+                .cursor()
+                .lookup_module_root()
+                .get_generated::<objects::Scope>(ObjectId(
+                    NodeId::id(self),
+                    Tag::String("view_inherent_impl"),
+                ))
+                .expect("couldn't get view inherent impl");
+            let function = |name: &str| {
+                impl_cursor
+                    .clone()
+                    .get_generated::<objects::Function>(ObjectId::from_name(name.to_owned()))
+                    .expect("couldn't get view function")
+                    .0
+                    .to_doc(ctx)
+            };
+            let read_body_function = function("read_body");
+            drop(impl_cursor);
+            let (check_function, _) = impl_namespace
+                .cursor()
+                .lookup_module_root()
+                .get_generated::<objects::Scope>(ObjectId(
+                    NodeId::id(self),
+                    Tag::String("inherent_impl"),
+                ))
+                .expect("couldn't get inherent impl")
+                .1
+                .get_generated::<objects::Function>(ObjectId(
+                    NodeId::id(self),
+                    Tag::String("encoding_check"),
+                ))
+                .expect("couldn't get encoding check function");
+
+            let (read_view_function, _) = impl_namespace
+                .insert_object_preserve_name(objects::Function::from_name("read_view".to_owned()))
+                .expect("couldn't generate read_view function");
+            let (read_checked_function, _) = impl_namespace
+                .insert_object_preserve_name(objects::Function::from_name(
+                    "read_checked".to_owned(),
+                ))
+                .expect("couldn't generate read_checked function");
+
+            drop(impl_namespace);
+
+            let construction = alloc
+                .text("Ok(Self { ")
+                .append(encoding_field.to_doc(ctx))
+                .append(": encoding })");
+            let read_view_body = alloc
+                .text("let (checked, encoding) = reader.read_encoding(|reader| ")
+                .append(message_type.to_doc(ctx))
+                .append("::")
+                .append(check_function.to_doc(ctx))
+                .append("(dependencies, reader));")
+                .append(alloc.hardline())
+                .append("checked?;");
+
+            let read_view_declaration = alloc
+                .text("fn ")
+                .append(read_view_function.to_doc(ctx))
+                .append("(dependencies: ")
+                .append(dependencies_type.to_doc(ctx))
+                .append(", reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(read_view_body)
+                        .append(alloc.hardline())
+                        .append(construction)
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}");
+
+            let read_checked_declaration = alloc
+                .text("fn ")
+                .append(read_checked_function.to_doc(ctx))
+                .append("(reader: &mut super::SliceReader<'a>) -> Self {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append("let (_, encoding) = reader.read_encoding(Self::")
+                        .append(read_body_function)
+                        .append(");")
+                        .append(alloc.hardline())
+                        .append("Self { ")
+                        .append(encoding_field.to_doc(ctx))
+                        .append(": encoding }")
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}");
+
+            let view_trait = match encoding_argument(ctx.options.encoding) {
+                Some(argument) => format!("super::DbufView<'a, {argument}>"),
+                None => "super::DbufView<'a>".to_owned(),
+            };
+            alloc
+                .text("impl<'a>")
+                .append(alloc.space())
+                .append(view_trait)
+                .append(alloc.space())
+                .append("for")
+                .append(alloc.space())
+                .append(view_type.to_doc(ctx))
+                .append("<'a>")
+                .append(alloc.space())
+                .append("{")
+                .append(
+                    alloc
+                        .hardline()
+                        .append(
+                            alloc.intersperse(
+                                [
+                                    alloc
+                                        .text("type Dependencies = ")
+                                        .append(dependencies_type.to_doc(ctx))
+                                        .append(";"),
+                                    read_view_declaration,
+                                    read_checked_declaration,
+                                ],
+                                alloc.hardline(),
+                            ),
+                        )
+                        .nest(NEST_UNIT)
+                        .append(alloc.hardline()),
+                )
+                .append("}")
+                .into_doc()
+        }
+    }
+
+    impl<'a> Constructor {
+        /// Generates type of view of `field`, which is absent if the field has default.
+        fn generate_field_view_type<'cursor>(
+            &self,
+            field: &Symbol,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> BoxDoc<'a> {
+            let view_type = field.ty.generate_as_view_type((ctx, namespace));
+            if self.default_of(field).is_some() {
+                ctx.alloc
+                    .text("Option<")
+                    .append(view_type)
+                    .append(">")
+                    .into_doc()
+            } else {
+                view_type
+            }
+        }
+
+        /// Generates expression reading view of `field` from `reader`.
+        fn generate_field_view_reading<'cursor>(
+            &self,
+            field: &Symbol,
+            reader: BoxDoc<'a>,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> BoxDoc<'a> {
+            let reading = field
+                .ty
+                .generate_view_reading(reader.clone(), (ctx, namespace));
+            // defaulted fields are absent if the input ends before them
+            if self.default_of(field).is_some() {
+                ctx.alloc
+                    .text("if ")
+                    .append(reader)
+                    .append(".is_empty() { None } else { Some(")
+                    .append(reading)
+                    .append(") }")
+                    .into_doc()
+            } else {
+                reading
+            }
+        }
 
-                let (field_type_module_prefix, field_type_module_cursor) = field_ty
-                    .lookup_type_module((ctx, namespace.cursor()))
-                    .expect("couldn't lookup type module");
+        /// Generates accessor of view of field at `index`, which skips preceding fields
+        /// and reads nothing after the field.
+        fn generate_view_accessor(
+            &self,
+            index: usize,
+            encoding_field: &objects::GeneratedVariable,
+            (ctx, namespace): MutContext<'a, '_, '_>,
+        ) -> BoxDoc<'a> {
+            let alloc = ctx.alloc;
+            let field = &self.fields[index];
 
-                // let namespace_cursor = namespace.cursor();
+            let (accessor, mut accessor_namespace) =
+                namespace.insert_object_auto_name(objects::Function::from_object(
+                    ObjectId(NodeId::id_rc(field), Tag::String("accessor")),
+                    field.name.to_string(),
+                ));
+            let (reader_variable, _) = accessor_namespace
+                .insert_object_preserve_name(objects::Variable::from_name("reader".to_owned()))
+                .expect("couldn't generate reader variable");
+            let reader = reader_variable.to_doc(ctx);
 
-                let field_dep_types: Vec<_> = field_ty
-                    .dependencies
-                    .iter()
-                    .map(|s| s.ty.get_type())
-                    .collect();
+            let skips = self.fields[..index].iter().map(|preceding| {
+                let skip = preceding
+                    .ty
+                    .generate_view_reading(reader.clone(), (ctx, accessor_namespace.cursor()))
+                    .append(";");
+                let skip = if self.default_of(preceding).is_some() {
+                    alloc
+                        .text("if !")
+                        .append(reader.clone())
+                        .append(".is_empty() { ")
+                        .append(skip)
+                        .append(" }")
+                        .into_doc()
+                } else {
+                    skip
+                };
+                skip.append(alloc.hardline())
+            });
 
-                let dependencies_struct = field_type_module_prefix.append(
-                    field_ty.generate_type_dependencies_struct(
-                        (ctx, field_type_module_cursor),
-                        field
-                            .ty
-                            .get_dependencies()
-                            .iter()
-                            .enumerate()
-                            .map(|(dep_idx, dep)| {
-                                let val = if is_enum_constructor {
-                                    let val = dep.generate_as_value(
-                                        (ctx, namespace.cursor()),
-                                        &EnumConstructorDeserializationObjectsLocator {},
-                                    );
-                                    if field_dep_types.get(dep_idx).is_some_and(|t| t.is_builtin)
-                                        || matches!(dep, ValueExpression::Variable(_))
-                                    {
-                                        val
-                                    } else {
-                                        ctx.alloc
-                                            .text("Box::new(")
-                                            .append(val)
-                                            .append(")")
-                                            .into_doc()
-                                    }
-                                } else {
-                                    let val = dep.generate_as_value(
-                                        (ctx, namespace.cursor()),
-                                        &MessageConstructorDeserializationObjectsLocator {},
-                                    );
-                                    if field_dep_types.get(dep_idx).is_some_and(|t| t.is_builtin)
-                                        || matches!(dep, ValueExpression::Variable(_))
-                                    {
-                                        val
-                                    } else {
-                                        ctx.alloc
-                                            .text("Box::new(")
-                                            .append(val)
-                                            .append(")")
-                                            .into_doc()
-                                    }
-                                };
-                                // Field variables are plain T (not Box<T>), so they need wrapping
-                                // when used as type dependencies (which expect Box<T>).
-                                // Skip wrapping for primitive dep types.
-                                let dep_ty =
-                                    field_dep_types.get(dep_idx).is_some_and(|t| t.is_builtin);
-                                if !dep_ty
-                                    && let ValueExpression::Variable(weak) = dep
-                                    && field_symbol_ptrs.contains(&(Weak::as_ptr(weak) as usize))
-                                {
-                                    return alloc
-                                        .text("Box::new(")
-                                        .append(val)
-                                        .append(")")
-                                        .into_doc();
-                                }
-                                val
-                            })
-                            .collect(),
-                    ),
-                );
+            alloc
+                .text("pub fn ")
+                .append(accessor.to_doc(ctx))
+                .append("(&self) -> ")
+                .append(self.generate_field_view_type(field, (ctx, accessor_namespace.cursor())))
+                .append(" {")
+                .append(
+                    alloc
+                        .hardline()
+                        .append("let ")
+                        .append(reader.clone())
+                        .append(" = &mut super::SliceReader::new(self.")
+                        .append(encoding_field.to_doc(ctx))
+                        .append(");")
+                        .append(alloc.hardline())
+                        .append(alloc.concat(skips))
+                        .append(self.generate_field_view_reading(
+                            field,
+                            reader.clone(),
+                            (ctx, accessor_namespace.cursor()),
+                        ))
+                        .nest(NEST_UNIT),
+                )
+                .append(alloc.hardline())
+                .append("}")
+                .into_doc()
+        }
+    }
 
-                let (field_type_type_prefix, _) = field_ty
-                    .lookup_type_type((ctx, namespace.cursor()))
-                    .expect("couldn't lookup type type");
+    impl<'a> TypeExpression {
+        /// Whether views of values of this type borrow the input.
+        fn view_borrows(&self) -> bool {
+            let ty = self.get_type();
+            !ty.is_builtin || matches!(ty.name.as_ref(), "String" | "Bytes" | "List" | "Array")
+        }
 
-                alloc
-                    .text("let")
-                    .append(alloc.space())
+        /// Generates type of views of values of this type.
+        pub(super) fn generate_as_view_type<'cursor>(
+            &self,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> BoxDoc<'a> {
+            if let Some(element) = self.sequence_element() {
+                return ctx
+                    .alloc
+                    .text("dbuf_rust_runtime::ListRef<'a, ")
+                    .append(element.generate_as_view_type((ctx, namespace)))
                     .append(
-                        namespace
-                            .insert_object_auto_name(objects::Variable::from_object(
-                                objects::ObjectId(ast::NodeId::id_rc(field), objects::Tag::None),
-                                field.name.to_string(),
-                            ))
-                            .0
-                            .to_doc(ctx),
+                        encoding_argument(ctx.options.encoding)
+                            .map(|argument| format!(", {argument}"))
+                            .unwrap_or_default(),
                     )
-                    .append(alloc.space())
-                    .append("=")
-                    .append(alloc.space())
-                    .append(field_type_type_prefix)
-                    .append("deserialize") // TODO
-                    .append("(")
-                    .append(alloc.intersperse(
-                        [dependencies_struct, reader_parameter.to_doc(ctx)],
-                        alloc.text(",").append(alloc.space()),
-                    ))
-                    .append(")")
-                    .append("?")
-                    .append(";")
-                    .append(alloc.hardline())
-            }));
+                    .append(">")
+                    .into_doc();
+            }
 
-            let constructor_construction = self.generate_constructor_construction(
-                (ctx, namespace.cursor()),
-                self.implicits
-                    .iter()
-                    .map(|implicit| {
-                        if is_enum_constructor {
-                            EnumConstructorDeserializationObjectsLocator {}.locate_variable(
-                                (ctx, namespace.cursor()),
-                                &Rc::downgrade(implicit),
-                            )
-                        } else {
-                            MessageConstructorDeserializationObjectsLocator {}.locate_variable(
-                                (ctx, namespace.cursor()),
-                                &Rc::downgrade(implicit),
-                            )
-                        }
-                    })
-                    .chain(self.fields.iter().map(|field| {
-                        let field_var = namespace
-                            .get_generated::<objects::Variable>(objects::ObjectId(
-                                ast::NodeId::id_rc(field),
-                                objects::Tag::None,
-                            ))
-                            .expect("couldn't get generated field variable")
-                            .0
-                            .to_doc(ctx);
+            let ty = self.get_type();
+            if ty.is_builtin {
+                let view_type = match ty.name.as_ref() {
+                    "String" => "&'a str",
+                    "Bytes" => "&'a [u8]",
+                    _ => ty.builtin_rust_type().expect("unknown builtin type"),
+                };
+                return ctx.alloc.text(view_type).into_doc();
+            }
 
-                        if field.ty.get_type().is_builtin {
-                            field_var
-                        } else {
-                            alloc
-                                .text("Box")
-                                .append("::")
-                                .append("new")
-                                .append("(")
-                                .append(field_var)
-                                .append(")")
-                                .into_doc()
-                        }
-                    }))
-                    .collect(),
-            );
+            let (type_module_prefix, type_module) = ty
+                .lookup_type_module((ctx, namespace))
+                .expect("couldn't lookup type module");
+            type_module_prefix
+                .append(
+                    type_module
+                        .get_generated::<objects::Type>(ObjectId(
+                            NodeId::id_rc(&ty),
+                            Tag::String("view"),
+                        ))
+                        .expect("couldn't get view type")
+                        .0
+                        .to_doc(ctx),
+                )
+                .append("<'a>")
+        }
 
-            fields_deserialization
-                .append(constructor_construction)
-                .append(".")
-                .append("map_err")
-                .append("(|e| super::DeserializeError::ConstructorError(e))")
-                .append(alloc.hardline())
+        /// Generates expression reading view of value of this type from `reader`,
+        /// which encoding is already checked.
+        fn generate_view_reading<'cursor>(
+            &self,
+            reader: BoxDoc<'a>,
+            (ctx, namespace): Context<
+                'a,
+                'cursor,
+                impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+            >,
+        ) -> BoxDoc<'a> {
+            ctx.alloc
+                .text("<")
+                .append(self.generate_as_view_type((ctx, namespace)))
+                .append(" as super::DbufView")
+                .append(
+                    encoding_argument(ctx.options.encoding)
+                        .map(|argument| format!("<'_, {argument}>"))
+                        .unwrap_or_default(),
+                )
+                .append(">::read_checked(")
+                .append(reader)
+                .append(")")
                 .into_doc()
         }
     }
@@ -2269,10 +4474,12 @@ mod value_from_expression {
                 ValueExpression::Variable(weak) => locator
                     .locate_variable((ctx, namespace), weak)
                     .append(".clone()"),
+                ValueExpression::Type(_) => panic!("type can not be used as value"),
             }
         }
     }
 
+    #[allow(clippy::too_many_lines, reason = "??? (127/100)")]
     fn generate_op_as_value<'a, 'cursor, L: Locator<'a>>(
         op_call: &OpCall,
         (ctx, namespace): Context<
@@ -2289,16 +4496,19 @@ mod value_from_expression {
                     Literal::Bool(val) => val.to_string(),
                     Literal::Int(val) => val.to_string(),
                     Literal::UInt(val) => val.to_string(),
+                    Literal::Sized(val) => val.to_string(),
+                    Literal::Double(val) => format!("dbuf_rust_runtime::Double({:?})", val.0),
                     Literal::Str(val) => {
                         // Escape backslashes and double-quotes.
                         let escaped = val.replace('\\', "\\\\").replace('"', "\\\"");
                         format!("String::from(\"{escaped}\")")
                     }
+                    Literal::Bytes(val) => format!("std::vec::Vec::<u8>::from({val:?})"),
                 };
                 alloc.text(string).into_doc()
             }
-            OpCall::Unary(unary_op, operand) => {
-                let operand = operand.generate_as_value((ctx, namespace.clone()), locator);
+            OpCall::Unary(unary_op, operand_expr) => {
+                let operand = operand_expr.generate_as_value((ctx, namespace.clone()), locator);
                 match unary_op {
                     UnaryOp::Access { to, field } => {
                         let to = to.upgrade().expect("access from unknown type");
@@ -2321,27 +4531,78 @@ mod value_from_expression {
                             .append(".")
                             .append(field.to_doc(ctx))
                     }
-                    UnaryOp::Minus => ctx.alloc.text("-").append(operand).into_doc(),
+                    UnaryOp::Length | UnaryOp::StringLength => ctx
+                        .alloc
+                        .text("(")
+                        .append(operand)
+                        .append(".len() as u64)")
+                        .into_doc(),
+                    // Literals are in range, so only other operands may overflow.
+                    UnaryOp::Minus
+                        if matches!(
+                            **operand_expr,
+                            ValueExpression::OpCall(OpCall::Literal(_))
+                        ) =>
+                    {
+                        ctx.alloc.text("-").append(operand).into_doc()
+                    }
+                    UnaryOp::Minus => ctx
+                        .alloc
+                        .text("super::DbufArithmetic::dbuf_neg(")
+                        .append(operand)
+                        .append(")?")
+                        .into_doc(),
                     UnaryOp::Bang => ctx.alloc.text("!").append(operand).into_doc(),
                 }
             }
+            OpCall::Binary(
+                binary_op @ (BinaryOp::Plus
+                | BinaryOp::Minus
+                | BinaryOp::Star
+                | BinaryOp::Slash
+                | BinaryOp::Percent
+                | BinaryOp::ShiftLeft
+                | BinaryOp::ShiftRight),
+                lhs,
+                rhs,
+            ) => {
+                // Operators of schemas fail on overflow or division by zero instead of
+                // panicking or wrapping like ones of Rust, so runtime helpers are called.
+                let (helper, is_fallible) = match binary_op {
+                    BinaryOp::Plus => ("DbufArithmetic::dbuf_add", true),
+                    BinaryOp::Minus => ("DbufArithmetic::dbuf_sub", true),
+                    BinaryOp::Star => ("DbufArithmetic::dbuf_mul", true),
+                    BinaryOp::Slash => ("DbufInteger::dbuf_div", true),
+                    BinaryOp::Percent => ("DbufInteger::dbuf_rem", true),
+                    BinaryOp::ShiftLeft => ("DbufInteger::dbuf_shl", false),
+                    _ => ("DbufInteger::dbuf_shr", false),
+                };
+                alloc
+                    .text("super::")
+                    .append(helper)
+                    .append("(")
+                    .append(lhs.generate_as_value((ctx, namespace.clone()), locator))
+                    .append(", ")
+                    .append(rhs.generate_as_value((ctx, namespace), locator))
+                    .append(if is_fallible { ")?" } else { ")" })
+                    .into_doc()
+            }
+            OpCall::Binary(BinaryOp::StartsWith, lhs, rhs) => alloc
+                .text("(")
+                .append(lhs.generate_as_value((ctx, namespace.clone()), locator))
+                .append(").starts_with(&")
+                .append(rhs.generate_as_value((ctx, namespace), locator))
+                .append(")")
+                .into_doc(),
             OpCall::Binary(binary_op, lhs, rhs) => {
                 let op = match binary_op {
-                    BinaryOp::Plus => alloc.text("+"),
-                    BinaryOp::Minus => alloc.text("-"),
-                    BinaryOp::Star => alloc.text("*"),
-
                     BinaryOp::BinaryAnd => alloc.text("&"),
                     BinaryOp::BinaryOr => alloc.text("|"),
+
+                    comparison => alloc.text(comparison.symbol()),
                 };
                 let lhs_doc = lhs.generate_as_value((ctx, namespace.clone()), locator);
                 let rhs_doc = rhs.generate_as_value((ctx, namespace), locator);
-                let rhs_doc =
-                    if matches!(binary_op, BinaryOp::Plus | BinaryOp::Minus | BinaryOp::Star) {
-                        alloc.text("&").append(rhs_doc).into_doc()
-                    } else {
-                        rhs_doc
-                    };
                 alloc
                     .text("(")
                     .append(lhs_doc)
@@ -2352,6 +4613,14 @@ mod value_from_expression {
                     .append(")")
                     .into_doc()
             }
+            // Full slices of `String` are `str` and of `Vec<u8>` are `[u8]`.
+            OpCall::Concat(lhs, rhs) => alloc
+                .text("[&")
+                .append(lhs.generate_as_value((ctx, namespace.clone()), locator))
+                .append("[..], &")
+                .append(rhs.generate_as_value((ctx, namespace), locator))
+                .append("[..]].concat()")
+                .into_doc(),
         }
     }
 
@@ -2429,20 +4698,30 @@ impl<'a> Constructor {
     ) -> BoxDoc<'a> {
         let alloc = ctx.alloc;
 
+        let id = objects::ObjectId(NodeId::id(self), Tag::None);
+        // Constructors are declared in inherent impl, but also called from impl of `DbufMessage`.
+        let constructor_func = namespace
+            .clone()
+            .get_generated::<objects::Function>(id.clone())
+            .map(|(generated, _)| generated)
+            .or_else(|| {
+                namespace
+                    .clone()
+                    .lookup_module_root()
+                    .lookup_generated::<objects::Scope>(ObjectId(
+                        NodeId::id_rc(&self.result_type.get_type()),
+                        Tag::String("inherent_impl"),
+                    ))?
+                    .get_generated::<objects::Function>(id)
+                    .map(|(generated, _)| generated)
+            })
+            .expect("couldn't get generated constructor constructor");
+
         // TODO: rewrite from just using "Self" to checking that we are in fact inside impl of Self and using it only then.
         alloc
             .text("Self")
             .append("::")
-            .append(
-                namespace
-                    .get_generated::<objects::Function>(objects::ObjectId(
-                        NodeId::id(self),
-                        Tag::None,
-                    ))
-                    .expect("couldn't get generated constructor constructor")
-                    .0
-                    .to_doc(ctx),
-            )
+            .append(constructor_func.to_doc(ctx))
             .append("(")
             .append(alloc.intersperse(values, alloc.text(",").append(alloc.space())))
             .append(")")
@@ -2455,27 +4734,13 @@ impl<'a> Symbol {
         self: Rc<Self>,
         (ctx, namespace): MutContext<'a, '_, '_>,
     ) -> BoxDoc<'a> {
-        let field_ty = self.ty.get_type();
-        let ty = if let Some(rust_ty) = field_ty.builtin_rust_type() {
-            ctx.alloc.text(rust_ty).into_doc()
+        let ty = self.ty.generate_as_rust_type((ctx, namespace.cursor()));
+        let ty = if self.ty.get_type().is_builtin {
+            ty
         } else {
             ctx.alloc
                 .text("super::Box<")
-                .append({
-                    let (type_module_prefix, type_module) = field_ty
-                        .lookup_type_module((ctx, namespace.cursor()))
-                        .expect("couldn't lookup type module");
-                    type_module_prefix.append(
-                        type_module
-                            .get_generated::<objects::Type>(ObjectId(
-                                NodeId::id_rc(&field_ty),
-                                Tag::String("type"),
-                            ))
-                            .expect("couldn't get message type")
-                            .0
-                            .to_doc(ctx),
-                    )
-                })
+                .append(ty)
                 .append(">")
                 .into_doc()
         };
@@ -2490,6 +4755,80 @@ impl<'a> Symbol {
     }
 }
 
+impl<'a> TypeExpression {
+    /// Rust type of values serialized with `DbufPrimitive`: builtin scalars and sequences of them.
+    pub fn primitive_rust_type(&self) -> Option<String> {
+        match self.sequence_element() {
+            Some(element) => element
+                .primitive_rust_type()
+                .map(|element| format!("std::vec::Vec<{element}>")),
+            None => self.get_type().builtin_rust_type().map(str::to_owned),
+        }
+    }
+
+    /// Generates Rust type of values of this type. Messages are not boxed.
+    pub fn generate_as_rust_type<'cursor>(
+        &self,
+        (ctx, namespace): Context<
+            'a,
+            'cursor,
+            impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+        >,
+    ) -> BoxDoc<'a> {
+        if let Some(rust_ty) = self.primitive_rust_type() {
+            return ctx.alloc.text(rust_ty).into_doc();
+        }
+        if let Some(element) = self.sequence_element() {
+            return ctx
+                .alloc
+                .text("std::vec::Vec<")
+                .append(element.generate_as_rust_type((ctx, namespace)))
+                .append(">")
+                .into_doc();
+        }
+
+        let ty = self.get_type();
+        let (type_module_prefix, type_module) = ty
+            .lookup_type_module((ctx, namespace))
+            .expect("couldn't lookup type module");
+        type_module_prefix.append(
+            type_module
+                .get_generated::<objects::Type>(ObjectId(NodeId::id_rc(&ty), Tag::String("type")))
+                .expect("couldn't get message type")
+                .0
+                .to_doc(ctx),
+        )
+    }
+}
+
+impl<'a> TypeExpression {
+    /// Generates Rust type of values of this type at the top level of the module,
+    /// where types are visible by their public aliases.
+    fn generate_as_top_level_rust_type(
+        &self,
+        (ctx, namespace): MutContext<'a, '_, '_>,
+    ) -> BoxDoc<'a> {
+        if let Some(rust_ty) = self.primitive_rust_type() {
+            return ctx.alloc.text(rust_ty).into_doc();
+        }
+        if let Some(element) = self.sequence_element() {
+            return ctx
+                .alloc
+                .text("std::vec::Vec<")
+                .append(element.generate_as_top_level_rust_type((ctx, namespace)))
+                .append(">")
+                .into_doc();
+        }
+
+        let ty = self.get_type();
+        namespace
+            .get_generated::<objects::Type>(ObjectId(NodeId::id_rc(&ty), Tag::String("type")))
+            .expect("couldn't get type alias")
+            .0
+            .to_doc(ctx)
+    }
+}
+
 // Those are quite dirty because there is no path api. So they need to return path prefix in return type as BoxDoc.
 // TODO: come up with some convenient path api.
 impl<'a> Type {
@@ -2564,7 +4903,7 @@ impl<'a> Type {
             .lookup_generated::<objects::Type>(ObjectId(NodeId::id(self), Tag::String("type")))
             .is_some()
         {
-            Some((ctx.alloc.text("Self").append("::").into_doc(), namespace))
+            Some((ctx.alloc.text("Self").into_doc(), namespace))
         } else {
             // dirty because there is no path api
             let mut path = None;
@@ -2588,7 +4927,7 @@ impl<'a> Type {
                         ),
                     );
                 });
-            Some((path.unwrap().append("::"), cursor))
+            Some((path.unwrap(), cursor))
         }
     }
 }
@@ -2598,10 +4937,20 @@ fn literal_as_pattern<'a>(op_call: &OpCall, alloc: &'a crate::format::BoxAllocat
         OpCall::Literal(Literal::Bool(val)) => alloc.text(val.to_string()).into_doc(),
         OpCall::Literal(Literal::Int(val)) => alloc.text(val.to_string()).into_doc(),
         OpCall::Literal(Literal::UInt(val)) => alloc.text(val.to_string()).into_doc(),
+        OpCall::Literal(Literal::Sized(val)) => alloc.text(val.to_string()).into_doc(),
         OpCall::Literal(Literal::Str(val)) => {
             let escaped = val.replace('\\', "\\\\").replace('"', "\\\"");
             alloc.text(format!("\"{escaped}\"")).into_doc()
         }
+        OpCall::Literal(Literal::Bytes(val)) => alloc.text(format!("{val:?}")).into_doc(),
+        // Negative integers are normalized to negation of literal.
+        OpCall::Unary(UnaryOp::Minus, operand) => match operand.as_ref() {
+            ValueExpression::OpCall(literal @ OpCall::Literal(_)) => alloc
+                .text("-")
+                .append(literal_as_pattern(literal, alloc))
+                .into_doc(),
+            _ => panic!("only negated literals are supported in pattern matching"),
+        },
         _ => panic!(
             "only literal patterns are supported in pattern matching; got a complex operator expression"
         ),
@@ -2646,7 +4995,144 @@ fn wrap_with_deferred_checks<'a>(
         })
 }
 
+/// Generates early return of `RefinementViolated` error when `predicate` of refinement
+/// of variable `name` does not hold.
+fn generate_refinement_check<'a>(
+    name: &Str,
+    predicate: &ValueExpression,
+    predicate_doc: BoxDoc<'a>,
+    is_deserialization: bool,
+    alloc: &'a crate::format::BoxAllocator,
+) -> BoxDoc<'a> {
+    // binary operations are already parenthesized
+    let condition = if predicate.can_fail() {
+        // failed operators make the predicate false instead of returning their errors
+        alloc
+            .text("!super::refinement_holds(|| Ok(")
+            .append(predicate_doc)
+            .append("))")
+    } else if matches!(predicate, ValueExpression::OpCall(OpCall::Binary(..))) {
+        alloc.text("!").append(predicate_doc)
+    } else {
+        alloc.text("!(").append(predicate_doc).append(")")
+    };
+    let error = alloc
+        .text("super::ConstructorError::RefinementViolated(\"")
+        .append(name.to_string())
+        .append("\")");
+    let error = if is_deserialization {
+        alloc
+            .text("super::DeserializeError::ConstructorError(")
+            .append(error)
+            .append(")")
+    } else {
+        error
+    };
+    alloc
+        .text("if ")
+        .append(condition)
+        .append(" {")
+        .append(
+            alloc
+                .hardline()
+                .append("return Err(")
+                .append(error)
+                .append(");")
+                .nest(NEST_UNIT)
+                .append(alloc.hardline()),
+        )
+        .append("}")
+        .append(alloc.hardline())
+        .into_doc()
+}
+
 impl<'a> ValueExpression {
+    /// Whether generated value returns errors of operators by `?`.
+    fn can_fail(&self) -> bool {
+        match self {
+            ValueExpression::OpCall(OpCall::Literal(_)) => false,
+            ValueExpression::OpCall(OpCall::Unary(UnaryOp::Minus, operand)) => {
+                !matches!(**operand, ValueExpression::OpCall(OpCall::Literal(_)))
+            }
+            ValueExpression::OpCall(OpCall::Unary(_, operand)) => operand.can_fail(),
+            ValueExpression::OpCall(OpCall::Binary(
+                BinaryOp::Plus
+                | BinaryOp::Minus
+                | BinaryOp::Star
+                | BinaryOp::Slash
+                | BinaryOp::Percent,
+                _,
+                _,
+            )) => true,
+            ValueExpression::OpCall(OpCall::Binary(_, lhs, rhs) | OpCall::Concat(lhs, rhs)) => {
+                lhs.can_fail() || rhs.can_fail()
+            }
+            ValueExpression::Constructor {
+                implicits,
+                arguments,
+                ..
+            } => implicits
+                .iter()
+                .chain(arguments)
+                .any(ValueExpression::can_fail),
+            ValueExpression::Variable(_) | ValueExpression::Type(_) => false,
+        }
+    }
+
+    /// The pattern if it starts with a literal or enum constructor,
+    /// which tells apart values of dependencies.
+    fn pattern_head(&self) -> Option<&Self> {
+        match self {
+            ValueExpression::OpCall(OpCall::Literal(_)) => Some(self),
+            ValueExpression::OpCall(OpCall::Unary(UnaryOp::Minus, operand)) => {
+                operand.pattern_head().map(|_| self)
+            }
+            ValueExpression::Constructor { call, .. } => {
+                let call = call.upgrade().expect("call to unknown constructor");
+                (call.result_type.get_type().kind == ast::TypeKind::Enum).then_some(self)
+            }
+            _ => None,
+        }
+    }
+
+    /// Generates pattern matching values that start with the same literal or
+    /// enum constructor as this pattern, see [`ValueExpression::pattern_head`].
+    fn generate_as_head_pattern<'cursor>(
+        &self,
+        (ctx, namespace): Context<
+            'a,
+            'cursor,
+            impl Cursor<&'cursor objects::GeneratedRustObject, ObjectId<'a>>,
+        >,
+    ) -> BoxDoc<'a> {
+        let alloc = ctx.alloc;
+        match self.pattern_head() {
+            Some(ValueExpression::OpCall(op_call)) => literal_as_pattern(op_call, alloc),
+            Some(ValueExpression::Constructor { call, .. }) => {
+                let call = call.upgrade().expect("call to unknown constructor");
+                let ty = call.result_type.get_type();
+                let (type_module_prefix, type_module_cursor) = ty
+                    .lookup_type_module((ctx, namespace))
+                    .expect("couldn't lookup type module");
+                let (body_type, body_type_cursor) = type_module_cursor
+                    .get_generated::<objects::Type>(ObjectId::from_name("Body".to_owned()))
+                    .expect("couldn't get generated Body type");
+                let (branch, _) = body_type_cursor
+                    .get_generated::<objects::Type>(ObjectId(
+                        NodeId::id_rc(&call),
+                        Tag::String("enum_branch"),
+                    ))
+                    .expect("couldn't get constructor enum branch");
+                type_module_prefix
+                    .append(body_type.to_doc(ctx))
+                    .append("::")
+                    .append(branch.to_doc(ctx))
+                    .append(" { .. }")
+            }
+            _ => alloc.text("_").into_doc(),
+        }
+    }
+
     #[allow(clippy::too_many_lines, reason = "??? (119/100)")]
     fn generate_as_pattern(
         &self,
@@ -2782,6 +5268,7 @@ impl<'a> ValueExpression {
                     ));
                 (variable.to_doc(ctx), vec![])
             }
+            ValueExpression::Type(_) => panic!("type can not be used as pattern"),
         }
     }
 }
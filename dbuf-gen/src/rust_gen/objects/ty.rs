@@ -127,10 +127,12 @@ impl<'id> Object<'id> for Type<'id> {
 }
 
 impl GeneratedType {
+    pub fn name(&self) -> String {
+        tag_format(self.name.clone(), self.tag)
+    }
+
     pub fn to_doc<'a>(&self, ctx: GlobalContext<'a>) -> BoxDoc<'a> {
-        ctx.alloc
-            .text(tag_format(self.name.clone(), self.tag))
-            .into_doc()
+        ctx.alloc.text(self.name()).into_doc()
     }
 }
 
@@ -1,8 +1,9 @@
 pub(super) use crate::{
     ast::{
-        self, BinaryOp, Constructor, Literal, Module, NodeId, OpCall, Symbol, Type, TypeExpression,
-        UnaryOp, ValueExpression,
+        self, Alias, BinaryOp, Constructor, Literal, Module, NodeId, OpCall, Str, Symbol, Type,
+        TypeExpression, UnaryOp, ValueExpression,
     },
+    codegen::{Encoding, Options},
     format::{BoxDoc, DocAllocator, DocBuilder, NEST_UNIT},
     generate::lookup::{Cursor, NodeCursor},
 };
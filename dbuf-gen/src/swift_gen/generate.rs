@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Write as _;
 
 use crate::ast;
@@ -6,18 +7,78 @@ use crate::ast::Module;
 /// Accumulate Swift code as an UTF-8 string – simple and fast for the needs
 /// of canonicalisation.
 pub fn generate_module(module: &Module) -> String {
-    let mut code = String::new();
-    code.push_str("import Foundation\n\n");
-
+    let mut types = String::new();
     for ty_rc in &module.types {
         let ty = ty_rc.as_ref();
-        code.push_str(&generate_type(ty));
-        code.push('\n');
+        types.push_str(&generate_type(ty));
+        types.push('\n');
+    }
+    for alias in &module.aliases {
+        fill_alias(&mut types, alias);
     }
 
+    let mut code = String::new();
+    code.push_str("import Foundation\n\n");
+    code.push_str(&wrap_in_package(&module.package, &types));
     code
 }
 
+/// Nests `code` into extension of namespace enum of `package`. Packages of several
+/// files share namespace enums, so they are declared once by [`generate_namespaces`].
+fn wrap_in_package(package: &[ast::Str], code: &str) -> String {
+    if package.is_empty() {
+        return code.to_owned();
+    }
+    let namespace: Vec<String> = package.iter().map(ToString::to_string).collect();
+
+    let mut s = String::new();
+    writeln!(s, "extension {} {{", namespace.join(".")).expect("Writing into String is always ok");
+    for line in code.trim_end().lines() {
+        if !line.is_empty() {
+            s.push_str("    ");
+            s.push_str(line);
+        }
+        s.push('\n');
+    }
+    s.push_str("}\n");
+    s
+}
+
+/// Namespace enum of package segment with enums of nested segments.
+#[derive(Default)]
+struct Namespace(BTreeMap<String, Namespace>);
+
+impl Namespace {
+    fn fill(&self, s: &mut String, indent: usize) {
+        for (name, nested) in &self.0 {
+            write!(s, "{:indent$}public enum {name} {{", "")
+                .expect("Writing into String is always ok");
+            if nested.0.is_empty() {
+                s.push_str("}\n");
+            } else {
+                s.push('\n');
+                nested.fill(s, indent + 4);
+                writeln!(s, "{:indent$}}}", "").expect("Writing into String is always ok");
+            }
+        }
+    }
+}
+
+/// Declares namespace enums of `packages` and their prefixes, each one once.
+pub fn generate_namespaces<'p>(packages: impl IntoIterator<Item = &'p [ast::Str]>) -> String {
+    let mut root = Namespace::default();
+    for package in packages {
+        let mut namespace = &mut root;
+        for segment in package {
+            namespace = namespace.0.entry(segment.to_string()).or_default();
+        }
+    }
+
+    let mut s = String::new();
+    root.fill(&mut s, 0);
+    s
+}
+
 fn generate_type(ty: &ast::Type) -> String {
     let mut s = String::new();
 
@@ -45,6 +106,11 @@ fn generate_type(ty: &ast::Type) -> String {
     // Serialization helpers
     fill_serialization_helpers(&mut s, ty);
 
+    // Decoding of absent fields with defaults
+    if ty.constructors.iter().any(|ctor| !ctor.defaults.is_empty()) {
+        fill_defaults_decoding(&mut s, ty);
+    }
+
     // Close struct
     s.push_str("    }\n");
 
@@ -176,9 +242,58 @@ fn fill_constructor_functions(s: &mut String, ty: &ast::Type, body_name: &str) {
         )
         .expect("Writing into String is always ok");
         s.push_str("        }\n\n");
+
+        if !constructor.defaults.is_empty() {
+            fill_defaults_overload(s, ty, constructor, &func_name);
+        }
     }
 }
 
+/// Overload of constructor function without defaulted fields.
+///
+/// Swift default arguments can not refer to other parameters, so defaults
+/// are computed in the body instead.
+fn fill_defaults_overload(
+    s: &mut String,
+    ty: &ast::Type,
+    constructor: &ast::Constructor,
+    func_name: &str,
+) {
+    let params = constructor
+        .implicits
+        .iter()
+        .chain(
+            constructor
+                .fields
+                .iter()
+                .filter(|field| constructor.default_of(field).is_none()),
+        )
+        .map(|param| format!("{}: {}", param.name, type_expr_to_swift(&param.ty)))
+        .collect::<Vec<_>>();
+    writeln!(
+        s,
+        "        public static func {func_name}({}) -> {} {{",
+        params.join(", "),
+        ty.name
+    )
+    .expect("Writing into String is always ok");
+
+    let args = constructor
+        .implicits
+        .iter()
+        .map(|imp| format!("{0}: {0}", imp.name))
+        .chain(constructor.fields.iter().map(|field| {
+            constructor.default_of(field).map_or_else(
+                || format!("{0}: {0}", field.name),
+                |default| format!("{}: {}", field.name, value_expr_to_swift(default)),
+            )
+        }))
+        .collect::<Vec<_>>();
+    writeln!(s, "            return {func_name}({})", args.join(", "))
+        .expect("Writing into String is always ok");
+    s.push_str("        }\n\n");
+}
+
 fn fill_serialization_helpers(s: &mut String, ty: &ast::Type) {
     s.push_str("        public func serialize() -> Data {\n");
     s.push_str("            return try! JSONEncoder().encode(self)\n");
@@ -194,6 +309,183 @@ fn fill_serialization_helpers(s: &mut String, ty: &ast::Type) {
     s.push_str("        }\n");
 }
 
+/// Decoder of type with fields with defaults, which are absent in encodings
+/// older than the fields.
+///
+/// Keys are the ones of synthesized `Codable`, so encoding is synthesized still.
+/// Defaults see only implicits bound to dependencies as a whole, so fields whose
+/// defaults use other implicits are required. Decoder replaces memberwise
+/// initializer, which is declared explicitly.
+fn fill_defaults_decoding(s: &mut String, ty: &ast::Type) {
+    s.push_str("\n        public init(body: Body, dependencies: Dependencies) {\n");
+    s.push_str("            self.body = body\n");
+    s.push_str("            self.dependencies = dependencies\n");
+    s.push_str("        }\n\n");
+
+    s.push_str("        private enum Keys: String, CodingKey {\n");
+    s.push_str("            case body, dependencies\n");
+    s.push_str("        }\n\n");
+
+    let case_names = ty
+        .constructors
+        .iter()
+        .map(|ctor| ctor.name.to_string().to_lowercase())
+        .collect::<Vec<_>>();
+    s.push_str("        private enum BodyKeys: String, CodingKey {\n");
+    writeln!(s, "            case {}", case_names.join(", "))
+        .expect("Writing into String is always ok");
+    s.push_str("        }\n\n");
+
+    for constructor in ty
+        .constructors
+        .iter()
+        .filter(|ctor| !ctor.fields.is_empty())
+    {
+        let fields = constructor
+            .fields
+            .iter()
+            .map(|field| field.name.to_string())
+            .collect::<Vec<_>>();
+        writeln!(
+            s,
+            "        private enum {}FieldKeys: String, CodingKey {{",
+            constructor.name
+        )
+        .expect("Writing into String is always ok");
+        writeln!(s, "            case {}", fields.join(", "))
+            .expect("Writing into String is always ok");
+        s.push_str("        }\n\n");
+    }
+
+    s.push_str("        public init(from decoder: Decoder) throws {\n");
+    s.push_str("            let container = try decoder.container(keyedBy: Keys.self)\n");
+    s.push_str(
+        "            let dependencies = try container.decode(Dependencies.self, forKey: .dependencies)\n",
+    );
+    s.push_str(
+        "            let body = try container.nestedContainer(keyedBy: BodyKeys.self, forKey: .body)\n",
+    );
+    for (constructor, case_name) in ty.constructors.iter().zip(&case_names) {
+        fill_constructor_decoding(s, ty, constructor, case_name);
+    }
+    s.push_str(
+        "            throw DecodingError.dataCorruptedError(forKey: .body, in: container, debugDescription: \"unknown constructor\")\n",
+    );
+    s.push_str("        }\n");
+}
+
+fn fill_constructor_decoding(
+    s: &mut String,
+    ty: &ast::Type,
+    constructor: &ast::Constructor,
+    case_name: &str,
+) {
+    writeln!(s, "            if body.contains(.{case_name}) {{")
+        .expect("Writing into String is always ok");
+
+    // implicits bound to dependencies as a whole, by names of dependencies
+    let dependency_exprs = match &constructor.result_type {
+        ast::TypeExpression::Type { dependencies, .. } => dependencies,
+    };
+    let bound = constructor
+        .implicits
+        .iter()
+        .filter_map(|implicit| {
+            let index = dependency_exprs.iter().position(|expr| {
+                matches!(expr, ast::ValueExpression::Variable(variable)
+                    if variable.upgrade().is_some_and(|v| v.name == implicit.name))
+            })?;
+            Some((implicit.name.clone(), ty.dependencies[index].name.clone()))
+        })
+        .collect::<Vec<_>>();
+    let defaults = constructor
+        .fields
+        .iter()
+        .filter_map(|field| Some((field, constructor.default_of(field)?)))
+        .filter(|(_, default)| {
+            constructor.implicits.iter().all(|implicit| {
+                !uses(default, &implicit.name)
+                    || bound.iter().any(|(name, _)| *name == implicit.name)
+            })
+        })
+        .collect::<Vec<_>>();
+    for (implicit, dependency) in &bound {
+        if defaults.iter().any(|(_, default)| uses(default, implicit)) {
+            writeln!(
+                s,
+                "                let {implicit} = dependencies.{dependency}"
+            )
+            .expect("Writing into String is always ok");
+        }
+    }
+
+    if constructor.fields.is_empty() {
+        writeln!(
+            s,
+            "                self.init(body: .{case_name}, dependencies: dependencies)"
+        )
+        .expect("Writing into String is always ok");
+    } else {
+        writeln!(
+            s,
+            "                let fields = try body.nestedContainer(keyedBy: {}FieldKeys.self, forKey: .{case_name})",
+            constructor.name
+        )
+        .expect("Writing into String is always ok");
+        let args = constructor
+            .fields
+            .iter()
+            .map(|field| {
+                let ty = type_expr_to_swift(&field.ty);
+                match defaults
+                    .iter()
+                    .find(|(defaulted, _)| defaulted.name == field.name)
+                {
+                    Some((_, default)) => format!(
+                        "{0}: try fields.decodeIfPresent({ty}.self, forKey: .{0}) ?? {1}",
+                        field.name,
+                        value_expr_to_swift(default)
+                    ),
+                    None => format!(
+                        "{0}: try fields.decode({ty}.self, forKey: .{0})",
+                        field.name
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(
+            s,
+            "                self.init(body: .{case_name}({}), dependencies: dependencies)",
+            args.join(", ")
+        )
+        .expect("Writing into String is always ok");
+    }
+    s.push_str("                return\n");
+    s.push_str("            }\n");
+}
+
+/// Whether `expr` refers to variable `name`.
+fn uses(expr: &ast::ValueExpression, name: &ast::Str) -> bool {
+    match expr {
+        ast::ValueExpression::Variable(variable) => variable
+            .upgrade()
+            .is_some_and(|variable| variable.name == *name),
+        ast::ValueExpression::Constructor {
+            implicits,
+            arguments,
+            ..
+        } => implicits.iter().chain(arguments).any(|arg| uses(arg, name)),
+        ast::ValueExpression::OpCall(op_call) => match op_call {
+            ast::OpCall::Literal(_) => false,
+            ast::OpCall::Unary(_, arg) => uses(arg, name),
+            ast::OpCall::Binary(_, lhs, rhs) | ast::OpCall::Concat(lhs, rhs) => {
+                uses(lhs, name) || uses(rhs, name)
+            }
+        },
+        ast::ValueExpression::Type(_) => false,
+    }
+}
+
 fn fill_typealias(s: &mut String, ty: &ast::Type, module_name: &str) {
     writeln!(
         s,
@@ -203,11 +495,27 @@ fn fill_typealias(s: &mut String, ty: &ast::Type, module_name: &str) {
     .expect("Writing into String is always ok");
 }
 
+fn fill_alias(s: &mut String, alias: &ast::Alias) {
+    writeln!(
+        s,
+        "public typealias {} = {}",
+        alias.name,
+        type_expr_to_swift(&alias.target)
+    )
+    .expect("Writing into String is always ok");
+}
+
 fn type_expr_to_swift(expr: &ast::TypeExpression) -> String {
+    if let Some(element) = expr.sequence_element() {
+        return format!("[{}]", type_expr_to_swift(element));
+    }
     match expr {
         ast::TypeExpression::Type { call, .. } => {
             let ty = call.upgrade().expect("dangling reference to type");
-            ty.name.to_string()
+            if ty.is_builtin && ty.name.as_ref() == ast::BYTES_NAME {
+                return "Data".to_owned();
+            }
+            ty.qualified_name()
         }
     }
 }
@@ -223,7 +531,7 @@ fn value_expr_to_swift(expr: &ast::ValueExpression) -> String {
             arguments,
         } => {
             let ctor = call.upgrade().expect("dangling constructor");
-            let ty_name = ctor.result_type.get_type().name.clone();
+            let ty_name = ctor.result_type.get_type().qualified_name();
             let mut res = format!(
                 "{}.{name}(",
                 ty_name,
@@ -249,15 +557,36 @@ fn value_expr_to_swift(expr: &ast::ValueExpression) -> String {
             res.push(')');
             res
         }
+        ast::ValueExpression::Type(_) => panic!("type can not be used as value"),
         ast::ValueExpression::OpCall(op) => match op {
             ast::OpCall::Literal(lit) => match lit {
                 ast::Literal::Int(i) => i.to_string(),
                 ast::Literal::Str(s) => format!("\"{s}\""),
                 ast::Literal::Bool(b) => b.to_string(),
                 ast::Literal::UInt(u) => u.to_string(),
+                ast::Literal::Sized(n) => n.value().to_string(),
+                ast::Literal::Double(d) => format!("{:?}", d.0),
+                ast::Literal::Bytes(b) => format!("Data({b:?})"),
             },
+            ast::OpCall::Unary(ast::UnaryOp::Length, expr) => {
+                format!("UInt({}.count)", value_expr_to_swift(expr))
+            }
+            ast::OpCall::Unary(ast::UnaryOp::StringLength, expr) => {
+                format!("UInt({}.utf8.count)", value_expr_to_swift(expr))
+            }
             ast::OpCall::Unary(_, expr) => format!("-{}", value_expr_to_swift(expr)),
-            ast::OpCall::Binary(_, lhs, rhs) => format!(
+            ast::OpCall::Binary(ast::BinaryOp::StartsWith, lhs, rhs) => format!(
+                "{}.hasPrefix({})",
+                value_expr_to_swift(lhs),
+                value_expr_to_swift(rhs)
+            ),
+            ast::OpCall::Binary(op, lhs, rhs) if op.is_comparison() || op.is_opaque() => format!(
+                "({} {} {})",
+                value_expr_to_swift(lhs),
+                op.symbol(),
+                value_expr_to_swift(rhs)
+            ),
+            ast::OpCall::Binary(_, lhs, rhs) | ast::OpCall::Concat(lhs, rhs) => format!(
                 "({} + {})",
                 value_expr_to_swift(lhs),
                 value_expr_to_swift(rhs)
@@ -1,5 +1,6 @@
 use crate::ast;
 use crate::ast::Str;
+use crate::import::Import;
 
 mod generate;
 
@@ -13,8 +14,31 @@ mod generate;
 /// replaced by a full-featured backend later.
 #[must_use]
 pub fn generate_module(module: &ast::elaborated::Module<Str>) -> String {
+    generate_module_with_imports(module, &[])
+}
+
+/// Generate Swift source code for module whose types may reference types of
+/// `imports`. Imported types live in the same Swift module, generated from
+/// their own files, so only types of `module` are emitted.
+#[must_use]
+pub fn generate_module_with_imports(
+    module: &ast::elaborated::Module<Str>,
+    imports: &[Import<'_>],
+) -> String {
     // Convert the elaborated AST used by the type-checker into the internal
     // representation expected by the generators.
-    let module = ast::Module::from_elaborated(module);
+    let module = ast::Module::from_elaborated_with_imports(module, imports);
     generate::generate_module(&module)
 }
+
+/// Generate Swift source code declaring namespace enums of packages of `modules`.
+///
+/// Code generated for a module in a package extends namespace enum of the package,
+/// which must be declared exactly once in the Swift module, so declarations go
+/// into a file of their own.
+#[must_use]
+pub fn generate_namespaces<'m>(
+    modules: impl IntoIterator<Item = &'m ast::elaborated::Module<Str>>,
+) -> String {
+    generate::generate_namespaces(modules.into_iter().map(|module| module.package.as_slice()))
+}
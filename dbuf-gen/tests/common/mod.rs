@@ -19,17 +19,21 @@ use indexmap::IndexMap;
 
 pub fn empty() -> e::Module<InternedString> {
     e::Module {
+        package: vec![],
         types: IndexMap::new(),
         constructors: vec![].into_iter().collect(),
+        aliases: IndexMap::new(),
     }
 }
 #[must_use]
 pub fn nat() -> e::Module<InternedString> {
     e::Module {
+        package: vec![],
         types: vec![(
             "Nat".to_owned().into(),
             e::Type {
                 dependencies: Vec::new(),
+                refinements: Vec::new(),
                 constructor_names: e::ConstructorNames::OfEnum(
                     ["Zero", "Suc"]
                         .into_iter()
@@ -47,10 +51,12 @@ pub fn nat() -> e::Module<InternedString> {
                 e::Constructor {
                     implicits: Vec::new(),
                     fields: Vec::new(),
+                    refinements: Vec::new(),
                     result_type: e::TypeExpression::TypeExpression {
                         name: "Nat".to_owned().into(),
                         dependencies: e::Rec::new([]),
                     },
+                    defaults: Vec::new(),
                 },
             ),
             (
@@ -64,20 +70,84 @@ pub fn nat() -> e::Module<InternedString> {
                             dependencies: e::Rec::new([]),
                         },
                     )],
+                    refinements: Vec::new(),
                     result_type: e::TypeExpression::TypeExpression {
                         name: "Nat".to_owned().into(),
                         dependencies: e::Rec::new([]),
                     },
+                    defaults: Vec::new(),
                 },
             ),
         ]
         .into_iter()
         .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+#[must_use]
+pub fn packaged_nat() -> e::Module<InternedString> {
+    e::Module {
+        package: vec!["acme".to_owned().into(), "math".to_owned().into()],
+        types: vec![(
+            "acme.math.Nat".to_owned().into(),
+            e::Type {
+                dependencies: Vec::new(),
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfEnum(
+                    ["acme.math.Zero", "acme.math.Suc"]
+                        .into_iter()
+                        .map(std::borrow::ToOwned::to_owned)
+                        .map(InternedString::from)
+                        .collect(),
+                ),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![
+            (
+                "acme.math.Zero".to_owned().into(),
+                e::Constructor {
+                    implicits: Vec::new(),
+                    fields: Vec::new(),
+                    refinements: Vec::new(),
+                    result_type: e::TypeExpression::TypeExpression {
+                        name: "acme.math.Nat".to_owned().into(),
+                        dependencies: e::Rec::new([]),
+                    },
+                    defaults: Vec::new(),
+                },
+            ),
+            (
+                "acme.math.Suc".to_owned().into(),
+                e::Constructor {
+                    implicits: Vec::new(),
+                    fields: vec![(
+                        "pred".to_owned().into(),
+                        e::TypeExpression::TypeExpression {
+                            name: "acme.math.Nat".to_owned().into(),
+                            dependencies: e::Rec::new([]),
+                        },
+                    )],
+                    refinements: Vec::new(),
+                    result_type: e::TypeExpression::TypeExpression {
+                        name: "acme.math.Nat".to_owned().into(),
+                        dependencies: e::Rec::new([]),
+                    },
+                    defaults: Vec::new(),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
     }
 }
 
+#[allow(clippy::too_many_lines, reason = "??? (102/100)")]
 pub fn vec() -> e::Module<InternedString> {
     e::Module {
+        package: vec![],
         types: vec![(
             "Vec".to_owned().into(),
             e::Type {
@@ -88,6 +158,7 @@ pub fn vec() -> e::Module<InternedString> {
                         dependencies: e::Rec::new([]),
                     },
                 )],
+                refinements: Vec::new(),
                 constructor_names: e::ConstructorNames::OfEnum(
                     ["Nil", "Cons"]
                         .into_iter()
@@ -105,6 +176,7 @@ pub fn vec() -> e::Module<InternedString> {
                 e::Constructor {
                     implicits: Vec::new(),
                     fields: Vec::new(),
+                    refinements: Vec::new(),
                     result_type: e::TypeExpression::TypeExpression {
                         name: "Vec".to_owned().into(),
                         dependencies: e::Rec::new([e::ValueExpression::Constructor {
@@ -117,6 +189,7 @@ pub fn vec() -> e::Module<InternedString> {
                             },
                         }]),
                     },
+                    defaults: Vec::new(),
                 },
             ),
             (
@@ -151,6 +224,7 @@ pub fn vec() -> e::Module<InternedString> {
                             },
                         ),
                     ],
+                    refinements: Vec::new(),
                     result_type: e::TypeExpression::TypeExpression {
                         name: "Vec".to_owned().into(),
                         dependencies: e::Rec::new([e::ValueExpression::Constructor {
@@ -169,16 +243,19 @@ pub fn vec() -> e::Module<InternedString> {
                             },
                         }]),
                     },
+                    defaults: Vec::new(),
                 },
             ),
         ]
         .into_iter()
         .collect(),
+        aliases: IndexMap::new(),
     }
 }
 
 pub fn user() -> e::Module<InternedString> {
     e::Module {
+        package: vec![],
         types: vec![(
             "User".to_owned().into(),
             e::Type {
@@ -189,6 +266,7 @@ pub fn user() -> e::Module<InternedString> {
                         dependencies: e::Rec::new([]),
                     },
                 )],
+                refinements: Vec::new(),
                 constructor_names: e::ConstructorNames::OfMessage("User".to_owned().into()),
             },
         )]
@@ -220,6 +298,7 @@ pub fn user() -> e::Module<InternedString> {
                         },
                     ),
                 ],
+                refinements: Vec::new(),
                 result_type: e::TypeExpression::TypeExpression {
                     name: "User".to_owned().into(),
                     dependencies: e::Rec::new([e::ValueExpression::Variable {
@@ -230,15 +309,18 @@ pub fn user() -> e::Module<InternedString> {
                         },
                     }]),
                 },
+                defaults: Vec::new(),
             },
         )]
         .into_iter()
         .collect(),
+        aliases: IndexMap::new(),
     }
 }
 
 pub fn inventory() -> e::Module<InternedString> {
     e::Module {
+        package: vec![],
         types: vec![(
             "Inventory".to_owned().into(),
             e::Type {
@@ -249,6 +331,7 @@ pub fn inventory() -> e::Module<InternedString> {
                         dependencies: e::Rec::new([]),
                     },
                 )],
+                refinements: Vec::new(),
                 constructor_names: e::ConstructorNames::OfMessage("Inventory".to_owned().into()),
             },
         )]
@@ -286,6 +369,7 @@ pub fn inventory() -> e::Module<InternedString> {
                         },
                     ),
                 ],
+                refinements: Vec::new(),
                 result_type: e::TypeExpression::TypeExpression {
                     name: "Inventory".to_owned().into(),
                     dependencies: e::Rec::new([e::ValueExpression::Variable {
@@ -296,19 +380,23 @@ pub fn inventory() -> e::Module<InternedString> {
                         },
                     }]),
                 },
+                defaults: Vec::new(),
             },
         )]
         .into_iter()
         .collect(),
+        aliases: IndexMap::new(),
     }
 }
 
 pub fn builtin_message() -> e::Module<InternedString> {
     e::Module {
+        package: vec![],
         types: vec![(
             "Builtins".to_owned().into(),
             e::Type {
                 dependencies: vec![],
+                refinements: Vec::new(),
                 constructor_names: e::ConstructorNames::OfMessage("Builtins".to_owned().into()),
             },
         )]
@@ -348,14 +436,857 @@ pub fn builtin_message() -> e::Module<InternedString> {
                         },
                     ),
                 ],
+                refinements: Vec::new(),
                 result_type: e::TypeExpression::TypeExpression {
                     name: "Builtins".to_owned().into(),
                     dependencies: e::Rec::new([]),
                 },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn sequences() -> e::Module<InternedString> {
+    let uint = || e::TypeExpression::TypeExpression {
+        name: "UInt".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let sequence = |name: &str, element: &str, rest: Vec<e::ValueExpression<InternedString>>| {
+        e::TypeExpression::TypeExpression {
+            name: name.to_owned().into(),
+            dependencies: std::iter::once(e::ValueExpression::Type {
+                ty: e::TypeExpression::TypeExpression {
+                    name: element.to_owned().into(),
+                    dependencies: e::Rec::new([]),
+                },
+            })
+            .chain(rest)
+            .collect(),
+        }
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Batch".to_owned().into(),
+            e::Type {
+                dependencies: vec![("n".to_owned().into(), uint())],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Batch".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Batch".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("n".to_owned().into(), uint())],
+                fields: vec![
+                    ("values".to_owned().into(), sequence("List", "Int", vec![])),
+                    (
+                        "flags".to_owned().into(),
+                        sequence(
+                            "Array",
+                            "Bool",
+                            vec![e::ValueExpression::Variable {
+                                name: "n".to_owned().into(),
+                                ty: uint(),
+                            }],
+                        ),
+                    ),
+                ],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Batch".to_owned().into(),
+                    dependencies: e::Rec::new([e::ValueExpression::Variable {
+                        name: "n".to_owned().into(),
+                        ty: uint(),
+                    }]),
+                },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn bytes() -> e::Module<InternedString> {
+    let bytes = || e::TypeExpression::TypeExpression {
+        name: "Bytes".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Blob".to_owned().into(),
+            e::Type {
+                dependencies: vec![("tag".to_owned().into(), bytes())],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Blob".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Blob".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("tag".to_owned().into(), bytes())],
+                fields: vec![("payload".to_owned().into(), bytes())],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Blob".to_owned().into(),
+                    dependencies: e::Rec::new([e::ValueExpression::Variable {
+                        name: "tag".to_owned().into(),
+                        ty: bytes(),
+                    }]),
+                },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn doubles() -> e::Module<InternedString> {
+    let double = || e::TypeExpression::TypeExpression {
+        name: "Double".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Scaled".to_owned().into(),
+            e::Type {
+                dependencies: vec![("factor".to_owned().into(), double())],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Scaled".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Scaled".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("factor".to_owned().into(), double())],
+                fields: vec![("value".to_owned().into(), double())],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Scaled".to_owned().into(),
+                    dependencies: e::Rec::new([e::ValueExpression::Variable {
+                        name: "factor".to_owned().into(),
+                        ty: double(),
+                    }]),
+                },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn sized_ints() -> e::Module<InternedString> {
+    let builtin = |name: &str| e::TypeExpression::TypeExpression {
+        name: name.to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Reading".to_owned().into(),
+            e::Type {
+                dependencies: vec![("channel".to_owned().into(), builtin("UInt8"))],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Reading".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Reading".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("channel".to_owned().into(), builtin("UInt8"))],
+                fields: vec![
+                    ("delta".to_owned().into(), builtin("Int16")),
+                    ("count".to_owned().into(), builtin("UInt32")),
+                    ("stamp".to_owned().into(), builtin("Int64")),
+                ],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Reading".to_owned().into(),
+                    dependencies: e::Rec::new([e::ValueExpression::Variable {
+                        name: "channel".to_owned().into(),
+                        ty: builtin("UInt8"),
+                    }]),
+                },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+#[must_use]
+pub fn refinements() -> e::Module<InternedString> {
+    use dbuf_core::ast::operators::{BinaryOp, Literal, OpCall};
+
+    let uint = || e::TypeExpression::TypeExpression {
+        name: "UInt".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let bool_expr = |op, lhs, rhs| e::ValueExpression::OpCall {
+        op_call: OpCall::Binary(op, e::Rec::new(lhs), e::Rec::new(rhs)),
+        result_type: e::TypeExpression::TypeExpression {
+            name: "Bool".to_owned().into(),
+            dependencies: e::Rec::new([]),
+        },
+    };
+    let variable = |name: &str| e::ValueExpression::Variable {
+        name: name.to_owned().into(),
+        ty: uint(),
+    };
+    let zero = e::ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::UInt(0)),
+        result_type: uint(),
+    };
+    let limit_refinement = (
+        "limit".to_owned().into(),
+        bool_expr(BinaryOp::Less, zero, variable("limit")),
+    );
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Bounded".to_owned().into(),
+            e::Type {
+                dependencies: vec![("limit".to_owned().into(), uint())],
+                refinements: vec![limit_refinement.clone()],
+                constructor_names: e::ConstructorNames::OfMessage("Bounded".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Bounded".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("limit".to_owned().into(), uint())],
+                fields: vec![
+                    ("used".to_owned().into(), uint()),
+                    ("spare".to_owned().into(), uint()),
+                ],
+                refinements: vec![
+                    limit_refinement,
+                    (
+                        "used".to_owned().into(),
+                        bool_expr(BinaryOp::LessEq, variable("used"), variable("limit")),
+                    ),
+                    (
+                        "spare".to_owned().into(),
+                        bool_expr(
+                            BinaryOp::LessEq,
+                            e::ValueExpression::OpCall {
+                                op_call: OpCall::Binary(
+                                    BinaryOp::Plus,
+                                    e::Rec::new(variable("used")),
+                                    e::Rec::new(variable("spare")),
+                                ),
+                                result_type: uint(),
+                            },
+                            variable("limit"),
+                        ),
+                    ),
+                ],
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Bounded".to_owned().into(),
+                    dependencies: e::Rec::new([variable("limit")]),
+                },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+#[must_use]
+pub fn derived() -> e::Module<InternedString> {
+    // message Range (start UInt) {
+    //     length UInt;
+    //     end UInt where end == start + length;
+    // }
+    use dbuf_core::ast::operators::{BinaryOp, OpCall};
+
+    let uint = || e::TypeExpression::TypeExpression {
+        name: "UInt".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let variable = |name: &str| e::ValueExpression::Variable {
+        name: name.to_owned().into(),
+        ty: uint(),
+    };
+    let end = e::ValueExpression::OpCall {
+        op_call: OpCall::Binary(
+            BinaryOp::Plus,
+            e::Rec::new(variable("start")),
+            e::Rec::new(variable("length")),
+        ),
+        result_type: uint(),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Range".to_owned().into(),
+            e::Type {
+                dependencies: vec![("start".to_owned().into(), uint())],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Range".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Range".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("start".to_owned().into(), uint())],
+                fields: vec![
+                    ("length".to_owned().into(), uint()),
+                    ("end".to_owned().into(), uint()),
+                ],
+                refinements: vec![(
+                    "end".to_owned().into(),
+                    e::ValueExpression::OpCall {
+                        op_call: OpCall::Binary(
+                            BinaryOp::Equal,
+                            e::Rec::new(variable("end")),
+                            e::Rec::new(end),
+                        ),
+                        result_type: e::TypeExpression::TypeExpression {
+                            name: "Bool".to_owned().into(),
+                            dependencies: e::Rec::new([]),
+                        },
+                    },
+                )],
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Range".to_owned().into(),
+                    dependencies: e::Rec::new([variable("start")]),
+                },
+                defaults: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+#[must_use]
+pub fn integer_ops() -> e::Module<InternedString> {
+    // message Layout (bits UInt) (offset Int8) {
+    //     bytes UInt = bits / 8u;
+    //     rest UInt = bits % 8u;
+    //     scaled Int8 = offset << 2i8;
+    //     halved Int8 = offset >> 1i8;
+    // }
+    use dbuf_core::ast::operators::{BinaryOp, Literal, OpCall, SizedInt};
+
+    let builtin = |name: &str| e::TypeExpression::TypeExpression {
+        name: name.to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let variable = |name: &str, ty| e::ValueExpression::Variable {
+        name: name.to_owned().into(),
+        ty: builtin(ty),
+    };
+    let literal = |literal, ty| e::ValueExpression::OpCall {
+        op_call: OpCall::Literal(literal),
+        result_type: builtin(ty),
+    };
+    let binary = |op, lhs, rhs, ty| e::ValueExpression::OpCall {
+        op_call: OpCall::Binary(op, e::Rec::new(lhs), e::Rec::new(rhs)),
+        result_type: builtin(ty),
+    };
+    let bits = || variable("bits", "UInt");
+    let offset = || variable("offset", "Int8");
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Layout".to_owned().into(),
+            e::Type {
+                dependencies: vec![
+                    ("bits".to_owned().into(), builtin("UInt")),
+                    ("offset".to_owned().into(), builtin("Int8")),
+                ],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Layout".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Layout".to_owned().into(),
+            e::Constructor {
+                implicits: vec![
+                    ("bits".to_owned().into(), builtin("UInt")),
+                    ("offset".to_owned().into(), builtin("Int8")),
+                ],
+                fields: vec![
+                    ("bytes".to_owned().into(), builtin("UInt")),
+                    ("rest".to_owned().into(), builtin("UInt")),
+                    ("scaled".to_owned().into(), builtin("Int8")),
+                    ("halved".to_owned().into(), builtin("Int8")),
+                ],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Layout".to_owned().into(),
+                    dependencies: e::Rec::new([bits(), offset()]),
+                },
+                defaults: vec![
+                    (
+                        "bytes".to_owned().into(),
+                        binary(
+                            BinaryOp::Slash,
+                            bits(),
+                            literal(Literal::UInt(8), "UInt"),
+                            "UInt",
+                        ),
+                    ),
+                    (
+                        "rest".to_owned().into(),
+                        binary(
+                            BinaryOp::Percent,
+                            bits(),
+                            literal(Literal::UInt(8), "UInt"),
+                            "UInt",
+                        ),
+                    ),
+                    (
+                        "scaled".to_owned().into(),
+                        binary(
+                            BinaryOp::ShiftLeft,
+                            offset(),
+                            literal(Literal::Sized(SizedInt::I8(2)), "Int8"),
+                            "Int8",
+                        ),
+                    ),
+                    (
+                        "halved".to_owned().into(),
+                        binary(
+                            BinaryOp::ShiftRight,
+                            offset(),
+                            literal(Literal::Sized(SizedInt::I8(1)), "Int8"),
+                            "Int8",
+                        ),
+                    ),
+                ],
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+#[must_use]
+pub fn defaults() -> e::Module<InternedString> {
+    // message Settings (version UInt) {
+    //     retries UInt = 3u;
+    //     level UInt = version + 1u;
+    // }
+    use dbuf_core::ast::operators::{BinaryOp, Literal, OpCall};
+
+    let uint = || e::TypeExpression::TypeExpression {
+        name: "UInt".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let literal = |value| e::ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::UInt(value)),
+        result_type: uint(),
+    };
+    let version = || e::ValueExpression::Variable {
+        name: "version".to_owned().into(),
+        ty: uint(),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Settings".to_owned().into(),
+            e::Type {
+                dependencies: vec![("version".to_owned().into(), uint())],
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Settings".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Settings".to_owned().into(),
+            e::Constructor {
+                implicits: vec![("version".to_owned().into(), uint())],
+                fields: vec![
+                    ("retries".to_owned().into(), uint()),
+                    ("level".to_owned().into(), uint()),
+                ],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Settings".to_owned().into(),
+                    dependencies: e::Rec::new([version()]),
+                },
+                defaults: vec![
+                    ("retries".to_owned().into(), literal(3)),
+                    (
+                        "level".to_owned().into(),
+                        e::ValueExpression::OpCall {
+                            op_call: OpCall::Binary(
+                                BinaryOp::Plus,
+                                e::Rec::new(version()),
+                                e::Rec::new(literal(1)),
+                            ),
+                            result_type: uint(),
+                        },
+                    ),
+                ],
+            },
+        )]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn profile() -> e::Module<InternedString> {
+    // message Profile {
+    //     settings Settings 1u;
+    //     id UInt;
+    // }
+    use dbuf_core::ast::operators::{Literal, OpCall};
+
+    let uint = || e::TypeExpression::TypeExpression {
+        name: "UInt".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![(
+            "Profile".to_owned().into(),
+            e::Type {
+                dependencies: Vec::new(),
+                refinements: Vec::new(),
+                constructor_names: e::ConstructorNames::OfMessage("Profile".to_owned().into()),
+            },
+        )]
+        .into_iter()
+        .collect(),
+        constructors: vec![(
+            "Profile".to_owned().into(),
+            e::Constructor {
+                implicits: Vec::new(),
+                fields: vec![
+                    (
+                        "settings".to_owned().into(),
+                        e::TypeExpression::TypeExpression {
+                            name: "Settings".to_owned().into(),
+                            dependencies: e::Rec::new([e::ValueExpression::OpCall {
+                                op_call: OpCall::Literal(Literal::UInt(1)),
+                                result_type: uint(),
+                            }]),
+                        },
+                    ),
+                    ("id".to_owned().into(), uint()),
+                ],
+                refinements: Vec::new(),
+                result_type: e::TypeExpression::TypeExpression {
+                    name: "Profile".to_owned().into(),
+                    dependencies: e::Rec::new([]),
+                },
+                defaults: Vec::new(),
             },
         )]
         .into_iter()
         .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn division() -> e::Module<InternedString> {
+    use dbuf_core::ast::operators::{BinaryOp, OpCall};
+
+    let uint = || e::TypeExpression::TypeExpression {
+        name: "UInt".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let variable = |name: &str| e::ValueExpression::Variable {
+        name: name.to_owned().into(),
+        ty: uint(),
+    };
+    let buffer = |size| e::TypeExpression::TypeExpression {
+        name: "Buffer".to_owned().into(),
+        dependencies: e::Rec::new([size]),
+    };
+    let quotient = e::ValueExpression::OpCall {
+        op_call: OpCall::Binary(
+            BinaryOp::Slash,
+            e::Rec::new(variable("bits")),
+            e::Rec::new(variable("divisor")),
+        ),
+        result_type: uint(),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![
+            (
+                "Buffer".to_owned().into(),
+                e::Type {
+                    dependencies: vec![("size".to_owned().into(), uint())],
+                    refinements: vec![],
+                    constructor_names: e::ConstructorNames::OfMessage("Buffer".to_owned().into()),
+                },
+            ),
+            (
+                "Packet".to_owned().into(),
+                e::Type {
+                    dependencies: vec![],
+                    refinements: vec![],
+                    constructor_names: e::ConstructorNames::OfMessage("Packet".to_owned().into()),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        constructors: vec![
+            (
+                "Buffer".to_owned().into(),
+                e::Constructor {
+                    implicits: vec![("size".to_owned().into(), uint())],
+                    fields: vec![],
+                    refinements: vec![],
+                    result_type: buffer(variable("size")),
+                    defaults: Vec::new(),
+                },
+            ),
+            (
+                "Packet".to_owned().into(),
+                e::Constructor {
+                    implicits: vec![],
+                    fields: vec![
+                        ("bits".to_owned().into(), uint()),
+                        ("divisor".to_owned().into(), uint()),
+                        ("payload".to_owned().into(), buffer(quotient)),
+                    ],
+                    refinements: vec![],
+                    result_type: e::TypeExpression::TypeExpression {
+                        name: "Packet".to_owned().into(),
+                        dependencies: e::Rec::new([]),
+                    },
+                    defaults: Vec::new(),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+pub fn strings() -> e::Module<InternedString> {
+    use dbuf_core::ast::operators::{BinaryOp, Literal, OpCall, UnaryOp};
+
+    let builtin = |name: &str| e::TypeExpression::TypeExpression {
+        name: name.to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let value = || e::ValueExpression::Variable {
+        name: "value".to_owned().into(),
+        ty: builtin("String"),
+    };
+    let length = e::ValueExpression::OpCall {
+        op_call: OpCall::Unary(
+            UnaryOp::Access("length".to_owned().into()),
+            e::Rec::new(value()),
+        ),
+        result_type: builtin("UInt"),
+    };
+    let prefix = e::ValueExpression::OpCall {
+        op_call: OpCall::Literal(Literal::Str("urn:".to_owned())),
+        result_type: builtin("String"),
+    };
+    let starts_with = e::ValueExpression::OpCall {
+        op_call: OpCall::Binary(
+            BinaryOp::StartsWith,
+            e::Rec::new(value()),
+            e::Rec::new(prefix),
+        ),
+        result_type: builtin("Bool"),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![
+            (
+                "Buffer".to_owned().into(),
+                e::Type {
+                    dependencies: vec![("size".to_owned().into(), builtin("UInt"))],
+                    refinements: vec![],
+                    constructor_names: e::ConstructorNames::OfMessage("Buffer".to_owned().into()),
+                },
+            ),
+            (
+                "Urn".to_owned().into(),
+                e::Type {
+                    dependencies: vec![],
+                    refinements: vec![],
+                    constructor_names: e::ConstructorNames::OfMessage("Urn".to_owned().into()),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        constructors: vec![
+            (
+                "Buffer".to_owned().into(),
+                e::Constructor {
+                    implicits: vec![("size".to_owned().into(), builtin("UInt"))],
+                    fields: vec![],
+                    refinements: vec![],
+                    result_type: e::TypeExpression::TypeExpression {
+                        name: "Buffer".to_owned().into(),
+                        dependencies: e::Rec::new([e::ValueExpression::Variable {
+                            name: "size".to_owned().into(),
+                            ty: builtin("UInt"),
+                        }]),
+                    },
+                    defaults: Vec::new(),
+                },
+            ),
+            (
+                "Urn".to_owned().into(),
+                e::Constructor {
+                    implicits: vec![],
+                    fields: vec![
+                        ("value".to_owned().into(), builtin("String")),
+                        (
+                            "payload".to_owned().into(),
+                            e::TypeExpression::TypeExpression {
+                                name: "Buffer".to_owned().into(),
+                                dependencies: e::Rec::new([length]),
+                            },
+                        ),
+                    ],
+                    refinements: vec![("value".to_owned().into(), starts_with)],
+                    result_type: builtin("Urn"),
+                    defaults: Vec::new(),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+/// enum Expr { Literal { value Int; } Block { body Stmt; result Expr; } }
+/// enum Stmt { Skip {} Assign { name String; value Expr; } }
+pub fn expr_stmt() -> e::Module<InternedString> {
+    let ty = |name: &str| e::TypeExpression::TypeExpression {
+        name: name.to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let constructor = |fields: &[(&str, &str)], result_type: &str| e::Constructor {
+        implicits: Vec::new(),
+        fields: fields
+            .iter()
+            .map(|&(name, field_type)| (name.to_owned().into(), ty(field_type)))
+            .collect(),
+        refinements: Vec::new(),
+        result_type: ty(result_type),
+        defaults: Vec::new(),
+    };
+    let of_enum = |names: &[&str]| e::Type {
+        dependencies: Vec::new(),
+        refinements: Vec::new(),
+        constructor_names: e::ConstructorNames::OfEnum(
+            names.iter().map(|&name| name.to_owned().into()).collect(),
+        ),
+    };
+
+    e::Module {
+        package: vec![],
+        types: vec![
+            ("Expr".to_owned().into(), of_enum(&["Literal", "Block"])),
+            ("Stmt".to_owned().into(), of_enum(&["Skip", "Assign"])),
+        ]
+        .into_iter()
+        .collect(),
+        constructors: vec![
+            (
+                "Literal".to_owned().into(),
+                constructor(&[("value", "Int")], "Expr"),
+            ),
+            (
+                "Block".to_owned().into(),
+                constructor(&[("body", "Stmt"), ("result", "Expr")], "Expr"),
+            ),
+            ("Skip".to_owned().into(), constructor(&[], "Stmt")),
+            (
+                "Assign".to_owned().into(),
+                constructor(&[("name", "String"), ("value", "Expr")], "Stmt"),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        aliases: IndexMap::new(),
+    }
+}
+
+#[must_use]
+pub fn aliases() -> e::Module<InternedString> {
+    // type Number = Nat;
+    // type Numbers = List Nat;
+    let nat = || e::TypeExpression::TypeExpression {
+        name: "Nat".to_owned().into(),
+        dependencies: e::Rec::new([]),
+    };
+    let alias = |target| e::Alias {
+        dependencies: Vec::new(),
+        refinements: Vec::new(),
+        target,
+    };
+
+    e::Module {
+        package: vec![],
+        types: IndexMap::new(),
+        constructors: vec![].into_iter().collect(),
+        aliases: vec![
+            ("Number".to_owned().into(), alias(nat())),
+            (
+                "Numbers".to_owned().into(),
+                alias(e::TypeExpression::TypeExpression {
+                    name: "List".to_owned().into(),
+                    dependencies: e::Rec::new([e::ValueExpression::Type { ty: nat() }]),
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect(),
     }
 }
 
@@ -378,6 +1309,71 @@ pub fn get_inventory_module() -> e::Module<InternedString> {
     create_module(vec![nat(), vec(), inventory()])
 }
 
+#[must_use]
+pub fn get_sequences_module() -> e::Module<InternedString> {
+    create_module(vec![sequences()])
+}
+
+#[must_use]
+pub fn get_bytes_module() -> e::Module<InternedString> {
+    create_module(vec![bytes()])
+}
+
+#[must_use]
+pub fn get_doubles_module() -> e::Module<InternedString> {
+    create_module(vec![doubles()])
+}
+
+#[must_use]
+pub fn get_sized_ints_module() -> e::Module<InternedString> {
+    create_module(vec![sized_ints()])
+}
+
+#[must_use]
+pub fn get_refinements_module() -> e::Module<InternedString> {
+    create_module(vec![refinements()])
+}
+
+#[must_use]
+pub fn get_division_module() -> e::Module<InternedString> {
+    create_module(vec![division()])
+}
+
+#[must_use]
+pub fn get_strings_module() -> e::Module<InternedString> {
+    create_module(vec![strings()])
+}
+
+#[must_use]
+pub fn get_expr_stmt_module() -> e::Module<InternedString> {
+    create_module(vec![expr_stmt()])
+}
+
+#[must_use]
+pub fn get_defaults_module() -> e::Module<InternedString> {
+    create_module(vec![defaults()])
+}
+
+#[must_use]
+pub fn get_nested_defaults_module() -> e::Module<InternedString> {
+    create_module(vec![defaults(), profile()])
+}
+
+#[must_use]
+pub fn get_derived_module() -> e::Module<InternedString> {
+    create_module(vec![nat(), vec(), derived()])
+}
+
+#[must_use]
+pub fn get_integer_ops_module() -> e::Module<InternedString> {
+    create_module(vec![integer_ops()])
+}
+
+#[must_use]
+pub fn get_aliases_module() -> e::Module<InternedString> {
+    create_module(vec![nat(), aliases()])
+}
+
 fn create_module(list: Vec<e::Module<InternedString>>) -> e::Module<InternedString> {
     list.into_iter().fold(empty(), |acc, item| {
         dbuf_core::ast::elaborated::Module::merge(&acc, item)
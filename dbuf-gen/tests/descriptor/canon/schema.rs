@@ -0,0 +1,3446 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod binaryop {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    mod descriptor {
+        pub(super) const BinaryAnd: u8 = 0;
+        pub(super) const BinaryOr: u8 = 1;
+        pub(super) const Caret: u8 = 2;
+        pub(super) const Equal: u8 = 3;
+        pub(super) const Greater: u8 = 4;
+        pub(super) const GreaterEq: u8 = 5;
+        pub(super) const Less: u8 = 6;
+        pub(super) const LessEq: u8 = 7;
+        pub(super) const Minus: u8 = 8;
+        pub(super) const NotEqual: u8 = 9;
+        pub(super) const Percent: u8 = 10;
+        pub(super) const Plus: u8 = 11;
+        pub(super) const ShiftLeft: u8 = 12;
+        pub(super) const ShiftRight: u8 = 13;
+        pub(super) const Slash: u8 = 14;
+        pub(super) const Star: u8 = 15;
+        pub(super) const StartsWith: u8 = 16;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        BinaryAnd {
+        
+        },
+        BinaryOr {
+        
+        },
+        Caret {
+        
+        },
+        Equal {
+        
+        },
+        Greater {
+        
+        },
+        GreaterEq {
+        
+        },
+        Less {
+        
+        },
+        LessEq {
+        
+        },
+        Minus {
+        
+        },
+        NotEqual {
+        
+        },
+        Percent {
+        
+        },
+        Plus {
+        
+        },
+        ShiftLeft {
+        
+        },
+        ShiftRight {
+        
+        },
+        Slash {
+        
+        },
+        Star {
+        
+        },
+        StartsWith {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BinaryOp {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl BinaryOp {
+        pub fn binaryand() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::BinaryAnd {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn binaryor() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::BinaryOr {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn caret() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Caret {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn equal() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Equal {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn greater() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Greater {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn greatereq() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::GreaterEq {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn less() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Less {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn lesseq() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::LessEq {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn minus() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Minus {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn notequal() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::NotEqual {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn percent() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Percent {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn plus() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Plus {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn shiftleft() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::ShiftLeft {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn shiftright() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::ShiftRight {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn slash() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Slash {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn star() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Star {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn startswith() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::StartsWith {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::BinaryAnd => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::BinaryOr => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Caret => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Equal => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Greater => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::GreaterEq => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Less => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::LessEq => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Minus => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::NotEqual => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Percent => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Plus => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::ShiftLeft => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::ShiftRight => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Slash => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Star => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::StartsWith => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for BinaryOp {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::BinaryAnd {  } => {
+                    writer.write_all(&[descriptor::BinaryAnd])?;
+                },
+                Body::BinaryOr {  } => {
+                    writer.write_all(&[descriptor::BinaryOr])?;
+                },
+                Body::Caret {  } => {
+                    writer.write_all(&[descriptor::Caret])?;
+                },
+                Body::Equal {  } => {
+                    writer.write_all(&[descriptor::Equal])?;
+                },
+                Body::Greater {  } => {
+                    writer.write_all(&[descriptor::Greater])?;
+                },
+                Body::GreaterEq {  } => {
+                    writer.write_all(&[descriptor::GreaterEq])?;
+                },
+                Body::Less {  } => {
+                    writer.write_all(&[descriptor::Less])?;
+                },
+                Body::LessEq {  } => {
+                    writer.write_all(&[descriptor::LessEq])?;
+                },
+                Body::Minus {  } => {
+                    writer.write_all(&[descriptor::Minus])?;
+                },
+                Body::NotEqual {  } => {
+                    writer.write_all(&[descriptor::NotEqual])?;
+                },
+                Body::Percent {  } => {
+                    writer.write_all(&[descriptor::Percent])?;
+                },
+                Body::Plus {  } => {
+                    writer.write_all(&[descriptor::Plus])?;
+                },
+                Body::ShiftLeft {  } => {
+                    writer.write_all(&[descriptor::ShiftLeft])?;
+                },
+                Body::ShiftRight {  } => {
+                    writer.write_all(&[descriptor::ShiftRight])?;
+                },
+                Body::Slash {  } => {
+                    writer.write_all(&[descriptor::Slash])?;
+                },
+                Body::Star {  } => {
+                    writer.write_all(&[descriptor::Star])?;
+                },
+                Body::StartsWith {  } => {
+                    writer.write_all(&[descriptor::StartsWith])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::BinaryAnd => {
+                    if let () = () {
+                        Self::binaryand().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::BinaryOr => {
+                    if let () = () {
+                        Self::binaryor().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Caret => {
+                    if let () = () {
+                        Self::caret().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Equal => {
+                    if let () = () {
+                        Self::equal().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Greater => {
+                    if let () = () {
+                        Self::greater().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::GreaterEq => {
+                    if let () = () {
+                        Self::greatereq().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Less => {
+                    if let () = () {
+                        Self::less().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::LessEq => {
+                    if let () = () {
+                        Self::lesseq().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Minus => {
+                    if let () = () {
+                        Self::minus().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::NotEqual => {
+                    if let () = () {
+                        Self::notequal().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Percent => {
+                    if let () = () {
+                        Self::percent().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Plus => {
+                    if let () = () {
+                        Self::plus().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::ShiftLeft => {
+                    if let () = () {
+                        Self::shiftleft().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::ShiftRight => {
+                    if let () = () {
+                        Self::shiftright().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Slash => {
+                    if let () = () {
+                        Self::slash().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Star => {
+                    if let () = () {
+                        Self::star().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::StartsWith => {
+                    if let () = () {
+                        Self::startswith().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef {
+        BinaryAnd {
+        
+        },
+        BinaryOr {
+        
+        },
+        Caret {
+        
+        },
+        Equal {
+        
+        },
+        Greater {
+        
+        },
+        GreaterEq {
+        
+        },
+        Less {
+        
+        },
+        LessEq {
+        
+        },
+        Minus {
+        
+        },
+        NotEqual {
+        
+        },
+        Percent {
+        
+        },
+        Plus {
+        
+        },
+        ShiftLeft {
+        
+        },
+        ShiftRight {
+        
+        },
+        Slash {
+        
+        },
+        Star {
+        
+        },
+        StartsWith {
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BinaryOpRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> BinaryOpRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::BinaryAnd => BodyRef::BinaryAnd {
+                
+                },
+                descriptor::BinaryOr => BodyRef::BinaryOr {
+                
+                },
+                descriptor::Caret => BodyRef::Caret {
+                
+                },
+                descriptor::Equal => BodyRef::Equal {
+                
+                },
+                descriptor::Greater => BodyRef::Greater {
+                
+                },
+                descriptor::GreaterEq => BodyRef::GreaterEq {
+                
+                },
+                descriptor::Less => BodyRef::Less {
+                
+                },
+                descriptor::LessEq => BodyRef::LessEq {
+                
+                },
+                descriptor::Minus => BodyRef::Minus {
+                
+                },
+                descriptor::NotEqual => BodyRef::NotEqual {
+                
+                },
+                descriptor::Percent => BodyRef::Percent {
+                
+                },
+                descriptor::Plus => BodyRef::Plus {
+                
+                },
+                descriptor::ShiftLeft => BodyRef::ShiftLeft {
+                
+                },
+                descriptor::ShiftRight => BodyRef::ShiftRight {
+                
+                },
+                descriptor::Slash => BodyRef::Slash {
+                
+                },
+                descriptor::Star => BodyRef::Star {
+                
+                },
+                descriptor::StartsWith => BodyRef::StartsWith {
+                
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for BinaryOpRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| BinaryOp::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use binaryop::BinaryOp as BinaryOp;
+
+pub mod literal {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    mod descriptor {
+        pub(super) const BoolLiteral: u8 = 0;
+        pub(super) const BytesLiteral: u8 = 1;
+        pub(super) const DoubleLiteral: u8 = 2;
+        pub(super) const Int16Literal: u8 = 3;
+        pub(super) const Int32Literal: u8 = 4;
+        pub(super) const Int64Literal: u8 = 5;
+        pub(super) const Int8Literal: u8 = 6;
+        pub(super) const IntLiteral: u8 = 7;
+        pub(super) const StringLiteral: u8 = 8;
+        pub(super) const UInt16Literal: u8 = 9;
+        pub(super) const UInt32Literal: u8 = 10;
+        pub(super) const UInt64Literal: u8 = 11;
+        pub(super) const UInt8Literal: u8 = 12;
+        pub(super) const UIntLiteral: u8 = 13;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        BoolLiteral {
+            value: bool
+        },
+        BytesLiteral {
+            value: std::vec::Vec<u8>
+        },
+        DoubleLiteral {
+            value: dbuf_rust_runtime::Double
+        },
+        Int16Literal {
+            value: i16
+        },
+        Int32Literal {
+            value: i32
+        },
+        Int64Literal {
+            value: i64
+        },
+        Int8Literal {
+            value: i8
+        },
+        IntLiteral {
+            value: i64
+        },
+        StringLiteral {
+            value: String
+        },
+        UInt16Literal {
+            value: u16
+        },
+        UInt32Literal {
+            value: u32
+        },
+        UInt64Literal {
+            value: u64
+        },
+        UInt8Literal {
+            value: u8
+        },
+        UIntLiteral {
+            value: u64
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Literal {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Literal {
+        pub fn boolliteral(value: bool) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::BoolLiteral {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn bytesliteral(value: std::vec::Vec<u8>) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::BytesLiteral {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn doubleliteral(value: dbuf_rust_runtime::Double) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::DoubleLiteral {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn int16literal(value: i16) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Int16Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn int32literal(value: i32) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Int32Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn int64literal(value: i64) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Int64Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn int8literal(value: i8) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Int8Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn intliteral(value: i64) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::IntLiteral {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn stringliteral(value: String) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::StringLiteral {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn uint16literal(value: u16) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::UInt16Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn uint32literal(value: u32) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::UInt32Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn uint64literal(value: u64) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::UInt64Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn uint8literal(value: u8) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::UInt8Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn uintliteral(value: u64) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::UIntLiteral {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::BoolLiteral => {
+                    if let () = () {
+                        <bool as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::BytesLiteral => {
+                    if let () = () {
+                        <&'a [u8] as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::DoubleLiteral => {
+                    if let () = () {
+                        <dbuf_rust_runtime::Double as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int16Literal => {
+                    if let () = () {
+                        <i16 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int32Literal => {
+                    if let () = () {
+                        <i32 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int64Literal => {
+                    if let () = () {
+                        <i64 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int8Literal => {
+                    if let () = () {
+                        <i8 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::IntLiteral => {
+                    if let () = () {
+                        <i64 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::StringLiteral => {
+                    if let () = () {
+                        <&'a str as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt16Literal => {
+                    if let () = () {
+                        <u16 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt32Literal => {
+                    if let () = () {
+                        <u32 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt64Literal => {
+                    if let () = () {
+                        <u64 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt8Literal => {
+                    if let () = () {
+                        <u8 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UIntLiteral => {
+                    if let () = () {
+                        <u64 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for Literal {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::BoolLiteral { value } => {
+                    writer.write_all(&[descriptor::BoolLiteral])?;
+                    <bool as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::BytesLiteral { value } => {
+                    writer.write_all(&[descriptor::BytesLiteral])?;
+                    <std::vec::Vec<u8> as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::DoubleLiteral { value } => {
+                    writer.write_all(&[descriptor::DoubleLiteral])?;
+                    <dbuf_rust_runtime::Double as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::Int16Literal { value } => {
+                    writer.write_all(&[descriptor::Int16Literal])?;
+                    <i16 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::Int32Literal { value } => {
+                    writer.write_all(&[descriptor::Int32Literal])?;
+                    <i32 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::Int64Literal { value } => {
+                    writer.write_all(&[descriptor::Int64Literal])?;
+                    <i64 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::Int8Literal { value } => {
+                    writer.write_all(&[descriptor::Int8Literal])?;
+                    <i8 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::IntLiteral { value } => {
+                    writer.write_all(&[descriptor::IntLiteral])?;
+                    <i64 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::StringLiteral { value } => {
+                    writer.write_all(&[descriptor::StringLiteral])?;
+                    <String as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::UInt16Literal { value } => {
+                    writer.write_all(&[descriptor::UInt16Literal])?;
+                    <u16 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::UInt32Literal { value } => {
+                    writer.write_all(&[descriptor::UInt32Literal])?;
+                    <u32 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::UInt64Literal { value } => {
+                    writer.write_all(&[descriptor::UInt64Literal])?;
+                    <u64 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::UInt8Literal { value } => {
+                    writer.write_all(&[descriptor::UInt8Literal])?;
+                    <u8 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+                Body::UIntLiteral { value } => {
+                    writer.write_all(&[descriptor::UIntLiteral])?;
+                    <u64 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::BoolLiteral => {
+                    if let () = () {
+                        let value = <bool as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::boolliteral(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::BytesLiteral => {
+                    if let () = () {
+                        let value = <std::vec::Vec<u8> as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::bytesliteral(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::DoubleLiteral => {
+                    if let () = () {
+                        let value = <dbuf_rust_runtime::Double as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::doubleliteral(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int16Literal => {
+                    if let () = () {
+                        let value = <i16 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::int16literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int32Literal => {
+                    if let () = () {
+                        let value = <i32 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::int32literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int64Literal => {
+                    if let () = () {
+                        let value = <i64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::int64literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Int8Literal => {
+                    if let () = () {
+                        let value = <i8 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::int8literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::IntLiteral => {
+                    if let () = () {
+                        let value = <i64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::intliteral(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::StringLiteral => {
+                    if let () = () {
+                        let value = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::stringliteral(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt16Literal => {
+                    if let () = () {
+                        let value = <u16 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::uint16literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt32Literal => {
+                    if let () = () {
+                        let value = <u32 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::uint32literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt64Literal => {
+                    if let () = () {
+                        let value = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::uint64literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UInt8Literal => {
+                    if let () = () {
+                        let value = <u8 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::uint8literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UIntLiteral => {
+                    if let () = () {
+                        let value = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::uintliteral(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        BoolLiteral {
+            value: bool
+        },
+        BytesLiteral {
+            value: &'a [u8]
+        },
+        DoubleLiteral {
+            value: dbuf_rust_runtime::Double
+        },
+        Int16Literal {
+            value: i16
+        },
+        Int32Literal {
+            value: i32
+        },
+        Int64Literal {
+            value: i64
+        },
+        Int8Literal {
+            value: i8
+        },
+        IntLiteral {
+            value: i64
+        },
+        StringLiteral {
+            value: &'a str
+        },
+        UInt16Literal {
+            value: u16
+        },
+        UInt32Literal {
+            value: u32
+        },
+        UInt64Literal {
+            value: u64
+        },
+        UInt8Literal {
+            value: u8
+        },
+        UIntLiteral {
+            value: u64
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct LiteralRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> LiteralRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::BoolLiteral => BodyRef::BoolLiteral {
+                    value: <bool as super::DbufView>::read_checked(reader)
+                },
+                descriptor::BytesLiteral => BodyRef::BytesLiteral {
+                    value: <&'a [u8] as super::DbufView>::read_checked(reader)
+                },
+                descriptor::DoubleLiteral => BodyRef::DoubleLiteral {
+                    value: <dbuf_rust_runtime::Double as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Int16Literal => BodyRef::Int16Literal {
+                    value: <i16 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Int32Literal => BodyRef::Int32Literal {
+                    value: <i32 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Int64Literal => BodyRef::Int64Literal {
+                    value: <i64 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Int8Literal => BodyRef::Int8Literal {
+                    value: <i8 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::IntLiteral => BodyRef::IntLiteral {
+                    value: <i64 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::StringLiteral => BodyRef::StringLiteral {
+                    value: <&'a str as super::DbufView>::read_checked(reader)
+                },
+                descriptor::UInt16Literal => BodyRef::UInt16Literal {
+                    value: <u16 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::UInt32Literal => BodyRef::UInt32Literal {
+                    value: <u32 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::UInt64Literal => BodyRef::UInt64Literal {
+                    value: <u64 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::UInt8Literal => BodyRef::UInt8Literal {
+                    value: <u8 as super::DbufView>::read_checked(reader)
+                },
+                descriptor::UIntLiteral => BodyRef::UIntLiteral {
+                    value: <u64 as super::DbufView>::read_checked(reader)
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for LiteralRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Literal::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use literal::Literal as Literal;
+
+pub mod typekind {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    mod descriptor {
+        pub(super) const EnumType: u8 = 0;
+        pub(super) const MessageType: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        EnumType {
+        
+        },
+        MessageType {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct TypeKind {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl TypeKind {
+        pub fn enumtype() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::EnumType {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn messagetype() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::MessageType {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::EnumType => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::MessageType => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for TypeKind {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::EnumType {  } => {
+                    writer.write_all(&[descriptor::EnumType])?;
+                },
+                Body::MessageType {  } => {
+                    writer.write_all(&[descriptor::MessageType])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::EnumType => {
+                    if let () = () {
+                        Self::enumtype().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::MessageType => {
+                    if let () = () {
+                        Self::messagetype().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef {
+        EnumType {
+        
+        },
+        MessageType {
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct TypeKindRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> TypeKindRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::EnumType => BodyRef::EnumType {
+                
+                },
+                descriptor::MessageType => BodyRef::MessageType {
+                
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for TypeKindRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| TypeKind::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use typekind::TypeKind as TypeKind;
+
+pub mod unaryop {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    mod descriptor {
+        pub(super) const Access: u8 = 0;
+        pub(super) const Negate: u8 = 1;
+        pub(super) const Not: u8 = 2;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Access {
+            field: String
+        },
+        Negate {
+        
+        },
+        Not {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct UnaryOp {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl UnaryOp {
+        pub fn access(field: String) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Access {
+                    field: field
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn negate() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Negate {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn not() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Not {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::Access => {
+                    if let () = () {
+                        <&'a str as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Negate => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Not => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for UnaryOp {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Access { field } => {
+                    writer.write_all(&[descriptor::Access])?;
+                    <String as super::DbufPrimitive>::dbuf_serialize(&field, writer)?;
+                },
+                Body::Negate {  } => {
+                    writer.write_all(&[descriptor::Negate])?;
+                },
+                Body::Not {  } => {
+                    writer.write_all(&[descriptor::Not])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::Access => {
+                    if let () = () {
+                        let field = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::access(field).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Negate => {
+                    if let () = () {
+                        Self::negate().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Not => {
+                    if let () = () {
+                        Self::not().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        Access {
+            field: &'a str
+        },
+        Negate {
+        
+        },
+        Not {
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct UnaryOpRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> UnaryOpRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::Access => BodyRef::Access {
+                    field: <&'a str as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Negate => BodyRef::Negate {
+                
+                },
+                descriptor::Not => BodyRef::Not {
+                
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for UnaryOpRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| UnaryOp::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use unaryop::UnaryOp as UnaryOp;
+
+pub mod typeexpr {
+    mod deps {
+        pub(super) use super::super::{{valueexpr, ValueExpr}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub dependencies: std::vec::Vec<deps::valueexpr::ValueExpr>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct TypeExpr {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl TypeExpr {
+        pub fn typeexpr(name: String, dependencies: std::vec::Vec<deps::valueexpr::ValueExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    dependencies: dependencies
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies_1 = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies_1 })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::valueexpr::ValueExprRef<'a>> as super::DbufView>::read_view(deps::valueexpr::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for TypeExpr {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, dependencies } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(dependencies.len() as u64), writer)?;
+            for element in dependencies {
+                <deps::ValueExpr as super::DbufMessage>::serialize(&element, writer)?;
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let dependencies = {
+                let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements = std::vec::Vec::new();
+                for _ in 0..length {
+                    elements.push(<deps::ValueExpr as super::DbufMessage>::deserialize(deps::valueexpr::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements
+            };
+            Self::typeexpr(name, dependencies).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub dependencies: dbuf_rust_runtime::ListRef<'a, deps::valueexpr::ValueExprRef<'a>>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct TypeExprRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> TypeExprRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn dependencies(&self) -> dbuf_rust_runtime::ListRef<'a, deps::valueexpr::ValueExprRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::valueexpr::ValueExprRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                dependencies: <dbuf_rust_runtime::ListRef<'a, deps::valueexpr::ValueExprRef<'a>> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for TypeExprRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| TypeExpr::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use typeexpr::TypeExpr as TypeExpr;
+
+pub mod valueexpr {
+    mod deps {
+        pub(super) use super::super::{{binaryop, BinaryOp}, {literal, Literal}, {typeexpr, TypeExpr}, {unaryop, UnaryOp}};
+    }
+    mod descriptor {
+        pub(super) const BinaryExpr: u8 = 0;
+        pub(super) const ConstructorExpr: u8 = 1;
+        pub(super) const LiteralExpr: u8 = 2;
+        pub(super) const TypeArgument: u8 = 3;
+        pub(super) const UnaryExpr: u8 = 4;
+        pub(super) const VariableExpr: u8 = 5;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        BinaryExpr {
+            op: super::Box<deps::binaryop::BinaryOp>,
+            lhs: super::Box<ValueExpr>,
+            rhs: super::Box<ValueExpr>,
+            resultType: super::Box<deps::typeexpr::TypeExpr>
+        },
+        ConstructorExpr {
+            name: String,
+            implicits: std::vec::Vec<ValueExpr>,
+            arguments: std::vec::Vec<ValueExpr>,
+            resultType: super::Box<deps::typeexpr::TypeExpr>
+        },
+        LiteralExpr {
+            literal: super::Box<deps::literal::Literal>,
+            resultType: super::Box<deps::typeexpr::TypeExpr>
+        },
+        TypeArgument {
+            ty: super::Box<deps::typeexpr::TypeExpr>
+        },
+        UnaryExpr {
+            op: super::Box<deps::unaryop::UnaryOp>,
+            operand: super::Box<ValueExpr>,
+            resultType: super::Box<deps::typeexpr::TypeExpr>
+        },
+        VariableExpr {
+            name: String,
+            ty: super::Box<deps::typeexpr::TypeExpr>
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ValueExpr {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl ValueExpr {
+        pub fn binaryexpr(op: super::Box<deps::binaryop::BinaryOp>, lhs: super::Box<ValueExpr>, rhs: super::Box<ValueExpr>, resultType: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            (),
+            ()) == ((),
+            (),
+            (),
+            ()) {
+                Ok(Body::BinaryExpr {
+                    op: op,
+                    lhs: lhs,
+                    rhs: rhs,
+                    resultType: resultType
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn constructorexpr(name: String, implicits: std::vec::Vec<ValueExpr>, arguments: std::vec::Vec<ValueExpr>, resultType: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            (),
+            ()) == ((),
+            (),
+            (),
+            ()) {
+                Ok(Body::ConstructorExpr {
+                    name: name,
+                    implicits: implicits,
+                    arguments: arguments,
+                    resultType: resultType
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn literalexpr(literal: super::Box<deps::literal::Literal>, resultType: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body::LiteralExpr {
+                    literal: literal,
+                    resultType: resultType
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn typeargument(ty: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::TypeArgument {
+                    ty: ty
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn unaryexpr(op: super::Box<deps::unaryop::UnaryOp>, operand: super::Box<ValueExpr>, resultType: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            ()) == ((),
+            (),
+            ()) {
+                Ok(Body::UnaryExpr {
+                    op: op,
+                    operand: operand,
+                    resultType: resultType
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn variableexpr(name: String, ty: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body::VariableExpr {
+                    name: name,
+                    ty: ty
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::BinaryExpr => {
+                    if let () = () {
+                        <deps::binaryop::BinaryOpRef<'a> as super::DbufView>::read_view(deps::binaryop::Dependencies {
+                        
+                        }, reader)?;
+                        <ValueExprRef<'a> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        <ValueExprRef<'a> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::ConstructorExpr => {
+                    if let () = () {
+                        <&'a str as super::DbufView>::read_view((), reader)?;
+                        <dbuf_rust_runtime::ListRef<'a, ValueExprRef<'a>> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        <dbuf_rust_runtime::ListRef<'a, ValueExprRef<'a>> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::LiteralExpr => {
+                    if let () = () {
+                        <deps::literal::LiteralRef<'a> as super::DbufView>::read_view(deps::literal::Dependencies {
+                        
+                        }, reader)?;
+                        <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::TypeArgument => {
+                    if let () = () {
+                        <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UnaryExpr => {
+                    if let () = () {
+                        <deps::unaryop::UnaryOpRef<'a> as super::DbufView>::read_view(deps::unaryop::Dependencies {
+                        
+                        }, reader)?;
+                        <ValueExprRef<'a> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::VariableExpr => {
+                    if let () = () {
+                        <&'a str as super::DbufView>::read_view((), reader)?;
+                        <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for ValueExpr {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::BinaryExpr { op, lhs, rhs, resultType } => {
+                    writer.write_all(&[descriptor::BinaryExpr])?;
+                    <deps::BinaryOp as super::DbufMessage>::serialize(&op, writer)?;
+                    <Self as super::DbufMessage>::serialize(&lhs, writer)?;
+                    <Self as super::DbufMessage>::serialize(&rhs, writer)?;
+                    <deps::TypeExpr as super::DbufMessage>::serialize(&resultType, writer)?;
+                },
+                Body::ConstructorExpr { name, implicits, arguments, resultType } => {
+                    writer.write_all(&[descriptor::ConstructorExpr])?;
+                    <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+                    <u64 as super::DbufPrimitive>::dbuf_serialize(&(implicits.len() as u64), writer)?;
+                    for element in implicits {
+                        <Self as super::DbufMessage>::serialize(&element, writer)?;
+                    }
+                    <u64 as super::DbufPrimitive>::dbuf_serialize(&(arguments.len() as u64), writer)?;
+                    for element_1 in arguments {
+                        <Self as super::DbufMessage>::serialize(&element_1, writer)?;
+                    }
+                    <deps::TypeExpr as super::DbufMessage>::serialize(&resultType, writer)?;
+                },
+                Body::LiteralExpr { literal, resultType } => {
+                    writer.write_all(&[descriptor::LiteralExpr])?;
+                    <deps::Literal as super::DbufMessage>::serialize(&literal, writer)?;
+                    <deps::TypeExpr as super::DbufMessage>::serialize(&resultType, writer)?;
+                },
+                Body::TypeArgument { ty } => {
+                    writer.write_all(&[descriptor::TypeArgument])?;
+                    <deps::TypeExpr as super::DbufMessage>::serialize(&ty, writer)?;
+                },
+                Body::UnaryExpr { op, operand, resultType } => {
+                    writer.write_all(&[descriptor::UnaryExpr])?;
+                    <deps::UnaryOp as super::DbufMessage>::serialize(&op, writer)?;
+                    <Self as super::DbufMessage>::serialize(&operand, writer)?;
+                    <deps::TypeExpr as super::DbufMessage>::serialize(&resultType, writer)?;
+                },
+                Body::VariableExpr { name, ty } => {
+                    writer.write_all(&[descriptor::VariableExpr])?;
+                    <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+                    <deps::TypeExpr as super::DbufMessage>::serialize(&ty, writer)?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::BinaryExpr => {
+                    if let () = () {
+                        let op = <deps::BinaryOp as super::DbufMessage>::deserialize(deps::binaryop::Dependencies {
+                        
+                        }, reader)?;
+                        let lhs = <Self as super::DbufMessage>::deserialize(Dependencies {
+                        
+                        }, reader)?;
+                        let rhs = <Self as super::DbufMessage>::deserialize(Dependencies {
+                        
+                        }, reader)?;
+                        let resultType = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::binaryexpr(Box::new(op), Box::new(lhs), Box::new(rhs), Box::new(resultType)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::ConstructorExpr => {
+                    if let () = () {
+                        let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        let implicits = {
+                            let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                            let mut elements = std::vec::Vec::new();
+                            for _ in 0..length {
+                                elements.push(<Self as super::DbufMessage>::deserialize(Dependencies {
+                                
+                                }, reader)?);
+                            }
+                            elements
+                        };
+                        let arguments = {
+                            let length_1 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                            let mut elements_1 = std::vec::Vec::new();
+                            for _ in 0..length_1 {
+                                elements_1.push(<Self as super::DbufMessage>::deserialize(Dependencies {
+                                
+                                }, reader)?);
+                            }
+                            elements_1
+                        };
+                        let resultType = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::constructorexpr(name, implicits, arguments, Box::new(resultType)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::LiteralExpr => {
+                    if let () = () {
+                        let literal = <deps::Literal as super::DbufMessage>::deserialize(deps::literal::Dependencies {
+                        
+                        }, reader)?;
+                        let resultType = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::literalexpr(Box::new(literal), Box::new(resultType)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::TypeArgument => {
+                    if let () = () {
+                        let ty = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::typeargument(Box::new(ty)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::UnaryExpr => {
+                    if let () = () {
+                        let op = <deps::UnaryOp as super::DbufMessage>::deserialize(deps::unaryop::Dependencies {
+                        
+                        }, reader)?;
+                        let operand = <Self as super::DbufMessage>::deserialize(Dependencies {
+                        
+                        }, reader)?;
+                        let resultType = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::unaryexpr(Box::new(op), Box::new(operand), Box::new(resultType)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::VariableExpr => {
+                    if let () = () {
+                        let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        let ty = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::variableexpr(name, Box::new(ty)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        BinaryExpr {
+            op: deps::binaryop::BinaryOpRef<'a>,
+            lhs: ValueExprRef<'a>,
+            rhs: ValueExprRef<'a>,
+            resultType: deps::typeexpr::TypeExprRef<'a>
+        },
+        ConstructorExpr {
+            name: &'a str,
+            implicits: dbuf_rust_runtime::ListRef<'a, ValueExprRef<'a>>,
+            arguments: dbuf_rust_runtime::ListRef<'a, ValueExprRef<'a>>,
+            resultType: deps::typeexpr::TypeExprRef<'a>
+        },
+        LiteralExpr {
+            literal: deps::literal::LiteralRef<'a>,
+            resultType: deps::typeexpr::TypeExprRef<'a>
+        },
+        TypeArgument {
+            ty: deps::typeexpr::TypeExprRef<'a>
+        },
+        UnaryExpr {
+            op: deps::unaryop::UnaryOpRef<'a>,
+            operand: ValueExprRef<'a>,
+            resultType: deps::typeexpr::TypeExprRef<'a>
+        },
+        VariableExpr {
+            name: &'a str,
+            ty: deps::typeexpr::TypeExprRef<'a>
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ValueExprRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ValueExprRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::BinaryExpr => BodyRef::BinaryExpr {
+                    op: <deps::binaryop::BinaryOpRef<'a> as super::DbufView>::read_checked(reader),
+                    lhs: <ValueExprRef<'a> as super::DbufView>::read_checked(reader),
+                    rhs: <ValueExprRef<'a> as super::DbufView>::read_checked(reader),
+                    resultType: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::ConstructorExpr => BodyRef::ConstructorExpr {
+                    name: <&'a str as super::DbufView>::read_checked(reader),
+                    implicits: <dbuf_rust_runtime::ListRef<'a, ValueExprRef<'a>> as super::DbufView>::read_checked(reader),
+                    arguments: <dbuf_rust_runtime::ListRef<'a, ValueExprRef<'a>> as super::DbufView>::read_checked(reader),
+                    resultType: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::LiteralExpr => BodyRef::LiteralExpr {
+                    literal: <deps::literal::LiteralRef<'a> as super::DbufView>::read_checked(reader),
+                    resultType: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::TypeArgument => BodyRef::TypeArgument {
+                    ty: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::UnaryExpr => BodyRef::UnaryExpr {
+                    op: <deps::unaryop::UnaryOpRef<'a> as super::DbufView>::read_checked(reader),
+                    operand: <ValueExprRef<'a> as super::DbufView>::read_checked(reader),
+                    resultType: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::VariableExpr => BodyRef::VariableExpr {
+                    name: <&'a str as super::DbufView>::read_checked(reader),
+                    ty: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for ValueExprRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| ValueExpr::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use valueexpr::ValueExpr as ValueExpr;
+
+pub mod binding {
+    mod deps {
+        pub(super) use super::super::{{typeexpr, TypeExpr}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub ty: super::Box<deps::typeexpr::TypeExpr>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Binding {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Binding {
+        pub fn binding(name: String, ty: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    ty: ty
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Binding {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, ty } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <deps::TypeExpr as super::DbufMessage>::serialize(&ty, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let ty = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+            
+            }, reader)?;
+            Self::binding(name, Box::new(ty)).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub ty: deps::typeexpr::TypeExprRef<'a>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BindingRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> BindingRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn ty(&self) -> deps::typeexpr::TypeExprRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                ty: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for BindingRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Binding::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use binding::Binding as Binding;
+
+pub mod fielddefault {
+    mod deps {
+        pub(super) use super::super::{{valueexpr, ValueExpr}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub value: super::Box<deps::valueexpr::ValueExpr>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct FieldDefault {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl FieldDefault {
+        pub fn fielddefault(name: String, value: super::Box<deps::valueexpr::ValueExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <deps::valueexpr::ValueExprRef<'a> as super::DbufView>::read_view(deps::valueexpr::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for FieldDefault {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, value } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <deps::ValueExpr as super::DbufMessage>::serialize(&value, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let value = <deps::ValueExpr as super::DbufMessage>::deserialize(deps::valueexpr::Dependencies {
+            
+            }, reader)?;
+            Self::fielddefault(name, Box::new(value)).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub value: deps::valueexpr::ValueExprRef<'a>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct FieldDefaultRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> FieldDefaultRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn value(&self) -> deps::valueexpr::ValueExprRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::valueexpr::ValueExprRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                value: <deps::valueexpr::ValueExprRef<'a> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for FieldDefaultRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| FieldDefault::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use fielddefault::FieldDefault as FieldDefault;
+
+pub mod refinement {
+    mod deps {
+        pub(super) use super::super::{{valueexpr, ValueExpr}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub predicate: super::Box<deps::valueexpr::ValueExpr>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Refinement {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Refinement {
+        pub fn refinement(name: String, predicate: super::Box<deps::valueexpr::ValueExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    predicate: predicate
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <deps::valueexpr::ValueExprRef<'a> as super::DbufView>::read_view(deps::valueexpr::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Refinement {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, predicate } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <deps::ValueExpr as super::DbufMessage>::serialize(&predicate, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let predicate = <deps::ValueExpr as super::DbufMessage>::deserialize(deps::valueexpr::Dependencies {
+            
+            }, reader)?;
+            Self::refinement(name, Box::new(predicate)).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub predicate: deps::valueexpr::ValueExprRef<'a>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct RefinementRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> RefinementRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn predicate(&self) -> deps::valueexpr::ValueExprRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::valueexpr::ValueExprRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                predicate: <deps::valueexpr::ValueExprRef<'a> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for RefinementRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Refinement::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use refinement::Refinement as Refinement;
+
+pub mod aliasdescriptor {
+    mod deps {
+        pub(super) use super::super::{{binding, Binding}, {refinement, Refinement}, {typeexpr, TypeExpr}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub dependencies: std::vec::Vec<deps::binding::Binding>,
+        pub refinements: std::vec::Vec<deps::refinement::Refinement>,
+        pub target: super::Box<deps::typeexpr::TypeExpr>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct AliasDescriptor {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl AliasDescriptor {
+        pub fn aliasdescriptor(name: String, dependencies: std::vec::Vec<deps::binding::Binding>, refinements: std::vec::Vec<deps::refinement::Refinement>, target: super::Box<deps::typeexpr::TypeExpr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            (),
+            ()) == ((),
+            (),
+            (),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    dependencies: dependencies,
+                    refinements: refinements,
+                    target: target
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies_1 = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies_1 })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_view(deps::binding::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_view(deps::refinement::Dependencies {
+            
+            }, reader)?;
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for AliasDescriptor {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, dependencies, refinements, target } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(dependencies.len() as u64), writer)?;
+            for element in dependencies {
+                <deps::Binding as super::DbufMessage>::serialize(&element, writer)?;
+            }
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(refinements.len() as u64), writer)?;
+            for element_1 in refinements {
+                <deps::Refinement as super::DbufMessage>::serialize(&element_1, writer)?;
+            }
+            <deps::TypeExpr as super::DbufMessage>::serialize(&target, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let dependencies = {
+                let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements = std::vec::Vec::new();
+                for _ in 0..length {
+                    elements.push(<deps::Binding as super::DbufMessage>::deserialize(deps::binding::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements
+            };
+            let refinements = {
+                let length_1 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_1 = std::vec::Vec::new();
+                for _ in 0..length_1 {
+                    elements_1.push(<deps::Refinement as super::DbufMessage>::deserialize(deps::refinement::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_1
+            };
+            let target = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+            
+            }, reader)?;
+            Self::aliasdescriptor(name, dependencies, refinements, Box::new(target)).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub dependencies: dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>>,
+        pub refinements: dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>>,
+        pub target: deps::typeexpr::TypeExprRef<'a>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct AliasDescriptorRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> AliasDescriptorRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn dependencies(&self) -> dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn refinements(&self) -> dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn target(&self) -> deps::typeexpr::TypeExprRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader);
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                dependencies: <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader),
+                refinements: <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader),
+                target: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for AliasDescriptorRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| AliasDescriptor::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use aliasdescriptor::AliasDescriptor as AliasDescriptor;
+
+pub mod constructordescriptor {
+    mod deps {
+        pub(super) use super::super::{{binding, Binding}, {fielddefault, FieldDefault}, {refinement, Refinement}, {typeexpr, TypeExpr}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub implicits: std::vec::Vec<deps::binding::Binding>,
+        pub fields: std::vec::Vec<deps::binding::Binding>,
+        pub resultType: super::Box<deps::typeexpr::TypeExpr>,
+        pub refinements: std::vec::Vec<deps::refinement::Refinement>,
+        pub defaults: std::vec::Vec<deps::fielddefault::FieldDefault>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ConstructorDescriptor {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl ConstructorDescriptor {
+        pub fn constructordescriptor(name: String, implicits: std::vec::Vec<deps::binding::Binding>, fields: std::vec::Vec<deps::binding::Binding>, resultType: super::Box<deps::typeexpr::TypeExpr>, refinements: std::vec::Vec<deps::refinement::Refinement>, defaults: std::vec::Vec<deps::fielddefault::FieldDefault>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            (),
+            (),
+            (),
+            ()) == ((),
+            (),
+            (),
+            (),
+            (),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    implicits: implicits,
+                    fields: fields,
+                    resultType: resultType,
+                    refinements: refinements,
+                    defaults: defaults
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_view(deps::binding::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_view(deps::binding::Dependencies {
+            
+            }, reader)?;
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_view(deps::typeexpr::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_view(deps::refinement::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::fielddefault::FieldDefaultRef<'a>> as super::DbufView>::read_view(deps::fielddefault::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for ConstructorDescriptor {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, implicits, fields, resultType, refinements, defaults } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(implicits.len() as u64), writer)?;
+            for element in implicits {
+                <deps::Binding as super::DbufMessage>::serialize(&element, writer)?;
+            }
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(fields.len() as u64), writer)?;
+            for element_1 in fields {
+                <deps::Binding as super::DbufMessage>::serialize(&element_1, writer)?;
+            }
+            <deps::TypeExpr as super::DbufMessage>::serialize(&resultType, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(refinements.len() as u64), writer)?;
+            for element_2 in refinements {
+                <deps::Refinement as super::DbufMessage>::serialize(&element_2, writer)?;
+            }
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(defaults.len() as u64), writer)?;
+            for element_3 in defaults {
+                <deps::FieldDefault as super::DbufMessage>::serialize(&element_3, writer)?;
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let implicits = {
+                let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements = std::vec::Vec::new();
+                for _ in 0..length {
+                    elements.push(<deps::Binding as super::DbufMessage>::deserialize(deps::binding::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements
+            };
+            let fields = {
+                let length_1 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_1 = std::vec::Vec::new();
+                for _ in 0..length_1 {
+                    elements_1.push(<deps::Binding as super::DbufMessage>::deserialize(deps::binding::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_1
+            };
+            let resultType = <deps::TypeExpr as super::DbufMessage>::deserialize(deps::typeexpr::Dependencies {
+            
+            }, reader)?;
+            let refinements = {
+                let length_2 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_2 = std::vec::Vec::new();
+                for _ in 0..length_2 {
+                    elements_2.push(<deps::Refinement as super::DbufMessage>::deserialize(deps::refinement::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_2
+            };
+            let defaults = {
+                let length_3 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_3 = std::vec::Vec::new();
+                for _ in 0..length_3 {
+                    elements_3.push(<deps::FieldDefault as super::DbufMessage>::deserialize(deps::fielddefault::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_3
+            };
+            Self::constructordescriptor(name, implicits, fields, Box::new(resultType), refinements, defaults).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub implicits: dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>>,
+        pub fields: dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>>,
+        pub resultType: deps::typeexpr::TypeExprRef<'a>,
+        pub refinements: dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>>,
+        pub defaults: dbuf_rust_runtime::ListRef<'a, deps::fielddefault::FieldDefaultRef<'a>>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ConstructorDescriptorRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ConstructorDescriptorRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn implicits(&self) -> dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn fields(&self) -> dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn resultType(&self) -> deps::typeexpr::TypeExprRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        pub fn refinements(&self) -> dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn defaults(&self) -> dbuf_rust_runtime::ListRef<'a, deps::fielddefault::FieldDefaultRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::fielddefault::FieldDefaultRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                implicits: <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader),
+                fields: <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader),
+                resultType: <deps::typeexpr::TypeExprRef<'a> as super::DbufView>::read_checked(reader),
+                refinements: <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader),
+                defaults: <dbuf_rust_runtime::ListRef<'a, deps::fielddefault::FieldDefaultRef<'a>> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for ConstructorDescriptorRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| ConstructorDescriptor::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use constructordescriptor::ConstructorDescriptor as ConstructorDescriptor;
+
+pub mod typedescriptor {
+    mod deps {
+        pub(super) use super::super::{{binding, Binding}, {constructordescriptor, ConstructorDescriptor}, {refinement, Refinement}, {typekind, TypeKind}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub name: String,
+        pub kind: super::Box<deps::typekind::TypeKind>,
+        pub dependencies: std::vec::Vec<deps::binding::Binding>,
+        pub refinements: std::vec::Vec<deps::refinement::Refinement>,
+        pub constructors: std::vec::Vec<deps::constructordescriptor::ConstructorDescriptor>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct TypeDescriptor {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl TypeDescriptor {
+        pub fn typedescriptor(name: String, kind: super::Box<deps::typekind::TypeKind>, dependencies: std::vec::Vec<deps::binding::Binding>, refinements: std::vec::Vec<deps::refinement::Refinement>, constructors: std::vec::Vec<deps::constructordescriptor::ConstructorDescriptor>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            (),
+            (),
+            ()) == ((),
+            (),
+            (),
+            (),
+            ()) {
+                Ok(Body {
+                    name: name,
+                    kind: kind,
+                    dependencies: dependencies,
+                    refinements: refinements,
+                    constructors: constructors
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies_1 = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies_1 })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a str as super::DbufView>::read_view((), reader)?;
+            <deps::typekind::TypeKindRef<'a> as super::DbufView>::read_view(deps::typekind::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_view(deps::binding::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_view(deps::refinement::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::constructordescriptor::ConstructorDescriptorRef<'a>> as super::DbufView>::read_view(deps::constructordescriptor::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for TypeDescriptor {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ name, kind, dependencies, refinements, constructors } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+            <deps::TypeKind as super::DbufMessage>::serialize(&kind, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(dependencies.len() as u64), writer)?;
+            for element in dependencies {
+                <deps::Binding as super::DbufMessage>::serialize(&element, writer)?;
+            }
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(refinements.len() as u64), writer)?;
+            for element_1 in refinements {
+                <deps::Refinement as super::DbufMessage>::serialize(&element_1, writer)?;
+            }
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(constructors.len() as u64), writer)?;
+            for element_2 in constructors {
+                <deps::ConstructorDescriptor as super::DbufMessage>::serialize(&element_2, writer)?;
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let kind = <deps::TypeKind as super::DbufMessage>::deserialize(deps::typekind::Dependencies {
+            
+            }, reader)?;
+            let dependencies = {
+                let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements = std::vec::Vec::new();
+                for _ in 0..length {
+                    elements.push(<deps::Binding as super::DbufMessage>::deserialize(deps::binding::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements
+            };
+            let refinements = {
+                let length_1 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_1 = std::vec::Vec::new();
+                for _ in 0..length_1 {
+                    elements_1.push(<deps::Refinement as super::DbufMessage>::deserialize(deps::refinement::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_1
+            };
+            let constructors = {
+                let length_2 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_2 = std::vec::Vec::new();
+                for _ in 0..length_2 {
+                    elements_2.push(<deps::ConstructorDescriptor as super::DbufMessage>::deserialize(deps::constructordescriptor::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_2
+            };
+            Self::typedescriptor(name, Box::new(kind), dependencies, refinements, constructors).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub name: &'a str,
+        pub kind: deps::typekind::TypeKindRef<'a>,
+        pub dependencies: dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>>,
+        pub refinements: dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>>,
+        pub constructors: dbuf_rust_runtime::ListRef<'a, deps::constructordescriptor::ConstructorDescriptorRef<'a>>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct TypeDescriptorRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> TypeDescriptorRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn name(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn kind(&self) -> deps::typekind::TypeKindRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::typekind::TypeKindRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        pub fn dependencies(&self) -> dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::typekind::TypeKindRef<'a> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn refinements(&self) -> dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::typekind::TypeKindRef<'a> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn constructors(&self) -> dbuf_rust_runtime::ListRef<'a, deps::constructordescriptor::ConstructorDescriptorRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::typekind::TypeKindRef<'a> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::constructordescriptor::ConstructorDescriptorRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                name: <&'a str as super::DbufView>::read_checked(reader),
+                kind: <deps::typekind::TypeKindRef<'a> as super::DbufView>::read_checked(reader),
+                dependencies: <dbuf_rust_runtime::ListRef<'a, deps::binding::BindingRef<'a>> as super::DbufView>::read_checked(reader),
+                refinements: <dbuf_rust_runtime::ListRef<'a, deps::refinement::RefinementRef<'a>> as super::DbufView>::read_checked(reader),
+                constructors: <dbuf_rust_runtime::ListRef<'a, deps::constructordescriptor::ConstructorDescriptorRef<'a>> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for TypeDescriptorRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| TypeDescriptor::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use typedescriptor::TypeDescriptor as TypeDescriptor;
+
+pub mod moduledescriptor {
+    mod deps {
+        pub(super) use super::super::{{aliasdescriptor, AliasDescriptor}, {typedescriptor, TypeDescriptor}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub version: u64,
+        pub packagePath: std::vec::Vec<String>,
+        pub types: std::vec::Vec<deps::typedescriptor::TypeDescriptor>,
+        pub aliases: std::vec::Vec<deps::aliasdescriptor::AliasDescriptor>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ModuleDescriptor {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl ModuleDescriptor {
+        pub fn moduledescriptor(version: u64, packagePath: std::vec::Vec<String>, types: std::vec::Vec<deps::typedescriptor::TypeDescriptor>, aliases: std::vec::Vec<deps::aliasdescriptor::AliasDescriptor>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            (),
+            ()) == ((),
+            (),
+            (),
+            ()) {
+                Ok(Body {
+                    version: version,
+                    packagePath: packagePath,
+                    types: types,
+                    aliases: aliases
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <u64 as super::DbufView>::read_view((), reader)?;
+            <dbuf_rust_runtime::ListRef<'a, &'a str> as super::DbufView>::read_view((), reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::typedescriptor::TypeDescriptorRef<'a>> as super::DbufView>::read_view(deps::typedescriptor::Dependencies {
+            
+            }, reader)?;
+            <dbuf_rust_runtime::ListRef<'a, deps::aliasdescriptor::AliasDescriptorRef<'a>> as super::DbufView>::read_view(deps::aliasdescriptor::Dependencies {
+            
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for ModuleDescriptor {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ version, packagePath, types, aliases } = &self.body;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&version, writer)?;
+            <std::vec::Vec<String> as super::DbufPrimitive>::dbuf_serialize(&packagePath, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(types.len() as u64), writer)?;
+            for element in types {
+                <deps::TypeDescriptor as super::DbufMessage>::serialize(&element, writer)?;
+            }
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&(aliases.len() as u64), writer)?;
+            for element_1 in aliases {
+                <deps::AliasDescriptor as super::DbufMessage>::serialize(&element_1, writer)?;
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let version = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let packagePath = <std::vec::Vec<String> as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let types = {
+                let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements = std::vec::Vec::new();
+                for _ in 0..length {
+                    elements.push(<deps::TypeDescriptor as super::DbufMessage>::deserialize(deps::typedescriptor::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements
+            };
+            let aliases = {
+                let length_1 = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                let mut elements_1 = std::vec::Vec::new();
+                for _ in 0..length_1 {
+                    elements_1.push(<deps::AliasDescriptor as super::DbufMessage>::deserialize(deps::aliasdescriptor::Dependencies {
+                    
+                    }, reader)?);
+                }
+                elements_1
+            };
+            Self::moduledescriptor(version, packagePath, types, aliases).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub version: u64,
+        pub packagePath: dbuf_rust_runtime::ListRef<'a, &'a str>,
+        pub types: dbuf_rust_runtime::ListRef<'a, deps::typedescriptor::TypeDescriptorRef<'a>>,
+        pub aliases: dbuf_rust_runtime::ListRef<'a, deps::aliasdescriptor::AliasDescriptorRef<'a>>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ModuleDescriptorRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ModuleDescriptorRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn version(&self) -> u64 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <u64 as super::DbufView>::read_checked(reader)
+        }
+        pub fn packagePath(&self) -> dbuf_rust_runtime::ListRef<'a, &'a str> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <u64 as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, &'a str> as super::DbufView>::read_checked(reader)
+        }
+        pub fn types(&self) -> dbuf_rust_runtime::ListRef<'a, deps::typedescriptor::TypeDescriptorRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <u64 as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, &'a str> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::typedescriptor::TypeDescriptorRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        pub fn aliases(&self) -> dbuf_rust_runtime::ListRef<'a, deps::aliasdescriptor::AliasDescriptorRef<'a>> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <u64 as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, &'a str> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::typedescriptor::TypeDescriptorRef<'a>> as super::DbufView>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, deps::aliasdescriptor::AliasDescriptorRef<'a>> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                version: <u64 as super::DbufView>::read_checked(reader),
+                packagePath: <dbuf_rust_runtime::ListRef<'a, &'a str> as super::DbufView>::read_checked(reader),
+                types: <dbuf_rust_runtime::ListRef<'a, deps::typedescriptor::TypeDescriptorRef<'a>> as super::DbufView>::read_checked(reader),
+                aliases: <dbuf_rust_runtime::ListRef<'a, deps::aliasdescriptor::AliasDescriptorRef<'a>> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for ModuleDescriptorRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| ModuleDescriptor::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use moduledescriptor::ModuleDescriptor as ModuleDescriptor;
@@ -0,0 +1,128 @@
+use dbuf_core::ast::elaborated as e;
+use dbuf_core::cst;
+use dbuf_core::elaboration::{self, builtins::BuiltinType};
+use dbuf_gen::descriptor_gen;
+use dbuf_gen::import::Import;
+use dbuf_rust_runtime::DeserializeError;
+use dbuf_rust_runtime::descriptor::{self as d, ModuleDescriptor};
+use pretty_assertions::assert_eq;
+
+use crate::common;
+
+fn json(descriptor: &ModuleDescriptor) -> String {
+    let mut writer = Vec::new();
+    descriptor
+        .write_json(&mut writer)
+        .expect("couldn't write json");
+    String::from_utf8(writer).expect("json must be correct utf8")
+}
+
+pub(super) fn binary(descriptor: &ModuleDescriptor) -> Vec<u8> {
+    let mut writer = Vec::new();
+    descriptor
+        .write_binary(&mut writer)
+        .expect("couldn't write binary");
+    writer
+}
+
+pub(super) fn elaborate_schema() -> e::Module<dbuf_core::arena::InternedString> {
+    let (tree, errors) = cst::parse_to_cst(d::SCHEMA);
+    assert!(errors.is_empty(), "schema must parse");
+    let ast = cst::convert_to_ast(&tree.expect("schema must parse"));
+    let (module, errors) = elaboration::elaborate(&ast);
+    assert!(errors.is_empty(), "schema must elaborate: {errors:?}");
+    module
+}
+
+#[test]
+fn defaults() {
+    let module = common::get_defaults_module();
+    let descriptor = descriptor_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/defaults.json");
+    assert_eq!(json(&descriptor), expected);
+}
+
+#[test]
+fn json_round_trip() {
+    let descriptor = descriptor_gen::generate_module(&common::get_defaults_module());
+
+    let read =
+        ModuleDescriptor::read_json(&mut json(&descriptor).as_bytes()).expect("couldn't read json");
+    assert_eq!(read, descriptor);
+}
+
+#[test]
+fn binary_round_trip() {
+    for module in [
+        common::get_defaults_module(),
+        common::get_nat_vec_module(),
+        common::get_sized_ints_module(),
+        common::get_doubles_module(),
+        elaborate_schema(),
+    ] {
+        let descriptor = descriptor_gen::generate_module(&module);
+        let bytes = binary(&descriptor);
+
+        let mut reader = bytes.as_slice();
+        let read = ModuleDescriptor::read_binary(&mut reader).expect("couldn't read binary");
+        assert!(reader.is_empty(), "descriptor must be read to the end");
+        assert_eq!(read, descriptor);
+    }
+}
+
+#[test]
+fn imported_types_are_described() {
+    let nat = common::nat();
+    let imports = [Import {
+        name: "nat",
+        module: &nat,
+    }];
+    let descriptor = descriptor_gen::generate_module_with_imports(&common::vec(), &imports);
+
+    let names: Vec<_> = descriptor.types.iter().map(|ty| ty.name.as_str()).collect();
+    assert_eq!(names, ["Nat", "Vec"]);
+    assert_eq!(
+        descriptor.types[0],
+        descriptor_gen::generate_module(&nat).types[0]
+    );
+}
+
+#[test]
+fn schema_describes_descriptors() {
+    let descriptor = descriptor_gen::generate_module(&elaborate_schema());
+
+    let root = descriptor
+        .types
+        .iter()
+        .find(|ty| ty.name == "ModuleDescriptor")
+        .expect("schema must describe ModuleDescriptor");
+    let fields: Vec<_> = root.constructors[0]
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect();
+    assert_eq!(fields, ["version", "packagePath", "types", "aliases"]);
+}
+
+#[test]
+fn unsupported_version_is_rejected() {
+    let mut descriptor = descriptor_gen::generate_module(&common::get_defaults_module());
+    descriptor.version = d::FORMAT_VERSION + 1;
+
+    assert!(matches!(
+        ModuleDescriptor::read_binary(&mut binary(&descriptor).as_slice()),
+        Err(DeserializeError::UnsupportedVersion(version)) if version == d::FORMAT_VERSION + 1
+    ));
+    assert!(ModuleDescriptor::read_json(&mut json(&descriptor).as_bytes()).is_err());
+}
+
+#[test]
+fn builtin_types_are_listed() {
+    let builtins: Vec<_> = BuiltinType::ALL
+        .iter()
+        .chain([&BuiltinType::Type])
+        .map(|builtin| builtin.as_str())
+        .collect();
+    assert_eq!(d::BUILTIN_TYPES, builtins);
+}
@@ -0,0 +1,3 @@
+mod canon_tests;
+#[cfg(feature = "rust")]
+mod schema_tests;
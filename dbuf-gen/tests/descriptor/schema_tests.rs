@@ -0,0 +1,83 @@
+use dbuf_gen::{codegen, descriptor_gen};
+use dbuf_rust_runtime::DbufMessage;
+use pretty_assertions::assert_eq;
+
+use super::canon_tests::{binary, elaborate_schema};
+
+#[test]
+fn schema_reader_is_generated() {
+    let mut writer = Vec::new();
+    assert!(codegen::generate_module(&elaborate_schema(), &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/schema.rs");
+    assert_eq!(code, expected);
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod schema {
+    include!("./canon/schema.rs");
+}
+
+#[test]
+fn schema_reader_decodes_binary_descriptors() {
+    for module in [
+        crate::common::get_defaults_module(),
+        crate::common::get_nat_vec_module(),
+        elaborate_schema(),
+    ] {
+        let descriptor = descriptor_gen::generate_module(&module);
+        let bytes = binary(&descriptor);
+
+        let mut reader = bytes.as_slice();
+        let read = schema::ModuleDescriptor::deserialize(
+            schema::moduledescriptor::Dependencies {},
+            &mut reader,
+        )
+        .expect("couldn't decode descriptor by generated reader");
+        assert!(reader.is_empty(), "descriptor must be read to the end");
+
+        assert_eq!(read.body.version, descriptor.version);
+        assert_eq!(read.body.packagePath, descriptor.package_path);
+        let types: Vec<_> = read
+            .body
+            .types
+            .iter()
+            .map(|ty| {
+                let constructors: Vec<_> = ty
+                    .body
+                    .constructors
+                    .iter()
+                    .map(|ctor| {
+                        let fields: Vec<_> = ctor
+                            .body
+                            .fields
+                            .iter()
+                            .map(|field| field.body.name.clone())
+                            .collect();
+                        (ctor.body.name.clone(), fields)
+                    })
+                    .collect();
+                (ty.body.name.clone(), constructors)
+            })
+            .collect();
+        let expected: Vec<_> = descriptor
+            .types
+            .iter()
+            .map(|ty| {
+                let constructors: Vec<_> = ty
+                    .constructors
+                    .iter()
+                    .map(|ctor| {
+                        let fields: Vec<_> =
+                            ctor.fields.iter().map(|field| field.name.clone()).collect();
+                        (ctor.name.clone(), fields)
+                    })
+                    .collect();
+                (ty.name.clone(), constructors)
+            })
+            .collect();
+        assert_eq!(types, expected);
+    }
+}
@@ -1,5 +1,7 @@
 mod common;
 
+#[cfg(feature = "descriptor")]
+mod descriptor;
 #[cfg(feature = "kotlin")]
 mod kotlin;
 #[cfg(feature = "rust")]
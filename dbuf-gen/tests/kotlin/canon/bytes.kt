@@ -0,0 +1,14 @@
+sealed class Blob {
+    val tag: ByteArray;
+    private constructor(tag: ByteArray) {
+        // constructor asserts
+        this.tag = tag;
+    }
+    class Blob: Blob {
+        val payload: ByteArray;
+        constructor(tag: ByteArray, payload: ByteArray): super(tag) {
+            // inner class asserts
+            this.payload = payload;
+        }
+    }
+}
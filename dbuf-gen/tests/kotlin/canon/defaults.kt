@@ -0,0 +1,16 @@
+sealed class Settings {
+    val version: UInt;
+    private constructor(version: UInt) {
+        // constructor asserts
+        this.version = version;
+    }
+    class Settings: Settings {
+        val retries: UInt;
+        val level: UInt;
+        constructor(version: UInt, retries: UInt = 3u, level: UInt = version+1u): super(version) {
+            // inner class asserts
+            this.retries = retries;
+            this.level = level;
+        }
+    }
+}
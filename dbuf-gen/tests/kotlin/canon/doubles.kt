@@ -0,0 +1,14 @@
+sealed class Scaled {
+    val factor: Double;
+    private constructor(factor: Double) {
+        // constructor asserts
+        this.factor = factor;
+    }
+    class Scaled: Scaled {
+        val value: Double;
+        constructor(factor: Double, value: Double): super(factor) {
+            // inner class asserts
+            this.value = value;
+        }
+    }
+}
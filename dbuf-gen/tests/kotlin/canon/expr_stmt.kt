@@ -0,0 +1,40 @@
+sealed class Expr {
+    private constructor() {
+        // constructor asserts
+    }
+    class Block: Expr {
+        val body: Stmt;
+        val result: Expr;
+        constructor(body: Stmt, result: Expr): super() {
+            // inner class asserts
+            this.body = body;
+            this.result = result;
+        }
+    }
+    class Literal: Expr {
+        val value: Int;
+        constructor(value: Int): super() {
+            // inner class asserts
+            this.value = value;
+        }
+    }
+}
+sealed class Stmt {
+    private constructor() {
+        // constructor asserts
+    }
+    class Assign: Stmt {
+        val name: String;
+        val value: Expr;
+        constructor(name: String, value: Expr): super() {
+            // inner class asserts
+            this.name = name;
+            this.value = value;
+        }
+    }
+    class Skip: Stmt {
+        constructor(): super() {
+            // inner class asserts
+        }
+    }
+}
@@ -0,0 +1,34 @@
+sealed class Layout {
+    val bits: UInt;
+    val offset: Byte;
+    private constructor(bits: UInt, offset: Byte) {
+        // constructor asserts
+        this.bits = bits;
+        this.offset = offset;
+    }
+    class Layout: Layout {
+        val bytes: UInt;
+        val rest: UInt;
+        val scaled: Byte;
+        val halved: Byte;
+        constructor(bits: UInt, offset: Byte, bytes: UInt = dbufDiv(bits, 8u), rest: UInt = dbufRem(bits, 8u), scaled: Byte = dbufShl(offset, (2).toByte()), halved: Byte = dbufShr(offset, (1).toByte())): super(bits, offset) {
+            // inner class asserts
+            this.bytes = bytes;
+            this.rest = rest;
+            this.scaled = scaled;
+            this.halved = halved;
+        }
+    }
+}
+private fun dbufDiv(lhs: UInt, rhs: UInt): UInt {
+    require(rhs.toULong() != 0uL) { "division by zero" }
+    return (lhs.toULong() / rhs.toULong()).toUInt()
+}
+private fun dbufRem(lhs: UInt, rhs: UInt): UInt {
+    require(rhs.toULong() != 0uL) { "division by zero" }
+    return (lhs.toULong() % rhs.toULong()).toUInt()
+}
+private fun dbufShl(lhs: Byte, rhs: Byte): Byte =
+    (if (rhs.toLong() !in 0L until 8L) 0L else lhs.toLong() shl rhs.toInt()).toByte()
+private fun dbufShr(lhs: Byte, rhs: Byte): Byte =
+    (if (rhs.toLong() !in 0L until 8L) lhs.toLong() shr 63 else lhs.toLong() shr rhs.toInt()).toByte()
@@ -0,0 +1,19 @@
+package acme.math
+
+sealed class Nat {
+    private constructor() {
+        // constructor asserts
+    }
+    class Suc: Nat {
+        val pred: acme.math.Nat;
+        constructor(pred: acme.math.Nat): super() {
+            // inner class asserts
+            this.pred = pred;
+        }
+    }
+    class Zero: Nat {
+        constructor(): super() {
+            // inner class asserts
+        }
+    }
+}
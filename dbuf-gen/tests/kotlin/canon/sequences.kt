@@ -0,0 +1,16 @@
+sealed class Batch {
+    val n: UInt;
+    private constructor(n: UInt) {
+        // constructor asserts
+        this.n = n;
+    }
+    class Batch: Batch {
+        val values: List<Int>;
+        val flags: List<Bool>;
+        constructor(n: UInt, values: List<Int>, flags: List<Bool>): super(n) {
+            // inner class asserts
+            this.values = values;
+            this.flags = flags;
+        }
+    }
+}
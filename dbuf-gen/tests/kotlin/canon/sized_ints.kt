@@ -0,0 +1,18 @@
+sealed class Reading {
+    val channel: UByte;
+    private constructor(channel: UByte) {
+        // constructor asserts
+        this.channel = channel;
+    }
+    class Reading: Reading {
+        val delta: Short;
+        val count: UInt;
+        val stamp: Long;
+        constructor(channel: UByte, delta: Short, count: UInt, stamp: Long): super(channel) {
+            // inner class asserts
+            this.delta = delta;
+            this.count = count;
+            this.stamp = stamp;
+        }
+    }
+}
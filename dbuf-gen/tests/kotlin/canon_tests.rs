@@ -22,6 +22,61 @@ fn nat_vec() {
     assert_eq!(code, expected);
 }
 
+#[test]
+fn packaged_nat() {
+    let module = common::packaged_nat();
+    let code = kotlin_gen::generate_module(&module);
+
+    println!("{code}");
+    let expected = include_str!("./canon/packaged_nat.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sequences() {
+    let module = common::get_sequences_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/sequences.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn bytes() {
+    let module = common::get_bytes_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/bytes.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn doubles() {
+    let module = common::get_doubles_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/doubles.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sized_ints() {
+    let module = common::get_sized_ints_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/sized_ints.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn expr_stmt() {
+    let module = common::get_expr_stmt_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/expr_stmt.kt");
+    assert_eq!(code, expected);
+}
+
 #[test]
 fn dependent_messages() {
     // message Sum (a Int) {}
@@ -314,3 +369,30 @@ fn complex_dependencies_substitution() {
     //     },
     // )
 }
+
+#[test]
+fn aliases() {
+    let module = common::get_aliases_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/aliases.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn defaults() {
+    let module = common::get_defaults_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/defaults.kt");
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn integer_ops() {
+    let module = common::get_integer_ops_module();
+    let code = kotlin_gen::generate_module(&module);
+
+    let expected = include_str!("./canon/integer_ops.kt");
+    assert_eq!(code, expected);
+}
@@ -0,0 +1,96 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod blob {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub payload: std::vec::Vec<u8>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub tag: std::vec::Vec<u8>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Blob {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Blob {
+        pub fn blob(tag: std::vec::Vec<u8>, payload: std::vec::Vec<u8>) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body {
+                    payload: payload
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                tag: tag.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <&'a [u8] as super::DbufView>::read_view((), reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Blob {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ payload } = &self.body;
+            <std::vec::Vec<u8> as super::DbufPrimitive>::dbuf_serialize(&payload, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let tag = dependencies.tag.clone();
+            let payload = <std::vec::Vec<u8> as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            Self::blob(tag.clone(), payload).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub payload: &'a [u8]
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BlobRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> BlobRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn payload(&self) -> &'a [u8] {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a [u8] as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                payload: <&'a [u8] as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for BlobRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Blob::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use blob::Blob as Blob;
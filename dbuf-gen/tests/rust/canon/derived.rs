@@ -0,0 +1,269 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod nat {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    mod descriptor {
+        pub(super) const Suc: u8 = 0;
+        pub(super) const Zero: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Suc {
+            pred: super::Box<Nat>
+        },
+        Zero {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Nat {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Nat {
+        pub fn suc(pred: super::Box<Nat>) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Suc {
+                    pred: pred
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn zero() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Zero {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+    }
+    impl super::DbufMessage for Nat {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Suc { pred } => {
+                    writer.write_all(&[descriptor::Suc])?;
+                    <Self as super::DbufMessage>::serialize(&pred, writer)?;
+                },
+                Body::Zero {  } => {
+                    writer.write_all(&[descriptor::Zero])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::Suc => {
+                    if let () = () {
+                        let pred = <Self as super::DbufMessage>::deserialize(Dependencies {
+                        
+                        }, reader)?;
+                        Self::suc(Box::new(pred)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Zero => {
+                    if let () = () {
+                        Self::zero().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+}
+
+pub use nat::Nat as Nat;
+
+pub mod vec {
+    mod deps {
+        pub(super) use super::super::{{nat, Nat}};
+    }
+    mod descriptor {
+        pub(super) const Cons: u8 = 0;
+        pub(super) const Nil: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Cons {
+            value: super::Box<deps::nat::Nat>,
+            tail: super::Box<Vec>
+        },
+        Nil {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub n: super::Box<deps::nat::Nat>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Vec {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Vec {
+        pub fn cons(p: super::Box<deps::nat::Nat>, value: super::Box<deps::nat::Nat>, tail: super::Box<Vec>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (&p.clone())) == ((),
+            (&tail.dependencies.n)) {
+                Ok(Body::Cons {
+                    value: value,
+                    tail: tail
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                n: Box::new(deps::nat::Nat::suc(p.clone()).expect("constructor 'Nat::Suc' failed"))
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn nil() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Nil {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                n: Box::new(deps::nat::Nat::zero().expect("constructor 'Nat::Zero' failed"))
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+    }
+    impl super::DbufMessage for Vec {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Cons { value, tail } => {
+                    <deps::Nat as super::DbufMessage>::serialize(&value, writer)?;
+                    <Self as super::DbufMessage>::serialize(&tail, writer)?;
+                },
+                Body::Nil {  } => {
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let descriptor = match (&dependencies.n.body) {
+                (deps::nat::Body::Suc { .. }) => descriptor::Cons,
+                (deps::nat::Body::Zero { .. }) => descriptor::Nil,
+                #[allow(unreachable_patterns)]
+                _ => return Err(super::DeserializeError::DependenciesDescriptorMismatch),
+            };
+            match descriptor {
+                descriptor::Cons => {
+                    if let (deps::nat::Body::Suc { pred: p }) = (dependencies.n.body) {
+                        let value = <deps::Nat as super::DbufMessage>::deserialize(deps::nat::Dependencies {
+                        
+                        }, reader)?;
+                        let tail = <Self as super::DbufMessage>::deserialize(Dependencies {
+                            n: p.clone().clone()
+                        }, reader)?;
+                        Self::cons(p.clone(), Box::new(value), Box::new(tail)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Nil => {
+                    if let (deps::nat::Body::Zero {  }) = (dependencies.n.body) {
+                        Self::nil().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+}
+
+pub use vec::Vec as Vec;
+
+pub mod range {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub length: u64,
+        pub end: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub start: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Range {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Range {
+        pub fn range(start: u64, length: u64, end: u64) -> Result<Self, super::ConstructorError> {
+            if !super::refinement_holds(|| Ok((end.clone() == super::DbufArithmetic::dbuf_add(start.clone(), length.clone())?))) {
+                return Err(super::ConstructorError::RefinementViolated("end"));
+            }
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    length: length,
+                    end: end
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                start: start.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+    }
+    impl super::DbufMessage for Range {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ length, end: _ } = &self.body;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&length, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let start = dependencies.start.clone();
+            let length = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            let end = super::DbufArithmetic::dbuf_add(start.clone(), length.clone())?;
+            if !super::refinement_holds(|| Ok((end.clone() == super::DbufArithmetic::dbuf_add(start.clone(), length.clone())?))) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("end")));
+            }
+            Self::range(start.clone(), length, end).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+}
+
+pub use range::Range as Range;
@@ -0,0 +1,96 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod scaled {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub value: dbuf_rust_runtime::Double
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub factor: dbuf_rust_runtime::Double
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Scaled {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Scaled {
+        pub fn scaled(factor: dbuf_rust_runtime::Double, value: dbuf_rust_runtime::Double) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                factor: factor.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <dbuf_rust_runtime::Double as super::DbufView>::read_view((), reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Scaled {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ value } = &self.body;
+            <dbuf_rust_runtime::Double as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let factor = dependencies.factor.clone();
+            let value = <dbuf_rust_runtime::Double as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            Self::scaled(factor.clone(), value).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef {
+        pub value: dbuf_rust_runtime::Double
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ScaledRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ScaledRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn value(&self) -> dbuf_rust_runtime::Double {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <dbuf_rust_runtime::Double as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            BodyRef {
+                value: <dbuf_rust_runtime::Double as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for ScaledRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Scaled::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use scaled::Scaled as Scaled;
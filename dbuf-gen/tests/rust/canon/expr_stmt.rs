@@ -0,0 +1,351 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod expr {
+    mod deps {
+        pub(super) use super::super::{{stmt, Stmt}};
+    }
+    mod descriptor {
+        pub(super) const Block: u8 = 0;
+        pub(super) const Literal: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Block {
+            body: super::Box<deps::stmt::Stmt>,
+            result: super::Box<Expr>
+        },
+        Literal {
+            value: i64
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Expr {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Expr {
+        pub fn block(body: super::Box<deps::stmt::Stmt>, result: super::Box<Expr>) -> Result<Self, super::ConstructorError> {
+            let body_1 = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body::Block {
+                    body: body,
+                    result: result
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body_1, dependencies: dependencies })
+        }
+        pub fn literal(value: i64) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Literal {
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::Block => {
+                    if let () = () {
+                        <deps::stmt::StmtRef<'a> as super::DbufView>::read_view(deps::stmt::Dependencies {
+                        
+                        }, reader)?;
+                        <ExprRef<'a> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Literal => {
+                    if let () = () {
+                        <i64 as super::DbufView>::read_view((), reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for Expr {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Block { body, result } => {
+                    writer.write_all(&[descriptor::Block])?;
+                    <deps::Stmt as super::DbufMessage>::serialize(&body, writer)?;
+                    <Self as super::DbufMessage>::serialize(&result, writer)?;
+                },
+                Body::Literal { value } => {
+                    writer.write_all(&[descriptor::Literal])?;
+                    <i64 as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::Block => {
+                    if let () = () {
+                        let body = <deps::Stmt as super::DbufMessage>::deserialize(deps::stmt::Dependencies {
+                        
+                        }, reader)?;
+                        let result = <Self as super::DbufMessage>::deserialize(Dependencies {
+                        
+                        }, reader)?;
+                        Self::block(Box::new(body), Box::new(result)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Literal => {
+                    if let () = () {
+                        let value = <i64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        Self::literal(value).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        Block {
+            body: deps::stmt::StmtRef<'a>,
+            result: ExprRef<'a>
+        },
+        Literal {
+            value: i64
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ExprRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ExprRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::Block => BodyRef::Block {
+                    body: <deps::stmt::StmtRef<'a> as super::DbufView>::read_checked(reader),
+                    result: <ExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Literal => BodyRef::Literal {
+                    value: <i64 as super::DbufView>::read_checked(reader)
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for ExprRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Expr::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use expr::Expr as Expr;
+
+pub mod stmt {
+    mod deps {
+        pub(super) use super::super::{{expr, Expr}};
+    }
+    mod descriptor {
+        pub(super) const Assign: u8 = 0;
+        pub(super) const Skip: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Assign {
+            name: String,
+            value: super::Box<deps::expr::Expr>
+        },
+        Skip {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Stmt {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Stmt {
+        pub fn assign(name: String, value: super::Box<deps::expr::Expr>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body::Assign {
+                    name: name,
+                    value: value
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn skip() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Skip {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::Assign => {
+                    if let () = () {
+                        <&'a str as super::DbufView>::read_view((), reader)?;
+                        <deps::expr::ExprRef<'a> as super::DbufView>::read_view(deps::expr::Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Skip => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for Stmt {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Assign { name, value } => {
+                    writer.write_all(&[descriptor::Assign])?;
+                    <String as super::DbufPrimitive>::dbuf_serialize(&name, writer)?;
+                    <deps::Expr as super::DbufMessage>::serialize(&value, writer)?;
+                },
+                Body::Skip {  } => {
+                    writer.write_all(&[descriptor::Skip])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::Assign => {
+                    if let () = () {
+                        let name = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+                        let value = <deps::Expr as super::DbufMessage>::deserialize(deps::expr::Dependencies {
+                        
+                        }, reader)?;
+                        Self::assign(name, Box::new(value)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Skip => {
+                    if let () = () {
+                        Self::skip().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        Assign {
+            name: &'a str,
+            value: deps::expr::ExprRef<'a>
+        },
+        Skip {
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct StmtRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> StmtRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::Assign => BodyRef::Assign {
+                    name: <&'a str as super::DbufView>::read_checked(reader),
+                    value: <deps::expr::ExprRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Skip => BodyRef::Skip {
+                
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for StmtRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Stmt::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use stmt::Stmt as Stmt;
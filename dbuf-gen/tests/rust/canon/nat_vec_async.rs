@@ -0,0 +1,425 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+use dbuf_rust_runtime::{BoxFuture, DbufMessageAsync, DbufPrimitiveAsync, defaulted_field_reader_async};
+use dbuf_rust_runtime::tokio::io::{AsyncRead, AsyncWrite, AsyncReadExt, AsyncWriteExt};
+pub mod nat {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    mod descriptor {
+        pub(super) const Suc: u8 = 0;
+        pub(super) const Zero: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Suc {
+            pred: super::Box<Nat>
+        },
+        Zero {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Nat {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Nat {
+        pub fn suc(pred: super::Box<Nat>) -> Result<Self, super::ConstructorError> {
+            let body = if (()) == (()) {
+                Ok(Body::Suc {
+                    pred: pred
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn zero() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Zero {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::Suc => {
+                    if let () = () {
+                        <NatRef<'a> as super::DbufView>::read_view(Dependencies {
+                        
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Zero => {
+                    if let () = () {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for Nat {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Suc { pred } => {
+                    writer.write_all(&[descriptor::Suc])?;
+                    <Self as super::DbufMessage>::serialize(&pred, writer)?;
+                },
+                Body::Zero {  } => {
+                    writer.write_all(&[descriptor::Zero])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::Suc => {
+                    if let () = () {
+                        let pred = <Self as super::DbufMessage>::deserialize(Dependencies {
+                        
+                        }, reader)?;
+                        Self::suc(Box::new(pred)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Zero => {
+                    if let () = () {
+                        Self::zero().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessageAsync for Nat {
+        fn serialize_async<'a, W: super::AsyncWrite + Unpin + Send>(&'a self, writer: &'a mut W) -> super::BoxFuture<'a, Result<(), super::Error>> {
+            Box::pin(async move {
+                match &self.body {
+                    Body::Suc { pred } => {
+                        super::AsyncWriteExt::write_all(writer, &[descriptor::Suc]).await?;
+                        <Self as super::DbufMessageAsync>::serialize_async(&pred, writer).await?;
+                    },
+                    Body::Zero {  } => {
+                        super::AsyncWriteExt::write_all(writer, &[descriptor::Zero]).await?;
+                    },
+                }
+                Ok(())
+            })
+        }
+        fn deserialize_async<R: super::AsyncRead + Unpin + Send>(dependencies: Dependencies, reader: &mut R) -> super::BoxFuture<'_, Result<Self, super::DeserializeError>> {
+            Box::pin(async move {
+                let mut descriptor = 0;
+                super::AsyncReadExt::read(reader, super::slice::from_mut(&mut descriptor)).await.map_err(|e| super::DeserializeError::IoError(e))?;
+                match descriptor {
+                    descriptor::Suc => {
+                        if let () = () {
+                            let pred = <Self as super::DbufMessageAsync>::deserialize_async(Dependencies {
+                            
+                            }, reader).await?;
+                            Self::suc(Box::new(pred)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                        } else {
+                            Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                        }},
+                    descriptor::Zero => {
+                        if let () = () {
+                            Self::zero().map_err(|e| super::DeserializeError::ConstructorError(e))
+                        } else {
+                            Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                        }},
+                    _ => Err(super::DeserializeError::UnknownDescriptor),
+                }
+            })
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        Suc {
+            pred: NatRef<'a>
+        },
+        Zero {
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct NatRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> NatRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::Suc => BodyRef::Suc {
+                    pred: <NatRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Zero => BodyRef::Zero {
+                
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for NatRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Nat::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use nat::Nat as Nat;
+
+pub mod vec {
+    mod deps {
+        pub(super) use super::super::{{nat, Nat}};
+    }
+    mod descriptor {
+        pub(super) const Cons: u8 = 0;
+        pub(super) const Nil: u8 = 1;
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Body {
+        Cons {
+            value: super::Box<deps::nat::Nat>,
+            tail: super::Box<Vec>
+        },
+        Nil {
+        
+        }
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub n: super::Box<deps::nat::Nat>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Vec {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Vec {
+        pub fn cons(p: super::Box<deps::nat::Nat>, value: super::Box<deps::nat::Nat>, tail: super::Box<Vec>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (&p.clone())) == ((),
+            (&tail.dependencies.n)) {
+                Ok(Body::Cons {
+                    value: value,
+                    tail: tail
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                n: Box::new(deps::nat::Nat::suc(p.clone()).expect("constructor 'Nat::Suc' failed"))
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn nil() -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body::Nil {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                n: Box::new(deps::nat::Nat::zero().expect("constructor 'Nat::Zero' failed"))
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+            match descriptor {
+                descriptor::Cons => {
+                    if let (deps::nat::Body::Suc { pred: p }) = (dependencies.n.body) {
+                        <deps::nat::NatRef<'a> as super::DbufView>::read_view(deps::nat::Dependencies {
+                        
+                        }, reader)?;
+                        <VecRef<'a> as super::DbufView>::read_view(Dependencies {
+                            n: p.clone().clone()
+                        }, reader)?;
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Nil => {
+                    if let (deps::nat::Body::Zero {  }) = (dependencies.n.body) {
+                        Ok(())
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessage for Vec {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            match &self.body {
+                Body::Cons { value, tail } => {
+                    writer.write_all(&[descriptor::Cons])?;
+                    <deps::Nat as super::DbufMessage>::serialize(&value, writer)?;
+                    <Self as super::DbufMessage>::serialize(&tail, writer)?;
+                },
+                Body::Nil {  } => {
+                    writer.write_all(&[descriptor::Nil])?;
+                },
+            }
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let mut descriptor = 0;
+            reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+            match descriptor {
+                descriptor::Cons => {
+                    if let (deps::nat::Body::Suc { pred: p }) = (dependencies.n.body) {
+                        let value = <deps::Nat as super::DbufMessage>::deserialize(deps::nat::Dependencies {
+                        
+                        }, reader)?;
+                        let tail = <Self as super::DbufMessage>::deserialize(Dependencies {
+                            n: p.clone().clone()
+                        }, reader)?;
+                        Self::cons(p.clone(), Box::new(value), Box::new(tail)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                descriptor::Nil => {
+                    if let (deps::nat::Body::Zero {  }) = (dependencies.n.body) {
+                        Self::nil().map_err(|e| super::DeserializeError::ConstructorError(e))
+                    } else {
+                        Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                    }},
+                _ => Err(super::DeserializeError::UnknownDescriptor),
+            }
+        }
+    }
+    impl super::DbufMessageAsync for Vec {
+        fn serialize_async<'a, W: super::AsyncWrite + Unpin + Send>(&'a self, writer: &'a mut W) -> super::BoxFuture<'a, Result<(), super::Error>> {
+            Box::pin(async move {
+                match &self.body {
+                    Body::Cons { value, tail } => {
+                        super::AsyncWriteExt::write_all(writer, &[descriptor::Cons]).await?;
+                        <deps::Nat as super::DbufMessageAsync>::serialize_async(&value, writer).await?;
+                        <Self as super::DbufMessageAsync>::serialize_async(&tail, writer).await?;
+                    },
+                    Body::Nil {  } => {
+                        super::AsyncWriteExt::write_all(writer, &[descriptor::Nil]).await?;
+                    },
+                }
+                Ok(())
+            })
+        }
+        fn deserialize_async<R: super::AsyncRead + Unpin + Send>(dependencies: Dependencies, reader: &mut R) -> super::BoxFuture<'_, Result<Self, super::DeserializeError>> {
+            Box::pin(async move {
+                let mut descriptor = 0;
+                super::AsyncReadExt::read(reader, super::slice::from_mut(&mut descriptor)).await.map_err(|e| super::DeserializeError::IoError(e))?;
+                match descriptor {
+                    descriptor::Cons => {
+                        if let (deps::nat::Body::Suc { pred: p }) = (dependencies.n.body) {
+                            let value = <deps::Nat as super::DbufMessageAsync>::deserialize_async(deps::nat::Dependencies {
+                            
+                            }, reader).await?;
+                            let tail = <Self as super::DbufMessageAsync>::deserialize_async(Dependencies {
+                                n: p.clone().clone()
+                            }, reader).await?;
+                            Self::cons(p.clone(), Box::new(value), Box::new(tail)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                        } else {
+                            Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                        }},
+                    descriptor::Nil => {
+                        if let (deps::nat::Body::Zero {  }) = (dependencies.n.body) {
+                            Self::nil().map_err(|e| super::DeserializeError::ConstructorError(e))
+                        } else {
+                            Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                        }},
+                    _ => Err(super::DeserializeError::UnknownDescriptor),
+                }
+            })
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub enum BodyRef<'a> {
+        Cons {
+            value: deps::nat::NatRef<'a>,
+            tail: VecRef<'a>
+        },
+        Nil {
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct VecRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> VecRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            match <u8 as super::DbufView>::read_checked(reader) {
+                descriptor::Cons => BodyRef::Cons {
+                    value: <deps::nat::NatRef<'a> as super::DbufView>::read_checked(reader),
+                    tail: <VecRef<'a> as super::DbufView>::read_checked(reader)
+                },
+                descriptor::Nil => BodyRef::Nil {
+                
+                },
+                _ => unreachable!("view is checked when read"),
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for VecRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Vec::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use vec::Vec as Vec;
@@ -0,0 +1,228 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod settings {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub retries: u64,
+        pub level: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub version: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Settings {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Settings {
+        pub fn settings(version: u64, retries: u64, level: u64) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    retries: retries,
+                    level: level
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                version: version.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        pub fn settings_with_defaults(version: u64) -> Result<Self, super::ConstructorError> {
+            Self::settings(version, 3, super::DbufArithmetic::dbuf_add(version.clone(), 1)?)
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            if !reader.is_empty() {
+                <u64 as super::DbufView>::read_view((), reader)?;
+            }
+            if !reader.is_empty() {
+                <u64 as super::DbufView>::read_view((), reader)?;
+            }
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Settings {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ retries, level } = &self.body;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&retries, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&level, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let version = dependencies.version.clone();
+            let retries = super::deserialize_or_default(reader, |reader| Ok(<u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?), || Ok(3))?;
+            let level = super::deserialize_or_default(reader, |reader| Ok(<u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?), || Ok(super::DbufArithmetic::dbuf_add(version.clone(), 1)?))?;
+            Self::settings(version.clone(), retries, level).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef {
+        pub retries: Option<u64>,
+        pub level: Option<u64>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct SettingsRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> SettingsRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn retries(&self) -> Option<u64> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            if reader.is_empty() { None } else { Some(<u64 as super::DbufView>::read_checked(reader)) }
+        }
+        pub fn level(&self) -> Option<u64> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            if !reader.is_empty() { <u64 as super::DbufView>::read_checked(reader); }
+            if reader.is_empty() { None } else { Some(<u64 as super::DbufView>::read_checked(reader)) }
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            BodyRef {
+                retries: if reader.is_empty() { None } else { Some(<u64 as super::DbufView>::read_checked(reader)) },
+                level: if reader.is_empty() { None } else { Some(<u64 as super::DbufView>::read_checked(reader)) }
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for SettingsRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Settings::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use settings::Settings as Settings;
+
+pub mod profile {
+    mod deps {
+        pub(super) use super::super::{{settings, Settings}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub settings: super::Box<deps::settings::Settings>,
+        pub id: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Profile {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Profile {
+        pub fn profile(settings: super::Box<deps::settings::Settings>, id: u64) -> Result<Self, super::ConstructorError> {
+            let body = if ((&1),
+            ()) == ((&settings.dependencies.version),
+            ()) {
+                Ok(Body {
+                    settings: settings,
+                    id: id
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <deps::settings::SettingsRef<'a> as super::DbufView>::read_view(deps::settings::Dependencies {
+                version: 1
+            }, reader)?;
+            <u64 as super::DbufView>::read_view((), reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Profile {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ settings, id } = &self.body;
+            <deps::Settings as super::DbufMessage>::serialize(&settings, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&id, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let settings = <deps::Settings as super::DbufMessage>::deserialize(deps::settings::Dependencies {
+                version: 1
+            }, reader)?;
+            let id = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            Self::profile(Box::new(settings), id).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub settings: deps::settings::SettingsRef<'a>,
+        pub id: u64
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ProfileRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ProfileRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn settings(&self) -> deps::settings::SettingsRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <deps::settings::SettingsRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        pub fn id(&self) -> u64 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <deps::settings::SettingsRef<'a> as super::DbufView>::read_checked(reader);
+            <u64 as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                settings: <deps::settings::SettingsRef<'a> as super::DbufView>::read_checked(reader),
+                id: <u64 as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for ProfileRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Profile::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use profile::Profile as Profile;
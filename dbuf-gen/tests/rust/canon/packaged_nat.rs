@@ -0,0 +1,168 @@
+pub mod acme {
+    pub mod math {
+        use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+        use std::io::{Write, Read, Error};
+        use std::slice;
+        pub mod nat {
+            mod deps {
+                // pub(super) use super::super::{};
+            }
+            mod descriptor {
+                pub(super) const Suc: u8 = 0;
+                pub(super) const Zero: u8 = 1;
+            }
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub enum Body {
+                Suc {
+                    pred: super::Box<Nat>
+                },
+                Zero {
+                
+                }
+            }
+            
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub struct Dependencies {
+            
+            }
+            
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub struct Nat {
+                pub body: Body,
+                pub dependencies: Dependencies
+            }
+            impl Nat {
+                pub fn suc(pred: super::Box<Nat>) -> Result<Self, super::ConstructorError> {
+                    let body = if (()) == (()) {
+                        Ok(Body::Suc {
+                            pred: pred
+                        })
+                    } else {
+                        Err(super::ConstructorError::MismatchedDependencies)
+                    }?;
+                    let dependencies = Dependencies {
+                    
+                    };
+                    Ok(Self { body: body, dependencies: dependencies })
+                }
+                pub fn zero() -> Result<Self, super::ConstructorError> {
+                    let body = if () == () {
+                        Ok(Body::Zero {
+                        
+                        })
+                    } else {
+                        Err(super::ConstructorError::MismatchedDependencies)
+                    }?;
+                    let dependencies = Dependencies {
+                    
+                    };
+                    Ok(Self { body: body, dependencies: dependencies })
+                }
+                fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+                    let descriptor = <u8 as super::DbufView>::read_view((), reader)?;
+                    match descriptor {
+                        descriptor::Suc => {
+                            if let () = () {
+                                <NatRef<'a> as super::DbufView>::read_view(Dependencies {
+                                
+                                }, reader)?;
+                                Ok(())
+                            } else {
+                                Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                            }},
+                        descriptor::Zero => {
+                            if let () = () {
+                                Ok(())
+                            } else {
+                                Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                            }},
+                        _ => Err(super::DeserializeError::UnknownDescriptor),
+                    }
+                }
+            }
+            impl super::DbufMessage for Nat {
+                type Dependencies = Dependencies;
+                fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+                    match &self.body {
+                        Body::Suc { pred } => {
+                            writer.write_all(&[descriptor::Suc])?;
+                            <Self as super::DbufMessage>::serialize(&pred, writer)?;
+                        },
+                        Body::Zero {  } => {
+                            writer.write_all(&[descriptor::Zero])?;
+                        },
+                    }
+                    Ok(())
+                }
+                fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+                    let mut descriptor = 0;
+                    reader.read(super::slice::from_mut(&mut descriptor)).map_err(|e| super::DeserializeError::IoError(e))?;
+                    match descriptor {
+                        descriptor::Suc => {
+                            if let () = () {
+                                let pred = <Self as super::DbufMessage>::deserialize(Dependencies {
+                                
+                                }, reader)?;
+                                Self::suc(Box::new(pred)).map_err(|e| super::DeserializeError::ConstructorError(e))
+                            } else {
+                                Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                            }},
+                        descriptor::Zero => {
+                            if let () = () {
+                                Self::zero().map_err(|e| super::DeserializeError::ConstructorError(e))
+                            } else {
+                                Err(super::DeserializeError::DependenciesDescriptorMismatch)
+                            }},
+                        _ => Err(super::DeserializeError::UnknownDescriptor),
+                    }
+                }
+            }
+            #[derive(Clone, Copy, Debug)]
+            pub enum BodyRef<'a> {
+                Suc {
+                    pred: NatRef<'a>
+                },
+                Zero {
+                
+                }
+            }
+            #[derive(Clone, Copy, Debug)]
+            pub struct NatRef<'a> {
+                encoding: &'a [u8]
+            }
+            impl<'a> NatRef<'a> {
+                pub fn encoding(&self) -> &'a [u8] {
+                    self.encoding
+                }
+                pub fn body(&self) -> BodyRef<'a> {
+                    Self::read_body(&mut super::SliceReader::new(self.encoding))
+                }
+                fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+                    match <u8 as super::DbufView>::read_checked(reader) {
+                        descriptor::Suc => BodyRef::Suc {
+                            pred: <NatRef<'a> as super::DbufView>::read_checked(reader)
+                        },
+                        descriptor::Zero => BodyRef::Zero {
+                        
+                        },
+                        _ => unreachable!("view is checked when read"),
+                    }
+                }
+            }
+            impl<'a> super::DbufView<'a> for NatRef<'a> {
+                type Dependencies = Dependencies;
+                fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+                    let (checked, encoding) = reader.read_encoding(|reader| Nat::check_encoding(dependencies, reader));
+                    checked?;
+                    Ok(Self { encoding: encoding })
+                }
+                fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+                    let (_, encoding) = reader.read_encoding(Self::read_body);
+                    Self { encoding: encoding }
+                }
+            }
+        }
+        
+        pub use nat::Nat as Nat;
+    }
+}
@@ -0,0 +1,138 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod bounded {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub used: u64,
+        pub spare: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub limit: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Bounded {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Bounded {
+        pub fn bounded(limit: u64, used: u64, spare: u64) -> Result<Self, super::ConstructorError> {
+            if !(0 < limit.clone()) {
+                return Err(super::ConstructorError::RefinementViolated("limit"));
+            }
+            if !(used.clone() <= limit.clone()) {
+                return Err(super::ConstructorError::RefinementViolated("used"));
+            }
+            if !super::refinement_holds(|| Ok((super::DbufArithmetic::dbuf_add(used.clone(), spare.clone())? <= limit.clone()))) {
+                return Err(super::ConstructorError::RefinementViolated("spare"));
+            }
+            let body = if ((),
+            ()) == ((),
+            ()) {
+                Ok(Body {
+                    used: used,
+                    spare: spare
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                limit: limit.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let limit = dependencies.limit.clone();
+            if !(0 < limit.clone()) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("limit")));
+            }
+            let used = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            if !(used.clone() <= limit.clone()) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("used")));
+            }
+            let spare = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            if !super::refinement_holds(|| Ok((super::DbufArithmetic::dbuf_add(used.clone(), spare.clone())? <= limit.clone()))) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("spare")));
+            }
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Bounded {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ used, spare } = &self.body;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&used, writer)?;
+            <u64 as super::DbufPrimitive>::dbuf_serialize(&spare, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let limit = dependencies.limit.clone();
+            if !(0 < limit.clone()) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("limit")));
+            }
+            let used = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            if !(used.clone() <= limit.clone()) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("used")));
+            }
+            let spare = <u64 as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            if !super::refinement_holds(|| Ok((super::DbufArithmetic::dbuf_add(used.clone(), spare.clone())? <= limit.clone()))) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("spare")));
+            }
+            Self::bounded(limit.clone(), used, spare).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef {
+        pub used: u64,
+        pub spare: u64
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BoundedRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> BoundedRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn used(&self) -> u64 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <u64 as super::DbufView>::read_checked(reader)
+        }
+        pub fn spare(&self) -> u64 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <u64 as super::DbufView>::read_checked(reader);
+            <u64 as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            BodyRef {
+                used: <u64 as super::DbufView>::read_checked(reader),
+                spare: <u64 as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for BoundedRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Bounded::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use bounded::Bounded as Bounded;
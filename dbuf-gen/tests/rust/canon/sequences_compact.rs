@@ -0,0 +1,135 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+use dbuf_rust_runtime::{BoxFuture, DbufMessageAsync, DbufPrimitiveAsync, defaulted_field_reader_async};
+use dbuf_rust_runtime::tokio::io::{AsyncRead, AsyncWrite, AsyncReadExt, AsyncWriteExt};
+pub mod batch {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub values: std::vec::Vec<i64>,
+        pub flags: std::vec::Vec<bool>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub n: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Batch {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Batch {
+        pub fn batch(n: u64, values: std::vec::Vec<i64>, flags: std::vec::Vec<bool>) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (&n.clone())) == ((),
+            (&(flags.len() as u64))) {
+                Ok(Body {
+                    values: values,
+                    flags: flags
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                n: n.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let n = dependencies.n.clone();
+            <dbuf_rust_runtime::ListRef<'a, i64, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_view((), reader)?;
+            let flags = <dbuf_rust_runtime::ListRef<'a, bool, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_view((), reader)?;
+            if n.clone() != (flags.len() as u64) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::MismatchedDependencies));
+            }
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Batch {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ values, flags } = &self.body;
+            <std::vec::Vec<i64> as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_serialize(&values, writer)?;
+            <std::vec::Vec<bool> as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_serialize(&flags, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let n = dependencies.n.clone();
+            let values = <std::vec::Vec<i64> as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_deserialize(reader)?;
+            let flags = <std::vec::Vec<bool> as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_deserialize(reader)?;
+            Self::batch(n.clone(), values, flags).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    impl super::DbufMessageAsync for Batch {
+        fn serialize_async<'a, W: super::AsyncWrite + Unpin + Send>(&'a self, writer: &'a mut W) -> super::BoxFuture<'a, Result<(), super::Error>> {
+            Box::pin(async move {
+                let Body{ values, flags } = &self.body;
+                <std::vec::Vec<i64> as super::DbufPrimitiveAsync<dbuf_rust_runtime::Compact>>::dbuf_serialize_async(&values, writer).await?;
+                <std::vec::Vec<bool> as super::DbufPrimitiveAsync<dbuf_rust_runtime::Compact>>::dbuf_serialize_async(&flags, writer).await?;
+                Ok(())
+            })
+        }
+        fn deserialize_async<R: super::AsyncRead + Unpin + Send>(dependencies: Dependencies, reader: &mut R) -> super::BoxFuture<'_, Result<Self, super::DeserializeError>> {
+            Box::pin(async move {
+                let n = dependencies.n.clone();
+                let values = <std::vec::Vec<i64> as super::DbufPrimitiveAsync<dbuf_rust_runtime::Compact>>::dbuf_deserialize_async(reader).await?;
+                let flags = <std::vec::Vec<bool> as super::DbufPrimitiveAsync<dbuf_rust_runtime::Compact>>::dbuf_deserialize_async(reader).await?;
+                Self::batch(n.clone(), values, flags).map_err(|e| super::DeserializeError::ConstructorError(e))
+            
+            })
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub values: dbuf_rust_runtime::ListRef<'a, i64, dbuf_rust_runtime::Compact>,
+        pub flags: dbuf_rust_runtime::ListRef<'a, bool, dbuf_rust_runtime::Compact>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BatchRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> BatchRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn values(&self) -> dbuf_rust_runtime::ListRef<'a, i64, dbuf_rust_runtime::Compact> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <dbuf_rust_runtime::ListRef<'a, i64, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+        }
+        pub fn flags(&self) -> dbuf_rust_runtime::ListRef<'a, bool, dbuf_rust_runtime::Compact> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <dbuf_rust_runtime::ListRef<'a, i64, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader);
+            <dbuf_rust_runtime::ListRef<'a, bool, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                values: <dbuf_rust_runtime::ListRef<'a, i64, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader),
+                flags: <dbuf_rust_runtime::ListRef<'a, bool, dbuf_rust_runtime::Compact> as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a, dbuf_rust_runtime::Compact> for BatchRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Batch::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use batch::Batch as Batch;
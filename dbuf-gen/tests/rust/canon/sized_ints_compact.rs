@@ -0,0 +1,125 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod reading {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub delta: i16,
+        pub count: u32,
+        pub stamp: i64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub channel: u8
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Reading {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Reading {
+        pub fn reading(channel: u8, delta: i16, count: u32, stamp: i64) -> Result<Self, super::ConstructorError> {
+            let body = if ((),
+            (),
+            ()) == ((),
+            (),
+            ()) {
+                Ok(Body {
+                    delta: delta,
+                    count: count,
+                    stamp: stamp
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                channel: channel.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            <i16 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_view((), reader)?;
+            <u32 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_view((), reader)?;
+            <i64 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_view((), reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Reading {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ delta, count, stamp } = &self.body;
+            <i16 as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_serialize(&delta, writer)?;
+            <u32 as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_serialize(&count, writer)?;
+            <i64 as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_serialize(&stamp, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let channel = dependencies.channel.clone();
+            let delta = <i16 as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_deserialize(reader)?;
+            let count = <u32 as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_deserialize(reader)?;
+            let stamp = <i64 as super::DbufPrimitive<dbuf_rust_runtime::Compact>>::dbuf_deserialize(reader)?;
+            Self::reading(channel.clone(), delta, count, stamp).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef {
+        pub delta: i16,
+        pub count: u32,
+        pub stamp: i64
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct ReadingRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> ReadingRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn delta(&self) -> i16 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <i16 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+        }
+        pub fn count(&self) -> u32 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <i16 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader);
+            <u32 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+        }
+        pub fn stamp(&self) -> i64 {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <i16 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader);
+            <u32 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader);
+            <i64 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            BodyRef {
+                delta: <i16 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader),
+                count: <u32 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader),
+                stamp: <i64 as super::DbufView<'_, dbuf_rust_runtime::Compact>>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a, dbuf_rust_runtime::Compact> for ReadingRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Reading::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use reading::Reading as Reading;
@@ -0,0 +1,209 @@
+use dbuf_rust_runtime::{Box, ConstructorError, DeserializeError, DbufArithmetic, DbufInteger, DbufMessage, DbufPrimitive, DbufView, SliceReader, deserialize_or_default, refinement_holds};
+use std::io::{Write, Read, Error};
+use std::slice;
+pub mod buffer {
+    mod deps {
+        // pub(super) use super::super::{};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+        pub size: u64
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Buffer {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Buffer {
+        pub fn buffer(size: u64) -> Result<Self, super::ConstructorError> {
+            let body = if () == () {
+                Ok(Body {
+                
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+                size: size.clone()
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Buffer {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{  } = &self.body;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let size = dependencies.size.clone();
+            Self::buffer(size.clone()).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef {
+    
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BufferRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> BufferRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef {
+            BodyRef {
+            
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for BufferRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Buffer::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use buffer::Buffer as Buffer;
+
+pub mod urn {
+    mod deps {
+        pub(super) use super::super::{{buffer, Buffer}};
+    }
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Body {
+        pub value: String,
+        pub payload: super::Box<deps::buffer::Buffer>
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Dependencies {
+    
+    }
+    
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Urn {
+        pub body: Body,
+        pub dependencies: Dependencies
+    }
+    impl Urn {
+        pub fn urn(value: String, payload: super::Box<deps::buffer::Buffer>) -> Result<Self, super::ConstructorError> {
+            if !(value.clone()).starts_with(&String::from("urn:")) {
+                return Err(super::ConstructorError::RefinementViolated("value"));
+            }
+            let body = if ((),
+            (&(value.clone().len() as u64))) == ((),
+            (&payload.dependencies.size)) {
+                Ok(Body {
+                    value: value,
+                    payload: payload
+                })
+            } else {
+                Err(super::ConstructorError::MismatchedDependencies)
+            }?;
+            let dependencies = Dependencies {
+            
+            };
+            Ok(Self { body: body, dependencies: dependencies })
+        }
+        fn check_encoding<'a>(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<(), super::DeserializeError> {
+            let value = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            if !(value.clone()).starts_with(&String::from("urn:")) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("value")));
+            }
+            <deps::buffer::BufferRef<'a> as super::DbufView>::read_view(deps::buffer::Dependencies {
+                size: (value.clone().clone().len() as u64)
+            }, reader)?;
+            Ok(())
+        
+        }
+    }
+    impl super::DbufMessage for Urn {
+        type Dependencies = Dependencies;
+        fn serialize<W: super::Write>(&self, writer: &mut W) -> Result<(), super::Error> {
+            let Body{ value, payload } = &self.body;
+            <String as super::DbufPrimitive>::dbuf_serialize(&value, writer)?;
+            <deps::Buffer as super::DbufMessage>::serialize(&payload, writer)?;
+            Ok(())
+        }
+        fn deserialize<R: super::Read>(dependencies: Dependencies, reader: &mut R) -> Result<Self, super::DeserializeError> {
+            let value = <String as super::DbufPrimitive>::dbuf_deserialize(reader)?;
+            if !(value.clone()).starts_with(&String::from("urn:")) {
+                return Err(super::DeserializeError::ConstructorError(super::ConstructorError::RefinementViolated("value")));
+            }
+            let payload = <deps::Buffer as super::DbufMessage>::deserialize(deps::buffer::Dependencies {
+                size: (value.clone().clone().len() as u64)
+            }, reader)?;
+            Self::urn(value, Box::new(payload)).map_err(|e| super::DeserializeError::ConstructorError(e))
+        
+        }
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct BodyRef<'a> {
+        pub value: &'a str,
+        pub payload: deps::buffer::BufferRef<'a>
+    }
+    #[derive(Clone, Copy, Debug)]
+    pub struct UrnRef<'a> {
+        encoding: &'a [u8]
+    }
+    impl<'a> UrnRef<'a> {
+        pub fn encoding(&self) -> &'a [u8] {
+            self.encoding
+        }
+        pub fn body(&self) -> BodyRef<'a> {
+            Self::read_body(&mut super::SliceReader::new(self.encoding))
+        }
+        pub fn value(&self) -> &'a str {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader)
+        }
+        pub fn payload(&self) -> deps::buffer::BufferRef<'a> {
+            let reader = &mut super::SliceReader::new(self.encoding);
+            <&'a str as super::DbufView>::read_checked(reader);
+            <deps::buffer::BufferRef<'a> as super::DbufView>::read_checked(reader)
+        }
+        fn read_body(reader: &mut super::SliceReader<'a>) -> BodyRef<'a> {
+            BodyRef {
+                value: <&'a str as super::DbufView>::read_checked(reader),
+                payload: <deps::buffer::BufferRef<'a> as super::DbufView>::read_checked(reader)
+            }
+        }
+    }
+    impl<'a> super::DbufView<'a> for UrnRef<'a> {
+        type Dependencies = Dependencies;
+        fn read_view(dependencies: Dependencies, reader: &mut super::SliceReader<'a>) -> Result<Self, super::DeserializeError> {
+            let (checked, encoding) = reader.read_encoding(|reader| Urn::check_encoding(dependencies, reader));
+            checked?;
+            Ok(Self { encoding: encoding })
+        }
+        fn read_checked(reader: &mut super::SliceReader<'a>) -> Self {
+            let (_, encoding) = reader.read_encoding(Self::read_body);
+            Self { encoding: encoding }
+        }
+    }
+}
+
+pub use urn::Urn as Urn;
@@ -1,5 +1,6 @@
 use crate::common::*;
 use dbuf_gen::codegen;
+use dbuf_gen::import::Import;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -48,6 +49,266 @@ fn nat_vec() {
     assert_eq!(code, expected);
 }
 
+#[test]
+fn imported_nat() {
+    let nat = nat();
+    let imports = [Import {
+        name: "nat",
+        module: &nat,
+    }];
+    let module = vec();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module_with_imports(&module, &imports, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/imported_nat.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn packaged_nat() {
+    let module = crate::common::packaged_nat();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/packaged_nat.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sequences() {
+    let module = crate::common::get_sequences_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/sequences.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn bytes() {
+    let module = crate::common::get_bytes_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/bytes.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn doubles() {
+    let module = crate::common::get_doubles_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/doubles.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sized_ints() {
+    let module = crate::common::get_sized_ints_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/sized_ints.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn refinements() {
+    let module = crate::common::get_refinements_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/refinements.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn division() {
+    let module = crate::common::get_division_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/division.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn strings() {
+    let module = crate::common::get_strings_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/strings.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn expr_stmt() {
+    let module = crate::common::get_expr_stmt_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/expr_stmt.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn aliases() {
+    let module = crate::common::get_aliases_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/aliases.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn defaults() {
+    let module = crate::common::get_defaults_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/defaults.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn nested_defaults() {
+    let module = crate::common::get_nested_defaults_module();
+    let mut writer = Vec::new();
+
+    assert!(codegen::generate_module(&module, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/nested_defaults.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn nat_vec_async() {
+    let module = get_nat_vec_module();
+    let mut writer = Vec::new();
+    let options = codegen::Options {
+        async_io: true,
+        ..codegen::Options::default()
+    };
+
+    assert!(codegen::generate_module_with_options(&module, &[], options, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/nat_vec_async.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn defaults_async() {
+    let module = crate::common::get_defaults_module();
+    let mut writer = Vec::new();
+    let options = codegen::Options {
+        async_io: true,
+        ..codegen::Options::default()
+    };
+
+    assert!(codegen::generate_module_with_options(&module, &[], options, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/defaults_async.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sequences_compact() {
+    let module = get_sequences_module();
+    let mut writer = Vec::new();
+    let options = codegen::Options {
+        async_io: true,
+        encoding: codegen::Encoding::Compact,
+        ..codegen::Options::default()
+    };
+
+    assert!(codegen::generate_module_with_options(&module, &[], options, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/sequences_compact.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sized_ints_compact() {
+    let module = get_sized_ints_module();
+    let mut writer = Vec::new();
+    let options = codegen::Options {
+        encoding: codegen::Encoding::Compact,
+        ..codegen::Options::default()
+    };
+
+    assert!(codegen::generate_module_with_options(&module, &[], options, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/sized_ints_compact.rs");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn derived() {
+    let module = crate::common::get_derived_module();
+    let mut writer = Vec::new();
+    let options = codegen::Options {
+        omit_derivable: true,
+        ..codegen::Options::default()
+    };
+
+    assert!(codegen::generate_module_with_options(&module, &[], options, &mut writer).is_ok());
+
+    let code = String::from_utf8(writer).expect("generated code must be correct utf8");
+    let expected = include_str!("./canon/derived.rs");
+
+    assert_eq!(code, expected);
+}
+
 #[test]
 fn dependent_messages() {
     // message Sum (a Int) {}
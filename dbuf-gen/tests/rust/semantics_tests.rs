@@ -1,3 +1,7 @@
+use dbuf_rust_runtime::{
+    Compact, DbufMessage, DbufMessageAsync, DbufPrimitive, DbufView, Double, Fixed,
+};
+use std::fmt::Debug;
 use std::io::{BufReader, BufWriter};
 
 #[allow(warnings)]
@@ -37,8 +41,7 @@ fn basic_serde() {
     let one = basic::Nat::suc(Box::new(zero)).expect("couldn't construct one");
 
     let mut writer = BufWriter::new(Vec::new());
-    one.clone()
-        .serialize(&mut writer)
+    one.serialize(&mut writer)
         .expect("couldn't serialize to writer");
 
     let buffer = writer.into_inner().expect("couldn't retrieve buffer");
@@ -92,8 +95,7 @@ fn nat_vec_serde() {
     .expect("couldn't construct cons");
 
     let mut writer = BufWriter::new(Vec::new());
-    vec.clone()
-        .serialize(&mut writer)
+    vec.serialize(&mut writer)
         .expect("couldn't serialize to writer");
 
     let buffer = writer.into_inner().expect("couldn't retrieve buffer");
@@ -105,3 +107,886 @@ fn nat_vec_serde() {
 
     assert_eq!(vec, vec_new);
 }
+
+#[test]
+fn nat_vec_views() {
+    let one = nat_vec::Nat::suc(Box::new(
+        nat_vec::Nat::zero().expect("couldn't construct zero"),
+    ))
+    .expect("couldn't construct one");
+    let vec = nat_vec::Vec::cons(
+        Box::new(nat_vec::Nat::zero().expect("couldn't construct zero")),
+        Box::new(one.clone()),
+        Box::new(nat_vec::Vec::nil().expect("couldn't construct nil")),
+    )
+    .expect("couldn't construct cons");
+
+    let mut buffer = Vec::new();
+    vec.serialize(&mut buffer)
+        .expect("couldn't serialize to buffer");
+    buffer.push(0xff);
+
+    let view = nat_vec::vec::VecRef::view(
+        nat_vec::vec::Dependencies {
+            n: Box::new(one.clone()),
+        },
+        &buffer,
+    )
+    .expect("couldn't view");
+    assert_eq!(view.encoding(), &buffer[..buffer.len() - 1]);
+    let nat_vec::vec::BodyRef::Cons { value, tail } = view.body() else {
+        panic!("expected cons");
+    };
+    assert!(matches!(value.body(), nat_vec::nat::BodyRef::Suc { .. }));
+    assert!(matches!(tail.body(), nat_vec::vec::BodyRef::Nil {}));
+
+    let zero = nat_vec::Nat::zero().expect("couldn't construct zero");
+    assert!(
+        nat_vec::vec::VecRef::view(nat_vec::vec::Dependencies { n: Box::new(zero) }, &buffer)
+            .is_err()
+    );
+
+    // unchecked types are checked by reading the encoding
+    assert!(nat_vec::nat::NatRef::view(nat_vec::nat::Dependencies {}, &[0, 0, 1]).is_ok());
+    assert!(nat_vec::nat::NatRef::view(nat_vec::nat::Dependencies {}, &[0, 0]).is_err());
+    assert!(nat_vec::nat::NatRef::view(nat_vec::nat::Dependencies {}, &[2]).is_err());
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod sequences {
+    include!("./canon/sequences.rs");
+}
+
+#[test]
+fn sequences_length_check() {
+    assert!(sequences::Batch::batch(2, vec![], vec![true, false]).is_ok());
+    assert!(sequences::Batch::batch(3, vec![1, 2, 3], vec![true]).is_err());
+}
+
+#[test]
+fn sequences_serde() {
+    let batch = sequences::Batch::batch(2, vec![-1, 0, 1], vec![true, false])
+        .expect("couldn't construct batch");
+
+    let mut writer = BufWriter::new(Vec::new());
+    batch
+        .serialize(&mut writer)
+        .expect("couldn't serialize to writer");
+
+    let buffer = writer.into_inner().expect("couldn't retrieve buffer");
+    assert_eq!(buffer.len(), 8 + 3 * 8 + 8 + 2);
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    let batch_new =
+        sequences::Batch::deserialize(sequences::batch::Dependencies { n: 2 }, &mut reader)
+            .expect("couldn't deserialize");
+
+    assert_eq!(batch, batch_new);
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    assert!(
+        sequences::Batch::deserialize(sequences::batch::Dependencies { n: 3 }, &mut reader)
+            .is_err()
+    );
+}
+
+#[test]
+fn sequences_views() {
+    let batch = sequences::Batch::batch(2, vec![-1, 0, 1], vec![true, false])
+        .expect("couldn't construct batch");
+
+    let mut buffer = Vec::new();
+    batch
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to buffer");
+
+    let view = sequences::batch::BatchRef::view(sequences::batch::Dependencies { n: 2 }, &buffer)
+        .expect("couldn't view");
+    assert_eq!(view.values().len(), 3);
+    assert_eq!(view.values().iter().collect::<Vec<_>>(), [-1, 0, 1]);
+    assert_eq!(view.flags().iter().collect::<Vec<_>>(), [true, false]);
+
+    assert!(
+        sequences::batch::BatchRef::view(sequences::batch::Dependencies { n: 3 }, &buffer).is_err()
+    );
+    assert!(
+        sequences::batch::BatchRef::view(
+            sequences::batch::Dependencies { n: 2 },
+            &buffer[..buffer.len() - 1]
+        )
+        .is_err()
+    );
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod bytes {
+    include!("./canon/bytes.rs");
+}
+
+#[test]
+fn bytes_serde() {
+    let blob = bytes::Blob::blob(vec![0x00, 0xff], vec![0xde, 0xad, 0xbe, 0xef])
+        .expect("couldn't construct blob");
+
+    let mut writer = BufWriter::new(Vec::new());
+    blob.serialize(&mut writer)
+        .expect("couldn't serialize to writer");
+
+    let buffer = writer.into_inner().expect("couldn't retrieve buffer");
+    assert_eq!(buffer, [4, 0, 0, 0, 0, 0, 0, 0, 0xde, 0xad, 0xbe, 0xef]);
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    let blob_new = bytes::Blob::deserialize(
+        bytes::blob::Dependencies {
+            tag: vec![0x00, 0xff],
+        },
+        &mut reader,
+    )
+    .expect("couldn't deserialize");
+
+    assert_eq!(blob, blob_new);
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod doubles {
+    include!("./canon/doubles.rs");
+}
+
+#[test]
+fn doubles_serde() {
+    let scaled = doubles::Scaled::scaled(Double(-0.0), Double(1.5)).expect("couldn't construct");
+
+    let mut writer = BufWriter::new(Vec::new());
+    scaled
+        .serialize(&mut writer)
+        .expect("couldn't serialize to writer");
+
+    let buffer = writer.into_inner().expect("couldn't retrieve buffer");
+    assert_eq!(buffer, 1.5f64.to_le_bytes());
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    let scaled_new = doubles::Scaled::deserialize(
+        doubles::scaled::Dependencies {
+            factor: Double(-0.0),
+        },
+        &mut reader,
+    )
+    .expect("couldn't deserialize");
+    assert_eq!(scaled, scaled_new);
+    assert_ne!(scaled_new.dependencies.factor, Double(0.0));
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod sized_ints {
+    include!("./canon/sized_ints.rs");
+}
+
+#[test]
+fn sized_ints_serde() {
+    let reading =
+        sized_ints::Reading::reading(3, -2, 70_000, i64::MIN).expect("couldn't construct");
+
+    let mut writer = BufWriter::new(Vec::new());
+    reading
+        .serialize(&mut writer)
+        .expect("couldn't serialize to writer");
+
+    let buffer = writer.into_inner().expect("couldn't retrieve buffer");
+    let expected = [
+        &(-2i16).to_le_bytes()[..],
+        &70_000u32.to_le_bytes(),
+        &i64::MIN.to_le_bytes(),
+    ]
+    .concat();
+    assert_eq!(buffer, expected);
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    let reading_new = sized_ints::Reading::deserialize(
+        sized_ints::reading::Dependencies { channel: 3 },
+        &mut reader,
+    )
+    .expect("couldn't deserialize");
+    assert_eq!(reading, reading_new);
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod refinements {
+    include!("./canon/refinements.rs");
+}
+
+#[test]
+fn refinements_are_checked() {
+    assert!(refinements::Bounded::bounded(10, 4, 6).is_ok());
+    assert!(matches!(
+        refinements::Bounded::bounded(0, 0, 0),
+        Err(dbuf_rust_runtime::ConstructorError::RefinementViolated(
+            "limit"
+        ))
+    ));
+    assert!(matches!(
+        refinements::Bounded::bounded(10, 11, 0),
+        Err(dbuf_rust_runtime::ConstructorError::RefinementViolated(
+            "used"
+        ))
+    ));
+
+    let buffer = [11u64.to_le_bytes(), 0u64.to_le_bytes()].concat();
+    let mut reader = BufReader::new(buffer.as_slice());
+    let deserialized = refinements::Bounded::deserialize(
+        refinements::bounded::Dependencies { limit: 10 },
+        &mut reader,
+    );
+    assert!(matches!(
+        deserialized,
+        Err(dbuf_rust_runtime::DeserializeError::ConstructorError(
+            dbuf_rust_runtime::ConstructorError::RefinementViolated("used")
+        ))
+    ));
+}
+
+#[test]
+fn overflowing_refinements_are_violated() {
+    assert!(matches!(
+        refinements::Bounded::bounded(u64::MAX, u64::MAX, 1),
+        Err(dbuf_rust_runtime::ConstructorError::RefinementViolated(
+            "spare"
+        ))
+    ));
+
+    let buffer = [u64::MAX.to_le_bytes(), 1u64.to_le_bytes()].concat();
+    let dependencies = || refinements::bounded::Dependencies { limit: u64::MAX };
+    let mut reader = BufReader::new(buffer.as_slice());
+    let deserialized = refinements::Bounded::deserialize(dependencies(), &mut reader);
+    assert!(matches!(
+        deserialized,
+        Err(dbuf_rust_runtime::DeserializeError::ConstructorError(
+            dbuf_rust_runtime::ConstructorError::RefinementViolated("spare")
+        ))
+    ));
+    assert!(matches!(
+        refinements::bounded::BoundedRef::view(dependencies(), &buffer),
+        Err(dbuf_rust_runtime::DeserializeError::ConstructorError(
+            dbuf_rust_runtime::ConstructorError::RefinementViolated("spare")
+        ))
+    ));
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod division {
+    include!("./canon/division.rs");
+}
+
+#[test]
+fn division_by_zero_is_constructor_error() {
+    let payload = || Box::new(division::Buffer::buffer(4).expect("buffer has no fields"));
+    assert!(division::Packet::packet(17, 4, payload()).is_ok());
+    assert!(matches!(
+        division::Packet::packet(17, 0, payload()),
+        Err(dbuf_rust_runtime::ConstructorError::DivisionByZero)
+    ));
+
+    let buffer = [17u64.to_le_bytes(), 0u64.to_le_bytes()].concat();
+    let mut reader = BufReader::new(buffer.as_slice());
+    let deserialized =
+        division::Packet::deserialize(division::packet::Dependencies {}, &mut reader);
+    assert!(matches!(
+        deserialized,
+        Err(dbuf_rust_runtime::DeserializeError::ConstructorError(
+            dbuf_rust_runtime::ConstructorError::DivisionByZero
+        ))
+    ));
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod strings {
+    include!("./canon/strings.rs");
+}
+
+#[test]
+fn strings_prefix_and_byte_length() {
+    let payload = |size| Box::new(strings::Buffer::buffer(size).expect("buffer has no fields"));
+    assert!(strings::Urn::urn("urn:ёж".to_owned(), payload(8)).is_ok());
+    assert!(matches!(
+        strings::Urn::urn("urn:ёж".to_owned(), payload(6)),
+        Err(dbuf_rust_runtime::ConstructorError::MismatchedDependencies)
+    ));
+    assert!(matches!(
+        strings::Urn::urn("isbn:1".to_owned(), payload(6)),
+        Err(dbuf_rust_runtime::ConstructorError::RefinementViolated(
+            "value"
+        ))
+    ));
+}
+
+#[test]
+fn strings_views() {
+    let payload = Box::new(strings::Buffer::buffer(8).expect("buffer has no fields"));
+    let urn = strings::Urn::urn("urn:ёж".to_owned(), payload).expect("couldn't construct urn");
+
+    let mut buffer = Vec::new();
+    urn.serialize(&mut buffer)
+        .expect("couldn't serialize to buffer");
+
+    let view =
+        strings::urn::UrnRef::view(strings::urn::Dependencies {}, &buffer).expect("couldn't view");
+    let value: &str = view.value();
+    assert_eq!(value, "urn:ёж");
+    assert_eq!(value.as_ptr(), buffer[8..].as_ptr());
+
+    // refinements are checked on viewing
+    let mut buffer = Vec::new();
+    "isbn:1"
+        .to_owned()
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to buffer");
+    assert!(strings::urn::UrnRef::view(strings::urn::Dependencies {}, &buffer).is_err());
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod expr_stmt {
+    include!("./canon/expr_stmt.rs");
+}
+
+#[test]
+fn expr_stmt_serde() {
+    let literal = |value| expr_stmt::Expr::literal(value).expect("couldn't construct literal");
+    let assign = expr_stmt::Stmt::assign("x".to_owned(), Box::new(literal(1)))
+        .expect("couldn't construct assign");
+    let block = expr_stmt::Expr::block(Box::new(assign), Box::new(literal(2)))
+        .expect("couldn't construct block");
+    let program = expr_stmt::Stmt::assign("y".to_owned(), Box::new(block))
+        .expect("couldn't construct program");
+
+    let mut writer = BufWriter::new(Vec::new());
+    program
+        .serialize(&mut writer)
+        .expect("couldn't serialize to writer");
+
+    let buffer = writer.into_inner().expect("couldn't retrieve buffer");
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    let program_new = expr_stmt::Stmt::deserialize(expr_stmt::stmt::Dependencies {}, &mut reader)
+        .expect("couldn't deserialize");
+
+    assert_eq!(program, program_new);
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod defaults {
+    include!("./canon/defaults.rs");
+}
+
+#[test]
+fn defaults_fill_missing_fields() {
+    let settings =
+        defaults::Settings::settings_with_defaults(4).expect("couldn't construct settings");
+    let explicit =
+        defaults::Settings::settings(4, 3, 5).expect("couldn't construct explicit settings");
+    assert_eq!(settings, explicit);
+
+    let mut writer = BufWriter::new(Vec::new());
+    settings
+        .serialize(&mut writer)
+        .expect("couldn't serialize to writer");
+
+    let mut buffer = writer.into_inner().expect("couldn't retrieve buffer");
+    // Drop `level`, as if written before the field was added.
+    buffer.truncate(buffer.len() - 8);
+
+    let mut reader = BufReader::new(buffer.as_slice());
+    let settings_new = defaults::Settings::deserialize(
+        defaults::settings::Dependencies { version: 4 },
+        &mut reader,
+    )
+    .expect("couldn't deserialize");
+
+    assert_eq!(settings, settings_new);
+}
+
+#[test]
+fn overflowing_defaults_are_errors() {
+    assert!(matches!(
+        defaults::Settings::settings_with_defaults(u64::MAX),
+        Err(dbuf_rust_runtime::ConstructorError::Overflow)
+    ));
+
+    // Only `retries` is encoded, so `level` is `version + 1`.
+    let buffer = 3u64.to_le_bytes();
+    let mut reader = BufReader::new(buffer.as_slice());
+    let deserialized = defaults::Settings::deserialize(
+        defaults::settings::Dependencies { version: u64::MAX },
+        &mut reader,
+    );
+    assert!(matches!(
+        deserialized,
+        Err(dbuf_rust_runtime::DeserializeError::ConstructorError(
+            dbuf_rust_runtime::ConstructorError::Overflow
+        ))
+    ));
+
+    let deserialized = block_on(defaults_async::Settings::deserialize_async(
+        defaults_async::settings::Dependencies { version: u64::MAX },
+        &mut buffer.as_slice(),
+    ));
+    assert!(matches!(
+        deserialized,
+        Err(dbuf_rust_runtime::DeserializeError::ConstructorError(
+            dbuf_rust_runtime::ConstructorError::Overflow
+        ))
+    ));
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod nested_defaults {
+    include!("./canon/nested_defaults.rs");
+}
+
+#[test]
+fn nested_defaults_round_trip() {
+    let settings =
+        nested_defaults::Settings::settings_with_defaults(1).expect("couldn't construct settings");
+    let profile = nested_defaults::Profile::profile(Box::new(settings), 7)
+        .expect("couldn't construct profile");
+
+    let mut buffer = Vec::new();
+    profile
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to buffer");
+
+    // Nested settings are followed by `id`, so their fields are all read.
+    let profile_new = nested_defaults::Profile::deserialize(
+        nested_defaults::profile::Dependencies {},
+        &mut buffer.as_slice(),
+    )
+    .expect("couldn't deserialize");
+    assert_eq!(profile, profile_new);
+
+    let view = nested_defaults::profile::ProfileRef::view(
+        nested_defaults::profile::Dependencies {},
+        &buffer,
+    )
+    .expect("couldn't view");
+    assert_eq!(view.settings().retries(), Some(3));
+    assert_eq!(view.settings().level(), Some(2));
+    assert_eq!(view.id(), 7);
+
+    // Absent fields are told only by the end of input, which nested values don't reach.
+    buffer.truncate(buffer.len() - 8);
+    assert!(
+        nested_defaults::Profile::deserialize(
+            nested_defaults::profile::Dependencies {},
+            &mut buffer.as_slice()
+        )
+        .is_err()
+    );
+}
+
+fn round_trip<M: DbufMessage + PartialEq + Debug>(value: &M, dependencies: M::Dependencies) {
+    let mut buffer = Vec::new();
+    value
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+
+    let value_new =
+        M::deserialize(dependencies, &mut buffer.as_slice()).expect("couldn't deserialize");
+
+    assert_eq!(value, &value_new);
+}
+
+#[test]
+fn generic_round_trip() {
+    let zero = basic::Nat::zero().expect("couldn't construct zero");
+    let one = basic::Nat::suc(Box::new(zero)).expect("couldn't construct one");
+    round_trip(&one, basic::nat::Dependencies {});
+
+    let vec = nat_vec::Vec::cons(
+        Box::new(nat_vec::Nat::zero().expect("couldn't construct zero")),
+        Box::new(nat_vec::Nat::zero().expect("couldn't construct zero")),
+        Box::new(nat_vec::Vec::nil().expect("couldn't construct nil")),
+    )
+    .expect("couldn't construct cons");
+    let dependencies = nat_vec::vec::Dependencies {
+        n: vec.dependencies.n.clone(),
+    };
+    round_trip(&vec, dependencies);
+
+    round_trip(&42u64, ());
+    round_trip(&"dbuf".to_owned(), ());
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod nat_vec_async {
+    include!("./canon/nat_vec_async.rs");
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod defaults_async {
+    include!("./canon/defaults_async.rs");
+}
+
+fn block_on<T>(future: impl Future<Output = T>) -> T {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("couldn't build runtime")
+        .block_on(future)
+}
+
+/// Encodes `value` over tokio I/O and checks that it is decoded back by both I/Os.
+fn async_round_trip<M: DbufMessageAsync + PartialEq + Debug>(
+    value: &M,
+    dependencies: impl Fn() -> M::Dependencies,
+) {
+    let buffer = block_on(async {
+        let mut buffer = Vec::new();
+        value
+            .serialize_async(&mut buffer)
+            .await
+            .expect("couldn't serialize to writer");
+        buffer
+    });
+
+    let mut blocking_buffer = Vec::new();
+    value
+        .serialize(&mut blocking_buffer)
+        .expect("couldn't serialize to writer");
+    assert_eq!(buffer, blocking_buffer);
+
+    let value_new = block_on(M::deserialize_async(dependencies(), &mut buffer.as_slice()))
+        .expect("couldn't deserialize");
+    assert_eq!(value, &value_new);
+}
+
+#[test]
+fn async_serde() {
+    let one = nat_vec_async::Nat::suc(Box::new(
+        nat_vec_async::Nat::zero().expect("couldn't construct zero"),
+    ))
+    .expect("couldn't construct one");
+    let vec = nat_vec_async::Vec::cons(
+        Box::new(nat_vec_async::Nat::zero().expect("couldn't construct zero")),
+        Box::new(one.clone()),
+        Box::new(nat_vec_async::Vec::nil().expect("couldn't construct nil")),
+    )
+    .expect("couldn't construct cons");
+    async_round_trip(&vec, || nat_vec_async::vec::Dependencies {
+        n: Box::new(one.clone()),
+    });
+
+    let zero = nat_vec_async::Nat::zero().expect("couldn't construct zero");
+    let mut buffer = Vec::new();
+    vec.serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    assert!(matches!(
+        block_on(nat_vec_async::Vec::deserialize_async(
+            nat_vec_async::vec::Dependencies { n: Box::new(zero) },
+            &mut buffer.as_slice(),
+        )),
+        Err(dbuf_rust_runtime::DeserializeError::DependenciesDescriptorMismatch)
+    ));
+
+    async_round_trip(&42u64, || ());
+    async_round_trip(&"dbuf".to_owned(), || ());
+}
+
+#[test]
+fn async_defaults_fill_missing_fields() {
+    let settings =
+        defaults_async::Settings::settings_with_defaults(4).expect("couldn't construct settings");
+    async_round_trip(&settings, || defaults_async::settings::Dependencies {
+        version: 4,
+    });
+
+    let mut buffer = Vec::new();
+    settings
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    // Drop `level`, as if written before the field was added.
+    buffer.truncate(buffer.len() - 8);
+
+    let settings_new = block_on(defaults_async::Settings::deserialize_async(
+        defaults_async::settings::Dependencies { version: 4 },
+        &mut buffer.as_slice(),
+    ))
+    .expect("couldn't deserialize");
+    assert_eq!(settings, settings_new);
+}
+
+#[test]
+fn async_frames() {
+    let settings = |retries| {
+        defaults_async::Settings::settings(4, retries, 5).expect("couldn't construct settings")
+    };
+    let dependencies = || defaults_async::settings::Dependencies { version: 4 };
+
+    let stream = block_on(async {
+        let mut stream = Vec::new();
+        for retries in 0..3 {
+            dbuf_rust_runtime::write_frame::<Fixed, _, _>(&settings(retries), &mut stream)
+                .await
+                .expect("couldn't write frame");
+        }
+        stream
+    });
+
+    let mut reader = stream.as_slice();
+    block_on(async {
+        for retries in 0..3 {
+            let frame = dbuf_rust_runtime::read_frame::<Fixed, _, _>(dependencies(), &mut reader)
+                .await
+                .expect("couldn't read frame");
+            assert_eq!(frame, Some(settings(retries)));
+        }
+        let end = dbuf_rust_runtime::read_frame::<Fixed, defaults_async::Settings, _>(
+            dependencies(),
+            &mut reader,
+        )
+        .await
+        .expect("couldn't read end of stream");
+        assert_eq!(end, None);
+    });
+
+    let mut truncated = &stream[..stream.len() - 1];
+    let frames = block_on(async {
+        let mut frames = 0;
+        while dbuf_rust_runtime::read_frame::<Fixed, defaults_async::Settings, _>(
+            dependencies(),
+            &mut truncated,
+        )
+        .await
+        .is_ok_and(|frame| frame.is_some())
+        {
+            frames += 1;
+        }
+        frames
+    });
+    assert_eq!(frames, 2);
+}
+
+#[test]
+fn async_compact_frames() {
+    let settings =
+        defaults_async::Settings::settings(4, 3, 5).expect("couldn't construct settings");
+    let dependencies = || defaults_async::settings::Dependencies { version: 4 };
+
+    let stream = block_on(async {
+        let mut stream = Vec::new();
+        dbuf_rust_runtime::write_frame::<Compact, _, _>(&settings, &mut stream)
+            .await
+            .expect("couldn't write frame");
+        stream
+    });
+    // Length of two `UInt`s is a single byte varint.
+    assert_eq!(stream.len(), 1 + 16);
+    assert_eq!(stream[0], 16);
+
+    let mut reader = stream.as_slice();
+    let frame = block_on(dbuf_rust_runtime::read_frame::<Compact, _, _>(
+        dependencies(),
+        &mut reader,
+    ))
+    .expect("couldn't read frame");
+    assert_eq!(frame, Some(settings));
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod sequences_compact {
+    include!("./canon/sequences_compact.rs");
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod sized_ints_compact {
+    include!("./canon/sized_ints_compact.rs");
+}
+
+#[test]
+fn compact_varints() {
+    fn encode<T: DbufPrimitive<Compact>>(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        value
+            .dbuf_serialize(&mut buffer)
+            .expect("couldn't serialize to writer");
+        buffer
+    }
+
+    assert_eq!(encode(&0u64), [0x00]);
+    assert_eq!(encode(&127u64), [0x7f]);
+    assert_eq!(encode(&300u64), [0xac, 0x02]);
+    assert_eq!(
+        encode(&u64::MAX),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+    );
+    assert_eq!(encode(&0i64), [0x00]);
+    assert_eq!(encode(&-1i64), [0x01]);
+    assert_eq!(encode(&1i64), [0x02]);
+    assert_eq!(encode(&-64i32), [0x7f]);
+    assert_eq!(encode(&64i16), [0x80, 0x01]);
+    assert_eq!(encode(&"dbuf".to_owned()), b"\x04dbuf");
+    assert_eq!(encode(&vec![1u32, 300]), [0x02, 0x01, 0xac, 0x02]);
+
+    for value in [0, 1, -1, 63, -64, i64::MAX, i64::MIN] {
+        let decoded =
+            <i64 as DbufPrimitive<Compact>>::dbuf_deserialize(&mut encode(&value).as_slice())
+                .expect("couldn't deserialize");
+        assert_eq!(value, decoded);
+    }
+
+    let too_long = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+    ];
+    assert!(<u64 as DbufPrimitive<Compact>>::dbuf_deserialize(&mut too_long.as_slice()).is_err());
+    let too_wide = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+    assert!(<u64 as DbufPrimitive<Compact>>::dbuf_deserialize(&mut too_wide.as_slice()).is_err());
+    assert!(
+        <u16 as DbufPrimitive<Compact>>::dbuf_deserialize(&mut encode(&65_536u64).as_slice())
+            .is_err()
+    );
+    assert!(
+        <i16 as DbufPrimitive<Compact>>::dbuf_deserialize(&mut encode(&40_000i32).as_slice())
+            .is_err()
+    );
+}
+
+#[test]
+fn compact_sized_ints_golden() {
+    let reading =
+        sized_ints_compact::Reading::reading(7, -2, 300, -1).expect("couldn't construct reading");
+    let golden = [0x03, 0xac, 0x02, 0x01];
+
+    let mut buffer = Vec::new();
+    reading
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    assert_eq!(buffer, golden);
+
+    let dependencies = || sized_ints_compact::reading::Dependencies { channel: 7 };
+    let reading_new = sized_ints_compact::Reading::deserialize(dependencies(), &mut &golden[..])
+        .expect("couldn't deserialize");
+    assert_eq!(reading, reading_new);
+
+    let view = sized_ints_compact::reading::ReadingRef::view(dependencies(), &golden)
+        .expect("couldn't view");
+    assert_eq!((view.delta(), view.count(), view.stamp()), (-2, 300, -1));
+
+    let extremes = sized_ints_compact::Reading::reading(7, i16::MIN, u32::MAX, i64::MIN)
+        .expect("couldn't construct reading");
+    let mut buffer = Vec::new();
+    extremes
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    assert_eq!(buffer.len(), 3 + 5 + 10);
+    let extremes_new = sized_ints_compact::Reading::deserialize(dependencies(), &mut &buffer[..])
+        .expect("couldn't deserialize");
+    assert_eq!(extremes, extremes_new);
+}
+
+/// Blocking, async and view code agree on bytes of compact encoding. Only generated Rust
+/// encodes messages in dbuf wire format, so there are no goldens of other languages.
+#[test]
+fn compact_sequences_golden() {
+    let batch = sequences_compact::Batch::batch(2, vec![-1, 0, 1, 300], vec![true, false])
+        .expect("couldn't construct batch");
+    let golden = [0x04, 0x01, 0x00, 0x02, 0xd8, 0x04, 0x02, 0x01, 0x00];
+    let dependencies = || sequences_compact::batch::Dependencies { n: 2 };
+
+    let mut buffer = Vec::new();
+    batch
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    assert_eq!(buffer, golden);
+
+    let async_buffer = block_on(async {
+        let mut buffer = Vec::new();
+        batch
+            .serialize_async(&mut buffer)
+            .await
+            .expect("couldn't serialize to writer");
+        buffer
+    });
+    assert_eq!(async_buffer, golden);
+
+    let batch_new = sequences_compact::Batch::deserialize(dependencies(), &mut &golden[..])
+        .expect("couldn't deserialize");
+    assert_eq!(batch, batch_new);
+    let batch_new = block_on(sequences_compact::Batch::deserialize_async(
+        dependencies(),
+        &mut &golden[..],
+    ))
+    .expect("couldn't deserialize");
+    assert_eq!(batch, batch_new);
+
+    let view =
+        sequences_compact::batch::BatchRef::view(dependencies(), &golden).expect("couldn't view");
+    assert_eq!(view.values().iter().collect::<Vec<_>>(), [-1, 0, 1, 300]);
+    assert_eq!(view.flags().iter().collect::<Vec<_>>(), [true, false]);
+    assert_eq!(view.encoding(), golden);
+
+    assert!(
+        sequences_compact::Batch::deserialize(dependencies(), &mut &golden[..golden.len() - 1])
+            .is_err()
+    );
+    assert!(
+        sequences_compact::batch::BatchRef::view(
+            sequences_compact::batch::Dependencies { n: 3 },
+            &golden
+        )
+        .is_err()
+    );
+}
+
+#[allow(warnings)]
+#[allow(clippy::all)]
+mod derived {
+    include!("./canon/derived.rs");
+}
+
+#[test]
+fn derived_values_are_omitted() {
+    let zero = || Box::new(derived::Nat::zero().expect("couldn't construct zero"));
+    let vec = derived::Vec::cons(
+        zero(),
+        zero(),
+        Box::new(derived::Vec::nil().expect("couldn't construct nil")),
+    )
+    .expect("couldn't construct cons");
+
+    let mut buffer = Vec::new();
+    vec.serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    // Only descriptors of `value`, since ones of `Vec` follow from its length.
+    assert_eq!(buffer, [1]);
+    round_trip(
+        &vec,
+        derived::vec::Dependencies {
+            n: vec.dependencies.n.clone(),
+        },
+    );
+    // Descriptor is taken from dependencies, so `Nil` is read by length zero.
+    let nil = derived::Vec::deserialize(
+        derived::vec::Dependencies { n: zero() },
+        &mut buffer.as_slice(),
+    )
+    .expect("couldn't deserialize");
+    assert!(matches!(nil.body, derived::vec::Body::Nil {}));
+
+    let range = derived::Range::range(3, 4, 7).expect("couldn't construct range");
+    let mut buffer = Vec::new();
+    range
+        .serialize(&mut buffer)
+        .expect("couldn't serialize to writer");
+    assert_eq!(buffer, 4u64.to_le_bytes());
+    round_trip(&range, derived::range::Dependencies { start: 3 });
+}
@@ -0,0 +1,35 @@
+import Foundation
+
+public enum blob {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case blob(payload: Data)
+    }
+
+    public struct Dependencies: Codable {
+        public var tag: Data
+    }
+
+    public struct Blob: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func blob(tag: Data, payload: Data) -> Blob {
+            let body = Body.blob(payload: payload)
+            let dependencies = Dependencies(tag: tag)
+            return Blob(body: body, dependencies: dependencies)
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Blob {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+    }
+}
+
+public typealias Blob = blob.Blob
+
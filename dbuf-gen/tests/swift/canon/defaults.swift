@@ -0,0 +1,69 @@
+import Foundation
+
+public enum settings {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case settings(retries: UInt, level: UInt)
+    }
+
+    public struct Dependencies: Codable {
+        public var version: UInt
+    }
+
+    public struct Settings: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func settings(version: UInt, retries: UInt, level: UInt) -> Settings {
+            let body = Body.settings(retries: retries, level: level)
+            let dependencies = Dependencies(version: version)
+            return Settings(body: body, dependencies: dependencies)
+        }
+
+        public static func settings(version: UInt) -> Settings {
+            return settings(version: version, retries: 3, level: (version + 1))
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Settings {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+
+        public init(body: Body, dependencies: Dependencies) {
+            self.body = body
+            self.dependencies = dependencies
+        }
+
+        private enum Keys: String, CodingKey {
+            case body, dependencies
+        }
+
+        private enum BodyKeys: String, CodingKey {
+            case settings
+        }
+
+        private enum SettingsFieldKeys: String, CodingKey {
+            case retries, level
+        }
+
+        public init(from decoder: Decoder) throws {
+            let container = try decoder.container(keyedBy: Keys.self)
+            let dependencies = try container.decode(Dependencies.self, forKey: .dependencies)
+            let body = try container.nestedContainer(keyedBy: BodyKeys.self, forKey: .body)
+            if body.contains(.settings) {
+                let version = dependencies.version
+                let fields = try body.nestedContainer(keyedBy: SettingsFieldKeys.self, forKey: .settings)
+                self.init(body: .settings(retries: try fields.decodeIfPresent(UInt.self, forKey: .retries) ?? 3, level: try fields.decodeIfPresent(UInt.self, forKey: .level) ?? (version + 1)), dependencies: dependencies)
+                return
+            }
+            throw DecodingError.dataCorruptedError(forKey: .body, in: container, debugDescription: "unknown constructor")
+        }
+    }
+}
+
+public typealias Settings = settings.Settings
+
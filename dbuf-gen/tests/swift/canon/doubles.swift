@@ -0,0 +1,35 @@
+import Foundation
+
+public enum scaled {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case scaled(value: Double)
+    }
+
+    public struct Dependencies: Codable {
+        public var factor: Double
+    }
+
+    public struct Scaled: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func scaled(factor: Double, value: Double) -> Scaled {
+            let body = Body.scaled(value: value)
+            let dependencies = Dependencies(factor: factor)
+            return Scaled(body: body, dependencies: dependencies)
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Scaled {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+    }
+}
+
+public typealias Scaled = scaled.Scaled
+
@@ -0,0 +1,80 @@
+import Foundation
+
+public enum expr {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case block(body: Stmt, result: Expr)
+        case literal(value: Int)
+    }
+
+    public struct Dependencies: Codable {
+    }
+
+    public struct Expr: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func block(body: Stmt, result: Expr) -> Expr {
+            let body = Body.block(body: body, result: result)
+            let dependencies = Dependencies()
+            return Expr(body: body, dependencies: dependencies)
+        }
+
+        public static func literal(value: Int) -> Expr {
+            let body = Body.literal(value: value)
+            let dependencies = Dependencies()
+            return Expr(body: body, dependencies: dependencies)
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Expr {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+    }
+}
+
+public typealias Expr = expr.Expr
+
+public enum stmt {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case assign(name: String, value: Expr)
+        case skip
+    }
+
+    public struct Dependencies: Codable {
+    }
+
+    public struct Stmt: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func assign(name: String, value: Expr) -> Stmt {
+            let body = Body.assign(name: name, value: value)
+            let dependencies = Dependencies()
+            return Stmt(body: body, dependencies: dependencies)
+        }
+
+        public static func skip() -> Stmt {
+            let body = Body.skip
+            let dependencies = Dependencies()
+            return Stmt(body: body, dependencies: dependencies)
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Stmt {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+    }
+}
+
+public typealias Stmt = stmt.Stmt
+
@@ -0,0 +1,35 @@
+import Foundation
+
+public enum batch {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case batch(values: [Int], flags: [Bool])
+    }
+
+    public struct Dependencies: Codable {
+        public var n: UInt
+    }
+
+    public struct Batch: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func batch(n: UInt, values: [Int], flags: [Bool]) -> Batch {
+            let body = Body.batch(values: values, flags: flags)
+            let dependencies = Dependencies(n: n)
+            return Batch(body: body, dependencies: dependencies)
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Batch {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+    }
+}
+
+public typealias Batch = batch.Batch
+
@@ -0,0 +1,35 @@
+import Foundation
+
+public enum reading {
+    public enum deps {}
+
+    public indirect enum Body: Codable {
+        case reading(delta: Int16, count: UInt32, stamp: Int64)
+    }
+
+    public struct Dependencies: Codable {
+        public var channel: UInt8
+    }
+
+    public struct Reading: Codable {
+        public var body: Body
+        public var dependencies: Dependencies
+
+        public static func reading(channel: UInt8, delta: Int16, count: UInt32, stamp: Int64) -> Reading {
+            let body = Body.reading(delta: delta, count: count, stamp: stamp)
+            let dependencies = Dependencies(channel: channel)
+            return Reading(body: body, dependencies: dependencies)
+        }
+
+        public func serialize() -> Data {
+            return try! JSONEncoder().encode(self)
+        }
+
+        public static func deserialize(_ data: Data) throws -> Reading {
+            return try JSONDecoder().decode(Self.self, from: data)
+        }
+    }
+}
+
+public typealias Reading = reading.Reading
+
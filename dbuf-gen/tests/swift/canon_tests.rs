@@ -22,3 +22,83 @@ fn nat_vec() {
 
     assert_eq!(code, expected);
 }
+
+#[test]
+fn packaged_nat() {
+    let module = common::packaged_nat();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/packaged_nat.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sequences() {
+    let module = common::get_sequences_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/sequences.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn bytes() {
+    let module = common::get_bytes_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/bytes.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn doubles() {
+    let module = common::get_doubles_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/doubles.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn sized_ints() {
+    let module = common::get_sized_ints_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/sized_ints.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn expr_stmt() {
+    let module = common::get_expr_stmt_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/expr_stmt.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn aliases() {
+    let module = common::get_aliases_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/aliases.swift");
+
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn defaults() {
+    let module = common::get_defaults_module();
+
+    let code = swift_gen::generate_module(&module);
+    let expected = include_str!("./canon/defaults.swift");
+
+    assert_eq!(code, expected);
+}
@@ -1 +1,2 @@
 mod canon_tests;
+mod multi_file_tests;
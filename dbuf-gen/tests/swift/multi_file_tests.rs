@@ -0,0 +1,130 @@
+use std::fs;
+use std::process::Command;
+
+use dbuf_core::arena::InternedString;
+use dbuf_core::ast::elaborated as e;
+use dbuf_core::cst;
+use dbuf_core::elaboration;
+use dbuf_gen::import::Import;
+use dbuf_gen::swift_gen;
+
+const COMMON: &str = "package acme.common;
+
+message Money {
+    amount Int;
+}
+";
+
+const BILLING: &str = "package acme.billing;
+
+import \"common.dbuf\";
+
+message Invoice {
+    total acme.common.Money;
+}
+";
+
+const REFUND: &str = "package acme.billing;
+
+import \"common.dbuf\";
+import \"billing.dbuf\";
+
+message Refund {
+    invoice Invoice;
+    amount acme.common.Money;
+}
+";
+
+fn elaborate(source: &str, imports: &[&e::Module<InternedString>]) -> e::Module<InternedString> {
+    let (tree, errors) = cst::parse_to_cst(source);
+    assert!(errors.is_empty(), "source must parse");
+    let tree = tree.expect("source must parse");
+    let package: Vec<InternedString> = cst::collect_package(&tree)
+        .map(|package| package.path.into_iter().map(Into::into).collect())
+        .unwrap_or_default();
+    let ast = cst::convert_to_ast(&tree);
+    let (module, mut errors) = elaboration::elaborate_in_package(&ast, &package, imports);
+    errors.retain(|error| !error.is_warning());
+    assert!(errors.is_empty(), "source must elaborate: {errors:?}");
+    module
+}
+
+/// Swift files generated for two files of `acme.billing` and one of `acme.common`.
+fn generate_files() -> Vec<(&'static str, String)> {
+    let common = elaborate(COMMON, &[]);
+    let billing = elaborate(BILLING, &[&common]);
+    let refund = elaborate(REFUND, &[&common, &billing]);
+
+    let import = |name, module| Import { name, module };
+    vec![
+        ("common.swift", swift_gen::generate_module(&common)),
+        (
+            "billing.swift",
+            swift_gen::generate_module_with_imports(&billing, &[import("common", &common)]),
+        ),
+        (
+            "refund.swift",
+            swift_gen::generate_module_with_imports(
+                &refund,
+                &[import("common", &common), import("billing", &billing)],
+            ),
+        ),
+        (
+            "DbufNamespaces.swift",
+            swift_gen::generate_namespaces([&common, &billing, &refund]),
+        ),
+    ]
+}
+
+#[test]
+fn namespaces_are_declared_once() {
+    let files = generate_files();
+
+    for namespace in ["acme", "billing", "common"] {
+        let declaration = format!("public enum {namespace} {{");
+        let declarations: usize = files
+            .iter()
+            .map(|(_, code)| code.matches(&declaration).count())
+            .sum();
+        assert_eq!(
+            declarations, 1,
+            "namespace {namespace} must be declared once"
+        );
+    }
+
+    let namespaces = &files[3].1;
+    assert_eq!(
+        namespaces,
+        "public enum acme {\n    public enum billing {}\n    public enum common {}\n}\n"
+    );
+    assert!(files[1].1.contains("extension acme.billing {"));
+    assert!(files[2].1.contains("extension acme.billing {"));
+}
+
+#[test]
+fn files_type_check_together() {
+    if Command::new("swiftc").arg("--version").output().is_err() {
+        eprintln!("swiftc is not available, generated files are not type-checked");
+        return;
+    }
+
+    let dir = std::env::temp_dir().join("dbuf_swift_multi_file");
+    fs::create_dir_all(&dir).expect("couldn't create directory for generated files");
+    let mut paths = Vec::new();
+    for (name, code) in generate_files() {
+        let path = dir.join(name);
+        fs::write(&path, code).expect("couldn't write generated file");
+        paths.push(path);
+    }
+
+    let output = Command::new("swiftc")
+        .arg("-typecheck")
+        .args(&paths)
+        .output()
+        .expect("couldn't run swiftc");
+    assert!(
+        output.status.success(),
+        "generated files must type-check together:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -157,7 +157,7 @@ impl Handler {
         let symbol = self
             .rename_cache
             .get(document, file.get_version(), pos)
-            .map_or_else(|| navigator.get_symbol(pos), |cached| cached);
+            .unwrap_or_else(|| navigator.get_symbol(pos));
 
         rename::renameable_to_symbol(&symbol, new_name, elaborated)?;
 
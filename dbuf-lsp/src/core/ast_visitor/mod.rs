@@ -48,7 +48,8 @@ pub struct Constructor<'a> {
 pub enum Visit<'a> {
     /// Keyword. Currently one of:
     /// * `message`,
-    /// * `enum`.
+    /// * `enum`,
+    /// * `type`.
     ///
     /// Can be skipped (to next type declaration).
     ///
@@ -277,11 +278,12 @@ pub trait Visitor<'a> {
 /// Visit whole ast. Skips parts, if visitor tells so.
 pub fn visit_ast<'a, V: Visitor<'a>>(ast: &'a ParsedAst, visitor: &mut V) -> Option<V::StopResult> {
     for td in ast {
-        let keyword = if matches!(td.body, TypeDefinition::Message(_)) {
-            get_keyword("message", td.name.get_location().get_start().get_line())
-        } else {
-            get_keyword("enum", td.name.get_location().get_start().get_line())
+        let keyword = match td.body {
+            TypeDefinition::Message(_) => "message",
+            TypeDefinition::Enum(_) => "enum",
+            TypeDefinition::Alias(_) => "type",
         };
+        let keyword = get_keyword(keyword, td.name.get_location().get_start().get_line());
 
         let res = visitor.visit(keyword);
         match res {
@@ -335,7 +337,10 @@ fn visit_type_declaration<'a, V: Visitor<'a>>(
     for d in &td.dependencies {
         let res = visitor.visit(Visit::Dependency(&d.name, &d.loc));
         match res {
-            VisitResult::Continue => visit_type_expression(d, visitor)?,
+            VisitResult::Continue => {
+                visit_type_expression(d, visitor)?;
+                visit_refinement(&d.data, visitor)?;
+            }
             VisitResult::Skip => (),
             VisitResult::Stop(r) => return stop(r),
         }
@@ -376,6 +381,7 @@ fn visit_type_declaration<'a, V: Visitor<'a>>(
                 }
             }
         }
+        TypeDefinition::Alias(target) => visit_type_expression(target, visitor)?,
     }
 
     next()
@@ -465,6 +471,40 @@ fn visit_constructor<'a, V: Visitor<'a>>(
         }
 
         visit_type_expression(field, visitor)?;
+        visit_refinement(&field.data, visitor)?;
+        visit_default(&field.data, visitor)?;
+    }
+
+    next()
+}
+
+fn visit_default<'a, V: Visitor<'a>>(
+    typed: &'a Typed<Loc, Name>,
+    visitor: &mut V,
+) -> Stop<V::StopResult> {
+    if let Some(default) = &typed.default {
+        let res = visitor.visit(Visit::Expression(&default.loc));
+        match res {
+            VisitResult::Continue => visit_expression(default, visitor)?,
+            VisitResult::Skip => (),
+            VisitResult::Stop(r) => return stop(r),
+        }
+    }
+
+    next()
+}
+
+fn visit_refinement<'a, V: Visitor<'a>>(
+    typed: &'a Typed<Loc, Name>,
+    visitor: &mut V,
+) -> Stop<V::StopResult> {
+    if let Some(refinement) = &typed.refinement {
+        let res = visitor.visit(Visit::Expression(&refinement.loc));
+        match res {
+            VisitResult::Continue => visit_expression(refinement, visitor)?,
+            VisitResult::Skip => (),
+            VisitResult::Stop(r) => return stop(r),
+        }
     }
 
     next()
@@ -505,6 +545,18 @@ fn visit_expression<'a, V: Visitor<'a>>(
     visitor: &mut V,
 ) -> Stop<V::StopResult> {
     match &e.node {
+        ExpressionNode::OpCall(OpCall::Binary(op, lhs, rhs)) if op.is_function() => {
+            let (op, loc) = get_operator(e);
+            let result = visitor.visit(Visit::Operator(op, loc));
+            match result {
+                VisitResult::Continue => {
+                    visit_expression(lhs, visitor)?;
+                    visit_expression(rhs, visitor)?;
+                }
+                VisitResult::Skip => panic!("operator can't be skipped"),
+                VisitResult::Stop(r) => return stop(r),
+            }
+        }
         ExpressionNode::OpCall(OpCall::Binary(_, lhs, rhs)) => {
             visit_expression(lhs, visitor)?;
             let (op, loc) = get_operator(e);
@@ -675,6 +727,24 @@ fn get_operator(e: &Expression<Loc, Name>) -> (&'static str, Loc) {
             let loc = Loc::new(start, end);
             ("|", loc)
         }
+        ExpressionNode::OpCall(OpCall::Binary(op, _, _)) if op.is_function() => {
+            let symbol = op.symbol();
+            let start = e.loc.get_start();
+            let mut end = start;
+            *end.get_column_mut() += symbol.len();
+            let loc = Loc::new(start, end);
+            (symbol, loc)
+        }
+        ExpressionNode::OpCall(OpCall::Binary(op, lhs, _))
+            if op.is_comparison() || op.is_opaque() =>
+        {
+            let symbol = op.symbol();
+            let start = lhs.loc.get_end();
+            let mut end = start;
+            *end.get_column_mut() += symbol.len();
+            let loc = Loc::new(start, end);
+            (symbol, loc)
+        }
         ExpressionNode::OpCall(OpCall::Unary(UnaryOp::Bang, expr)) => {
             let end = expr.loc.get_start();
             let mut start = end;
@@ -5,10 +5,17 @@ use std::{collections::HashSet, sync::LazyLock};
 
 use std::string::ToString;
 
-static BUILTIN_TYPES: LazyLock<HashSet<String>> =
-    LazyLock::new(|| HashSet::from(["Int", "String", "Bool", "UInt"].map(ToString::to_string)));
+static BUILTIN_TYPES: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    HashSet::from(
+        [
+            "Int", "String", "Bool", "UInt", "Double", "Bytes", "List", "Array", "Int8", "Int16",
+            "Int32", "Int64", "UInt8", "UInt16", "UInt32", "UInt64",
+        ]
+        .map(ToString::to_string),
+    )
+});
 static KEYWORDS: LazyLock<HashSet<String>> =
-    LazyLock::new(|| HashSet::from(["message", "enum"].map(ToString::to_string)));
+    LazyLock::new(|| HashSet::from(["message", "enum", "type"].map(ToString::to_string)));
 
 /// Returns builtin types set.
 pub fn get_builtin_types() -> &'static HashSet<String> {
@@ -43,6 +43,7 @@ pub struct PrettyPrinter<'a, W: Write> {
 impl<'a, W: Write> PrettyPrinter<'a, W> {
     const MESSAGE_TEXT: &'a str = "message ";
     const ENUM_TEXT: &'a str = "enum ";
+    const ALIAS_TEXT: &'a str = "type ";
 
     /// Creates new printer for `writer` with default parameters.
     pub fn new(writer: &'a mut W) -> Self {
@@ -210,7 +211,7 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
                     }
                 }
             }
-            None => {}
+            Some(TypeDefinition::Alias(_)) | None => {}
         }
     }
 
@@ -227,6 +228,10 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
                 self.write(Self::ENUM_TEXT);
                 self.write(&definition.name);
             }
+            TypeDefinition::Alias(_) => {
+                self.write(Self::ALIAS_TEXT);
+                self.write(&definition.name);
+            }
         }
 
         self.write_if(!self.header_only || self.with_dependencies, " ");
@@ -241,6 +246,15 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
             }
         }
 
+        if let TypeDefinition::Alias(target) = &type_declaration.body {
+            if !self.header_only {
+                self.write("= ");
+                self.print_type_expression(target);
+                self.write(";");
+            }
+            return;
+        }
+
         if !self.header_only {
             self.write("{");
 
@@ -255,6 +269,7 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
                         self.print_enum_bracnh(branch);
                     }
                 }
+                TypeDefinition::Alias(_) => unreachable!("alias has no body"),
             }
 
             self.new_line();
@@ -262,11 +277,11 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
         }
     }
 
-    fn print_dependency(&mut self, dependency: &Definition<Loc, Name, TypeExpression<Loc, Name>>) {
+    fn print_dependency(&mut self, dependency: &Definition<Loc, Name, Typed<Loc, Name>>) {
         self.write("(");
         self.write(&dependency.name);
         self.write(" ");
-        self.print_type_expression(&dependency.data);
+        self.print_typed(&dependency.data);
         self.write(")");
     }
 
@@ -351,13 +366,25 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
 
             self.write(&definition.name);
             self.write(" ");
-            self.print_type_expression(&definition.data);
+            self.print_typed(&definition.data);
             self.write(";");
 
             first = false;
         }
     }
 
+    fn print_typed(&mut self, typed: &Typed<Loc, Name>) {
+        self.print_type_expression(&typed.ty);
+        if let Some(refinement) = &typed.refinement {
+            self.write(" where ");
+            self.print_expression(refinement);
+        }
+        if let Some(default) = &typed.default {
+            self.write(" = ");
+            self.print_expression(default);
+        }
+    }
+
     fn print_type_expression(&mut self, type_expression: &TypeExpression<Loc, Name>) {
         match &type_expression.node {
             ExpressionNode::FunCall { fun, args } => {
@@ -420,6 +447,14 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
             OpCall::Unary(op, expr) => {
                 self.print_unary(op, expr);
             }
+            OpCall::Binary(op, expr_left, expr_right) if op.is_function() => {
+                self.print_binary(*op);
+                self.write("(");
+                self.print_expression(expr_left);
+                self.write(", ");
+                self.print_expression(expr_right);
+                self.write(")");
+            }
             OpCall::Binary(op, expr_left, expr_right) => {
                 self.write("(");
 
@@ -457,6 +492,15 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
                 self.write(ui.to_string());
                 self.write("u");
             }
+            Literal::Bytes(b) => {
+                self.write(bytes_literal(b));
+            }
+            Literal::Double(d) => {
+                self.write(double_literal(d.0));
+            }
+            Literal::Sized(s) => {
+                self.write(s.to_string());
+            }
         }
     }
 
@@ -486,22 +530,6 @@ impl<'a, W: Write> PrettyPrinter<'a, W> {
     }
 
     fn print_binary(&mut self, op: BinaryOp) {
-        match op {
-            BinaryOp::BinaryAnd => {
-                self.write("&");
-            }
-            BinaryOp::Minus => {
-                self.write("-");
-            }
-            BinaryOp::BinaryOr => {
-                self.write("|");
-            }
-            BinaryOp::Plus => {
-                self.write("+");
-            }
-            BinaryOp::Star => {
-                self.write("*");
-            }
-        }
+        self.write(op.symbol());
     }
 }
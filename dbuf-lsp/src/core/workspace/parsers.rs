@@ -12,6 +12,7 @@ use crate::core::errors::Error;
 use dbuf_core::cst::convert_to_ast;
 use dbuf_core::cst::parse_to_cst;
 use dbuf_core::elaboration::elaborate;
+use dbuf_core::error::ElaboratingError;
 
 /// Builds `CST` based on `text`.
 pub fn get_cst(text: &str) -> (Option<Cst>, Vec<Error>) {
@@ -26,9 +27,14 @@ pub fn get_parsed(cst: &Cst) -> (Option<ParsedAst>, Vec<Error>) {
 }
 
 /// Builds `ElaboratedAst` based on `ParsedAst`.
+///
+/// `ElaboratedAst` is returned only if there are no errors except warnings, since
+/// navigation expects every declaration of `ParsedAst` to be elaborated.
 pub fn get_elaborated(past: &ParsedAst) -> (Option<ElaboratedAst>, Vec<Error>) {
-    match elaborate(past) {
-        Ok(east) => (Some(east), vec![]),
-        Err(err) => (None, vec![err.into()]),
-    }
+    let (east, errors) = elaborate(past);
+    let east = errors
+        .iter()
+        .all(ElaboratingError::is_warning)
+        .then_some(east);
+    (east, errors.into_iter().map(Into::into).collect())
 }
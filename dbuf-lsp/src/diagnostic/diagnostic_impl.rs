@@ -2,6 +2,7 @@ use tower_lsp::lsp_types::Diagnostic;
 use tower_lsp::lsp_types::DiagnosticRelatedInformation;
 use tower_lsp::lsp_types::DiagnosticSeverity;
 use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::NumberOrString;
 use tower_lsp::lsp_types::Url;
 
 use crate::core::workspace::File;
@@ -50,6 +51,7 @@ where
 
 struct ErrorBuilder {
     severity: DiagnosticSeverity,
+    code: Option<String>,
     message: String,
     related_info: Vec<(String, Loc)>,
 }
@@ -70,6 +72,7 @@ impl Buildable<ParsingError> for BadCallChain {
 
         ErrorBuilder {
             severity: DiagnosticSeverity::WARNING,
+            code: None,
             message,
             related_info,
         }
@@ -88,6 +91,7 @@ impl Buildable<ParsingError> for MissingComma {
 
         ErrorBuilder {
             severity: DiagnosticSeverity::WARNING,
+            code: None,
             message,
             related_info,
         }
@@ -103,6 +107,7 @@ impl Buildable<ParsingError> for TypedHole {
 
         ErrorBuilder {
             severity: DiagnosticSeverity::WARNING,
+            code: None,
             message,
             related_info,
         }
@@ -118,6 +123,7 @@ impl Buildable<ParsingError> for ParserLexingError {
 
         ErrorBuilder {
             severity: DiagnosticSeverity::WARNING,
+            code: None,
             message,
             related_info,
         }
@@ -151,6 +157,7 @@ impl Buildable<ParsingError> for () {
 
         ErrorBuilder {
             severity: DiagnosticSeverity::ERROR,
+            code: None,
             message,
             related_info,
         }
@@ -171,30 +178,24 @@ impl Buildable<()> for ParsingError {
 
 impl Buildable<()> for ElaboratingError {
     fn build(&self, &(): &()) -> ErrorBuilder {
-        let message = self.stage.error.to_string();
-
-        let mut related_info = vec![];
-
-        match &self.stage.error {
-            e::Error::Cycle(entries) => {
-                related_info = entries
-                    .iter()
-                    .map(|(name, loc)| (format!("{name} is part of the cycle"), *loc))
-                    .collect();
-            }
-            e::Error::NoInitialConstructor(entries) => {
-                related_info = entries
-                    .iter()
-                    .map(|(name, loc)| (format!("{name} has no initial constructor"), *loc))
-                    .collect();
-            }
-            _ => {}
+        let error = &self.stage.error;
+        let mut message = match error.help() {
+            Some(help) => format!("{error}\nhelp: {help}"),
+            None => error.to_string(),
+        };
+        if let e::Error::TypeHole(goal) = error {
+            let _ = write!(message, "\ngoal:\n{goal}");
         }
 
         ErrorBuilder {
-            severity: DiagnosticSeverity::ERROR,
+            severity: if self.is_warning() {
+                DiagnosticSeverity::WARNING
+            } else {
+                DiagnosticSeverity::ERROR
+            },
+            code: Some(error.code().to_owned()),
             message,
-            related_info,
+            related_info: error.labels(),
         }
     }
 }
@@ -216,7 +217,7 @@ impl ErrorBuilder {
         Diagnostic {
             range: at.to_lsp(),
             severity: Some(self.severity),
-            code: None,
+            code: self.code.map(NumberOrString::String),
             code_description: None,
             source: None,
             message: self.message,
@@ -7,7 +7,7 @@ mod token;
 #[cfg(test)]
 mod tests;
 
-use dbuf_core::ast::operators::Literal;
+use dbuf_core::ast::operators::{Literal, bytes_literal, double_literal};
 use modifier::Modifier;
 use token::Token;
 
@@ -160,6 +160,15 @@ impl SemanticTokenVisitor<'_> {
                 let str = "\"".to_owned() + s + "\"";
                 self.push_str(&str, *location, Token::String);
             }
+            Literal::Double(d) => {
+                self.push_str(&double_literal(d.0), *location, Token::Number);
+            }
+            Literal::Sized(s) => {
+                self.push_str(&s.to_string(), *location, Token::Number);
+            }
+            Literal::Bytes(b) => {
+                self.push_str(&bytes_literal(b), *location, Token::String);
+            }
         }
     }
 
@@ -3,9 +3,32 @@
 use tower_lsp::lsp_types::{LanguageString, MarkedString};
 
 use crate::core::dbuf_language::get_builtin_types;
+use crate::core::errors::Error;
 use crate::core::navigator::Symbol;
 use crate::core::pretty_printer::PrettyPrinter;
-use crate::core::workspace::{ElaboratedHelper, File};
+use crate::core::workspace::{ElaboratedHelper, File, LocationHelper};
+use dbuf_core::error::elaborating as e;
+use tower_lsp::lsp_types::Position;
+
+/// Goal of typed hole at `pos`, which shows expected type and variables in scope.
+pub fn get_goal_hover(pos: Position, file: &File) -> Option<Vec<MarkedString>> {
+    file.get_errors().iter().find_map(|error| {
+        let Error::ElaboratingError(error) = error else {
+            return None;
+        };
+        let e::Error::TypeHole(goal) = &error.stage.error else {
+            return None;
+        };
+        error.stage.loc.filter(|loc| loc.contains(pos))?;
+        Some(vec![
+            MarkedString::LanguageString(LanguageString {
+                language: "dbuf".to_owned(),
+                value: goal.to_string(),
+            }),
+            MarkedString::String("goal of typed hole".to_owned()),
+        ])
+    })
+}
 
 pub fn get_hover(symbol: Symbol, file: &File) -> Option<Vec<MarkedString>> {
     let elaborated = file.get_elaborated().take()?;
@@ -135,6 +135,7 @@ impl Handler {
     /// * For fields: Type name ('message Type'), Constructor if not message('    Ctr'), field declaration
     /// * For constructors: Type name ('enum Enum'), Constructor declaration without pattern
     /// * For aliases: Type name ('enum Enum') with dependencies, enum branch
+    /// * For typed holes: goal with expected type and variables in scope
     ///
     /// # Errors
     ///
@@ -146,6 +147,12 @@ impl Handler {
         document: &Url,
     ) -> Result<Option<Hover>> {
         let file = access.read(document);
+        if let Some(strings) = hover::get_goal_hover(pos, &file) {
+            return Ok(Some(Hover {
+                contents: HoverContents::Array(strings),
+                range: None,
+            }));
+        }
         let Some(navigator) = Navigator::new(&file) else {
             return Ok(None);
         };
@@ -1,7 +1,7 @@
 //! Module exports structs for Command Line Interface.
 use std::path::PathBuf;
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 /// A rich serialization protocol for dependently typed data.
 #[derive(Parser)]
@@ -31,9 +31,34 @@ pub struct CompileParams {
     #[arg(short, long, default_value = ".")]
     pub path: PathBuf,
 
-    /// Output languages.
+    /// Output languages, `rust-async` for Rust with tokio I/O,
+    /// or `descriptor` for schema descriptor in JSON and binary.
     #[arg(short, long, num_args=1..)]
     pub output: Vec<String>,
+
+    /// Directories to search imported files in.
+    #[arg(short = 'I', long)]
+    pub include: Vec<PathBuf>,
+
+    /// Encoding of integers and lengths of sequences in generated Rust,
+    /// other languages support only `fixed` one.
+    #[arg(long, value_enum, default_value_t = Encoding::Fixed)]
+    pub encoding: Encoding,
+
+    /// Leave out of generated Rust encoding values that are determined by dependencies,
+    /// like descriptors of enums matched by them. Views are not generated then,
+    /// and other languages don't support it.
+    #[arg(long, default_value = "false")]
+    pub omit_derivable: bool,
+}
+
+/// Wire encodings of integers and lengths of sequences.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    /// Little-endian bytes of width of integers, and 8 bytes of lengths.
+    Fixed,
+    /// Varints of integers and lengths, zig-zag ones of signed integers.
+    Compact,
 }
 
 #[derive(Args, Debug)]
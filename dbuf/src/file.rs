@@ -9,13 +9,14 @@ use dbuf_core::ast::elaborated as e;
 use dbuf_core::ast::parsed as p;
 use dbuf_core::cst as c;
 
-use dbuf_core::elaboration::elaborate;
+use dbuf_core::elaboration::elaborate_in_graph;
+use dbuf_core::error::ElaboratingError;
 
 use crate::file_content::FileContent;
 use crate::reporter::Reporter;
 
 type Cst = c::Tree;
-type Ast = p::Module<Location<Offset>, LocatedName<InternedString, Offset>>;
+pub type Ast = p::Module<Location<Offset>, LocatedName<InternedString, Offset>>;
 type East = e::Module<InternedString>;
 
 /// Structure representing one file.
@@ -26,6 +27,8 @@ pub struct File<'a> {
     cst: Option<Cst>,
     /// AST representation of file.
     ast: Option<Ast>,
+    /// Package declared in file.
+    package: Vec<InternedString>,
     /// EAST representation of file.
     east: Option<East>,
 }
@@ -37,6 +40,7 @@ impl<'a> File<'a> {
             content,
             cst: None,
             ast: None,
+            package: vec![],
             east: None,
         }
     }
@@ -74,19 +78,27 @@ impl<'a> File<'a> {
     pub fn process_ast(&mut self, _reporter: &mut Reporter) {
         if let Some(cst) = self.get_cst() {
             let ast = c::convert_to_ast(cst);
+            self.package = c::collect_package(cst)
+                .map(|package| package.path.into_iter().map(Into::into).collect())
+                .unwrap_or_default();
             self.ast = ast.into();
         }
     }
 
-    /// Builds east on file.
-    pub fn process_east(&mut self, reporter: &mut Reporter) {
+    /// Builds east on file using elaborated modules of files it imports,
+    /// while `importers` are asts of files that import it.
+    ///
+    /// Every elaboration error and warning is reported, and east is built only if
+    /// there are no errors.
+    pub fn process_east(&mut self, imports: &[&East], importers: &[&Ast], reporter: &mut Reporter) {
         if let Some(ast) = self.get_ast() {
-            self.east = match elaborate(ast) {
-                Ok(east) => east.into(),
-                Err(err) => {
-                    return reporter.report(&dbuf_core::error::GeneralError::Elaborating(err));
-                }
-            };
+            let (east, errors) = elaborate_in_graph(ast, &self.package, imports, importers);
+            if errors.iter().all(ElaboratingError::is_warning) {
+                self.east = east.into();
+            }
+            for err in errors {
+                reporter.report(&err.into());
+            }
         }
     }
 }
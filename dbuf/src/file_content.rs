@@ -4,6 +4,8 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use dbuf_core::loader::SourceFile;
+
 pub struct FileContent {
     /// Name of file.
     name: String,
@@ -43,6 +45,14 @@ impl FileContent {
         })
     }
 
+    /// Create File struct from file loaded with its imports.
+    pub fn from_source(source: &SourceFile) -> FileContent {
+        FileContent {
+            name: source.name(),
+            content: source.content.clone(),
+        }
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
@@ -8,6 +8,7 @@ use dbuf_core::location::Location;
 use dbuf_core::location::Offset;
 
 use dbuf_core::error::elaborating;
+use dbuf_core::error::loading;
 use dbuf_core::error::parsing::*;
 use dbuf_core::error::*;
 
@@ -66,6 +67,7 @@ impl<'a> Reporter<'a> {
     /// Report an error.
     pub fn report(&mut self, err: &GeneralError) {
         let r = match err {
+            GeneralError::Loading(error) => error.report(&self.meta, &()),
             GeneralError::Lexing(error) => error.report(&self.meta, &()),
             GeneralError::Parsing(error) => error.report(&self.meta, &()),
             GeneralError::Elaborating(error) => error.report(&self.meta, &()),
@@ -282,43 +284,76 @@ impl Reportable<()> for ParsingError {
     }
 }
 
+impl Reportable<()> for LoadingError {
+    fn report<'a>(&self, meta: &Metadata<'a>, _extra: &()) -> Report<'a, (&'a str, Range<usize>)> {
+        let message = self.stage.error.to_string();
+        let primary_span = meta.convert_location(&self.stage.loc.unwrap_or_default());
+        let primary_loc = (meta.content.get_name(), primary_span);
+
+        let label_message = match &self.stage.error {
+            loading::Error::UnresolvedImport(_) => "File not found",
+            loading::Error::ImportCycle(_) => "Import is part of the cycle",
+            loading::Error::Unreadable(..) => "File is not readable",
+        };
+
+        Report::build(ReportKind::Error, primary_loc.clone())
+            .with_message(&message)
+            .with_label(
+                Label::new(primary_loc)
+                    .with_color(Color::Red)
+                    .with_message(label_message),
+            )
+            .finish()
+    }
+}
+
 impl Reportable<()> for ElaboratingError {
     fn report<'a>(&self, meta: &Metadata<'a>, _extra: &()) -> Report<'a, (&'a str, Range<usize>)> {
         let message = self.stage.error.to_string();
         let primary_span = meta.convert_location(&self.stage.loc.unwrap_or_default());
         let primary_loc = (meta.content.get_name(), primary_span);
 
-        let mut report =
-            Report::build(ReportKind::Error, primary_loc.clone()).with_message(&message);
-
-        match &self.stage.error {
-            elaborating::Error::Cycle(entries) => {
-                for (name, loc) in entries {
-                    let span = meta.convert_location(loc);
-                    report = report.with_label(
-                        Label::new((meta.content.get_name(), span))
-                            .with_color(Color::Red)
-                            .with_message(format!("{name} is part of the cycle")),
-                    );
-                }
-            }
-            elaborating::Error::NoInitialConstructor(entries) => {
-                for (name, loc) in entries {
-                    let span = meta.convert_location(loc);
-                    report = report.with_label(
-                        Label::new((meta.content.get_name(), span))
-                            .with_color(Color::Red)
-                            .with_message(format!("{name} has no initial constructor")),
-                    );
-                }
-            }
-            _ => {
-                report = report.with_label(
-                    Label::new(primary_loc)
-                        .with_color(Color::Red)
-                        .with_message(&message),
-                );
-            }
+        let (kind, color) = if self.is_warning() {
+            (ReportKind::Warning, Color::Yellow)
+        } else {
+            (ReportKind::Error, Color::Red)
+        };
+
+        let mut report = Report::build(kind, primary_loc.clone())
+            .with_code(self.stage.error.code())
+            .with_message(&message);
+
+        if let Some(help) = self.stage.error.help() {
+            report = report.with_help(help);
+        }
+        if let Some(lint) = self.stage.error.lint() {
+            report = report.with_note(format!(
+                "silence with `// dbuf:allow({})` before the declaration",
+                lint.name()
+            ));
+        }
+        if let elaborating::Error::TypeHole(goal) = &self.stage.error {
+            report = report.with_note(format!("goal:\n{goal}"));
+        }
+
+        // Labels of cycles and missing constructors already point to all declarations.
+        if !matches!(
+            self.stage.error,
+            elaborating::Error::Cycle(_) | elaborating::Error::NoInitialConstructor(_)
+        ) {
+            report = report.with_label(
+                Label::new(primary_loc)
+                    .with_color(color)
+                    .with_message(&message),
+            );
+        }
+        for (label, loc) in self.stage.error.labels() {
+            let span = meta.convert_location(&loc);
+            report = report.with_label(
+                Label::new((meta.content.get_name(), span))
+                    .with_color(color)
+                    .with_message(label),
+            );
         }
 
         report.finish()
@@ -1,75 +1,140 @@
 //! Module contains entry point to run compiler.
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
+use std::iter::zip;
 use std::path;
 use std::process::exit;
 use std::sync::LazyLock;
 
 use dbuf_core::arena::InternedString;
 use dbuf_core::ast::elaborated as e;
+use dbuf_core::error::{GeneralError, LoadingError};
+use dbuf_core::loader::{Loader, SourceFile};
 type ElaboratedModule = e::Module<InternedString>;
 
-use super::file::File;
+use super::file::{Ast, File};
 use super::reporter::Reporter;
-use crate::cli::CompileParams;
+use crate::cli::{CompileParams, Encoding};
 use crate::file_content::FileContent;
 
+/// Elaborated modules imported by a file, paired with names of files they come from.
+type Imports<'a> = [(&'a str, &'a ElaboratedModule)];
+
 /// Configuration for supported language generation
 struct LanguageConfig {
     /// Extensions of files for that language.
     extension: &'static str,
-    /// Code generation function for language.
-    codegen: fn(&ElaboratedModule) -> String,
+    /// Code generation function for language, which takes options of generated code from params.
+    codegen: fn(&ElaboratedModule, &Imports<'_>, &CompileParams) -> Vec<u8>,
+    /// Whether wire format options are accepted. Only generated Rust encodes messages
+    /// in wire format they choose, and descriptors don't depend on them.
+    wire_options: bool,
 }
 
-/// Supported languages.
-static LANGUAGES: LazyLock<HashMap<&str, Option<LanguageConfig>>> = LazyLock::new(|| {
+/// Supported languages, some of them are generated into several files.
+static LANGUAGES: LazyLock<HashMap<&str, Option<Vec<LanguageConfig>>>> = LazyLock::new(|| {
     #[cfg(feature = "rust")]
-    let rust_set = Some(LanguageConfig {
+    let rust_set = Some(vec![LanguageConfig {
         extension: ".rs",
         codegen: rust_gen_impl::run,
-    });
+        wire_options: true,
+    }]);
     #[cfg(not(feature = "rust"))]
     let rust_set = None;
 
+    #[cfg(feature = "rust")]
+    let rust_async_set = Some(vec![LanguageConfig {
+        extension: ".rs",
+        codegen: rust_gen_impl::run_async,
+        wire_options: true,
+    }]);
+    #[cfg(not(feature = "rust"))]
+    let rust_async_set = None;
+
     #[cfg(feature = "kotlin")]
-    let kotlin_set = Some(LanguageConfig {
+    let kotlin_set = Some(vec![LanguageConfig {
         extension: ".kt",
         codegen: kotlin_gen_impl::run,
-    });
+        wire_options: false,
+    }]);
     #[cfg(not(feature = "kotlin"))]
     let kotlin_set = None;
 
     #[cfg(feature = "swift")]
-    let swift_set = Some(LanguageConfig {
+    let swift_set = Some(vec![LanguageConfig {
         extension: ".swift",
         codegen: swift_gen_impl::run,
-    });
+        wire_options: false,
+    }]);
     #[cfg(not(feature = "swift"))]
     let swift_set = None;
 
+    #[cfg(feature = "descriptor")]
+    let descriptor_set = Some(vec![
+        LanguageConfig {
+            extension: ".descriptor.json",
+            codegen: descriptor_gen_impl::run_json,
+            wire_options: true,
+        },
+        LanguageConfig {
+            extension: ".descriptor.bin",
+            codegen: descriptor_gen_impl::run_binary,
+            wire_options: true,
+        },
+    ]);
+    #[cfg(not(feature = "descriptor"))]
+    let descriptor_set = None;
+
     HashMap::from([
         ("rust", rust_set),
+        ("rust-async", rust_async_set),
         ("kotlin", kotlin_set),
         ("swift", swift_set),
+        ("descriptor", descriptor_set),
     ])
 });
 
 /// Main for compiler.
 pub fn run(params: &CompileParams) -> ! {
-    let file_content = match FileContent::new(&params.file) {
-        Ok(file) => file,
+    let loader = Loader::new(params.include.clone());
+    let sources = match loader.load(&params.file) {
+        Ok(sources) => sources,
         Err(err) => {
-            eprintln!("{err}");
+            report_loading(err);
             exit(1);
         }
     };
-    let mut file = File::new(&file_content);
 
-    let mut reporter = Reporter::new(&file_content);
-    let r = process(params, &mut file, &mut reporter);
+    let contents: Vec<FileContent> = sources.iter().map(FileContent::from_source).collect();
+    let mut files: Vec<File> = contents.iter().map(File::new).collect();
+    let mut reporters: Vec<Reporter> = contents.iter().map(Reporter::new).collect();
+
+    // Elaboration of a file needs asts of files importing it, so every file is parsed first.
+    for (file, reporter) in zip(&mut files, &mut reporters) {
+        file.process_cst(reporter);
+        file.process_ast(reporter);
+    }
+
+    let mut r = Ok(());
+    for (index, mut reporter) in reporters.into_iter().enumerate() {
+        let imports = transitive_imports(&sources, index);
+        let (processed, rest) = files.split_at_mut(index);
+        let (file, later) = rest.split_first_mut().expect("file is among loaded ones");
+        let importers: Vec<&Ast> = transitive_importers(&sources, index)
+            .into_iter()
+            .filter_map(|i| later[i - index - 1].get_ast())
+            .collect();
 
-    reporter.print();
+        let res = process(params, file, processed, &imports, &importers, &mut reporter);
+        reporter.print();
+
+        r = r.and(res);
+    }
+
+    #[cfg(feature = "swift")]
+    if r.is_ok() {
+        r = swift_gen_impl::write_namespaces(params, &files);
+    }
 
     if r.is_ok() {
         exit(0);
@@ -78,11 +143,74 @@ pub fn run(params: &CompileParams) -> ! {
     }
 }
 
-/// Process a file.
-fn process(params: &CompileParams, file: &mut File, reporter: &mut Reporter) -> Result<(), ()> {
-    file.process_cst(reporter);
-    file.process_ast(reporter);
-    file.process_east(reporter);
+/// Report error that occurred while loading files.
+fn report_loading(err: LoadingError) {
+    if err.loc.is_none() {
+        eprintln!("{err}");
+        return;
+    }
+
+    match FileContent::new(&err.file) {
+        Ok(content) => {
+            let mut reporter = Reporter::new(&content);
+            reporter.report(&GeneralError::Loading(err));
+            reporter.print();
+        }
+        Err(_) => eprintln!("{err}"),
+    }
+}
+
+/// Indices of files transitively imported by file with `index`.
+///
+/// Indices are sorted, so every file goes after files it imports.
+fn transitive_imports(sources: &[SourceFile], index: usize) -> Vec<usize> {
+    let mut visited = BTreeSet::new();
+    let mut stack = sources[index].imports.clone();
+    while let Some(next) = stack.pop() {
+        if visited.insert(next) {
+            stack.extend_from_slice(&sources[next].imports);
+        }
+    }
+    visited.into_iter().collect()
+}
+
+/// Indices of files that transitively import file with `index`.
+///
+/// Files go after files they import, so indices are greater than `index`.
+fn transitive_importers(sources: &[SourceFile], index: usize) -> Vec<usize> {
+    (index + 1..sources.len())
+        .filter(|&other| transitive_imports(sources, other).contains(&index))
+        .collect()
+}
+
+/// Process a parsed file.
+///
+/// `processed` are files that go before current one and
+/// `imports` are indices of files it imports among them,
+/// while `importers` are asts of files that import it.
+fn process(
+    params: &CompileParams,
+    file: &mut File,
+    processed: &[File],
+    imports: &[usize],
+    importers: &[&Ast],
+    reporter: &mut Reporter,
+) -> Result<(), ()> {
+    let imported: Option<Vec<(&str, &ElaboratedModule)>> = imports
+        .iter()
+        .map(|&i| {
+            processed[i]
+                .get_east()
+                .map(|east| (processed[i].get_name(), east))
+        })
+        .collect();
+    let Some(imported) = imported else {
+        eprintln!("File {} imports files with errors", file.get_name());
+        return Err(());
+    };
+
+    let modules: Vec<&ElaboratedModule> = imported.iter().map(|&(_, module)| module).collect();
+    file.process_east(&modules, importers, reporter);
 
     let out_dir = path::Path::new(&params.path);
     for out in &params.output {
@@ -91,28 +219,48 @@ fn process(params: &CompileParams, file: &mut File, reporter: &mut Reporter) ->
             return Err(());
         };
 
-        let Some(config) = opt_config else {
+        let Some(configs) = opt_config else {
             eprintln!("Feature for language {out} is not enabled");
             return Err(());
         };
 
-        let file_name = file.get_name().to_string() + config.extension;
-        let to = out_dir.join(file_name);
-
-        if let Some(elaborated) = file.get_east() {
-            let output = (config.codegen)(elaborated);
-            write_generated(output, &to)?;
-        } else {
+        let Some(elaborated) = file.get_east() else {
             eprintln!("No elaborated ast to generate code");
             return Err(());
+        };
+
+        if let Some(option) = wire_option(params)
+            && configs.iter().any(|config| !config.wire_options)
+        {
+            eprintln!("Language {out} doesn't support {option}");
+            return Err(());
+        }
+
+        for config in configs {
+            let file_name = file.get_name().to_string() + config.extension;
+            let to = out_dir.join(file_name);
+
+            let output = (config.codegen)(elaborated, &imported, params);
+            write_generated(output, &to)?;
         }
     }
 
     Ok(())
 }
 
+/// Wire format option of `params` that is not default, if any.
+fn wire_option(params: &CompileParams) -> Option<&'static str> {
+    if params.encoding != Encoding::Fixed {
+        Some("--encoding compact")
+    } else if params.omit_derivable {
+        Some("--omit-derivable")
+    } else {
+        None
+    }
+}
+
 /// Write generated text to path
-fn write_generated(generated: String, to: &path::Path) -> Result<(), ()> {
+fn write_generated(generated: Vec<u8>, to: &path::Path) -> Result<(), ()> {
     fs::write(to, generated).map_err(|e| {
         eprintln!("Error while creating file: {e}");
     })
@@ -120,37 +268,157 @@ fn write_generated(generated: String, to: &path::Path) -> Result<(), ()> {
 
 #[cfg(feature = "kotlin")]
 mod kotlin_gen_impl {
-    use super::ElaboratedModule;
+    use super::{CompileParams, ElaboratedModule, Imports};
+    use dbuf_gen::import::Import;
     use dbuf_gen::kotlin_gen;
 
     /// impl of kotlin code generation.
-    pub fn run(module: &ElaboratedModule) -> String {
-        kotlin_gen::generate_module(module)
+    pub fn run(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        _params: &CompileParams,
+    ) -> Vec<u8> {
+        let imports: Vec<Import> = imports
+            .iter()
+            .map(|&(name, module)| Import { name, module })
+            .collect();
+        kotlin_gen::generate_module_with_imports(module, &imports).into_bytes()
     }
 }
 
 #[cfg(feature = "rust")]
 mod rust_gen_impl {
-    use super::ElaboratedModule;
+    use super::{CompileParams, ElaboratedModule, Imports};
+    use crate::cli::Encoding;
     use dbuf_gen::codegen;
+    use dbuf_gen::import::Import;
 
     /// impl of rust code generation.
-    pub fn run(module: &ElaboratedModule) -> String {
+    pub fn run(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        params: &CompileParams,
+    ) -> Vec<u8> {
+        generate(module, imports, options(params))
+    }
+
+    /// impl of rust code generation with async I/O over tokio.
+    pub fn run_async(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        params: &CompileParams,
+    ) -> Vec<u8> {
+        let options = codegen::Options {
+            async_io: true,
+            ..options(params)
+        };
+        generate(module, imports, options)
+    }
+
+    fn options(params: &CompileParams) -> codegen::Options {
+        let encoding = match params.encoding {
+            Encoding::Fixed => codegen::Encoding::Fixed,
+            Encoding::Compact => codegen::Encoding::Compact,
+        };
+        codegen::Options {
+            encoding,
+            omit_derivable: params.omit_derivable,
+            ..codegen::Options::default()
+        }
+    }
+
+    fn generate(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        options: codegen::Options,
+    ) -> Vec<u8> {
+        let imports: Vec<Import> = imports
+            .iter()
+            .map(|&(name, module)| Import { name, module })
+            .collect();
         let mut writer = Vec::new();
         // FIXME
-        let res = codegen::generate_module(module, &mut writer);
+        let res = codegen::generate_module_with_options(module, &imports, options, &mut writer);
         assert!(res.is_ok());
-        String::from_utf8(writer).expect("generated code must be correct utf8")
+        writer
     }
 }
 
 #[cfg(feature = "swift")]
 mod swift_gen_impl {
-    use super::ElaboratedModule;
+    use super::{CompileParams, ElaboratedModule, File, Imports, path, write_generated};
+    use dbuf_gen::import::Import;
     use dbuf_gen::swift_gen;
 
+    /// File declaring namespace enums of packages, which files of packaged modules extend.
+    const NAMESPACES_FILE: &str = "DbufNamespaces.swift";
+
     /// impl of swift code generation.
-    pub fn run(module: &ElaboratedModule) -> String {
-        swift_gen::generate_module(module)
+    pub fn run(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        _params: &CompileParams,
+    ) -> Vec<u8> {
+        let imports: Vec<Import> = imports
+            .iter()
+            .map(|&(name, module)| Import { name, module })
+            .collect();
+        swift_gen::generate_module_with_imports(module, &imports).into_bytes()
+    }
+
+    /// Writes namespace enums of packages of compiled `files`, if Swift is generated
+    /// and some of them are packaged.
+    pub fn write_namespaces(params: &CompileParams, files: &[File]) -> Result<(), ()> {
+        if !params.output.iter().any(|out| out == "swift") {
+            return Ok(());
+        }
+        let modules: Vec<&ElaboratedModule> = files.iter().filter_map(File::get_east).collect();
+        if modules.iter().all(|module| module.package.is_empty()) {
+            return Ok(());
+        }
+
+        let to = path::Path::new(&params.path).join(NAMESPACES_FILE);
+        write_generated(swift_gen::generate_namespaces(modules).into_bytes(), &to)
+    }
+}
+
+#[cfg(feature = "descriptor")]
+mod descriptor_gen_impl {
+    use super::{CompileParams, ElaboratedModule, Imports};
+    use dbuf_gen::descriptor_gen;
+    use dbuf_gen::import::Import;
+
+    /// Imported types are described by descriptor of the file too.
+    fn to_imports<'a>(imports: &Imports<'a>) -> Vec<Import<'a>> {
+        imports
+            .iter()
+            .map(|&(name, module)| Import { name, module })
+            .collect()
+    }
+
+    /// impl of descriptor generation in JSON.
+    pub fn run_json(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        _params: &CompileParams,
+    ) -> Vec<u8> {
+        let mut writer = Vec::new();
+        descriptor_gen::generate_module_with_imports(module, &to_imports(imports))
+            .write_json(&mut writer)
+            .expect("writing into Vec is always ok");
+        writer
+    }
+
+    /// impl of descriptor generation in dbuf binary encoding.
+    pub fn run_binary(
+        module: &ElaboratedModule,
+        imports: &Imports<'_>,
+        _params: &CompileParams,
+    ) -> Vec<u8> {
+        let mut writer = Vec::new();
+        descriptor_gen::generate_module_with_imports(module, &to_imports(imports))
+            .write_binary(&mut writer)
+            .expect("writing into Vec is always ok");
+        writer
     }
 }
@@ -0,0 +1,390 @@
+//! Descriptors of elaborated DependoBuf modules.
+//!
+//! Descriptor lists types, constructors and aliases of a schema with all
+//! dependencies, refinements and defaults, so tools can inspect schemas
+//! without compiling them. Descriptors are stored either as JSON or in
+//! binary, which is the dbuf encoding of [`SCHEMA`].
+//!
+//! Names of types declared in a package are qualified with it. Descriptors generated
+//! by the compiler describe types of imported schemas too, so types that are not
+//! described are builtin (see [`BUILTIN_TYPES`]).
+use std::io::{self, Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DbufPrimitive, DeserializeError, Double};
+
+/// Version of descriptor format, descriptors of other versions are rejected.
+pub const FORMAT_VERSION: u64 = 1;
+
+/// Schema of binary descriptors, whose root is `ModuleDescriptor`.
+pub const SCHEMA: &str = include_str!("../descriptor.dbuf");
+
+/// Names of builtin types, which are referenced by descriptors but never described.
+pub const BUILTIN_TYPES: &[&str] = &[
+    "Bool", "Int", "UInt", "Int8", "Int16", "Int32", "Int64", "UInt8", "UInt16", "UInt32",
+    "UInt64", "Double", "String", "Bytes", "List", "Array", "Type",
+];
+
+/// Descriptor of a module.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleDescriptor {
+    /// Version of format, which is [`FORMAT_VERSION`].
+    pub version: u64,
+    /// Package of the module, outermost segment first.
+    pub package_path: Vec<String>,
+    /// Types in topologically sorted order.
+    pub types: Vec<TypeDescriptor>,
+    /// Aliases in topologically sorted order.
+    pub aliases: Vec<AliasDescriptor>,
+}
+
+/// Descriptor of a type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeDescriptor {
+    pub name: String,
+    pub kind: TypeKind,
+    pub dependencies: Vec<Binding>,
+    /// Refinements of dependencies.
+    pub refinements: Vec<Refinement>,
+    /// Constructors of enums are sorted by name, so index of constructor
+    /// is the tag written before its fields in binary encoding.
+    pub constructors: Vec<ConstructorDescriptor>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeKind {
+    MessageType,
+    EnumType,
+}
+
+/// Descriptor of a constructor.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstructorDescriptor {
+    pub name: String,
+    /// Implicit arguments, which dependencies of the result type are expressed with.
+    pub implicits: Vec<Binding>,
+    pub fields: Vec<Binding>,
+    pub result_type: TypeExpr,
+    /// Refinements of fields, preceded by refinements of dependencies
+    /// expressed with implicit arguments.
+    pub refinements: Vec<Refinement>,
+    /// Default values of fields expressed with implicit arguments.
+    pub defaults: Vec<FieldDefault>,
+}
+
+/// Descriptor of a type alias.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasDescriptor {
+    pub name: String,
+    pub dependencies: Vec<Binding>,
+    pub refinements: Vec<Refinement>,
+    /// Aliased type, which refers to the dependencies.
+    pub target: TypeExpr,
+}
+
+/// Typed variable.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Binding {
+    pub name: String,
+    pub ty: TypeExpr,
+}
+
+/// `Bool` predicate on variable `name`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Refinement {
+    pub name: String,
+    pub predicate: ValueExpr,
+}
+
+/// Default value of field `name`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldDefault {
+    pub name: String,
+    pub value: ValueExpr,
+}
+
+/// Call to dependent type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeExpr {
+    pub name: String,
+    /// Dependencies, where type arguments are [`ValueExpr::TypeArgument`].
+    pub dependencies: Vec<ValueExpr>,
+}
+
+/// Expression returning value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all_fields = "camelCase")]
+pub enum ValueExpr {
+    LiteralExpr {
+        literal: Literal,
+        result_type: TypeExpr,
+    },
+    UnaryExpr {
+        op: UnaryOp,
+        operand: Box<ValueExpr>,
+        result_type: TypeExpr,
+    },
+    BinaryExpr {
+        op: BinaryOp,
+        lhs: Box<ValueExpr>,
+        rhs: Box<ValueExpr>,
+        result_type: TypeExpr,
+    },
+    ConstructorExpr {
+        name: String,
+        implicits: Vec<ValueExpr>,
+        arguments: Vec<ValueExpr>,
+        result_type: TypeExpr,
+    },
+    VariableExpr {
+        name: String,
+        ty: TypeExpr,
+    },
+    /// Type passed as argument of parametric builtin type, like `T` in `List T`.
+    TypeArgument {
+        ty: TypeExpr,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnaryOp {
+    /// Access the field of a message.
+    Access {
+        field: String,
+    },
+    Negate,
+    Not,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BinaryOp {
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    ShiftLeft,
+    ShiftRight,
+    Caret,
+    BinaryAnd,
+    BinaryOr,
+    Equal,
+    NotEqual,
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+    StartsWith,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Literal {
+    BoolLiteral { value: bool },
+    IntLiteral { value: i64 },
+    UIntLiteral { value: u64 },
+    StringLiteral { value: String },
+    BytesLiteral { value: Vec<u8> },
+    DoubleLiteral { value: Double },
+    Int8Literal { value: i8 },
+    Int16Literal { value: i16 },
+    Int32Literal { value: i32 },
+    Int64Literal { value: i64 },
+    UInt8Literal { value: u8 },
+    UInt16Literal { value: u16 },
+    UInt32Literal { value: u32 },
+    UInt64Literal { value: u64 },
+}
+
+impl ModuleDescriptor {
+    /// Writes descriptor as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///  Returns an error if writing into `writer` fails.
+    pub fn write_json<W: Write>(&self, writer: &mut W) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, self)
+    }
+
+    /// Reads descriptor from JSON.
+    ///
+    /// # Errors
+    ///  When JSON is not a descriptor of [`FORMAT_VERSION`].
+    pub fn read_json<R: Read>(reader: &mut R) -> serde_json::Result<Self> {
+        let descriptor: Self = serde_json::from_reader(reader)?;
+        if descriptor.version != FORMAT_VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "unsupported descriptor version {}",
+                descriptor.version
+            )));
+        }
+        Ok(descriptor)
+    }
+
+    /// Writes descriptor in binary encoding.
+    ///
+    /// # Errors
+    ///  Returns an I/O error if writing into `writer` fails.
+    pub fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.dbuf_serialize(writer)
+    }
+
+    /// Reads descriptor in binary encoding.
+    ///
+    /// # Errors
+    ///  * `DeserializeError::UnsupportedVersion` when descriptor is not of [`FORMAT_VERSION`].
+    ///  * Any error of reading dbuf encoding.
+    pub fn read_binary<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+        Self::dbuf_deserialize(reader)
+    }
+}
+
+/// Fields of messages are encoded one after another.
+macro_rules! message_encoding {
+    ($ty:ident { $($field:ident),* $(,)? }) => {
+        impl DbufPrimitive for $ty {
+            fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                $(<_ as DbufPrimitive>::dbuf_serialize(&self.$field, writer)?;)*
+                Ok(())
+            }
+            fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+                Ok(Self {
+                    $($field: <_ as DbufPrimitive>::dbuf_deserialize(reader)?,)*
+                })
+            }
+        }
+    };
+}
+
+/// Fields of enum constructors are preceded by tag, which is index of
+/// constructor among constructors of the enum sorted by name.
+macro_rules! enum_encoding {
+    ($ty:ident { $($variant:ident = $tag:literal { $($field:ident),* }),* $(,)? }) => {
+        impl DbufPrimitive for $ty {
+            fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                match self {
+                    $(Self::$variant { $($field),* } => {
+                        writer.write_all(&[$tag])?;
+                        $(<_ as DbufPrimitive>::dbuf_serialize($field, writer)?;)*
+                    })*
+                }
+                Ok(())
+            }
+            fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+                match <u8 as DbufPrimitive>::dbuf_deserialize(reader)? {
+                    $($tag => Ok(Self::$variant {
+                        $($field: <_ as DbufPrimitive>::dbuf_deserialize(reader)?),*
+                    }),)*
+                    _ => Err(DeserializeError::UnknownDescriptor),
+                }
+            }
+        }
+    };
+}
+
+impl DbufPrimitive for ModuleDescriptor {
+    fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        <_ as DbufPrimitive>::dbuf_serialize(&self.version, writer)?;
+        <_ as DbufPrimitive>::dbuf_serialize(&self.package_path, writer)?;
+        <_ as DbufPrimitive>::dbuf_serialize(&self.types, writer)?;
+        <_ as DbufPrimitive>::dbuf_serialize(&self.aliases, writer)
+    }
+    fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+        let version = <u64 as DbufPrimitive>::dbuf_deserialize(reader)?;
+        if version != FORMAT_VERSION {
+            return Err(DeserializeError::UnsupportedVersion(version));
+        }
+        Ok(Self {
+            version,
+            package_path: <_ as DbufPrimitive>::dbuf_deserialize(reader)?,
+            types: <_ as DbufPrimitive>::dbuf_deserialize(reader)?,
+            aliases: <_ as DbufPrimitive>::dbuf_deserialize(reader)?,
+        })
+    }
+}
+
+message_encoding!(TypeDescriptor {
+    name,
+    kind,
+    dependencies,
+    refinements,
+    constructors,
+});
+message_encoding!(ConstructorDescriptor {
+    name,
+    implicits,
+    fields,
+    result_type,
+    refinements,
+    defaults,
+});
+message_encoding!(AliasDescriptor {
+    name,
+    dependencies,
+    refinements,
+    target,
+});
+message_encoding!(Binding { name, ty });
+message_encoding!(Refinement { name, predicate });
+message_encoding!(FieldDefault { name, value });
+message_encoding!(TypeExpr { name, dependencies });
+
+enum_encoding!(TypeKind {
+    EnumType = 0 {},
+    MessageType = 1 {},
+});
+enum_encoding!(ValueExpr {
+    BinaryExpr = 0 { op, lhs, rhs, result_type },
+    ConstructorExpr = 1 { name, implicits, arguments, result_type },
+    LiteralExpr = 2 { literal, result_type },
+    TypeArgument = 3 { ty },
+    UnaryExpr = 4 { op, operand, result_type },
+    VariableExpr = 5 { name, ty },
+});
+enum_encoding!(UnaryOp {
+    Access = 0 { field },
+    Negate = 1 {},
+    Not = 2 {},
+});
+enum_encoding!(BinaryOp {
+    BinaryAnd = 0 {},
+    BinaryOr = 1 {},
+    Caret = 2 {},
+    Equal = 3 {},
+    Greater = 4 {},
+    GreaterEq = 5 {},
+    Less = 6 {},
+    LessEq = 7 {},
+    Minus = 8 {},
+    NotEqual = 9 {},
+    Percent = 10 {},
+    Plus = 11 {},
+    ShiftLeft = 12 {},
+    ShiftRight = 13 {},
+    Slash = 14 {},
+    Star = 15 {},
+    StartsWith = 16 {},
+});
+enum_encoding!(Literal {
+    BoolLiteral = 0 { value },
+    BytesLiteral = 1 { value },
+    DoubleLiteral = 2 { value },
+    Int16Literal = 3 { value },
+    Int32Literal = 4 { value },
+    Int64Literal = 5 { value },
+    Int8Literal = 6 { value },
+    IntLiteral = 7 { value },
+    StringLiteral = 8 { value },
+    UInt16Literal = 9 { value },
+    UInt32Literal = 10 { value },
+    UInt64Literal = 11 { value },
+    UInt8Literal = 12 { value },
+    UIntLiteral = 13 { value },
+});
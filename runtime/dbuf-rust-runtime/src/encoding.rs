@@ -0,0 +1,145 @@
+use std::io::{self, Read, Write};
+
+use crate::{DbufPrimitive, DeserializeError, LiteralError, deserialize_length, serialize_length};
+
+/// Wire encoding of integers and lengths of sequences, which is a type argument of
+/// [`DbufPrimitive`], [`crate::DbufView`] and [`crate::ListRef`].
+///
+/// Other primitives are encoded the same in every encoding: `Bool`, bytes and `Double`
+/// as themselves, strings and sequences as their length followed by their contents.
+pub trait Encoding: Send + Sync + 'static {
+    /// Writes length prefix of a sequence.
+    ///
+    /// # Errors
+    ///  Returns an I/O error if writing into `writer` fails.
+    fn serialize_length<W: Write>(len: usize, writer: &mut W) -> io::Result<()>;
+
+    /// Reads length prefix of a sequence.
+    ///
+    /// # Errors
+    ///  * `DeserializeError::IoError` when reading from `reader` fails.
+    ///  * `LiteralError` when length does not fit into `usize`.
+    fn deserialize_length<R: Read>(reader: &mut R) -> Result<usize, DeserializeError>;
+}
+
+/// Default encoding, where integers and lengths are little-endian bytes of their width,
+/// and lengths are 8 bytes wide.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fixed;
+
+impl Encoding for Fixed {
+    fn serialize_length<W: Write>(len: usize, writer: &mut W) -> io::Result<()> {
+        serialize_length(len, writer)
+    }
+
+    fn deserialize_length<R: Read>(reader: &mut R) -> Result<usize, DeserializeError> {
+        deserialize_length(reader)
+    }
+}
+
+/// Encoding, where integers wider than a byte and lengths are variable-length:
+/// unsigned ones are LEB128 varints, and signed ones are zig-zag mapped to unsigned
+/// first, so small numbers of both signs take few bytes.
+///
+/// Varints longer than needed are accepted, but never written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Compact;
+
+impl Encoding for Compact {
+    fn serialize_length<W: Write>(len: usize, writer: &mut W) -> io::Result<()> {
+        serialize_varint(len as u64, writer)
+    }
+
+    fn deserialize_length<R: Read>(reader: &mut R) -> Result<usize, DeserializeError> {
+        usize::try_from(deserialize_varint(reader)?)
+            .map_err(|_| LiteralError("Sequence length too long".to_string()))
+    }
+}
+
+/// Maximal length of varint of `u64`.
+pub(crate) const MAX_VARINT_LEN: usize = 10;
+
+fn serialize_varint<W: Write>(mut value: u64, writer: &mut W) -> io::Result<()> {
+    let mut buf = [0u8; MAX_VARINT_LEN];
+    let mut len = 0;
+    loop {
+        #[allow(clippy::cast_possible_truncation, reason = "masked to 7 bits")]
+        let low = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = low;
+            len += 1;
+            break;
+        }
+        buf[len] = low | 0x80;
+        len += 1;
+    }
+    writer.write_all(&buf[..len])
+}
+
+fn deserialize_varint<R: Read>(reader: &mut R) -> Result<u64, DeserializeError> {
+    let mut value = 0u64;
+    for index in 0..MAX_VARINT_LEN {
+        let mut byte = [0u8; 1];
+        reader
+            .read_exact(&mut byte)
+            .map_err(DeserializeError::IoError)?;
+        let [byte] = byte;
+        let bits = u64::from(byte & 0x7f);
+        let shift = 7 * index;
+        // The last byte holds only the highest bit of `u64`.
+        if index == MAX_VARINT_LEN - 1 && bits > 1 {
+            break;
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(LiteralError("Varint overflows u64".to_string()))
+}
+
+/// Maps signed integers to unsigned ones, so that small absolute values stay small.
+#[allow(clippy::cast_sign_loss, reason = "zig-zag reinterprets bits")]
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+#[allow(clippy::cast_possible_wrap, reason = "zig-zag reinterprets bits")]
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+macro_rules! compact_unsigned {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl DbufPrimitive<Compact> for $ty {
+            fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                serialize_varint(u64::from(*self), writer)
+            }
+            fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+                <$ty>::try_from(deserialize_varint(reader)?).map_err(|_| {
+                    LiteralError(concat!("Varint overflows ", stringify!($ty)).to_string())
+                })
+            }
+        }
+    )+};
+}
+
+compact_unsigned!(u16, u32, u64);
+
+macro_rules! compact_signed {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl DbufPrimitive<Compact> for $ty {
+            fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                serialize_varint(zigzag(i64::from(*self)), writer)
+            }
+            fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+                <$ty>::try_from(unzigzag(deserialize_varint(reader)?)).map_err(|_| {
+                    LiteralError(concat!("Varint overflows ", stringify!($ty)).to_string())
+                })
+            }
+        }
+    )+};
+}
+
+compact_signed!(i16, i32, i64);
@@ -1,10 +1,34 @@
 use crate::DeserializeError::LiteralError;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::io;
 use std::io::{Read, Write};
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[cfg(feature = "descriptor")]
+pub mod descriptor;
+mod encoding;
+#[cfg(feature = "tokio")]
+mod tokio_io;
+mod view;
+
+pub use encoding::{Compact, Encoding, Fixed};
+#[cfg(feature = "tokio")]
+pub use tokio;
+#[cfg(feature = "tokio")]
+pub use tokio_io::{
+    BoxFuture, DbufMessageAsync, DbufPrimitiveAsync, defaulted_field_reader_async,
+    deserialize_length_async, read_frame, serialize_length_async, write_frame,
+};
+pub use view::{DbufView, ListIter, ListRef, SliceReader};
 
 #[derive(Debug)]
 pub enum ConstructorError {
     MismatchedDependencies,
+    RefinementViolated(&'static str),
+    DivisionByZero,
+    /// Result of arithmetic operator is out of range of its integer type.
+    Overflow,
 }
 
 #[derive(Debug)]
@@ -14,11 +38,126 @@ pub enum DeserializeError {
     UnknownDescriptor,
     DependenciesDescriptorMismatch,
     LiteralError(String),
+    /// Encoding of versioned format, like descriptors, has unsupported version.
+    UnsupportedVersion(u64),
+}
+
+impl From<ConstructorError> for DeserializeError {
+    fn from(error: ConstructorError) -> Self {
+        DeserializeError::ConstructorError(error)
+    }
 }
 
 pub type Box<T> = std::boxed::Box<T>;
 
-pub trait DbufPrimitive: Sized {
+/// Integer operators of dbuf schemas, which are not operators of Rust.
+///
+/// They never overflow: division wraps (`MIN / -1` is `MIN`), and shifts by amount
+/// out of `0..BITS` shift out all bits, keeping only the sign for `>>`.
+pub trait DbufInteger: Sized {
+    /// Division rounding towards zero.
+    ///
+    /// # Errors
+    ///  `ConstructorError::DivisionByZero` if `rhs` is zero.
+    fn dbuf_div(self, rhs: Self) -> Result<Self, ConstructorError>;
+
+    /// Remainder of division, which has sign of `self`.
+    ///
+    /// # Errors
+    ///  `ConstructorError::DivisionByZero` if `rhs` is zero.
+    fn dbuf_rem(self, rhs: Self) -> Result<Self, ConstructorError>;
+
+    #[must_use]
+    fn dbuf_shl(self, rhs: Self) -> Self;
+
+    /// Arithmetic shift for signed integers.
+    #[must_use]
+    fn dbuf_shr(self, rhs: Self) -> Self;
+}
+
+macro_rules! dbuf_integer {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl DbufInteger for $ty {
+            fn dbuf_div(self, rhs: Self) -> Result<Self, ConstructorError> {
+                if rhs == 0 {
+                    Err(ConstructorError::DivisionByZero)
+                } else {
+                    Ok(self.wrapping_div(rhs))
+                }
+            }
+            fn dbuf_rem(self, rhs: Self) -> Result<Self, ConstructorError> {
+                if rhs == 0 {
+                    Err(ConstructorError::DivisionByZero)
+                } else {
+                    Ok(self.wrapping_rem(rhs))
+                }
+            }
+            fn dbuf_shl(self, rhs: Self) -> Self {
+                u32::try_from(rhs)
+                    .ok()
+                    .and_then(|shift| self.checked_shl(shift))
+                    .unwrap_or(0)
+            }
+            fn dbuf_shr(self, rhs: Self) -> Self {
+                u32::try_from(rhs)
+                    .ok()
+                    .and_then(|shift| self.checked_shr(shift))
+                    // All bits are shifted out, only sign of signed integers remains.
+                    .unwrap_or((self >> (<$ty>::BITS - 1)) >> 1)
+            }
+        }
+    )+};
+}
+
+dbuf_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Arithmetic operators of dbuf schemas, which fail on overflow of integers.
+pub trait DbufArithmetic: Sized {
+    /// # Errors
+    ///  `ConstructorError::Overflow` if result is out of range.
+    fn dbuf_add(self, rhs: Self) -> Result<Self, ConstructorError>;
+
+    /// # Errors
+    ///  `ConstructorError::Overflow` if result is out of range.
+    fn dbuf_sub(self, rhs: Self) -> Result<Self, ConstructorError>;
+
+    /// # Errors
+    ///  `ConstructorError::Overflow` if result is out of range.
+    fn dbuf_mul(self, rhs: Self) -> Result<Self, ConstructorError>;
+
+    /// # Errors
+    ///  `ConstructorError::Overflow` if result is out of range.
+    fn dbuf_neg(self) -> Result<Self, ConstructorError>;
+}
+
+macro_rules! dbuf_arithmetic {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl DbufArithmetic for $ty {
+            fn dbuf_add(self, rhs: Self) -> Result<Self, ConstructorError> {
+                self.checked_add(rhs).ok_or(ConstructorError::Overflow)
+            }
+            fn dbuf_sub(self, rhs: Self) -> Result<Self, ConstructorError> {
+                self.checked_sub(rhs).ok_or(ConstructorError::Overflow)
+            }
+            fn dbuf_mul(self, rhs: Self) -> Result<Self, ConstructorError> {
+                self.checked_mul(rhs).ok_or(ConstructorError::Overflow)
+            }
+            fn dbuf_neg(self) -> Result<Self, ConstructorError> {
+                self.checked_neg().ok_or(ConstructorError::Overflow)
+            }
+        }
+    )+};
+}
+
+dbuf_arithmetic!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Whether refinement `predicate` holds. Predicates, which operators fail on, don't hold.
+pub fn refinement_holds(predicate: impl FnOnce() -> Result<bool, ConstructorError>) -> bool {
+    predicate().unwrap_or(false)
+}
+
+/// Value of builtin type, which is encoded in encoding `E` of integers and lengths.
+pub trait DbufPrimitive<E = Fixed>: Sized {
     /// Serialize method for primitive types
     ///
     /// # Errors
@@ -33,7 +172,7 @@ pub trait DbufPrimitive: Sized {
     fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError>;
 }
 
-impl DbufPrimitive for bool {
+impl<E: Encoding> DbufPrimitive<E> for bool {
     fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
         writer.write_all(&[u8::from(*self)])
     }
@@ -46,45 +185,208 @@ impl DbufPrimitive for bool {
     }
 }
 
-impl DbufPrimitive for i64 {
+/// Single byte of `Bytes` payload, so `Vec<u8>` is encoded as length-prefixed raw bytes.
+impl<E: Encoding> DbufPrimitive<E> for u8 {
     fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
-        writer.write_all(&self.to_le_bytes())
+        writer.write_all(&[*self])
     }
     fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
-        let mut buf = [0u8; 8];
+        let mut buf = [0u8; 1];
         reader
             .read_exact(&mut buf)
             .map_err(DeserializeError::IoError)?;
-        Ok(i64::from_le_bytes(buf))
+        Ok(buf[0])
     }
 }
 
-impl DbufPrimitive for u64 {
+impl<E: Encoding> DbufPrimitive<E> for i8 {
     fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
         writer.write_all(&self.to_le_bytes())
     }
     fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
-        let mut buf = [0u8; 8];
+        let mut buf = [0u8; 1];
         reader
             .read_exact(&mut buf)
             .map_err(DeserializeError::IoError)?;
-        Ok(u64::from_le_bytes(buf))
+        Ok(i8::from_le_bytes(buf))
+    }
+}
+
+/// Integers are encoded as little-endian bytes of their width in `Fixed` encoding.
+macro_rules! little_endian_primitive {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl DbufPrimitive<Fixed> for $ty {
+            fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+            fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+                let mut buf = [0u8; size_of::<$ty>()];
+                reader
+                    .read_exact(&mut buf)
+                    .map_err(DeserializeError::IoError)?;
+                Ok(<$ty>::from_le_bytes(buf))
+            }
+        }
+    )+};
+}
+
+little_endian_primitive!(i16, i32, i64, u16, u32, u64);
+
+/// Value of dbuf type, which is decoded given values of dependencies of the type.
+///
+/// Implemented by generated types, and by primitives, which have no dependencies.
+/// So generic code can encode and decode values of any type, e.g.
+/// `fn receive<M: DbufMessage>(dependencies: M::Dependencies, socket: &mut TcpStream)`.
+pub trait DbufMessage: Sized {
+    /// Values of dependencies of the type, which are not encoded with the value.
+    type Dependencies;
+
+    /// Writes encoding of value.
+    ///
+    /// # Errors
+    ///  Returns an I/O error if writing into `writer` fails.
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads value of type with `dependencies`.
+    ///
+    /// Trailing fields with defaults may be absent if `reader` ends before them,
+    /// see [`deserialize_or_default`].
+    ///
+    /// # Errors
+    ///  * `DeserializeError::IoError` when reading from `reader` fails.
+    ///  * `DeserializeError::ConstructorError` when decoded value violates
+    ///    checks of its constructor, e.g. refinements.
+    ///  * Other errors when encoding is malformed.
+    fn deserialize<R: Read>(
+        dependencies: Self::Dependencies,
+        reader: &mut R,
+    ) -> Result<Self, DeserializeError>;
+}
+
+impl<T: DbufPrimitive> DbufMessage for T {
+    type Dependencies = ();
+
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.dbuf_serialize(writer)
+    }
+
+    fn deserialize<R: Read>((): (), reader: &mut R) -> Result<Self, DeserializeError> {
+        T::dbuf_deserialize(reader)
     }
 }
 
-impl DbufPrimitive for String {
+/// IEEE-754 double of dbuf `Double` type.
+///
+/// Doubles are compared bitwise, like in type dependencies of dbuf schemas,
+/// so every value (including `NaN`) is equal to itself and `0.0` differs from `-0.0`.
+/// Doubles are ordered by IEEE-754 total order, which is consistent with that equality.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "descriptor", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "descriptor", serde(transparent))]
+pub struct Double(pub f64);
+
+impl PartialEq for Double {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Double {}
+
+impl PartialOrd for Double {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Double {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for Double {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<f64> for Double {
+    fn from(value: f64) -> Self {
+        Double(value)
+    }
+}
+
+impl From<Double> for f64 {
+    fn from(value: Double) -> Self {
+        value.0
+    }
+}
+
+impl Add<&Double> for Double {
+    type Output = Double;
+    fn add(self, rhs: &Double) -> Double {
+        Double(self.0 + rhs.0)
+    }
+}
+
+impl Sub<&Double> for Double {
+    type Output = Double;
+    fn sub(self, rhs: &Double) -> Double {
+        Double(self.0 - rhs.0)
+    }
+}
+
+impl Mul<&Double> for Double {
+    type Output = Double;
+    fn mul(self, rhs: &Double) -> Double {
+        Double(self.0 * rhs.0)
+    }
+}
+
+impl Neg for Double {
+    type Output = Double;
+    fn neg(self) -> Double {
+        Double(-self.0)
+    }
+}
+
+/// Operators of doubles never fail, they result in infinities or `NaN` instead.
+impl DbufArithmetic for Double {
+    fn dbuf_add(self, rhs: Self) -> Result<Self, ConstructorError> {
+        Ok(self + &rhs)
+    }
+    fn dbuf_sub(self, rhs: Self) -> Result<Self, ConstructorError> {
+        Ok(self - &rhs)
+    }
+    fn dbuf_mul(self, rhs: Self) -> Result<Self, ConstructorError> {
+        Ok(self * &rhs)
+    }
+    fn dbuf_neg(self) -> Result<Self, ConstructorError> {
+        Ok(-self)
+    }
+}
+
+impl<E: Encoding> DbufPrimitive<E> for Double {
     fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
-        let len = self.len() as u64;
-        writer.write_all(&len.to_le_bytes())?;
-        writer.write_all(self.as_bytes())
+        writer.write_all(&self.0.to_le_bytes())
     }
     fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
-        let mut len_buf = [0u8; 8];
+        let mut buf = [0u8; 8];
         reader
-            .read_exact(&mut len_buf)
+            .read_exact(&mut buf)
             .map_err(DeserializeError::IoError)?;
-        let len = usize::try_from(u64::from_le_bytes(len_buf))
-            .map_err(|_| LiteralError("Line length too long".to_string()))?;
+        Ok(Double(f64::from_le_bytes(buf)))
+    }
+}
+
+impl<E: Encoding> DbufPrimitive<E> for String {
+    fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        E::serialize_length(self.len(), writer)?;
+        writer.write_all(self.as_bytes())
+    }
+    fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+        let len = E::deserialize_length(reader)?;
         let mut bytes = vec![0u8; len];
         reader
             .read_exact(&mut bytes)
@@ -93,3 +395,82 @@ impl DbufPrimitive for String {
             .map_err(|_| LiteralError("Invalid UTF-8 sequence in string".to_string()))
     }
 }
+
+impl<E: Encoding, T: DbufPrimitive<E>> DbufPrimitive<E> for Vec<T> {
+    fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        E::serialize_length(self.len(), writer)?;
+        self.iter()
+            .try_for_each(|element| element.dbuf_serialize(writer))
+    }
+    fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+        let len = E::deserialize_length(reader)?;
+        (0..len).map(|_| T::dbuf_deserialize(reader)).collect()
+    }
+}
+
+/// Boxed values, e.g. of recursive types, are encoded like unboxed ones.
+impl<E: Encoding, T: DbufPrimitive<E>> DbufPrimitive<E> for Box<T> {
+    fn dbuf_serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        T::dbuf_serialize(self, writer)
+    }
+    fn dbuf_deserialize<R: Read>(reader: &mut R) -> Result<Self, DeserializeError> {
+        T::dbuf_deserialize(reader).map(Box::new)
+    }
+}
+
+/// Writes length prefix of a sequence in `Fixed` encoding.
+///
+/// # Errors
+///  Returns an I/O error if the `write_all` method throws an error.
+pub fn serialize_length<W: Write>(len: usize, writer: &mut W) -> io::Result<()> {
+    writer.write_all(&(len as u64).to_le_bytes())
+}
+
+/// Reads length prefix of a sequence in `Fixed` encoding.
+///
+/// # Errors
+///  * `DeserializeError::IoError` when `read_exact` method on the buffer throws an error.
+///  * `LiteralError` when length does not fit into `usize`.
+pub fn deserialize_length<R: Read>(reader: &mut R) -> Result<usize, DeserializeError> {
+    let mut len_buf = [0u8; 8];
+    reader
+        .read_exact(&mut len_buf)
+        .map_err(DeserializeError::IoError)?;
+    usize::try_from(u64::from_le_bytes(len_buf))
+        .map_err(|_| LiteralError("Sequence length too long".to_string()))
+}
+
+/// Reads field with default value, which is absent in encodings older than the field.
+///
+/// Fields are encoded one after another, so the field is absent if the input ends
+/// right before it. Only the end of input tells that, so only the top-level value,
+/// like the one of a frame read by `read_frame`, may lack fields: a nested value is
+/// followed by encodings of other fields, which are read in place of its absent ones.
+/// Elaboration keeps fields with defaults trailing, so they are the only ones absent.
+///
+/// Readers of nested values are erased to `&mut dyn Read`, so
+/// recursive types instantiate `deserialize` only once.
+///
+/// # Errors
+///  * `DeserializeError::IoError` when `read` method on the buffer throws an error.
+///  * Any error of `deserialize`.
+///  * `DeserializeError::ConstructorError` when operators of `default` fail.
+#[allow(
+    clippy::mut_mut,
+    reason = "generated code takes readers by `&mut R`, where `R` must be sized"
+)]
+pub fn deserialize_or_default<R: Read, T>(
+    reader: &mut R,
+    deserialize: impl FnOnce(&mut &mut dyn Read) -> Result<T, DeserializeError>,
+    default: impl FnOnce() -> Result<T, ConstructorError>,
+) -> Result<T, DeserializeError> {
+    let mut first = 0u8;
+    let read = reader
+        .read(std::slice::from_mut(&mut first))
+        .map_err(DeserializeError::IoError)?;
+    if read == 0 {
+        return default().map_err(DeserializeError::ConstructorError);
+    }
+    let mut rest = std::slice::from_ref(&first).chain(reader);
+    deserialize(&mut (&mut rest as &mut dyn Read))
+}
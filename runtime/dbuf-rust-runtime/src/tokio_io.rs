@@ -0,0 +1,337 @@
+use std::io;
+use std::pin::Pin;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::encoding::MAX_VARINT_LEN;
+use crate::{
+    Compact, DbufMessage, DbufPrimitive, DeserializeError, Double, Encoding, Fixed, LiteralError,
+};
+
+/// Primitive, which is encoded and decoded over tokio I/O.
+///
+/// Encodings are the same as of [`DbufPrimitive`] in encoding `E`.
+pub trait DbufPrimitiveAsync<E = Fixed>: DbufPrimitive<E> + Send + Sync + 'static {
+    /// Writes encoding of primitive.
+    ///
+    /// # Errors
+    ///  Returns an I/O error if writing into `writer` fails.
+    fn dbuf_serialize_async<W: AsyncWrite + Unpin + Send>(
+        &self,
+        writer: &mut W,
+    ) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// Reads primitive.
+    ///
+    /// # Errors
+    ///  * `DeserializeError::IoError` when reading from `reader` fails.
+    ///  * `LiteralError` when literal parsing fails.
+    fn dbuf_deserialize_async<R: AsyncRead + Unpin + Send>(
+        reader: &mut R,
+    ) -> impl Future<Output = Result<Self, DeserializeError>> + Send;
+}
+
+/// Fixed-width primitives are read whole and decoded like in blocking I/O.
+macro_rules! fixed_width_primitive_async {
+    ($generics:tt $encoding:ty => $($ty:ty: $width:expr),+ $(,)?) => {$(
+        fixed_width_primitive_async!(@impl $generics $encoding, $ty, $width);
+    )+};
+    (@impl [$($generics:tt)*] $encoding:ty, $ty:ty, $width:expr) => {
+        impl<$($generics)*> DbufPrimitiveAsync<$encoding> for $ty {
+            async fn dbuf_serialize_async<W: AsyncWrite + Unpin + Send>(
+                &self,
+                writer: &mut W,
+            ) -> io::Result<()> {
+                let mut buf = [0u8; $width];
+                <$ty as DbufPrimitive<$encoding>>::dbuf_serialize(self, &mut buf.as_mut_slice())?;
+                writer.write_all(&buf).await
+            }
+
+            async fn dbuf_deserialize_async<R: AsyncRead + Unpin + Send>(
+                reader: &mut R,
+            ) -> Result<Self, DeserializeError> {
+                let mut buf = [0u8; $width];
+                reader
+                    .read_exact(&mut buf)
+                    .await
+                    .map_err(DeserializeError::IoError)?;
+                <$ty as DbufPrimitive<$encoding>>::dbuf_deserialize(&mut buf.as_slice())
+            }
+        }
+    };
+}
+
+fixed_width_primitive_async!([E: Encoding] E => bool: 1, u8: 1, i8: 1, Double: 8);
+
+fixed_width_primitive_async!(
+    [] Fixed =>
+    i16: 2,
+    i32: 4,
+    i64: 8,
+    u16: 2,
+    u32: 4,
+    u64: 8,
+);
+
+/// Varints are read byte by byte up to the last one and decoded like in blocking I/O.
+macro_rules! varint_primitive_async {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl DbufPrimitiveAsync<Compact> for $ty {
+            async fn dbuf_serialize_async<W: AsyncWrite + Unpin + Send>(
+                &self,
+                writer: &mut W,
+            ) -> io::Result<()> {
+                let mut buf = [0u8; MAX_VARINT_LEN];
+                let mut rest = buf.as_mut_slice();
+                <$ty as DbufPrimitive<Compact>>::dbuf_serialize(self, &mut rest)?;
+                let len = MAX_VARINT_LEN - rest.len();
+                writer.write_all(&buf[..len]).await
+            }
+
+            async fn dbuf_deserialize_async<R: AsyncRead + Unpin + Send>(
+                reader: &mut R,
+            ) -> Result<Self, DeserializeError> {
+                let mut buf = [0u8; MAX_VARINT_LEN];
+                let mut len = 0;
+                while len < MAX_VARINT_LEN {
+                    buf[len] = reader.read_u8().await.map_err(DeserializeError::IoError)?;
+                    len += 1;
+                    if buf[len - 1] & 0x80 == 0 {
+                        break;
+                    }
+                }
+                <$ty as DbufPrimitive<Compact>>::dbuf_deserialize(&mut &buf[..len])
+            }
+        }
+    )+};
+}
+
+varint_primitive_async!(i16, i32, i64, u16, u32, u64);
+
+impl<E: Encoding> DbufPrimitiveAsync<E> for String
+where
+    u64: DbufPrimitiveAsync<E>,
+{
+    async fn dbuf_serialize_async<W: AsyncWrite + Unpin + Send>(
+        &self,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        serialize_length_in::<E, W>(self.len(), writer).await?;
+        writer.write_all(self.as_bytes()).await
+    }
+
+    async fn dbuf_deserialize_async<R: AsyncRead + Unpin + Send>(
+        reader: &mut R,
+    ) -> Result<Self, DeserializeError> {
+        let len = deserialize_length_in::<E, R>(reader).await?;
+        let bytes = read_exact_async(reader, len).await?;
+        String::from_utf8(bytes)
+            .map_err(|_| LiteralError("Invalid UTF-8 sequence in string".to_string()))
+    }
+}
+
+impl<E: Encoding, T: DbufPrimitiveAsync<E>> DbufPrimitiveAsync<E> for Vec<T>
+where
+    u64: DbufPrimitiveAsync<E>,
+{
+    async fn dbuf_serialize_async<W: AsyncWrite + Unpin + Send>(
+        &self,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        serialize_length_in::<E, W>(self.len(), writer).await?;
+        for element in self {
+            element.dbuf_serialize_async(writer).await?;
+        }
+        Ok(())
+    }
+
+    async fn dbuf_deserialize_async<R: AsyncRead + Unpin + Send>(
+        reader: &mut R,
+    ) -> Result<Self, DeserializeError> {
+        let len = deserialize_length_in::<E, R>(reader).await?;
+        let mut elements = Vec::new();
+        for _ in 0..len {
+            elements.push(T::dbuf_deserialize_async(reader).await?);
+        }
+        Ok(elements)
+    }
+}
+
+/// Future of encoding or decoding value, which is boxed, so recursive types can
+/// encode their values over encodings of values of the same type.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Value of dbuf type, which is encoded and decoded over tokio I/O.
+///
+/// Implemented by types generated with async I/O, and by primitives.
+/// Encodings are the same as of [`DbufMessage`].
+pub trait DbufMessageAsync: DbufMessage + Send + Sync + 'static {
+    /// Writes encoding of value.
+    ///
+    /// # Errors
+    ///  Returns an I/O error if writing into `writer` fails.
+    fn serialize_async<'a, W: AsyncWrite + Unpin + Send>(
+        &'a self,
+        writer: &'a mut W,
+    ) -> BoxFuture<'a, io::Result<()>>;
+
+    /// Reads value of type with `dependencies`.
+    ///
+    /// # Errors
+    ///  Same as of [`DbufMessage::deserialize`].
+    fn deserialize_async<R: AsyncRead + Unpin + Send>(
+        dependencies: Self::Dependencies,
+        reader: &mut R,
+    ) -> BoxFuture<'_, Result<Self, DeserializeError>>;
+}
+
+impl<T: DbufPrimitiveAsync> DbufMessageAsync for T {
+    fn serialize_async<'a, W: AsyncWrite + Unpin + Send>(
+        &'a self,
+        writer: &'a mut W,
+    ) -> BoxFuture<'a, io::Result<()>> {
+        Box::pin(self.dbuf_serialize_async(writer))
+    }
+
+    fn deserialize_async<R: AsyncRead + Unpin + Send>(
+        (): (),
+        reader: &mut R,
+    ) -> BoxFuture<'_, Result<Self, DeserializeError>> {
+        Box::pin(T::dbuf_deserialize_async(reader))
+    }
+}
+
+/// Writes length prefix of a sequence in `Fixed` encoding.
+///
+/// # Errors
+///  Returns an I/O error if writing into `writer` fails.
+pub async fn serialize_length_async<W: AsyncWrite + Unpin + Send>(
+    len: usize,
+    writer: &mut W,
+) -> io::Result<()> {
+    serialize_length_in::<Fixed, W>(len, writer).await
+}
+
+/// Reads length prefix of a sequence in `Fixed` encoding.
+///
+/// # Errors
+///  * `DeserializeError::IoError` when reading from `reader` fails.
+///  * `LiteralError` when length does not fit into `usize`.
+pub async fn deserialize_length_async<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+) -> Result<usize, DeserializeError> {
+    deserialize_length_in::<Fixed, R>(reader).await
+}
+
+/// Lengths are encoded as `UInt` in every encoding.
+async fn serialize_length_in<E, W: AsyncWrite + Unpin + Send>(
+    len: usize,
+    writer: &mut W,
+) -> io::Result<()>
+where
+    u64: DbufPrimitiveAsync<E>,
+{
+    <u64 as DbufPrimitiveAsync<E>>::dbuf_serialize_async(&(len as u64), writer).await
+}
+
+async fn deserialize_length_in<E, R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+) -> Result<usize, DeserializeError>
+where
+    u64: DbufPrimitiveAsync<E>,
+{
+    usize::try_from(<u64 as DbufPrimitiveAsync<E>>::dbuf_deserialize_async(reader).await?)
+        .map_err(|_| LiteralError("Sequence length too long".to_string()))
+}
+
+/// Reader of field with default value, which is absent in encodings older than the field,
+/// or `None` if the input ends right before the field.
+///
+/// Same as [`crate::deserialize_or_default`], readers of nested values are erased,
+/// so recursive types instantiate `deserialize_async` only once.
+///
+/// # Errors
+///  `DeserializeError::IoError` when reading from `reader` fails.
+pub async fn defaulted_field_reader_async<'r, R: AsyncRead + Unpin + Send>(
+    reader: &'r mut R,
+) -> Result<Option<Box<dyn AsyncRead + Unpin + Send + 'r>>, DeserializeError> {
+    let mut first = 0u8;
+    let read = reader
+        .read(std::slice::from_mut(&mut first))
+        .await
+        .map_err(DeserializeError::IoError)?;
+    if read == 0 {
+        return Ok(None);
+    }
+    Ok(Some(Box::new(io::Cursor::new([first]).chain(reader))))
+}
+
+/// Reads exactly `len` bytes, allocating only as much as is read,
+/// so lengths of malformed input can't exhaust memory.
+async fn read_exact_async<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+    len: usize,
+) -> Result<Vec<u8>, DeserializeError> {
+    let mut bytes = Vec::new();
+    (&mut *reader)
+        .take(len as u64)
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(DeserializeError::IoError)?;
+    if bytes.len() < len {
+        return Err(DeserializeError::IoError(
+            io::ErrorKind::UnexpectedEof.into(),
+        ));
+    }
+    Ok(bytes)
+}
+
+/// Writes `message` as a frame of stream of messages: length of its encoding followed
+/// by the encoding, so frames can be skipped or read into a buffer before decoding.
+///
+/// Length is encoded in encoding `E`, which is the one of generated code of `M`.
+///
+/// # Errors
+///  Returns an I/O error if writing into `writer` fails.
+pub async fn write_frame<E, M: DbufMessage, W: AsyncWrite + Unpin + Send>(
+    message: &M,
+    writer: &mut W,
+) -> io::Result<()>
+where
+    u64: DbufPrimitiveAsync<E>,
+{
+    let mut encoding = Vec::new();
+    message.serialize(&mut encoding)?;
+    serialize_length_in::<E, W>(encoding.len(), writer).await?;
+    writer.write_all(&encoding).await
+}
+
+/// Reads frame written by [`write_frame`] in encoding `E`, or `None` if the stream has ended.
+///
+/// Bytes of the frame after the encoding of message are ignored,
+/// so newer writers can append fields with default values.
+///
+/// # Errors
+///  * `DeserializeError::IoError` when reading from `reader` fails or the stream
+///    ends inside of a frame.
+///  * Any error of [`DbufMessage::deserialize`].
+pub async fn read_frame<E, M: DbufMessage, R: AsyncRead + Unpin + Send>(
+    dependencies: M::Dependencies,
+    reader: &mut R,
+) -> Result<Option<M>, DeserializeError>
+where
+    u64: DbufPrimitiveAsync<E>,
+{
+    let mut first = 0u8;
+    let read = reader
+        .read(std::slice::from_mut(&mut first))
+        .await
+        .map_err(DeserializeError::IoError)?;
+    if read == 0 {
+        return Ok(None);
+    }
+    let mut length = std::slice::from_ref(&first).chain(&mut *reader);
+    let len = deserialize_length_in::<E, _>(&mut length).await?;
+    let encoding = read_exact_async(reader, len).await?;
+    M::deserialize(dependencies, &mut encoding.as_slice()).map(Some)
+}
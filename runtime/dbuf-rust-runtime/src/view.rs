@@ -0,0 +1,264 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::marker::PhantomData;
+
+use crate::{DbufPrimitive, DeserializeError, Double, Encoding, Fixed, LiteralError};
+
+/// Reader of encoding in memory, which lends strings and bytes of the input to views.
+#[derive(Clone, Copy, Debug)]
+pub struct SliceReader<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> SliceReader<'a> {
+    #[must_use]
+    pub fn new(input: &'a [u8]) -> Self {
+        SliceReader { rest: input }
+    }
+
+    /// Input that is not read yet.
+    #[must_use]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Reads next `len` bytes of input.
+    ///
+    /// # Errors
+    ///  `DeserializeError::IoError` if input is shorter than `len`.
+    pub fn read_slice(&mut self, len: usize) -> Result<&'a [u8], DeserializeError> {
+        if len > self.rest.len() {
+            return Err(DeserializeError::IoError(
+                io::ErrorKind::UnexpectedEof.into(),
+            ));
+        }
+        let (slice, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Ok(slice)
+    }
+
+    /// Runs `read` and returns its result together with input it has read.
+    pub fn read_encoding<T>(&mut self, read: impl FnOnce(&mut Self) -> T) -> (T, &'a [u8]) {
+        let start = self.rest;
+        let result = read(self);
+        let encoding = &start[..start.len() - self.rest.len()];
+        (result, encoding)
+    }
+}
+
+impl Read for SliceReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.rest.read(buf)
+    }
+}
+
+/// Read-only view of encoded value of dbuf type, which borrows input instead of decoding it.
+///
+/// Views are checked on reading like decoded values, so their accessors don't fail.
+/// Scalars are viewed as themselves, `String` as `&str`, `Bytes` as `&[u8]`,
+/// sequences as [`ListRef`] and generated types as their `Ref` types.
+/// Views read encodings in encoding `E` of integers and lengths.
+pub trait DbufView<'a, E = Fixed>: Copy {
+    /// Values of dependencies of viewed type.
+    type Dependencies;
+
+    /// Reads view of value of viewed type with `dependencies`.
+    ///
+    /// # Errors
+    ///  Same as of decoding the value with `DbufMessage::deserialize`.
+    fn read_view(
+        dependencies: Self::Dependencies,
+        reader: &mut SliceReader<'a>,
+    ) -> Result<Self, DeserializeError>;
+
+    /// Reads view of value, which encoding was already checked by `read_view`.
+    ///
+    /// # Panics
+    ///  If encoding is malformed.
+    fn read_checked(reader: &mut SliceReader<'a>) -> Self;
+
+    /// Reads view of value encoded at the start of `input`.
+    ///
+    /// # Errors
+    ///  Same as of decoding the value with `DbufMessage::deserialize`.
+    fn view(dependencies: Self::Dependencies, input: &'a [u8]) -> Result<Self, DeserializeError> {
+        Self::read_view(dependencies, &mut SliceReader::new(input))
+    }
+}
+
+const CHECKED: &str = "view is checked when read";
+
+macro_rules! scalar_view {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl<E: Encoding> DbufView<'_, E> for $ty
+        where
+            $ty: DbufPrimitive<E>,
+        {
+            type Dependencies = ();
+
+            fn read_view((): (), reader: &mut SliceReader<'_>) -> Result<Self, DeserializeError> {
+                <$ty as DbufPrimitive<E>>::dbuf_deserialize(reader)
+            }
+
+            fn read_checked(reader: &mut SliceReader<'_>) -> Self {
+                <$ty as DbufPrimitive<E>>::dbuf_deserialize(reader).expect(CHECKED)
+            }
+        }
+    )+};
+}
+
+scalar_view!(bool, i8, i16, i32, i64, u8, u16, u32, u64, Double);
+
+impl<'a, E: Encoding> DbufView<'a, E> for &'a str {
+    type Dependencies = ();
+
+    fn read_view((): (), reader: &mut SliceReader<'a>) -> Result<Self, DeserializeError> {
+        let bytes = <&[u8] as DbufView<E>>::read_view((), reader)?;
+        str::from_utf8(bytes)
+            .map_err(|_| LiteralError("Invalid UTF-8 sequence in string".to_string()))
+    }
+
+    fn read_checked(reader: &mut SliceReader<'a>) -> Self {
+        <Self as DbufView<E>>::read_view((), reader).expect(CHECKED)
+    }
+}
+
+impl<'a, E: Encoding> DbufView<'a, E> for &'a [u8] {
+    type Dependencies = ();
+
+    fn read_view((): (), reader: &mut SliceReader<'a>) -> Result<Self, DeserializeError> {
+        let len = E::deserialize_length(reader)?;
+        reader.read_slice(len)
+    }
+
+    fn read_checked(reader: &mut SliceReader<'a>) -> Self {
+        <Self as DbufView<E>>::read_view((), reader).expect(CHECKED)
+    }
+}
+
+/// View of sequence in encoding `E`, whose elements are read when iterated.
+pub struct ListRef<'a, T, E = Fixed> {
+    len: usize,
+    elements: &'a [u8],
+    element: PhantomData<(T, E)>,
+}
+
+impl<T, E> Clone for ListRef<'_, T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for ListRef<'_, T, E> {}
+
+impl<'a, T: DbufView<'a, E>, E> ListRef<'a, T, E> {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> ListIter<'a, T, E> {
+        ListIter {
+            remaining: self.len,
+            reader: SliceReader::new(self.elements),
+            element: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: DbufView<'a, E> + fmt::Debug, E> fmt::Debug for ListRef<'a, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: DbufView<'a, E>, E> IntoIterator for ListRef<'a, T, E> {
+    type Item = T;
+    type IntoIter = ListIter<'a, T, E>;
+
+    fn into_iter(self) -> ListIter<'a, T, E> {
+        self.iter()
+    }
+}
+
+impl<'a, T: DbufView<'a, E>, E> IntoIterator for &ListRef<'a, T, E> {
+    type Item = T;
+    type IntoIter = ListIter<'a, T, E>;
+
+    fn into_iter(self) -> ListIter<'a, T, E> {
+        self.iter()
+    }
+}
+
+impl<'a, T: DbufView<'a, E>, E: Encoding> DbufView<'a, E> for ListRef<'a, T, E>
+where
+    T::Dependencies: Clone,
+{
+    type Dependencies = T::Dependencies;
+
+    fn read_view(
+        dependencies: T::Dependencies,
+        reader: &mut SliceReader<'a>,
+    ) -> Result<Self, DeserializeError> {
+        let len = E::deserialize_length(reader)?;
+        let (checked, elements) = reader.read_encoding(|reader| {
+            (0..len).try_for_each(|_| T::read_view(dependencies.clone(), reader).map(drop))
+        });
+        checked?;
+        Ok(ListRef {
+            len,
+            elements,
+            element: PhantomData,
+        })
+    }
+
+    fn read_checked(reader: &mut SliceReader<'a>) -> Self {
+        let len = E::deserialize_length(reader).expect(CHECKED);
+        let ((), elements) = reader.read_encoding(|reader| {
+            for _ in 0..len {
+                T::read_checked(reader);
+            }
+        });
+        ListRef {
+            len,
+            elements,
+            element: PhantomData,
+        }
+    }
+}
+
+/// Iterator over elements of [`ListRef`].
+pub struct ListIter<'a, T, E = Fixed> {
+    remaining: usize,
+    reader: SliceReader<'a>,
+    element: PhantomData<(T, E)>,
+}
+
+impl<'a, T: DbufView<'a, E>, E> Iterator for ListIter<'a, T, E> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(T::read_checked(&mut self.reader))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: DbufView<'a, E>, E> ExactSizeIterator for ListIter<'a, T, E> {}